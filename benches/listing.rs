@@ -0,0 +1,192 @@
+//! Baseline timings for the listing hot paths.
+//!
+//! Run with `cargo bench`; add `-- <filter>` (e.g. `-- list/100k`) to pick a
+//! group. Every iteration bypasses the caches so the filesystem is actually
+//! read, as on a fresh `ce` invocation.
+
+use cerium::cli::args::Args;
+use cerium::display::layout::column::Column;
+use cerium::display::layout::width::Width;
+use cerium::fs::cache::CacheSettings;
+use cerium::fs::dir::DirReader;
+use cerium::fs::tree::TreeBuilder;
+use clap::Parser;
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use std::fs::{self, File};
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Entry counts each benchmark runs at.
+const SIZES: &[(&str, usize)] = &[("1k", 1_000), ("100k", 100_000)];
+
+/// Entry counts for comparing directory reading strategies, up to the huge
+/// directories where batching matters.
+#[cfg(all(feature = "getdents", any(target_os = "linux", target_os = "android")))]
+const READDIR_SIZES: &[(&str, usize)] = &[("100k", 100_000), ("1m", 1_000_000)];
+
+/// Files per directory in the nested trees.
+const FANOUT: usize = 100;
+
+/// Creates `count` files directly under `base`, with a few extensions mixed in.
+fn flat_tree(count: usize) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    for index in 0..count {
+        let extension = ["rs", "txt", "md", "tar.gz"][index % 4];
+        File::create(temp_dir.path().join(format!("file_{index:06}.{extension}"))).unwrap();
+    }
+    temp_dir
+}
+
+/// Creates `count` files spread over nested directories of [`FANOUT`] files each.
+fn nested_tree(count: usize) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    for index in 0..count {
+        let bucket = index / FANOUT;
+        let directory = temp_dir
+            .path()
+            .join(format!("d{:03}", bucket / FANOUT))
+            .join(format!("d{:03}", bucket % FANOUT));
+        if index % FANOUT == 0 {
+            fs::create_dir_all(&directory).unwrap();
+        }
+        File::create(directory.join(format!("file_{index:06}.rs"))).unwrap();
+    }
+    temp_dir
+}
+
+/// Lists a directory and every directory below it, as `-R` reads them.
+fn list_recursive(reader: &DirReader, args: &Args) -> usize {
+    let entries = reader.list(args);
+    entries.len()
+        + entries
+            .iter()
+            .filter(|entry| entry.is_dir())
+            .map(|entry| list_recursive(&DirReader::from(entry.path().to_path_buf()), args))
+            .sum::<usize>()
+}
+
+fn args(path: &Path, flags: &[&str]) -> Args {
+    let mut argv = vec!["ce"];
+    argv.extend_from_slice(flags);
+    argv.push(path.to_str().unwrap());
+    Args::parse_from(argv)
+}
+
+fn bench_list(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("list");
+    group.sample_size(10);
+
+    for (label, count) in SIZES {
+        let tree = flat_tree(*count);
+        let reader = DirReader::from(tree.path().to_path_buf());
+
+        let names_only = args(tree.path(), &[]);
+        group.bench_function(BenchmarkId::new("names", label), |bencher| {
+            bencher.iter(|| black_box(reader.list(&names_only)))
+        });
+
+        let long = args(tree.path(), &["-l"]);
+        group.bench_function(BenchmarkId::new("metadata", label), |bencher| {
+            bencher.iter(|| black_box(reader.list(&long)))
+        });
+
+        // Many small directories, where per-directory setup such as buffers adds up
+        let nested = nested_tree(*count);
+        let nested_reader = DirReader::from(nested.path().to_path_buf());
+        let recursive = args(nested.path(), &["-R"]);
+        group.bench_function(BenchmarkId::new("recursive", label), |bencher| {
+            bencher.iter(|| black_box(list_recursive(&nested_reader, &recursive)))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_width(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("width");
+    group.sample_size(10);
+    let columns = [
+        Column::Permissions,
+        Column::Size,
+        Column::Modified,
+        Column::Name,
+    ];
+
+    for (label, count) in SIZES {
+        let tree = flat_tree(*count);
+        let long = args(tree.path(), &["-l", "-H"]);
+        let entries = DirReader::from(tree.path().to_path_buf()).list(&long);
+
+        group.bench_function(BenchmarkId::new("calculate", label), |bencher| {
+            bencher.iter(|| black_box(Width::new().calculate(&entries, &columns, &long)))
+        });
+    }
+
+    group.bench_function("measure_ansi_text", |bencher| {
+        let text = "\x1b[1;38;2;250;189;47mnaïve 日本語 🦀.rs\x1b[0m ".repeat(16);
+        bencher.iter(|| black_box(Width::measure_ansi_text(&text)))
+    });
+
+    group.finish();
+}
+
+fn bench_tree(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("tree");
+    group.sample_size(10);
+
+    for (label, count) in SIZES {
+        let tree = nested_tree(*count);
+        let builder = TreeBuilder::new(tree.path().to_path_buf());
+
+        let plain = args(tree.path(), &["-t"]);
+        group.bench_function(BenchmarkId::new("build", label), |bencher| {
+            bencher.iter(|| black_box(builder.build(&plain)))
+        });
+    }
+
+    group.finish();
+}
+
+/// Compares std's `readdir` (32 KiB batches) with raw `getdents64` and its 1 MiB buffer.
+#[cfg(all(feature = "getdents", any(target_os = "linux", target_os = "android")))]
+fn bench_readdir(criterion: &mut Criterion) {
+    use cerium::fs::feature::getdents;
+
+    let mut group = criterion.benchmark_group("readdir");
+    group.sample_size(10);
+
+    for (label, count) in READDIR_SIZES {
+        let tree = flat_tree(*count);
+
+        group.bench_function(BenchmarkId::new("std", label), |bencher| {
+            bencher.iter(|| {
+                let items: Vec<_> = fs::read_dir(tree.path())
+                    .unwrap()
+                    .filter_map(Result::ok)
+                    .map(|entry| (entry.path(), entry.file_type().ok()))
+                    .collect();
+                black_box(items)
+            })
+        });
+
+        group.bench_function(BenchmarkId::new("getdents", label), |bencher| {
+            bencher.iter(|| black_box(getdents::read_dir(tree.path()).unwrap()))
+        });
+    }
+
+    group.finish();
+}
+
+#[cfg(not(all(feature = "getdents", any(target_os = "linux", target_os = "android"))))]
+fn bench_readdir(_criterion: &mut Criterion) {}
+
+fn setup(criterion: &mut Criterion) {
+    CacheSettings::setup(true);
+    bench_readdir(criterion);
+    bench_list(criterion);
+    bench_width(criterion);
+    bench_tree(criterion);
+}
+
+criterion_group!(benches, setup);
+criterion_main!(benches);
@@ -0,0 +1,12 @@
+#![no_main]
+
+use cerium::display::layout::width::Width;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let text = String::from_utf8_lossy(data);
+    let width = Width::measure_ansi_text(&text);
+
+    // Escape sequences only ever hide characters, and no character is wider than two columns
+    assert!(width <= 2 * text.chars().count());
+});
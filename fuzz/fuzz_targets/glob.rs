@@ -0,0 +1,16 @@
+#![no_main]
+
+use cerium::fs::glob::Glob;
+use libfuzzer_sys::fuzz_target;
+
+// Input is `pattern\0text`; without a separator the input is matched against itself.
+fuzz_target!(|data: &[u8]| {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let (pattern, text) = input.split_once('\0').unwrap_or((input, input));
+
+    if let Ok(glob) = Glob::new(pattern) {
+        glob.is_match(text);
+    }
+});
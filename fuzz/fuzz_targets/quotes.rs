@@ -0,0 +1,25 @@
+#![no_main]
+
+use cerium::cli::flags::QuoteStyle;
+use cerium::display::output::quotes::Quotes;
+use libfuzzer_sys::fuzz_target;
+
+// The first byte picks the quote style and alignment; the rest is the filename.
+fuzz_target!(|data: &[u8]| {
+    let Some((&selector, name)) = data.split_first() else {
+        return;
+    };
+    let Ok(name) = std::str::from_utf8(name) else {
+        return;
+    };
+
+    let style = match selector % 4 {
+        0 => QuoteStyle::Auto,
+        1 => QuoteStyle::Double,
+        2 => QuoteStyle::Single,
+        _ => QuoteStyle::Never,
+    };
+
+    let quoted = Quotes::new(name).apply(style, selector & 0x80 != 0);
+    assert!(quoted.len() >= name.len());
+});
@@ -15,39 +15,77 @@ DESCRIPTIONS = {
     "cli": "Command-line argument parsing and flag structures.",
     "cli/mod.rs": "CLI module exports.",
     "cli/args.rs": "CLI argument parser and configuration.",
+    "cli/clipboard.rs": "--copy-paths: copy the listed entries' paths through the terminal via OSC 52.",
+    "cli/exec.rs": "--select/--exec: run a confirmed command for each matching entry.",
     "cli/flags.rs": "Enums for CLI flags (e.g., `--colours=WHEN`, `--date=TYPE`, etc.).",
+    "cli/shell.rs": "`--print-dir-on-exit` contract for the shell cd wrappers.",
+    "cli/bookmarks.rs": "Bookmarks from cerium.toml and `@name` argument expansion.",
+    "cli/profiles.rs": "Named flag bundles from cerium.toml, selected with --profile or CE_PROFILE.",
+    "cli/settings.rs": "Per-thread output settings, so embedders can run listings with different options concurrently.",
     # Filesystem module
     "fs": "Filesystem management (entries, directories, metadata).",
     "fs/mod.rs": "Filesystem module exports.",
     "fs/cache.rs": "In-memory caching of entry data for performance.",
     "fs/context.rs": "Filesystem context for directory traversal and entry collection.",
+    "fs/dedupe.rs": "Hard link de-duplication for --dedupe.",
+    "fs/clock.rs": "Clock trait with system and fixed implementations for relative dates.",
+    "fs/collate.rs": "Locale-aware name collation for --sort-key locale (collate feature).",
+    "fs/descend.rs": "Traversal guard skipping system and pseudo-filesystem directories.",
     "fs/dir.rs": "Directory traversal and filesystem operations.",
     "fs/entry": "Entry types and metadata representation.",
     "fs/entry/mod.rs": "Entry module exports and shared entry logic.",
     "fs/entry/directory.rs": "Directory entry type and metadata.",
     "fs/entry/file.rs": "File entry type and metadata.",
     "fs/entry/symlink.rs": "Symlink entry type and metadata.",
-    "fs/feature": "Additional filesystem features (magic, checksum).",
+    "fs/feature": "Additional filesystem features (magic, checksum, apps, getdents).",
     "fs/feature/mod.rs": "Features module exports.",
+    "fs/feature/apps.rs": "Default applications from the XDG MIME database.",
     "fs/feature/checksum.rs": "Entry checksum calculation.",
+    "fs/feature/getdents.rs": "Directory reading via raw getdents64 with a large buffer.",
     "fs/feature/magic.rs": "File magic type detection via libmagic.",
+    "fs/feature/verify.rs": "Checking entries against sha256sum-style manifests for --checksum-verify.",
     "fs/metadata.rs": "File metadata extraction and handling.",
     "fs/permissions.rs": "Entry permissions extraction and handling.",
     "fs/hyperlink.rs": "Terminal hyperlinks (OSC 8) for filesystem entries.",
     "fs/symlink.rs": "Symlink utilities (reading targets, formats display).",
     "fs/acl.rs": "ACL (Access Control List) detection and handling.",
     "fs/xattr.rs": "Extended attributes (xattr) detection and handling.",
-    "fs/mountpoint.rs": "Mountpoint detection for filesystem entries.",
+    "fs/mountpoint.rs": "Mountpoint detection and btrfs/ZFS volume probes for filesystem entries.",
+    "fs/names.rs": "Suspicious filename checks for --validate-names.",
+    "fs/oci.rs": "Merged layer view of OCI/Docker image tarballs for --oci.",
+    "fs/notes.rs": "Per-directory notes from .ce-notes.toml sidecar files.",
+    "fs/describe.rs": "Description= of systemd units and Name= of desktop entries for --describe.",
+    "fs/trash.rs": "Original path and deletion date of XDG trash entries from .trashinfo files.",
+    "fs/preview.rs": "Single-line head/tail previews of file contents for --head and --tail.",
+    "fs/network.rs": "Bounded-time lookups and a per-device circuit breaker for network mounts.",
+    "fs/git.rs": "Repository discovery and git status lookups for --git.",
+    "fs/gitignore.rs": "Hierarchical .gitignore parsing and matching for --git-ignore.",
     "fs/glob.rs": "Glob pattern matching for search and hide filtering.",
+    "fs/highlight.rs": "File operands highlighted within their directory for --context-listing.",
     "fs/search.rs": "File search functionality using glob patterns.",
+    "fs/sniff.rs": "Pure-Rust file type sniffing from leading bytes (the Android --magic backend).",
+    "fs/store.rs": "Persistent cache of directory sizes, checksums and magic descriptions for --persistent-cache.",
     "fs/tree.rs": "Tree structure and builder for hierarchical directory representation.",
+    "fs/vfs.rs": "Fs trait with real and in-memory implementations for traversal and metadata.",
+    "fs/watch.rs": "inotify/kqueue watcher that waits for listed paths to change (--watch).",
     # Display module
     "display": "Display system (modes, layouts, themes, and output formatting).",
     "display/mod.rs": "Display module exports.",
     "display/mode.rs": "DisplayMode trait for different output formats.",
     "display/factory.rs": "Factory for creating appropriate display modes based on args.",
+    "display/events.rs": "Created/modified/deleted JSON events for --watch --output ndjson.",
     "display/grid.rs": "Grid display mode for compact multi-column layout.",
+    "display/html.rs": "HTML page display mode (table rows, headings and document wrapper).",
+    "display/group.rs": "Sectioned rendering for --group-by (extension, type, owner, date).",
     "display/list.rs": "List display mode with column-based table output.",
+    "display/narrow.rs": "Narrow display mode stacking each entry's columns under its name.",
+    "display/catalog.rs": "--list-columns and --list-themes, generated from the column selector and theme registry.",
+    "display/links.rs": "Symlink audit table for --link-map.",
+    "display/limit.rs": "Per-directory entry cap and trailer for --max-entries.",
+    "display/ndjson.rs": "Newline-delimited JSON renderer for --output ndjson.",
+    "display/stream.rs": "Unaligned long-format renderer for --long-stream.",
+    "display/usage.rs": "Inode usage report for --inode-usage.",
+    "display/tally.rs": "Entry and byte accumulator behind the --grand-total footer.",
     "display/summary.rs": "Summary trait and counting helpers for directory/file totals.",
     "display/tree.rs": "Tree display mode for hierarchical directory view.",
     "display/traversal.rs": "RecursiveTraversal trait for recursive directory rendering.",
@@ -55,9 +93,12 @@ DESCRIPTIONS = {
     "display/output": "Output formatting (banners, formats, population, quotes, terminal).",
     "display/output/mod.rs": "Output module exports.",
     "display/output/populate.rs": "Populates table rows with formatted entry data.",
+    "display/output/errors.rs": "Structured diagnostics for --errors json and --output json.",
+    "display/output/escape.rs": "Visible escaping of control and bidi characters in printed names and values.",
     "display/output/quotes.rs": "Shell-safe text quoting utilities (single, double, auto).",
     "display/output/terminal.rs": "Terminal capabilities detection and configuration.",
     # Display output formats
+    "display/output/html.rs": "HTML escaping and ANSI-to-HTML conversion.",
     "display/output/formats": "Formats for output (dates, sizes, permissions, etc.).",
     "display/output/formats/mod.rs": "Format module exports.",
     "display/output/formats/format.rs": "Format trait used as an implementation base for formats.",
@@ -71,7 +112,17 @@ DESCRIPTIONS = {
     "display/layout/mod.rs": "Layout module exports.",
     "display/layout/column.rs": "Column definitions, selectors, and width calculations.",
     "display/layout/row.rs": "Row structure and value resolution for columns.",
+    "display/layout/intern.rs": "Global interner for repeated owner, group and permission cells.",
+    "display/layout/template.rs": "Template parser and renderer for --format.",
+    "display/output/secret.rs": "Heuristic for group/world-readable secret-looking files.",
+    "display/output/json.rs": "Minimal JSON serialisation of entries (string escaping, metadata fields).",
+    "display/output/log.rs": "Per-module logfmt debug logging for --debug and --debug-file.",
+    "display/layout/locale.rs": "Localised column headers (language from config or locale).",
+    "display/layout/pending.rs": "Deferred checksum, magic and directory-size values filled in the background under --watch.",
+    "display/layout/graph.rs": "Proportional size bars for the --size-graph Share column.",
+    "display/layout/totals.rs": "Totals footer row (summed sizes/blocks, entry count).",
     "display/layout/alignment.rs": "Text alignment and padding utilities.",
+    "display/layout/bidi.rs": "Right-to-left detection and bidi isolation of names.",
     "display/layout/width.rs": "Cached width calculator for optimised text measurement.",
     "display/layout/unicode_width.rs": "Unicode character width calculation via libc wcwidth().",
     "display/layout/term_grid": "Terminal grid layout calculator for multi-column display.",
@@ -87,6 +138,7 @@ DESCRIPTIONS = {
     "display/theme/config": "TOML-based theme configuration system.",
     "display/theme/config/mod.rs": "Config module exports and theme loader.",
     "display/theme/config/colour.rs": "Colour deserialisation (RGB and named colours).",
+    "display/theme/config/schema.rs": "Config schema version, key migration and --check-config validation.",
     "display/theme/config/theme.rs": "Theme struct with semantic colour categories and Gruvbox default.",
     # Display styles submodule
     "display/styles": "Styling system for cells, columns, and entries.",
@@ -100,23 +152,78 @@ DESCRIPTIONS = {
 
 # Descriptions for test files using relative paths to tests/
 TEST_DESCRIPTIONS = {
+    "cli_bookmarks.rs": "Tests for bookmark parsing and `@name` expansion.",
+    "cli_catalog.rs": "Tests for --list-columns and --list-themes output.",
+    "cli_debug.rs": "Tests for --debug targets, --debug-file and --verbose logging.",
+    "cli_fit_screen.rs": "Tests for --fit-screen name shortening and -1 spreading.",
+    "cli_profiles.rs": "Tests for merging --profile flags under explicit ones.",
+    "cli_settings.rs": "Tests for scoping output settings to a thread.",
     "common": "Shared test helpers and fixtures.",
     "common/mod.rs": "Common test utilities (default args, temp directory setup).",
     "fs_symlink.rs": "Tests for symlink formatting and parsing.",
+    "fs_git.rs": "Tests for git status parsing and directory summaries.",
+    "fs_git_once.rs": "Tests that concurrent lookups share one git status run per repository.",
+    "fs_gitignore.rs": "Tests for .gitignore pattern matching and filtered listings.",
+    "cli_flags.rs": "Tests for --size-filter and --newer/--older expression parsing.",
     "fs_glob.rs": "Tests for glob pattern matching.",
+    "fs_highlight.rs": "Tests for swapping file operands for their directory.",
     "fs_hyperlink.rs": "Tests for terminal hyperlink (OSC 8) wrapping.",
     "fs_permissions.rs": "Tests for permission extraction and file type detection.",
     "fs_entry.rs": "Tests for entry creation, metadata loading, and caching.",
     "fs_dir.rs": "Tests for directory traversal, sorting, and filtering.",
     "fs_tree.rs": "Tests for tree structure building and node layout.",
     "fs_search.rs": "Tests for file search with glob patterns.",
+    "fs_apps.rs": "Tests for XDG MIME globs and default application lookup.",
+    "fs_sniff.rs": "Tests for content-based file type sniffing.",
+    "fs_notes.rs": "Tests for .ce-notes.toml loading and note sanitising.",
+    "fs_describe.rs": "Tests for unit file and desktop entry summaries.",
+    "fs_store.rs": "Tests for the persistent cache file format, pruning and reuse.",
+    "fs_trash.rs": "Tests for .trashinfo parsing and trash can detection.",
+    "fs_preview.rs": "Tests for head/tail previews, split characters and binary detection.",
+    "fs_metadata.rs": "Tests for allocated size, compression ratio and FIEMAP checks.",
+    "fs_mountpoint.rs": "Tests for btrfs/ZFS volume detection.",
+    "fs_network.rs": "Tests for timeouts and the per-device circuit breaker on network mounts.",
+    "fs_names.rs": "Tests for filename validation (control, bidi, mixed-script, trailing space).",
+    "fs_oci.rs": "Tests for image tarball layer merging and whiteouts.",
+    "fs_collate.rs": "Tests for locale collation and --sort-key locale.",
+    "fs_getdents.rs": "Tests for raw getdents64 listing against std::fs::read_dir.",
+    "fs_descend.rs": "Tests for skipping system directories during traversal.",
+    "fs_dedupe.rs": "Tests for collapsing hard-linked entries.",
+    "fs_verify.rs": "Tests for manifest parsing and OK/FAILED/MISSING verdicts.",
+    "fs_vfs.rs": "Tests for directory listing against the in-memory filesystem.",
+    "fs_watch.rs": "Tests for change notification and settling bursts of events.",
+    "cli_clipboard.rs": "Tests for OSC 52 encoding, the payload cap, separators and copied paths.",
+    "cli_watch_events.rs": "Tests for --watch --output ndjson change events.",
+    "cli_exec.rs": "Tests for --select/--exec substitution, filters, dry runs and failures.",
+    "cli_paths.rs": "Tests for listing several path operands with per-directory headers.",
+    "cli_style_caps.rs": "Tests for --style-caps and the TERM-based attribute downgrade.",
+    "cli_width.rs": "Tests for --assume-width, COLUMNS and --force-grid width detection.",
+    "cli_snapshots.rs": "Golden snapshots of --deterministic output over a fixture tree.",
+    "fs_sort_properties.rs": "Property tests for sort ordering, stability, and reversal.",
+    "display_layout_properties.rs": "Property tests for grid width and cell coverage.",
+    "display_quotes_properties.rs": "Property tests for shell round-tripping of quoted names.",
+    "display_date.rs": "Tests for date formatting against a fixed clock.",
+    "display_locale.rs": "Tests for header language selection and translation.",
+    "display_escape.rs": "Tests for escaping control and bidi characters.",
+    "display_json.rs": "Tests for JSON string escaping and entry serialisation.",
+    "display_bidi.rs": "Tests for bidi isolation of right-to-left names.",
+    "display_group.rs": "Tests for the order of --group-by sections.",
+    "display_html.rs": "Tests for HTML escaping, ANSI conversion and --output html pages.",
+    "display_narrow.rs": "Tests for the narrow layout and when it is chosen.",
+    "display_errors.rs": "Tests for collecting structured diagnostics.",
     "display_permission_format.rs": "Tests for symbolic, octal, and hex permission formats.",
     "display_term_grid.rs": "Tests for terminal grid layout and column fitting.",
     "display_unicode_width.rs": "Tests for Unicode character width calculation.",
+    "display_size_graph.rs": "Tests for size graph bar drawing.",
     "display_width.rs": "Tests for cached width measurement.",
+    "display_totals.rs": "Tests for the --totals footer values in list and tree tables.",
+    "display_intern.rs": "Tests for sharing one allocation per interned cell string.",
     "display_theme_colour.rs": "Tests for colour deserialisation (RGB and named).",
     "display_theme.rs": "Tests for theme creation and TOML deserialisation.",
+    "display_icons.rs": "Tests for --icons auto detection and the Unicode fallback icon set.",
     "display_theme_config.rs": "Tests for theme config loading and fallback.",
+    "display_config_schema.rs": "Tests for config key migration, validation and --check-config.",
+    "display_template.rs": "Tests for --format template parsing.",
 }
 
 
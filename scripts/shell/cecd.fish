@@ -0,0 +1,33 @@
+# MIT License
+
+# Copyright (c) 2025 Ritchie Mwewa
+
+# Permission is hereby granted, free of charge, to any person obtaining a copy
+# of this software and associated documentation files (the "Software"), to deal
+# in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+# copies of the Software, and to permit persons to whom the Software is
+# furnished to do so, subject to the following conditions:
+
+# The above copyright notice and this permission notice shall be included in all
+# copies or substantial portions of the Software.
+
+# THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+# IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+# FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+# AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+# LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+# OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+# SOFTWARE.
+
+# Change directory with cerium.
+#
+# Save as ~/.config/fish/functions/cecd.fish, or source it from config.fish.
+#
+# `cecd [ARGS...]` runs `ce` as usual and then changes the shell's working
+# directory to the one cerium reports on file descriptor 3 via
+# `--print-dir-on-exit`.
+function cecd --description 'List a directory with cerium and cd into it'
+    set -l dir (command ce --print-dir-on-exit 3 $argv 3>&1 1>/dev/tty)
+    or return
+    test -n "$dir"; and cd -- $dir
+end
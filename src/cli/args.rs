@@ -23,28 +23,32 @@ SOFTWARE.
 */
 
 use crate::cli::flags::{
-    DateFormat, IndicatorStyle, NumberFormat, OwnershipFormat, PermissionFormat, QuoteStyle,
-    ShowColour, ShowHyperlink, ShowIcons, SizeFormat, SortBy,
+    ContentLimit, CopySeparator, DateFormat, ErrorFormat, GroupBy, IndicatorStyle, NumberFormat,
+    OutputFormat, OwnershipFormat, PermissionFormat, QuoteStyle, ShowColour, ShowHyperlink,
+    ShowIcons, SizeFilter, SizeFormat, SortBy, SortKey, StyleCaps, TimeFilter,
 };
 
 #[cfg(feature = "checksum")]
 use crate::cli::flags::HashAlgorithm;
 
+use crate::display::layout::column::Column;
+use crate::display::layout::template::Template;
+use crate::display::theme::config::Theme;
 use clap::{Parser, ValueHint};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Parsed command-line arguments controlling listing behaviour and output formatting.
 #[derive(Parser, Debug, Clone)]
 #[command(name = crate::NAME, author = crate::AUTHORS, version, about=crate::DESCRIPTION)]
 pub struct Args {
-    #[arg(default_value = ".", value_hint = ValueHint::AnyPath)]
-    pub path: PathBuf,
+    #[arg(default_value = ".", num_args = 1.., value_hint = ValueHint::AnyPath)]
+    pub paths: Vec<PathBuf>,
 
     /// Display one entry per line
     #[arg(short = '1', long)]
     pub oneline: bool,
 
-    /// Don't ignore entries starting with `.`
+    /// Don't ignore hidden entries (starting with `.` or flagged hidden by the OS)
     #[arg(short, long)]
     pub all: bool,
 
@@ -64,7 +68,11 @@ pub struct Args {
     #[arg(short = 'B', long)]
     pub block_size: bool,
 
-    /// This entry's creation date
+    /// This entry's status change time (ctime), distinct from its creation and modification dates
+    #[arg(long)]
+    pub changed: bool,
+
+    /// This entry's creation (birth) date, falling back to ctime where the filesystem does not record one
     #[arg(short, long)]
     pub created: bool,
 
@@ -86,10 +94,57 @@ pub struct Args {
     )]
     pub find: String,
 
+    /// Run --exec for each entry whose name matches GLOB (the listing filters -a, -d, -f, -R, --hide apply)
+    #[arg(
+        long,
+        value_name = "GLOB",
+        requires = "exec",
+        conflicts_with_all = ["tree", "find", "long_stream", "inode_usage", "link_map", "bookmarks"]
+    )]
+    pub select: Option<String>,
+
+    /// Command to run for each --select match; `{}` becomes the quoted path (appended if absent). Asks first
+    #[arg(long, value_name = "COMMAND", requires = "select")]
+    pub exec: Option<String>,
+
+    /// Print the commands --exec would run, without running them
+    #[arg(long, requires = "exec")]
+    pub dry_run: bool,
+
+    /// Run --exec commands without asking for confirmation
+    #[arg(long, requires = "exec")]
+    pub yes: bool,
+
+    /// Copy the absolute paths of the listed entries (or --find matches) to the clipboard via the terminal
+    #[arg(long, conflicts_with_all = ["bookmarks", "select"])]
+    pub copy_paths: bool,
+
+    /// Keep the listing on screen and redraw it whenever a listed path changes (until interrupted);
+    /// with --output ndjson, print a JSON event per change instead
+    #[arg(
+        long,
+        conflicts_with_all = ["select", "copy_paths", "print_dir_on_exit", "bookmarks"]
+    )]
+    pub watch: bool,
+
+    /// Put each copied path on its own line, or space-separate them (quoted where needed) for a shell
+    #[arg(
+        long,
+        value_enum,
+        value_name = "SEP",
+        default_value = "newline",
+        requires = "copy_paths"
+    )]
+    pub copy_separator: CopySeparator,
+
     /// Display this entry's group
     #[arg(short = 'g', long)]
     pub group: bool,
 
+    /// Split the listing into headed sections by KEY
+    #[arg(long, value_enum, value_name = "KEY", conflicts_with = "tree")]
+    pub group_by: Option<GroupBy>,
+
     /// Display number of hard links (format affected by --number-format)
     #[arg(long)]
     pub hard_links: bool,
@@ -98,10 +153,30 @@ pub struct Args {
     #[arg(short = 'H', long)]
     pub headers: bool,
 
+    /// Show a footer row totalling sizes and blocks and counting entries (requires --headers)
+    #[arg(long, requires = "headers")]
+    pub totals: bool,
+
+    /// Finish with a footer counting the files, directories and symlinks listed and their total size
+    #[arg(long)]
+    pub grand_total: bool,
+
+    /// List at most N entries per directory, with a trailer counting the rest (grid and long views)
+    #[arg(long, value_name = "N", conflicts_with = "tree")]
+    pub max_entries: Option<usize>,
+
     /// Omit (a comma-separated list of) implied entries from output
     #[arg(long, value_name = "ENTRIES", value_delimiter = ',')]
     pub hide: Vec<String>,
 
+    /// Expand `*`, `?` and `**` in PATH arguments here instead of in the shell (quote them)
+    #[arg(long)]
+    pub glob_arg: bool,
+
+    /// List a file argument's directory instead, with the file highlighted among its siblings
+    #[arg(long)]
+    pub context_listing: bool,
+
     /// Hyperlink entry names WHEN
     #[arg(long, value_enum, default_value = "never", value_name = "WHEN")]
     pub hyperlink: ShowHyperlink,
@@ -110,14 +185,29 @@ pub struct Args {
     #[arg(short, long)]
     pub inode: bool,
 
-    /// When viewing symlinks, show metadata for the link target rather than for the link itself
+    /// Follow symlinks: show the target's metadata, count linked directories towards sizes and descend into them with --recursive or --tree (each directory is entered once)
     #[arg(short = 'L', long)]
     pub dereference: bool,
 
+    /// Like --dereference, but only for symlinks given on the command line
+    #[arg(long)]
+    pub dereference_command_line: bool,
+
     /// Long listing format, show permissions, user, group, size, and modified date
     #[arg(short, long)]
     pub long: bool,
 
+    /// Long format printed as each entry is statted: unsorted, unaligned columns (for slow filesystems)
+    #[arg(
+        long,
+        conflicts_with_all = ["tree", "totals", "group_by", "max_entries", "output", "find", "inode_usage", "link_map"]
+    )]
+    pub long_stream: bool,
+
+    /// Don't sort: list entries in directory order, the fastest for huge directories (--sort none)
+    #[arg(short = 'U', long)]
+    pub unsorted: bool,
+
     /// This entry's last modification datetime
     #[arg(short, long)]
     pub modified: bool,
@@ -126,6 +216,62 @@ pub struct Args {
     #[arg(long)]
     pub mountpoint: bool,
 
+    /// Flag names with control characters, bidi overrides, mixed-script letters or trailing spaces
+    #[arg(long)]
+    pub validate_names: bool,
+
+    /// Show notes from each directory's `.ce-notes.toml`
+    #[arg(long)]
+    pub notes: bool,
+
+    /// Show the Description= of systemd units and the Name= of desktop entries
+    #[arg(long)]
+    pub describe: bool,
+
+    /// Preview the first N bytes of regular files on one line (up to 4096)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..=4096))]
+    pub head: Option<u64>,
+
+    /// Preview the last N bytes of regular files on one line (up to 4096)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..=4096))]
+    pub tail: Option<u64>,
+
+    /// Show each entry's git status (staged, then unstaged, as in `git status -s`)
+    #[arg(long)]
+    pub git: bool,
+
+    /// Hide entries matched by `.gitignore` files and `.git/info/exclude`
+    #[arg(long)]
+    pub git_ignore: bool,
+
+    /// List the bookmarks defined in cerium.toml (use them as `ce @name`)
+    #[arg(long)]
+    pub bookmarks: bool,
+
+    /// Check cerium.toml for unknown keys, invalid values and renamed keys, then exit
+    #[arg(long)]
+    pub check_config: bool,
+
+    /// List every column with its --columns name and the flags that enable it, then exit
+    #[arg(long)]
+    pub list_columns: bool,
+
+    /// List the built-in themes with a swatch of their colours, then exit
+    #[arg(long)]
+    pub list_themes: bool,
+
+    /// Apply the flags of a [profile.NAME] table in cerium.toml (default: $CE_PROFILE)
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Write the final directory to file descriptor FD on exit (for the shell `cd` wrappers)
+    #[arg(long, value_name = "FD", value_parser = clap::value_parser!(i32).range(3..))]
+    pub print_dir_on_exit: Option<i32>,
+
+    /// Skip metadata lookups on network filesystems (NFS, SMB, sshfs, ...)
+    #[arg(long)]
+    pub no_network_metadata: bool,
+
     /// This entry's permissions
     #[arg(short, long)]
     pub permissions: bool,
@@ -134,6 +280,38 @@ pub struct Args {
     #[arg(long)]
     pub prune: bool,
 
+    /// Only list files larger (+10M), smaller (-4k) or exactly (512) this size; directories are kept
+    #[arg(long, value_name = "SIZE", allow_hyphen_values = true)]
+    pub size_filter: Option<SizeFilter>,
+
+    /// Skip reading files larger than this for content columns (checksums, magic, head/tail), or none
+    #[arg(long, value_name = "SIZE", default_value = "1G")]
+    pub content_limit: ContentLimit,
+
+    /// Only list files modified after this age (2d, 3h, 1w) or date (2024-01-01); directories are kept
+    #[arg(long, value_name = "TIME")]
+    pub newer: Option<TimeFilter>,
+
+    /// Only list files modified before this age (2d, 3h, 1w) or date (2024-01-01); directories are kept
+    #[arg(long, value_name = "TIME")]
+    pub older: Option<TimeFilter>,
+
+    /// Only list files modified since midnight, newest first unless --sort is given
+    #[arg(long, conflicts_with_all = ["this_week", "newer"])]
+    pub today: bool,
+
+    /// Only list files modified since Monday, newest first unless --sort is given
+    #[arg(long, conflicts_with = "newer")]
+    pub this_week: bool,
+
+    /// Collapse names that refer to the same file (hard links) into one entry, counted once
+    #[arg(long)]
+    pub dedupe: bool,
+
+    /// Count every hard link of a file towards directory sizes (by default, like du, once)
+    #[arg(long)]
+    pub count_links: bool,
+
     /// How to quote entry names
     #[arg(short = 'q', long, value_enum, default_value = "auto")]
     pub quote_name: QuoteStyle,
@@ -146,18 +324,95 @@ pub struct Args {
     #[arg(short = 'R', long, conflicts_with_all = ["tree"])]
     pub recursive: bool,
 
+    /// Directories recursion never descends into, along with pseudo-filesystem mounts; bare names like `target` match at any depth (`none` to disable)
+    #[arg(
+        long,
+        value_name = "PATHS",
+        value_delimiter = ',',
+        default_value = "/proc,/sys,/dev,/run"
+    )]
+    pub skip_dirs: Vec<PathBuf>,
+
+    /// Only descend into directories matching these glob patterns (e.g. `src*,crates/*`)
+    #[arg(long, value_name = "GLOBS", value_delimiter = ',')]
+    pub enter: Vec<String>,
+
+    /// Descend at most N levels below the listed directory with --tree or --recursive (1 = its entries only)
+    #[arg(
+        long,
+        value_name = "N",
+        value_parser = clap::value_parser!(u32).range(1..),
+        visible_alias = "depth",
+        conflicts_with = "inode_usage"
+    )]
+    pub level: Option<u32>,
+
+    /// Don't descend into other filesystems (mount points) with --recursive, --tree or --dir-size
+    #[arg(long)]
+    pub one_file_system: bool,
+
+    /// Leave out empty directory sections when listing recursively
+    #[arg(long, requires = "recursive")]
+    pub skip_empty_sections: bool,
+
     /// Display this entry's size
     #[arg(short, long)]
     pub size: bool,
 
-    /// Sort entries by ...
-    #[arg(long, value_enum, value_name = "BY", default_value = "name")]
-    pub sort: SortBy,
+    /// Sort entries by ... (several keys, e.g. size,name, break ties in order)
+    #[arg(
+        long,
+        value_enum,
+        value_name = "BY",
+        value_delimiter = ',',
+        default_value = "name"
+    )]
+    pub sort: Vec<SortBy>,
+
+    /// Compare names case-insensitively as displayed, or exactly as stored
+    #[arg(long, value_enum, value_name = "KEY", default_value = "display")]
+    pub sort_key: SortKey,
+
+    /// List directories before files (each group keeps the sort order)
+    #[arg(long, conflicts_with = "group_directories_last")]
+    pub group_directories_first: bool,
+
+    /// List directories after files (each group keeps the sort order)
+    #[arg(long)]
+    pub group_directories_last: bool,
+
+    /// Sort by the values a column shows (e.g. size, user, magic, sha256), numbers by value; ties keep --sort order
+    #[arg(long, value_name = "COLUMN")]
+    pub sort_column: Option<Column>,
+
+    /// With --sort extension, follow each extension's entries with a dimmed count and size
+    #[arg(long)]
+    pub subtotals: bool,
+
+    /// Report how many inodes each entry uses, recursively, largest first
+    #[arg(long, conflicts_with_all = ["tree", "recursive", "find", "output"])]
+    pub inode_usage: bool,
+
+    /// List every symlink in the tree with its resolved target, flagging broken, out-of-tree and cross-device links
+    #[arg(long, conflicts_with_all = ["tree", "recursive", "find", "output", "inode_usage"])]
+    pub link_map: bool,
+
+    /// Show the allocated size and the apparent-to-allocated ratio (transparent compression on btrfs/ZFS)
+    #[arg(long)]
+    pub compression: bool,
 
     /// Show the recursive byte size of directories in the size column instead of the item count
     #[arg(short = 'S', long)]
     pub dir_size: bool,
 
+    /// Draw a bar of each entry's share of the listed total next to its size (directories counted recursively)
+    #[arg(long)]
+    pub size_graph: bool,
+
+    /// Like du: show the size column with directories at their recursive size, sorting by it with --sort size
+    #[arg(long)]
+    pub du: bool,
+
     /// Display directories hierarchically (tree view)
     #[arg(short, long, conflicts_with = "recursive")]
     pub tree: bool,
@@ -166,14 +421,54 @@ pub struct Args {
     #[arg(short, long)]
     pub user: bool,
 
-    /// What the heck happened? (this will only make sense when used with --find)
+    /// What the heck happened? Explains ⚠ badges and logs what --find and --hide do (as --debug fs::dir,fs::search)
     #[arg(short, long)]
     pub verbose: bool,
 
+    /// Bypass all caches (for benchmarking and debugging stale values); git status still runs once per repository per listing
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Keep checksums and magic descriptions in ~/.cache/cerium for later runs
+    #[arg(long)]
+    pub persistent_cache: bool,
+
+    /// Print hit/miss counts for each cache to stderr after listing
+    #[arg(long)]
+    pub cache_stats: bool,
+
+    /// Log what traversal, filtering and layout decide for these modules to stderr (e.g. fs::glob,display::layout::width; all for everything)
+    #[arg(long, value_name = "TARGETS", value_delimiter = ',')]
+    pub debug: Vec<String>,
+
+    /// Append --debug output to this file instead of writing it to stderr
+    #[arg(long, value_name = "FILE", requires = "debug")]
+    pub debug_file: Option<PathBuf>,
+
+    /// Byte-stable output for snapshots: no colours, icons or hyperlinks, 80 columns, English headers, UTC dates, and "now" pinned to 2025-01-01
+    #[arg(long)]
+    pub deterministic: bool,
+
     /// Set output width to COLS (0 = no limit)
     #[arg(short = 'w', long, value_name = "COLS")]
     pub width: Option<usize>,
 
+    /// Lay out output as if the terminal were COLS wide (overrides detection and COLUMNS)
+    #[arg(long, value_name = "COLS", value_parser = clap::value_parser!(u16).range(1..))]
+    pub assume_width: Option<u16>,
+
+    /// Keep the interactive grid width when piped (e.g. `ce --force-grid | tee file`)
+    #[arg(long)]
+    pub force_grid: bool,
+
+    /// Shorten names (and spread -1 into a grid) so the listing fits the terminal height
+    #[arg(long)]
+    pub fit_screen: bool,
+
+    /// Stack each entry's columns under its name (automatic for tables below 40 columns)
+    #[arg(long)]
+    pub narrow: bool,
+
     /// Display extended attributes (xattr)
     #[arg(short, long)]
     pub xattr: bool,
@@ -198,6 +493,26 @@ pub struct Args {
     #[arg(short = 'C', long, value_enum, default_value = "auto", value_name = "WHEN", visible_aliases = ["colors"], help_heading = "Display")]
     pub colours: ShowColour,
 
+    /// Text attributes the terminal supports: full, basic (no italic/strikethrough), or none
+    #[arg(
+        long,
+        value_enum,
+        default_value = "auto",
+        value_name = "CAPS",
+        help_heading = "Display"
+    )]
+    pub style_caps: StyleCaps,
+
+    /// Text shown for values that are unavailable or don't apply
+    #[arg(
+        long,
+        value_name = "STR",
+        default_value = "-",
+        allow_hyphen_values = true,
+        help_heading = "Display"
+    )]
+    pub placeholder: String,
+
     /// Show icons WHEN
     #[arg(
         short = 'I',
@@ -209,22 +524,90 @@ pub struct Args {
     )]
     pub icons: ShowIcons,
 
+    /// Use a built-in colour theme instead of the one from the config file
+    #[arg(
+        long,
+        value_name = "NAME",
+        value_parser = clap::builder::PossibleValuesParser::new(Theme::BUILTIN),
+        help_heading = "Display"
+    )]
+    pub theme: Option<String>,
+
+    /// Output format (ndjson prints one JSON object per entry, json one document with entries and errors, html a standalone page)
+    #[arg(
+        long,
+        value_enum,
+        default_value = "text",
+        value_name = "FORMAT",
+        help_heading = "Display"
+    )]
+    pub output: OutputFormat,
+
+    /// Report missing paths, unreadable directories and failed stats as text, or as JSON lines on stderr
+    #[arg(
+        long,
+        value_enum,
+        default_value = "text",
+        value_name = "FORMAT",
+        help_heading = "Display"
+    )]
+    pub errors: ErrorFormat,
+
+    /// Print each entry through a template, e.g. "{perm} {size:>8} {mtime} {name}"
+    #[arg(
+        long,
+        value_name = "TEMPLATE",
+        conflicts_with = "tree",
+        help_heading = "Display"
+    )]
+    pub format: Option<Template>,
+
+    /// Show exactly these columns, in this order (e.g. perm,user,size,git,name)
+    #[arg(
+        long,
+        value_name = "COLUMNS",
+        value_delimiter = ',',
+        help_heading = "Display"
+    )]
+    pub columns: Vec<Column>,
+
     #[cfg(feature = "checksum")]
     /// Checksum!
     #[arg(long, value_name = "ALGORITHM", help_heading = "Features")]
     pub checksum: Option<HashAlgorithm>,
 
-    #[cfg(all(feature = "magic", not(target_os = "android")))]
+    #[cfg(feature = "checksum")]
+    /// Check entries against a sha256sum-style manifest, showing OK or FAILED (files it lists that are gone are MISSING)
+    #[arg(long, value_name = "FILE", help_heading = "Features")]
+    pub checksum_verify: Option<PathBuf>,
+
+    #[cfg(feature = "magic")]
     /// File magic type
     #[arg(long, help_heading = "Features")]
     pub magic: bool,
 
+    #[cfg(feature = "apps")]
+    /// Default application that opens each entry (from the XDG MIME associations)
+    #[arg(long, help_heading = "Features")]
+    pub apps: bool,
+
+    #[cfg(feature = "volumes")]
+    /// Mark directories that are btrfs subvolumes or ZFS datasets, and snapshots of either
+    #[arg(long, help_heading = "Features")]
+    pub volumes: bool,
+
+    #[cfg(feature = "oci")]
+    /// Treat PATH as an OCI or Docker image tarball and show its merged filesystem as a tree
+    #[arg(long, help_heading = "Features")]
+    pub oci: bool,
+
     // Formatting section
-    /// How to display dates (affects the output of --created, --modified, and --accessed)
+    /// How to display dates: locale, humanly, timestamp, or custom:<FMT> with a strftime pattern
+    /// (affects the output of --created, --changed, --modified, and --accessed)
     #[arg(
         long,
-        value_enum,
         default_value = "humanly",
+        value_name = "FORMAT",
         help_heading = "Formatting"
     )]
     pub date_format: DateFormat,
@@ -262,31 +645,99 @@ pub struct Args {
 }
 
 impl Args {
+    /// Returns the first path operand, the root of the listing being rendered.
+    ///
+    /// # Returns
+    /// The first path given on the command line (`.` when none was).
+    pub fn root(&self) -> &Path {
+        self.paths.first().map_or(Path::new("."), PathBuf::as_path)
+    }
+
+    /// Turns `--today` or `--this-week` into the `--newer` bound it stands for.
+    ///
+    /// Unless `--sort` was given, the listing is also sorted newest first (`-r`
+    /// then puts the oldest first).
+    ///
+    /// # Parameters
+    /// - `sort_given`: Whether `--sort` was passed explicitly.
+    pub fn apply_time_window(&mut self, sort_given: bool) {
+        let window = if self.today {
+            TimeFilter::Today
+        } else if self.this_week {
+            TimeFilter::ThisWeek
+        } else {
+            return;
+        };
+
+        self.newer = Some(window);
+        if !sort_given {
+            self.sort = vec![SortBy::Modified];
+            self.reverse = !self.reverse;
+        }
+    }
+
+    /// Turns `--du` into the size column with recursive directory sizes (`-s -S`).
+    ///
+    /// `--size-graph` measures directories recursively too, so it also turns on
+    /// `--dir-size` to keep the size column in step with the bars.
+    pub fn apply_du(&mut self) {
+        if self.du {
+            self.size = true;
+            self.dir_size = true;
+        }
+        if self.size_graph {
+            self.dir_size = true;
+        }
+    }
+
+    /// Overrides every environment-dependent presentation setting for `--deterministic`.
+    ///
+    /// Colours, text attributes, icons and hyperlinks are switched off, and the
+    /// layout width defaults to 80 columns unless `--assume-width` is also given.
+    pub fn pin_for_determinism(&mut self) {
+        self.colours = ShowColour::Never;
+        self.style_caps = StyleCaps::None;
+        self.icons = ShowIcons::Never;
+        self.hyperlink = ShowHyperlink::Never;
+        self.assume_width = self.assume_width.or(Some(80));
+    }
+
     /// Checks if any table-specific columns are requested.
     ///
     /// # Parameters
     /// - `args`: Parsed command-line arguments to inspect.
     ///
     /// # Returns
-    /// `true` if any table-only columns (magic, checksum, xattr, acl, context, mountpoint, or oneline) are requested.
+    /// `true` if any table-only columns (magic, checksum, apps, xattr, acl, context, mountpoint, or oneline) are requested.
     pub(crate) fn is_args_requesting_table_column(args: &Args) -> bool {
-        #[cfg(all(feature = "magic", not(target_os = "android")))]
+        #[cfg(feature = "magic")]
         let magic = args.magic;
-        #[cfg(any(not(feature = "magic"), target_os = "android"))]
+        #[cfg(not(feature = "magic"))]
         let magic = false;
 
         #[cfg(feature = "checksum")]
-        let checksum = args.checksum.is_some();
+        let checksum = args.checksum.is_some() || args.checksum_verify.is_some();
 
         #[cfg(not(feature = "checksum"))]
         let checksum = false;
 
+        #[cfg(feature = "apps")]
+        let apps = args.apps;
+        #[cfg(not(feature = "apps"))]
+        let apps = false;
+
         magic
             || checksum
+            || apps
             || args.xattr
             || args.acl
             || args.context
             || args.mountpoint
+            || args.validate_names
+            || args.notes
+            || args.describe
+            || args.head.is_some()
+            || args.tail.is_some()
             || args.oneline
     }
 
@@ -310,6 +761,25 @@ impl Args {
         }
     }
 
+    /// Checks whether entries are left in the order the directory was read in.
+    ///
+    /// # Returns
+    /// `true` with `-U`/`--unsorted` or `--sort none`.
+    pub fn is_unsorted(&self) -> bool {
+        self.unsorted || self.sort == [SortBy::None]
+    }
+
+    /// Checks whether metadata for a path should follow symlinks.
+    ///
+    /// # Parameters
+    /// - `is_operand`: Whether the path was given on the command line.
+    ///
+    /// # Returns
+    /// `true` with `--dereference`, or with `--dereference-command-line` for an operand.
+    pub fn dereferences(&self, is_operand: bool) -> bool {
+        self.dereference || (is_operand && self.dereference_command_line)
+    }
+
     /// Checks whether the specified arguments request entry metadata.
     ///
     /// # Parameters
@@ -321,6 +791,7 @@ impl Args {
         args.long
             || args.size
             || args.created
+            || args.changed
             || args.modified
             || args.accessed
             || args.permissions
@@ -330,5 +801,10 @@ impl Args {
             || args.user
             || args.group
             || args.inode
+            || args.compression
+            || args.size_graph
+            || args.subtotals
+            || args.format.is_some()
+            || !args.columns.is_empty()
     }
 }
@@ -0,0 +1,340 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Path bookmarks from the `[bookmarks]` table of `cerium.toml`.
+//!
+//! A bookmark is either a bare path or a table with a path and default flags:
+//!
+//! ```toml
+//! [bookmarks]
+//! work = "~/projects/work"
+//! logs = { path = "/var/log", flags = ["-l", "--sort=modified"] }
+//! ```
+//!
+//! `ce @logs` then expands to `ce -l --sort=modified /var/log` before the
+//! arguments are parsed for good.
+
+use crate::cli::profiles::Profiles;
+use crate::display::styles::element::ElementStyle;
+use crate::display::theme::config;
+use clap::{Arg, Command};
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// A named path with optional default flags.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bookmark {
+    pub path: PathBuf,
+    pub flags: Vec<String>,
+}
+
+/// The set of bookmarks defined in the config file.
+#[derive(Debug, Default)]
+pub struct Bookmarks {
+    entries: BTreeMap<String, Bookmark>,
+}
+
+impl Bookmarks {
+    /// Loads bookmarks from the config file.
+    ///
+    /// # Returns
+    /// The defined bookmarks, or none if the config file is missing or invalid
+    /// (`config::report` already warns about an unreadable config).
+    pub fn load() -> Self {
+        config::table().map(Self::from_table).unwrap_or_default()
+    }
+
+    /// Parses the `[bookmarks]` table out of a config file's contents.
+    ///
+    /// # Parameters
+    /// - `contents`: The TOML text of the config file.
+    ///
+    /// # Returns
+    /// The bookmarks found, keyed by name.
+    pub fn from_toml(contents: &str) -> Self {
+        toml::from_str::<toml::Table>(contents)
+            .map(|root| Self::from_table(&root))
+            .unwrap_or_default()
+    }
+
+    /// Reads the `[bookmarks]` table of a parsed config file.
+    ///
+    /// Entries that are neither a string nor a table with a string `path` are skipped.
+    ///
+    /// # Parameters
+    /// - `root`: The top-level config table.
+    ///
+    /// # Returns
+    /// The bookmarks found, keyed by name.
+    pub fn from_table(root: &toml::Table) -> Self {
+        let Some(table) = root.get("bookmarks").and_then(toml::Value::as_table) else {
+            return Self::default();
+        };
+
+        let entries = table
+            .iter()
+            .filter_map(|(name, value)| {
+                let (path, flags) = match value {
+                    toml::Value::String(path) => (path.as_str(), Vec::new()),
+                    toml::Value::Table(table) => {
+                        let path = table.get("path")?.as_str()?;
+                        let flags = table
+                            .get("flags")
+                            .and_then(toml::Value::as_array)
+                            .map(|flags| {
+                                flags
+                                    .iter()
+                                    .filter_map(|flag| flag.as_str().map(String::from))
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+                        (path, flags)
+                    }
+                    _ => return None,
+                };
+
+                let bookmark = Bookmark {
+                    path: Self::expand_home(path),
+                    flags,
+                };
+                Some((name.clone(), bookmark))
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Looks up a bookmark by name.
+    ///
+    /// # Parameters
+    /// - `name`: The bookmark name, without the leading `@`.
+    pub fn get(&self, name: &str) -> Option<&Bookmark> {
+        self.entries.get(name)
+    }
+
+    /// Replaces `@name` operands with their bookmarked path, adding the bookmark's default flags.
+    ///
+    /// Only operands are expanded, so an option value such as `--find @todo` is
+    /// left alone. Default flags go in front of the user's own arguments, leaving
+    /// out any flag the user passed (in whatever spelling) or one that conflicts
+    /// with it, so explicit flags always win. An `@name` that isn't a bookmark is
+    /// left alone if a file by that name exists.
+    ///
+    /// # Parameters
+    /// - `command`: The CLI definition, used to tell flags from operands.
+    /// - `argv`: The raw command line, including the program name.
+    ///
+    /// # Returns
+    /// The expanded command line, or an error naming an unknown bookmark or a
+    /// bookmark flag the CLI doesn't have.
+    pub fn expand(&self, command: &Command, argv: Vec<OsString>) -> Result<Vec<OsString>, String> {
+        let mut expanded = argv.clone();
+        let mut used = Vec::new();
+
+        for token in Self::tokens(command, &argv[1..]) {
+            let Token::Operand(index) = token else {
+                continue;
+            };
+            let arg = &argv[index + 1];
+            let Some(name) = arg.to_str().and_then(|text| text.strip_prefix('@')) else {
+                continue;
+            };
+
+            match self.get(name) {
+                Some(bookmark) => {
+                    expanded[index + 1] = bookmark.path.clone().into_os_string();
+                    used.push((name, bookmark));
+                }
+                None if Path::new(arg).exists() => {}
+                None => return Err(format!("unknown bookmark: @{}", name)),
+            }
+        }
+
+        if used.iter().all(|(_, bookmark)| bookmark.flags.is_empty()) {
+            return Ok(expanded);
+        }
+
+        // An invalid command line is clap's to report, so it's passed on as is
+        let Ok(matches) = command.clone().try_get_matches_from(&expanded) else {
+            return Ok(expanded);
+        };
+
+        let mut added = Vec::new();
+        let mut flags = Vec::new();
+        for (name, bookmark) in used {
+            let words: Vec<OsString> = bookmark.flags.iter().map(OsString::from).collect();
+            for token in Self::tokens(command, &words) {
+                match token {
+                    Token::Flag(Some(arg), words) => {
+                        // An earlier bookmark's flag counts as given too
+                        if Profiles::overridden(command, &matches, arg)
+                            || added.contains(&arg.get_id())
+                        {
+                            continue;
+                        }
+                        added.push(arg.get_id());
+                        flags.extend(words);
+                    }
+                    Token::Flag(None, words) => {
+                        return Err(format!(
+                            "unknown flag `{}` in bookmark @{}",
+                            words[0].to_string_lossy(),
+                            name
+                        ));
+                    }
+                    Token::Operand(index) => {
+                        return Err(format!(
+                            "unexpected argument `{}` in bookmark @{}",
+                            bookmark.flags[index], name
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut merged = expanded[..1].to_vec();
+        merged.extend(flags);
+        merged.extend(expanded[1..].iter().cloned());
+        Ok(merged)
+    }
+
+    /// Prints every bookmark with its path and default flags.
+    pub fn print(&self) {
+        if self.entries.is_empty() {
+            println!("No bookmarks defined. Add a [bookmarks] table to cerium.toml.");
+            return;
+        }
+
+        let width = self
+            .entries
+            .keys()
+            .map(|name| name.len() + 1)
+            .max()
+            .unwrap_or(0);
+
+        for (name, bookmark) in &self.entries {
+            let label = format!("@{:<width$}", name, width = width - 1);
+            let flags = bookmark.flags.join(" ");
+            println!(
+                "{} {} {}",
+                ElementStyle::table_header(&label),
+                bookmark.path.display(),
+                ElementStyle::tree_connector(&flags)
+            );
+        }
+    }
+
+    /// Expands a leading `~/` to the user's home directory.
+    ///
+    /// # Parameters
+    /// - `path`: The configured path.
+    fn expand_home(path: &str) -> PathBuf {
+        match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+            (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+            _ => PathBuf::from(path),
+        }
+    }
+
+    /// Splits arguments into flags and operands the way clap reads them.
+    ///
+    /// A flag that takes a value claims the next argument unless it was given
+    /// as `--flag=value` or `-fvalue`, and a cluster such as `-la` is split into
+    /// one flag per letter. Everything after `--` is an operand.
+    ///
+    /// # Parameters
+    /// - `command`: The CLI definition, used to look up flags.
+    /// - `args`: The arguments, without the program name.
+    ///
+    /// # Returns
+    /// Each flag with the arguments that spell it, and each operand by its index in `args`.
+    fn tokens<'a>(command: &'a Command, args: &[OsString]) -> Vec<Token<'a>> {
+        let takes_value = |arg: &Arg| arg.get_action().takes_values();
+        let mut tokens = Vec::new();
+        let mut args = args.iter().enumerate();
+
+        while let Some((index, arg)) = args.next() {
+            let text = arg.to_string_lossy();
+
+            if text == "--" {
+                tokens.extend(args.by_ref().map(|(index, _)| Token::Operand(index)));
+                break;
+            }
+
+            if let Some(long) = text.strip_prefix("--") {
+                let (name, value) = match long.split_once('=') {
+                    Some((name, _)) => (name, true),
+                    None => (long, false),
+                };
+                let flag = command.get_arguments().find(|arg| {
+                    arg.get_long() == Some(name)
+                        || arg
+                            .get_all_aliases()
+                            .is_some_and(|aliases| aliases.contains(&name))
+                });
+                let mut words = vec![arg.clone()];
+                if !value && flag.is_some_and(takes_value) {
+                    words.extend(args.next().map(|(_, value)| value.clone()));
+                }
+                tokens.push(Token::Flag(flag, words));
+                continue;
+            }
+
+            let Some(cluster) = text.strip_prefix('-').filter(|cluster| !cluster.is_empty()) else {
+                tokens.push(Token::Operand(index));
+                continue;
+            };
+
+            for (at, short) in cluster.char_indices() {
+                let flag = command.get_arguments().find(|arg| {
+                    arg.get_short() == Some(short)
+                        || arg
+                            .get_all_short_aliases()
+                            .is_some_and(|aliases| aliases.contains(&short))
+                });
+                let mut words = vec![OsString::from(format!("-{}", short))];
+                if flag.is_some_and(takes_value) {
+                    let rest = &cluster[at + short.len_utf8()..];
+                    match rest.strip_prefix('=').unwrap_or(rest) {
+                        "" => words.extend(args.next().map(|(_, value)| value.clone())),
+                        value => words.push(OsString::from(value)),
+                    }
+                    tokens.push(Token::Flag(flag, words));
+                    break;
+                }
+                tokens.push(Token::Flag(flag, words));
+            }
+        }
+
+        tokens
+    }
+}
+
+/// One piece of a command line, as [`Bookmarks::tokens`] splits it.
+enum Token<'a> {
+    /// A flag, if the CLI has it, with the arguments that spell it and its value.
+    Flag(Option<&'a Arg>, Vec<OsString>),
+    /// An operand, by its index among the arguments.
+    Operand(usize),
+}
@@ -0,0 +1,181 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! `--copy-paths`: put the listed entries' absolute paths on the clipboard.
+//!
+//! The paths are sent to the terminal as an OSC 52 escape sequence rather than
+//! handed to a local clipboard tool, so copying works the same over SSH and
+//! inside tmux. Terminals refuse (or truncate) oversized OSC 52 payloads, so
+//! the copy is refused above [`MAX_PAYLOAD`].
+
+use crate::cli::args::Args;
+use crate::cli::flags::CopySeparator;
+use crate::display::output::escape::Escape;
+use crate::display::output::quotes::Quotes;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// The largest base64 payload sent, in bytes (xterm's default OSC 52 limit).
+pub const MAX_PAYLOAD: usize = 100_000;
+
+/// Base64 alphabet used to encode the OSC 52 payload.
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Copies entry paths to the system clipboard through the terminal.
+pub struct Clipboard;
+
+impl Clipboard {
+    /// Joins paths into the text to copy.
+    ///
+    /// With spaces, each path is quoted as a single shell word where needed,
+    /// ` -> ` included. Paths that aren't valid UTF-8 are refused rather than
+    /// copied with replacement characters, which would name a different file.
+    ///
+    /// # Parameters
+    /// - `paths`: The paths to join.
+    /// - `separator`: Newline-separated, or space-separated with shell quoting where needed.
+    ///
+    /// # Returns
+    /// The clipboard text, without a trailing separator, or an error naming a non-UTF-8 path.
+    pub fn join(paths: &[PathBuf], separator: CopySeparator) -> Result<String, String> {
+        let texts = paths
+            .iter()
+            .map(|path| {
+                path.to_str().ok_or_else(|| {
+                    format!(
+                        "--copy-paths: {} is not valid UTF-8 and can't be copied as text",
+                        Escape::text(&path.to_string_lossy())
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(match separator {
+            CopySeparator::Newline => texts.join("\n"),
+            CopySeparator::Space => texts
+                .iter()
+                .map(|text| Quotes::new(text).single_quote_word_conditional())
+                .collect::<Vec<_>>()
+                .join(" "),
+        })
+    }
+
+    /// Wraps text in the OSC 52 "set clipboard" sequence.
+    ///
+    /// Inside tmux the sequence is wrapped in a DCS passthrough so it reaches
+    /// the outer terminal.
+    ///
+    /// # Parameters
+    /// - `text`: The text to copy.
+    /// - `in_tmux`: Whether to wrap the sequence for tmux.
+    ///
+    /// # Returns
+    /// The escape sequence, or an error if the payload exceeds [`MAX_PAYLOAD`].
+    pub fn osc52(text: &str, in_tmux: bool) -> Result<String, String> {
+        let payload = Self::base64(text.as_bytes());
+        if payload.len() > MAX_PAYLOAD {
+            return Err(format!(
+                "--copy-paths: {} bytes encoded exceeds the {} byte clipboard limit; narrow the listing",
+                payload.len(),
+                MAX_PAYLOAD
+            ));
+        }
+
+        let sequence = format!("\x1b]52;c;{payload}\x07");
+        Ok(if in_tmux {
+            format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+        } else {
+            sequence
+        })
+    }
+
+    /// Copies the listed entries' paths, reporting how many on stderr.
+    ///
+    /// The sequence is written to `/dev/tty`, so piping the listing elsewhere
+    /// still reaches the clipboard.
+    ///
+    /// # Parameters
+    /// - `paths`: The absolute paths the listing printed, from its [`Tally`](crate::display::tally::Tally).
+    /// - `args`: Parsed command-line arguments (checked for `copy_separator`).
+    ///
+    /// # Returns
+    /// `Ok(())` once written (or when nothing was listed), otherwise an error message.
+    pub fn copy(paths: &[PathBuf], args: &Args) -> Result<(), String> {
+        if paths.is_empty() {
+            eprintln!("--copy-paths: nothing to copy");
+            return Ok(());
+        }
+
+        let sequence = Self::osc52(
+            &Self::join(paths, args.copy_separator)?,
+            std::env::var_os("TMUX").is_some(),
+        )?;
+
+        let mut tty = File::options()
+            .write(true)
+            .open("/dev/tty")
+            .map_err(|error| format!("--copy-paths: no terminal to copy through: {error}"))?;
+        tty.write_all(sequence.as_bytes())
+            .and_then(|_| tty.flush())
+            .map_err(|error| format!("--copy-paths: {error}"))?;
+
+        match paths.len() {
+            1 => eprintln!("Copied 1 path to the clipboard."),
+            count => eprintln!("Copied {count} paths to the clipboard."),
+        }
+        Ok(())
+    }
+
+    /// Encodes bytes as padded standard base64.
+    ///
+    /// # Parameters
+    /// - `bytes`: The bytes to encode.
+    ///
+    /// # Returns
+    /// The base64 text.
+    fn base64(bytes: &[u8]) -> String {
+        let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+        for chunk in bytes.chunks(3) {
+            let buffer = [
+                chunk[0],
+                chunk.get(1).copied().unwrap_or(0),
+                chunk.get(2).copied().unwrap_or(0),
+            ];
+            let triple = u32::from_be_bytes([0, buffer[0], buffer[1], buffer[2]]);
+
+            for index in 0..4 {
+                if index <= chunk.len() {
+                    let sextet = (triple >> (18 - 6 * index)) & 0x3f;
+                    encoded.push(BASE64[sextet as usize] as char);
+                } else {
+                    encoded.push('=');
+                }
+            }
+        }
+
+        encoded
+    }
+}
@@ -0,0 +1,262 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! `--select GLOB --exec COMMAND`: run a command for each matching entry.
+//!
+//! Matches are collected with the same search and filters as `--find`
+//! (`-a`, `-d`, `-f`, `-R`, `--hide`, ...), so a listing can be turned into
+//! an action without re-expressing it for `find | xargs`. Nothing runs
+//! without confirmation on the terminal unless `--yes` is given, and
+//! `--dry-run` only prints the commands.
+//!
+//! Paths never become part of the shell script: each command runs as
+//! `sh -c SCRIPT sh PATH`, with `{}` standing for `"$1"`, so names holding
+//! quotes, `;` or `$(...)` are only ever data, and bytes that aren't UTF-8
+//! reach the command unchanged.
+
+use crate::cli::args::Args;
+use crate::display::output::escape::Escape;
+use crate::display::output::quotes::Quotes;
+use crate::fs::search::Search;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Arc;
+
+/// The placeholder replaced by each matching path.
+pub const PLACEHOLDER: &str = "{}";
+
+/// What the placeholder becomes in the script: the path, passed as the first argument.
+const ARGUMENT: &str = "\"$1\"";
+
+/// Runs a shell command for every entry selected by a glob.
+pub struct Exec<'a> {
+    /// The glob matched against entry names.
+    pattern: &'a str,
+    /// The command template, containing [`PLACEHOLDER`] or not.
+    template: &'a str,
+    /// Whether to print the commands instead of running them.
+    dry_run: bool,
+    /// Whether to skip the confirmation prompt.
+    assume_yes: bool,
+}
+
+impl<'a> Exec<'a> {
+    /// Builds the action requested on the command line, if any.
+    ///
+    /// # Parameters
+    /// - `args`: Parsed command-line arguments.
+    ///
+    /// # Returns
+    /// `Some` when both `--select` and `--exec` were given.
+    pub fn from_args(args: &'a Args) -> Option<Self> {
+        Some(Self {
+            pattern: args.select.as_deref()?,
+            template: args.exec.as_deref()?,
+            dry_run: args.dry_run,
+            assume_yes: args.yes,
+        })
+    }
+
+    /// Builds the script `sh -c` runs for every path, which it gets as `$1`.
+    ///
+    /// Every `{}` (quoted by the user or not) becomes `"$1"`; without a `{}`,
+    /// `"$1"` is appended, like `xargs -n1`.
+    ///
+    /// # Parameters
+    /// - `template`: The `--exec` command.
+    ///
+    /// # Returns
+    /// The shell script, the same for every path.
+    pub fn script_for(template: &str) -> String {
+        if template.contains(PLACEHOLDER) {
+            template
+                .replace("'{}'", ARGUMENT)
+                .replace("\"{}\"", ARGUMENT)
+                .replace(PLACEHOLDER, ARGUMENT)
+        } else {
+            format!("{template} {ARGUMENT}")
+        }
+    }
+
+    /// Shows the command for one path, as `--dry-run` and the confirmation list print it.
+    ///
+    /// Only for reading: the command that runs gets the path as an argument
+    /// (see [`Exec::script_for`]), never spliced into the script. Control
+    /// characters in the path are escaped, so a name can't redraw the prompt,
+    /// and the whole path is quoted as one word, ` -> ` included.
+    ///
+    /// # Parameters
+    /// - `template`: The `--exec` command.
+    /// - `path`: The matching entry's path.
+    ///
+    /// # Returns
+    /// The template with every `{}` replaced by the single-quoted path, or the
+    /// path appended.
+    pub fn command_for(template: &str, path: &Path) -> String {
+        let shown = Escape::value(Arc::from(path.to_string_lossy()));
+        let quoted = Quotes::new(&shown).single_quote_word();
+
+        if template.contains(PLACEHOLDER) {
+            template.replace(PLACEHOLDER, &quoted)
+        } else {
+            format!("{template} {quoted}")
+        }
+    }
+
+    /// Selects the matching entries and runs (or prints) the command for each.
+    ///
+    /// # Parameters
+    /// - `args`: Parsed command-line arguments (the listing filters apply to every path).
+    ///
+    /// # Returns
+    /// `Ok(())` if every command succeeded (or nothing ran), otherwise an error message.
+    pub fn run(&self, args: &Args) -> Result<(), String> {
+        let mut paths: Vec<PathBuf> = Vec::new();
+        for root in &args.paths {
+            let search = Search::new(self.pattern, root.clone()).map_err(|error| {
+                format!("--select: invalid pattern '{}': {}", self.pattern, error)
+            })?;
+            let root_args = Args {
+                paths: vec![root.clone()],
+                ..args.clone()
+            };
+            paths.extend(
+                search
+                    .find(&root_args)
+                    .iter()
+                    .map(|entry| entry.path().to_path_buf()),
+            );
+        }
+
+        if paths.is_empty() {
+            eprintln!("--select: nothing matches '{}'", self.pattern);
+            return Ok(());
+        }
+
+        let commands: Vec<String> = paths
+            .iter()
+            .map(|path| Self::command_for(self.template, path))
+            .collect();
+
+        if self.dry_run {
+            for command in &commands {
+                println!("{command}");
+            }
+            return Ok(());
+        }
+
+        if !self.assume_yes && !Self::confirm(&commands)? {
+            eprintln!("Aborted; nothing was run.");
+            return Ok(());
+        }
+
+        let script = Self::script_for(self.template);
+        let failed = paths
+            .iter()
+            .zip(&commands)
+            .filter(|(path, command)| !Self::execute(&script, path, command))
+            .count();
+
+        match failed {
+            0 => Ok(()),
+            _ => Err(format!("{} of {} commands failed", failed, commands.len())),
+        }
+    }
+
+    /// Runs the script for one path through `sh -c`, reporting failures on stderr.
+    ///
+    /// # Parameters
+    /// - `script`: The script from [`Exec::script_for`].
+    /// - `path`: The path, passed to the script as `$1`.
+    /// - `command`: The command as shown, for error messages.
+    ///
+    /// # Returns
+    /// `true` if the command exited successfully.
+    fn execute(script: &str, path: &Path, command: &str) -> bool {
+        // `sh` fills `$0`, the path is `$1`
+        let status = Command::new("sh")
+            .arg("-c")
+            .arg(script)
+            .arg("sh")
+            .arg(path)
+            .status();
+
+        match status {
+            Ok(status) if status.success() => true,
+            Ok(status) => {
+                eprintln!("--exec: `{command}` failed ({status})");
+                false
+            }
+            Err(error) => {
+                eprintln!("--exec: could not run `{command}`: {error}");
+                false
+            }
+        }
+    }
+
+    /// Shows the commands and asks on the terminal whether to run them.
+    ///
+    /// The answer is read from `/dev/tty`, so piped stdin can't confirm by accident.
+    ///
+    /// # Parameters
+    /// - `commands`: The commands that would run.
+    ///
+    /// # Returns
+    /// `Ok(true)` only for an explicit `y`/`yes`, or an error if there is no terminal.
+    fn confirm(commands: &[String]) -> Result<bool, String> {
+        let tty = File::options()
+            .read(true)
+            .write(true)
+            .open("/dev/tty")
+            .map_err(|_| {
+                "--exec: no terminal to confirm on; review with --dry-run, then pass --yes"
+                    .to_string()
+            })?;
+
+        let mut prompt = tty.try_clone().map_err(|error| error.to_string())?;
+        for command in commands {
+            writeln!(prompt, "  {command}").map_err(|error| error.to_string())?;
+        }
+        write!(
+            prompt,
+            "Run {} command{}? [y/N] ",
+            commands.len(),
+            if commands.len() == 1 { "" } else { "s" }
+        )
+        .map_err(|error| error.to_string())?;
+        prompt.flush().map_err(|error| error.to_string())?;
+
+        let mut answer = String::new();
+        BufReader::new(tty)
+            .read_line(&mut answer)
+            .map_err(|error| error.to_string())?;
+
+        Ok(matches!(
+            answer.trim().to_ascii_lowercase().as_str(),
+            "y" | "yes"
+        ))
+    }
+}
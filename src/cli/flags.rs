@@ -22,25 +22,57 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
+use crate::fs::clock::{Clock, ClockSettings};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Datelike, Days, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use clap::ValueEnum;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
 /// Controls how dates are formatted in output.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum DateFormat {
     Locale,
     Humanly,
     Timestamp,
+    /// A strftime pattern given as `custom:<FMT>`, e.g. `custom:%Y-%m-%d %H:%M`.
+    Custom(Arc<str>),
+}
+
+impl FromStr for DateFormat {
+    type Err = String;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        if let Some(pattern) = expression.strip_prefix("custom:") {
+            let items = StrftimeItems::new(pattern);
+            if pattern.is_empty() || items.clone().any(|item| matches!(item, Item::Error)) {
+                return Err(format!("invalid strftime pattern '{pattern}'"));
+            }
+            return Ok(Self::Custom(pattern.into()));
+        }
+
+        match expression.to_ascii_lowercase().as_str() {
+            "locale" => Ok(Self::Locale),
+            "humanly" => Ok(Self::Humanly),
+            "timestamp" => Ok(Self::Timestamp),
+            _ => Err(format!(
+                "invalid date format '{expression}' (expected locale, humanly, timestamp or custom:<FMT>)"
+            )),
+        }
+    }
 }
 
 /// Controls how numeric values (hard links, blocks) are formatted.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ValueEnum)]
 pub enum NumberFormat {
     Humanly,
     Natural,
 }
 
 /// Controls how user and group ownership is displayed.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ValueEnum)]
 pub enum OwnershipFormat {
     Name,
     Id,
@@ -69,7 +101,7 @@ pub enum IndicatorStyle {
 }
 
 /// Controls how file permissions are formatted.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ValueEnum)]
 pub enum PermissionFormat {
     Symbolic,
     Octal,
@@ -77,7 +109,7 @@ pub enum PermissionFormat {
 }
 
 /// Controls how file sizes are formatted.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ValueEnum)]
 pub enum SizeFormat {
     Bytes,
     Binary,
@@ -85,15 +117,40 @@ pub enum SizeFormat {
 }
 
 /// Determines the field used to sort directory entries.
-#[derive(Clone, Copy, Debug, ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
 pub enum SortBy {
     Name,
     Size,
     Created,
+    Changed,
     Accessed,
     Modified,
     Extension,
     Inode,
+    Children,
+    Version,
+    None,
+}
+
+/// Determines how names compare when sorting by name, extension, version, or children.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum SortKey {
+    /// Ignore case, breaking ties between names that only differ in case by the name as shown
+    Display,
+    /// Compare names exactly as stored, so uppercase sorts before lowercase
+    Raw,
+    /// Compare names by the collation rules of the locale in `LC_COLLATE` (or `LANG`)
+    #[cfg(feature = "collate")]
+    Locale,
+}
+
+/// Determines the key used to split a listing into sections.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum GroupBy {
+    Ext,
+    Type,
+    Owner,
+    Date,
 }
 
 /// Controls when ANSI colours are used in output.
@@ -104,6 +161,15 @@ pub enum ShowColour {
     Never,
 }
 
+/// Selects which text attributes (italic, underline, ...) the terminal can render.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum StyleCaps {
+    Auto,
+    Full,
+    Basic,
+    None,
+}
+
 /// Controls when OSC 8 hyperlinks wrap entry names.
 #[derive(Clone, Copy, Debug, ValueEnum)]
 pub enum ShowHyperlink {
@@ -112,6 +178,36 @@ pub enum ShowHyperlink {
     Never,
 }
 
+/// Selects the output format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Ndjson,
+    Json,
+    Html,
+}
+
+impl OutputFormat {
+    /// Checks whether entries are written as JSON objects (`ndjson` or `json`).
+    pub fn is_json(self) -> bool {
+        matches!(self, Self::Ndjson | Self::Json)
+    }
+}
+
+/// Selects how diagnostics (missing paths, unreadable directories, failed stats) are reported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ErrorFormat {
+    Text,
+    Json,
+}
+
+/// Separates the paths `--copy-paths` puts on the clipboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CopySeparator {
+    Newline,
+    Space,
+}
+
 /// Controls how entry names are quoted in output.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
 pub enum QuoteStyle {
@@ -121,6 +217,259 @@ pub enum QuoteStyle {
     Never,
 }
 
+/// How a [`SizeFilter`] compares an entry's size with its bound.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SizeComparison {
+    Larger,
+    Smaller,
+    Exactly,
+}
+
+/// A `--size-filter` expression such as `+10M` (larger than), `-4k` (smaller than) or `512` (exactly).
+///
+/// Units are binary (`k`, `M`, `G`, `T`, case-insensitive, with an optional
+/// trailing `B` or `iB`); a bare number counts bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SizeFilter {
+    pub comparison: SizeComparison,
+    pub bytes: u64,
+}
+
+impl SizeFilter {
+    /// Checks whether a size satisfies the filter.
+    ///
+    /// # Parameters
+    /// - `size`: The entry's size in bytes.
+    ///
+    /// # Returns
+    /// `true` if the size is on the requested side of the bound.
+    pub fn matches(&self, size: u64) -> bool {
+        match self.comparison {
+            SizeComparison::Larger => size > self.bytes,
+            SizeComparison::Smaller => size < self.bytes,
+            SizeComparison::Exactly => size == self.bytes,
+        }
+    }
+
+    /// Parses a byte count with an optional binary unit, e.g. `512`, `4k` or `10MiB`.
+    ///
+    /// # Returns
+    /// The number of bytes, or `None` if the text isn't a size or overflows.
+    fn bytes(text: &str) -> Option<u64> {
+        let digits_end = text
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(text.len());
+        let (number, unit) = text.split_at(digits_end);
+        let number: u64 = number.parse().ok()?;
+
+        let unit = unit.to_ascii_lowercase();
+        let unit = unit
+            .strip_suffix("ib")
+            .or_else(|| unit.strip_suffix('b'))
+            .unwrap_or(&unit);
+        let shift = match unit {
+            "" => 0,
+            "k" => 10,
+            "m" => 20,
+            "g" => 30,
+            "t" => 40,
+            _ => return None,
+        };
+
+        number.checked_mul(1 << shift)
+    }
+}
+
+impl FromStr for SizeFilter {
+    type Err = String;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid size '{expression}' (expected e.g. +10M, -4k or 512)");
+
+        let (comparison, rest) = match expression.trim().split_at_checked(1) {
+            Some(("+", rest)) => (SizeComparison::Larger, rest),
+            Some(("-", rest)) => (SizeComparison::Smaller, rest),
+            _ => (SizeComparison::Exactly, expression.trim()),
+        };
+
+        let bytes = Self::bytes(rest).ok_or_else(invalid)?;
+        Ok(Self { comparison, bytes })
+    }
+}
+
+/// The `--content-limit` on how large a file may be for columns that read its
+/// contents (checksums, magic, head and tail previews).
+///
+/// Takes the same sizes as `--size-filter` (`512`, `64M`, `2G`), or `none` to
+/// read files of any size.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContentLimit {
+    pub bytes: Option<u64>,
+    label: Arc<str>,
+}
+
+impl ContentLimit {
+    /// Checks whether a file is small enough to have its contents read.
+    ///
+    /// Symlinks are followed, as the content columns read the target. Paths that
+    /// can't be statted are admitted and left to the column to report.
+    ///
+    /// # Parameters
+    /// - `path`: The file about to be read.
+    ///
+    /// # Returns
+    /// `true` if there's no limit or the file is within it.
+    pub fn admits(&self, path: &Path) -> bool {
+        let Some(limit) = self.bytes else {
+            return true;
+        };
+        std::fs::metadata(path).map_or(true, |metadata| metadata.len() <= limit)
+    }
+
+    /// Returns the placeholder shown instead of a value for files over the limit, e.g. `>1G`.
+    pub fn placeholder(&self) -> Arc<str> {
+        self.label.clone()
+    }
+}
+
+impl FromStr for ContentLimit {
+    type Err = String;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let expression = expression.trim();
+        if expression.eq_ignore_ascii_case("none") {
+            return Ok(Self {
+                bytes: None,
+                label: "".into(),
+            });
+        }
+
+        let bytes = SizeFilter::bytes(expression).ok_or_else(|| {
+            format!("invalid size '{expression}' (expected e.g. 64M, 2G or none)")
+        })?;
+        Ok(Self {
+            bytes: Some(bytes),
+            label: format!(">{expression}").into(),
+        })
+    }
+}
+
+/// A point in time for `--newer`/`--older`: an age such as `2d`, or a date such as `2024-01-01`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeFilter {
+    /// This long before now (units `s`, `m`, `h`, `d`, `w`).
+    Ago(Duration),
+    /// A wall-clock date and time, placed in a time zone when resolved.
+    At(NaiveDateTime),
+    /// The most recent midnight (`--today`).
+    Today,
+    /// Midnight at the start of the current week, a Monday (`--this-week`).
+    ThisWeek,
+}
+
+impl TimeFilter {
+    /// Resolves the filter to an instant.
+    ///
+    /// # Parameters
+    /// - `clock`: The clock ages are measured back from.
+    ///
+    /// # Returns
+    /// The cutoff in seconds since the Unix epoch.
+    pub fn cutoff(&self, clock: &dyn Clock) -> i64 {
+        match self {
+            Self::Ago(age) => {
+                let instant = clock
+                    .now()
+                    .checked_sub(*age)
+                    .unwrap_or(SystemTime::UNIX_EPOCH);
+                match instant.duration_since(SystemTime::UNIX_EPOCH) {
+                    Ok(since) => since.as_secs() as i64,
+                    Err(before) => -(before.duration().as_secs() as i64),
+                }
+            }
+            // Local time, or UTC under `--deterministic`
+            Self::At(datetime) if ClockSettings::is_deterministic() => {
+                Utc.from_utc_datetime(datetime).timestamp()
+            }
+            Self::At(datetime) => Local
+                .from_local_datetime(datetime)
+                .earliest()
+                .unwrap_or_else(|| Utc.from_utc_datetime(datetime).into())
+                .timestamp(),
+            Self::Today | Self::ThisWeek => {
+                let now = DateTime::<Utc>::from(clock.now());
+                let today = if ClockSettings::is_deterministic() {
+                    now.date_naive()
+                } else {
+                    now.with_timezone(&Local).date_naive()
+                };
+                let start = match self {
+                    Self::ThisWeek => {
+                        today - Days::new(today.weekday().num_days_from_monday().into())
+                    }
+                    _ => today,
+                };
+
+                Self::At(start.and_time(NaiveTime::MIN)).cutoff(clock)
+            }
+        }
+    }
+
+    /// Parses an age such as `90m` or `2w`.
+    ///
+    /// # Returns
+    /// The age, or `None` if the text isn't a number followed by a unit.
+    fn parse_age(text: &str) -> Option<Duration> {
+        let (number, unit) = text.split_at_checked(text.len().checked_sub(1)?)?;
+        let number: u64 = number.parse().ok()?;
+        let seconds = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 24 * 60 * 60,
+            "w" => 7 * 24 * 60 * 60,
+            _ => return None,
+        };
+
+        number.checked_mul(seconds).map(Duration::from_secs)
+    }
+
+    /// Parses a date (`2024-01-01`) or date and time (`2024-01-01 12:30`, `2024-01-01T12:30:00`).
+    ///
+    /// # Returns
+    /// The date and time (midnight for a bare date), or `None` if the text isn't a date.
+    fn parse_date(text: &str) -> Option<NaiveDateTime> {
+        [
+            "%Y-%m-%d %H:%M:%S",
+            "%Y-%m-%dT%H:%M:%S",
+            "%Y-%m-%d %H:%M",
+            "%Y-%m-%dT%H:%M",
+        ]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                .ok()?
+                .and_hms_opt(0, 0, 0)
+        })
+    }
+}
+
+impl FromStr for TimeFilter {
+    type Err = String;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let text = expression.trim();
+
+        Self::parse_age(text)
+            .map(Self::Ago)
+            .or_else(|| Self::parse_date(text).map(Self::At))
+            .ok_or_else(|| {
+                format!("invalid time '{expression}' (expected an age like 2d or 3h, or a date like 2024-01-01)")
+            })
+    }
+}
+
 #[cfg(feature = "checksum")]
 /// Hash algorithm selection for checksum computation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ValueEnum)]
@@ -23,4 +23,10 @@ SOFTWARE.
 */
 
 pub mod args;
+pub mod bookmarks;
+pub mod clipboard;
+pub mod exec;
 pub mod flags;
+pub mod profiles;
+pub mod settings;
+pub mod shell;
@@ -0,0 +1,187 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Named flag bundles from the `[profile.<name>]` tables of `cerium.toml`.
+//!
+//! Each key is a long flag name (with `_` or `-`) and each value its argument:
+//!
+//! ```toml
+//! [profile.review]
+//! long = true
+//! git = true
+//! sort = "modified"
+//! ```
+//!
+//! `ce --profile review` (or `CE_PROFILE=review ce`) then lists as if
+//! `--long --git --sort=modified` had been given. Flags passed on the command
+//! line win over the profile's, including flags that conflict with them.
+
+use crate::display::theme::config;
+use clap::parser::ValueSource;
+use clap::{Arg, ArgMatches, Command};
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+
+/// The environment variable naming the profile to use when `--profile` isn't given.
+pub const PROFILE_VAR: &str = "CE_PROFILE";
+
+/// The set of profiles defined in the config file.
+#[derive(Debug, Default)]
+pub struct Profiles {
+    entries: BTreeMap<String, toml::Table>,
+}
+
+impl Profiles {
+    /// Loads profiles from the config file.
+    ///
+    /// # Returns
+    /// The defined profiles, or none if the config file is missing or invalid.
+    pub fn load() -> Self {
+        config::table().map(Self::from_table).unwrap_or_default()
+    }
+
+    /// Reads the `[profile]` table of a parsed config file.
+    ///
+    /// Entries that aren't tables are skipped.
+    ///
+    /// # Parameters
+    /// - `root`: The top-level config table.
+    ///
+    /// # Returns
+    /// The profiles found, keyed by name.
+    pub fn from_table(root: &toml::Table) -> Self {
+        let Some(table) = root.get("profile").and_then(toml::Value::as_table) else {
+            return Self::default();
+        };
+
+        let entries = table
+            .iter()
+            .filter_map(|(name, value)| Some((name.clone(), value.as_table()?.clone())))
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Merges the selected profile's flags into the command line.
+    ///
+    /// The profile is the one named by `--profile`, or else by [`PROFILE_VAR`].
+    /// Its flags go in front of the user's arguments, leaving out any flag the
+    /// user passed or one that conflicts with it.
+    ///
+    /// # Parameters
+    /// - `command`: The CLI definition, used to look up flags.
+    /// - `matches`: The command line as parsed without the profile.
+    /// - `argv`: The raw command line, including the program name.
+    ///
+    /// # Returns
+    /// The command line to parse instead, `None` if no profile is selected, or
+    /// an error naming an unknown profile or key.
+    pub fn apply(
+        &self,
+        command: &Command,
+        matches: &ArgMatches,
+        argv: &[OsString],
+    ) -> Result<Option<Vec<OsString>>, String> {
+        let name = match matches.get_one::<String>("profile") {
+            Some(name) => name.clone(),
+            None => match std::env::var(PROFILE_VAR) {
+                Ok(name) if !name.is_empty() => name,
+                _ => return Ok(None),
+            },
+        };
+
+        let profile = self
+            .entries
+            .get(&name)
+            .ok_or_else(|| format!("unknown profile: {name}"))?;
+
+        let mut flags = Vec::new();
+
+        for (key, value) in profile {
+            let id = key.replace('-', "_");
+            let arg = command
+                .get_arguments()
+                .find(|arg| arg.get_id() == id.as_str() && arg.get_long().is_some())
+                .filter(|_| id != "profile")
+                .ok_or_else(|| format!("unknown flag `{key}` in profile {name}"))?;
+
+            if Self::overridden(command, matches, arg) {
+                continue;
+            }
+
+            let long = arg.get_long().unwrap_or_default();
+            match value {
+                toml::Value::Boolean(true) => flags.push(format!("--{long}")),
+                toml::Value::Boolean(false) => {}
+                toml::Value::Array(values) => {
+                    for value in values {
+                        flags.push(format!("--{long}={}", Self::argument(value, key, &name)?));
+                    }
+                }
+                value => flags.push(format!("--{long}={}", Self::argument(value, key, &name)?)),
+            }
+        }
+
+        let mut merged = argv[..1].to_vec();
+        merged.extend(flags.into_iter().map(OsString::from));
+        merged.extend(argv[1..].iter().cloned());
+        Ok(Some(merged))
+    }
+
+    /// Checks whether the command line already settles a flag.
+    ///
+    /// Explicit flags win over configured ones, and so do flags the configured
+    /// one would clash with (a conflict may be declared on either side).
+    ///
+    /// # Parameters
+    /// - `command`: The CLI definition, used to look up conflicts.
+    /// - `matches`: The command line as parsed without the configured flags.
+    /// - `arg`: The configured flag.
+    pub(crate) fn overridden(command: &Command, matches: &ArgMatches, arg: &Arg) -> bool {
+        let given = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+        let clashes = command.get_arguments().any(|other| {
+            given(other.get_id().as_str())
+                && (command.get_arg_conflicts_with(arg).contains(&other)
+                    || command.get_arg_conflicts_with(other).contains(&arg))
+        });
+        given(arg.get_id().as_str()) || clashes
+    }
+
+    /// Turns a profile value into a flag argument.
+    ///
+    /// # Parameters
+    /// - `value`: A string, integer, or float from the profile.
+    /// - `key`: The key it belongs to, for the error message.
+    /// - `profile`: The profile's name, for the error message.
+    fn argument(value: &toml::Value, key: &str, profile: &str) -> Result<String, String> {
+        match value {
+            toml::Value::String(text) => Ok(text.clone()),
+            toml::Value::Integer(number) => Ok(number.to_string()),
+            toml::Value::Float(number) => Ok(number.to_string()),
+            _ => Err(format!(
+                "unsupported value for `{key}` in profile {profile}"
+            )),
+        }
+    }
+}
@@ -0,0 +1,167 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Output settings that can differ between listings running in one process.
+//!
+//! `main` configures the process once through each `*Settings::setup` (colours,
+//! icons, hyperlinks, theme, header language, clock, caches, network metadata
+//! and layout width). Code embedding cerium can instead run a listing inside
+//! [`Settings::scope`], which overrides all of them for the calling thread, so
+//! listings with different settings can run side by side on separate threads.
+//! Directory reads that fan out over rayon carry the scope to the workers.
+//!
+//! Display caches key their values by the format they were rendered with, so
+//! scoped listings never see each other's strings. What a run reports at the
+//! end (error summaries, the `--checksum-verify` manifest, `--context-listing`
+//! highlights) is still process-wide and belongs to `main`'s one listing.
+
+use crate::cli::flags::StyleCaps;
+use crate::display::layout::locale::{HeaderLocale, Language};
+use crate::display::layout::width::Width;
+use crate::display::theme::colours::{ColourSettings, RgbColours, StyleCapsSettings};
+use crate::display::theme::config::Theme;
+use crate::display::theme::icons::{IconSet, IconSettings};
+use crate::fs::cache::CacheSettings;
+use crate::fs::clock::ClockSettings;
+use crate::fs::hyperlink::HyperlinkSettings;
+use crate::fs::network::NetworkSettings;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+thread_local! {
+    /// The settings of the listing running on this thread, if it runs in a scope.
+    static SCOPED: RefCell<Option<Arc<Settings>>> = const { RefCell::new(None) };
+}
+
+/// Every setting that shapes a listing's output beyond its [`Args`](crate::cli::args::Args).
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// Emit ANSI colours
+    pub colours: bool,
+    /// Text attributes the terminal can draw
+    pub style_caps: StyleCaps,
+    /// Show entry icons
+    pub icons: bool,
+    /// Which glyphs icons are drawn from
+    pub icon_set: IconSet,
+    /// Emit OSC 8 hyperlinks
+    pub hyperlinks: bool,
+    pub theme: Arc<Theme>,
+    /// Language of the column headers
+    pub language: Language,
+    /// Pin "now" and print dates in UTC (`--deterministic`)
+    pub deterministic: bool,
+    /// Bypass every cache (`--no-cache`)
+    pub no_cache: bool,
+    /// Skip metadata lookups on network mounts
+    pub skip_network_metadata: bool,
+    /// Terminal width to lay out for instead of detecting it
+    pub assumed_width: Option<usize>,
+    /// Fall back to the controlling terminal's width when stdout isn't one
+    pub force_grid: bool,
+}
+
+impl Settings {
+    /// Returns the settings in effect on this thread: its scope's, or the process-wide ones.
+    ///
+    /// Start from this and change what should differ before calling [`Settings::scope`].
+    ///
+    /// # Panics
+    /// Outside a scope, if [`RgbColours::init`] hasn't set the process-wide theme.
+    pub fn current() -> Self {
+        if let Some(settings) = Self::scoped() {
+            return (*settings).clone();
+        }
+
+        Self {
+            colours: ColourSettings::is_enabled(),
+            style_caps: StyleCapsSettings::caps(),
+            icons: IconSettings::enabled(),
+            icon_set: IconSettings::icon_set(),
+            hyperlinks: HyperlinkSettings::is_enabled(),
+            theme: RgbColours::theme(),
+            language: HeaderLocale::language(),
+            deterministic: ClockSettings::is_deterministic(),
+            no_cache: CacheSettings::is_disabled(),
+            skip_network_metadata: NetworkSettings::is_skipping(),
+            assumed_width: Width::assumed(),
+            force_grid: Width::is_grid_forced(),
+        }
+    }
+
+    /// Runs `run` with these settings in place of the process-wide ones on this thread.
+    ///
+    /// Scopes nest; the previous settings are restored when `run` returns or panics.
+    ///
+    /// # Parameters
+    /// - `run`: The listing to run, e.g. a call to `DisplayFactory::print_all`.
+    ///
+    /// # Returns
+    /// Whatever `run` returns.
+    pub fn scope<R>(self: &Arc<Self>, run: impl FnOnce() -> R) -> R {
+        Self::within(Some(self.clone()), run)
+    }
+
+    /// Returns the settings of this thread's scope, to hand to worker threads.
+    ///
+    /// # Returns
+    /// The scoped settings, or `None` if the process-wide ones apply.
+    pub(crate) fn scoped() -> Option<Arc<Self>> {
+        SCOPED.with(|scoped| scoped.borrow().clone())
+    }
+
+    /// Runs `run` under the scope captured by [`Settings::scoped`] on another thread.
+    ///
+    /// # Parameters
+    /// - `settings`: The scope to enter, or `None` for the process-wide settings.
+    /// - `run`: The work to do.
+    ///
+    /// # Returns
+    /// Whatever `run` returns.
+    pub(crate) fn within<R>(settings: Option<Arc<Self>>, run: impl FnOnce() -> R) -> R {
+        /// Puts the previous scope back, even when unwinding.
+        struct Restore(Option<Arc<Settings>>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                SCOPED.with(|scoped| *scoped.borrow_mut() = previous);
+            }
+        }
+
+        let _restore = Restore(SCOPED.with(|scoped| scoped.replace(settings)));
+        run()
+    }
+
+    /// Reads one setting from this thread's scope.
+    ///
+    /// # Parameters
+    /// - `read`: Picks the setting out of the scoped [`Settings`].
+    ///
+    /// # Returns
+    /// The scoped value, or `None` outside a scope, where callers use the process-wide one.
+    pub(crate) fn get<R>(read: impl FnOnce(&Settings) -> R) -> Option<R> {
+        SCOPED.with(|scoped| scoped.borrow().as_deref().map(read))
+    }
+}
@@ -0,0 +1,79 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! The `--print-dir-on-exit FD` contract used by the shipped shell functions.
+//!
+//! A child process can't change its parent shell's working directory, so
+//! `scripts/shell/` provides small wrapper functions that run `ce` with an
+//! extra file descriptor open, read the directory written to it, and `cd`
+//! there. Today that is the directory that was listed; a future interactive
+//! mode will write the directory the user navigated to instead.
+
+use std::fs::File;
+use std::io::Write;
+use std::mem::ManuallyDrop;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::io::FromRawFd;
+use std::path::Path;
+
+/// Writes the final directory to a caller-provided file descriptor.
+pub struct ExitDir;
+
+impl ExitDir {
+    /// Writes the absolute directory for `path` to `fd`, followed by a newline.
+    ///
+    /// Files resolve to their parent directory. The descriptor is checked with
+    /// `fcntl` first and is never closed here, so a bad or shared fd can't be
+    /// clobbered.
+    ///
+    /// # Parameters
+    /// - `fd`: The descriptor number given to `--print-dir-on-exit`.
+    /// - `path`: The listed path.
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or an error message if the fd is invalid or the write fails.
+    pub fn write(fd: i32, path: &Path) -> Result<(), String> {
+        if unsafe { libc::fcntl(fd, libc::F_GETFD) } == -1 {
+            return Err(format!(
+                "--print-dir-on-exit: file descriptor {} is not open",
+                fd
+            ));
+        }
+
+        let absolute = path
+            .canonicalize()
+            .map_err(|error| format!("--print-dir-on-exit: {}: {}", path.display(), error))?;
+        let dir = if absolute.is_dir() {
+            absolute.as_path()
+        } else {
+            absolute.parent().unwrap_or(&absolute)
+        };
+
+        // Borrow the descriptor without taking ownership of (and closing) it
+        let mut file = ManuallyDrop::new(unsafe { File::from_raw_fd(fd) });
+        file.write_all(dir.as_os_str().as_bytes())
+            .and_then(|_| file.write_all(b"\n"))
+            .map_err(|error| format!("--print-dir-on-exit: {}", error))
+    }
+}
@@ -0,0 +1,224 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! `--list-columns` and `--list-themes`: what this build can show.
+//!
+//! Both lists are generated rather than written down. Columns come from
+//! [`Column::all`], and the flags that enable each one are found by running
+//! [`Selector`] over every flag the CLI defines, so a new column or flag shows
+//! up here without touching this file. Themes come from [`Theme::BUILTIN`].
+
+use crate::cli::args::Args;
+use crate::display::layout::alignment::{Align, Alignment};
+use crate::display::layout::column::{Column, Selector};
+use crate::display::layout::width::Width;
+use crate::display::styles::element::ElementStyle;
+use crate::display::theme::colours::ColourPaint;
+use crate::display::theme::colours::ColourSettings;
+use crate::display::theme::config::{self, Theme};
+use clap::{CommandFactory, Parser};
+use nu_ansi_term::Style;
+use std::collections::HashMap;
+
+/// The theme used when neither `--theme` nor the config names one.
+const DEFAULT_THEME: &str = "catppuccin-mocha";
+
+/// Prints the columns and themes this build supports.
+pub struct Catalog;
+
+impl Catalog {
+    /// Prints every column with its `--columns` name, header, and enabling flags.
+    pub fn print_columns() {
+        let baseline = Selector::select(&Args::parse_from(["ce"]));
+        let flags = Self::enabling_flags(&baseline);
+        let rows: Vec<[String; 3]> = Column::all()
+            .into_iter()
+            .map(|column| {
+                let enabled_by = match flags.get(&column) {
+                    Some(flags) => flags.join(", "),
+                    None if baseline.contains(&column) => "always shown".to_string(),
+                    None => "--columns only".to_string(),
+                };
+                [
+                    column.name().to_string(),
+                    column.header().to_string(),
+                    enabled_by,
+                ]
+            })
+            .collect();
+
+        let header = ["Column", "Header", "Enabled by"].map(String::from);
+        let widths: Vec<usize> = (0..2)
+            .map(|index| {
+                rows.iter()
+                    .chain([&header])
+                    .map(|row| Width::measure_ansi_text(&row[index]))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let line = |row: &[String; 3]| {
+            format!(
+                "{}  {}  {}",
+                Align::pad(&row[0], widths[0], Alignment::Left),
+                Align::pad(&row[1], widths[1], Alignment::Left),
+                row[2]
+            )
+        };
+
+        println!("{}", ElementStyle::table_header(&line(&header)));
+        for row in &rows {
+            println!("{}", line(row));
+        }
+    }
+
+    /// Prints every built-in theme, with a swatch of its colours when colour is on.
+    ///
+    /// # Parameters
+    /// - `args`: CLI arguments; the theme in effect (`--theme`, else the config's) is marked.
+    pub fn print_themes(args: &Args) {
+        let configured = config::table()
+            .and_then(|table| table.get("theme")?.as_str())
+            .filter(|name| Theme::builtin(name).is_some());
+        let active = args
+            .theme
+            .as_deref()
+            .or(configured)
+            .unwrap_or(DEFAULT_THEME);
+
+        let width = Theme::BUILTIN
+            .iter()
+            .map(|name| name.len())
+            .max()
+            .unwrap_or(0);
+
+        for name in Theme::BUILTIN {
+            let marker = if name == active { "*" } else { " " };
+            let swatch = Theme::builtin(name)
+                .filter(|_| ColourSettings::is_enabled())
+                .map(|theme| Self::swatch(&theme))
+                .unwrap_or_default();
+            if swatch.is_empty() {
+                println!("{marker} {name}");
+            } else {
+                println!("{marker} {name:<width$}  {swatch}");
+            }
+        }
+    }
+
+    /// Paints a block in each of a theme's most visible colours.
+    ///
+    /// # Parameters
+    /// - `theme`: The theme to sample.
+    fn swatch(theme: &Theme) -> String {
+        [
+            &theme.entry_directory,
+            &theme.entry_file,
+            &theme.entry_symlink,
+            &theme.code_rust,
+            &theme.code_python,
+            &theme.web_json,
+            &theme.doc_markdown,
+            &theme.media_image,
+            &theme.archive,
+            &theme.table_header,
+        ]
+        .iter()
+        .map(|entry| Style::new().fg(entry.colour).apply_to("██"))
+        .collect()
+    }
+
+    /// Finds the flags that add each column, by selecting columns for every flag in turn.
+    ///
+    /// Flags that take a value are tried with each of their possible values, or
+    /// with a placeholder. A column's flags are ordered from the most specific
+    /// (adding the fewest columns) to the least, so `--permissions` comes
+    /// before `--long`.
+    ///
+    /// # Parameters
+    /// - `baseline`: The columns shown when no flags are given.
+    ///
+    /// # Returns
+    /// The flags that add each column, as they would be typed.
+    fn enabling_flags(baseline: &[Column]) -> HashMap<Column, Vec<String>> {
+        let mut command = Args::command();
+        command.build();
+
+        let mut found: HashMap<Column, Vec<(usize, String)>> = HashMap::new();
+
+        for arg in command.get_arguments() {
+            let Some(long) = arg.get_long() else {
+                continue;
+            };
+            // `--columns` lists them all by name; hidden flags aren't advertised
+            if arg.is_hide_set() || arg.get_id() == "columns" {
+                continue;
+            }
+
+            let tries: Vec<(String, String)> = if !arg.get_action().takes_values() {
+                vec![(format!("--{long}"), format!("--{long}"))]
+            } else if arg.get_possible_values().is_empty() {
+                let placeholder = arg
+                    .get_value_names()
+                    .and_then(|names| names.first())
+                    .map_or("VALUE".to_string(), |name| name.to_string());
+                vec![(format!("--{long} {placeholder}"), format!("--{long}=1"))]
+            } else {
+                arg.get_possible_values()
+                    .iter()
+                    .filter(|value| !value.is_hide_set())
+                    .map(|value| {
+                        let value = value.get_name();
+                        (format!("--{long} {value}"), format!("--{long}={value}"))
+                    })
+                    .collect()
+            };
+
+            for (shown, typed) in tries {
+                let Ok(args) = Args::try_parse_from(["ce", typed.as_str()]) else {
+                    continue;
+                };
+                let added: Vec<Column> = Selector::select(&args)
+                    .into_iter()
+                    .filter(|column| !baseline.contains(column))
+                    .collect();
+                for column in &added {
+                    found
+                        .entry(*column)
+                        .or_default()
+                        .push((added.len(), shown.clone()));
+                }
+            }
+        }
+
+        found
+            .into_iter()
+            .map(|(column, mut flags)| {
+                flags.sort();
+                (column, flags.into_iter().map(|(_, flag)| flag).collect())
+            })
+            .collect()
+    }
+}
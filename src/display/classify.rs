@@ -26,7 +26,7 @@ SOFTWARE.
 //!
 //! This mirrors GNU coreutils `ls`'s `get_type_indicator()`: the character
 //! appended after an entry name depends on the entry's `st_mode` and the
-//! selected [`IndicatorStyle`].
+//! selected [`IndicatorStyle`], plus BSD's `%` for overlayfs whiteouts.
 
 use crate::cli::args::Args;
 use crate::cli::flags::IndicatorStyle;
@@ -89,12 +89,17 @@ pub(crate) fn indicator(entry: &Entry, args: &Args) -> Option<char> {
             if style == IndicatorStyle::Slash {
                 return None;
             }
-            let mode = entry
+            let meta = entry
                 .metadata()
                 .filter(|meta| meta.mode != 0)
-                .map(|meta| meta.mode)
-                .or_else(|| Metadata::load(entry.path(), false).ok().map(|m| m.mode))?;
-            from_mode(mode, style)
+                .cloned()
+                .or_else(|| Metadata::load(entry.path(), false).ok())?;
+
+            // BSD `ls -F` marks whiteouts with '%'; overlayfs stores them as 0/0 devices
+            if meta.is_whiteout() {
+                return Some('%');
+            }
+            from_mode(meta.mode, style)
         }
     }
 }
@@ -0,0 +1,220 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+//! Change events for `--watch --output ndjson`.
+//!
+//! Instead of redrawing the listing, each change to a watched path prints one
+//! JSON line, the entry's usual object with the kind of change in front:
+//!
+//! ```text
+//! {"event":"created","name":"a.txt","path":"./a.txt","type":"file","size":0,...}
+//! {"event":"deleted","name":"a.txt","path":"./a.txt","type":"file","size":0,...}
+//! ```
+//!
+//! The watch backends only name the paths that changed, so the kind is worked
+//! out against the entries listed before the change: a path that wasn't listed
+//! was created, a listed one that is gone was deleted, and one still there was
+//! modified. Deleted entries carry the metadata they had last. The listing
+//! filters (`-a`, `--hide`, ...) apply, so hidden entries make no events.
+
+use crate::cli::args::Args;
+use crate::display::output::json::Json;
+use crate::fs::dir::DirReader;
+use crate::fs::entry::Entry;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// What happened to an entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change {
+    Created,
+    Modified,
+    Deleted,
+}
+
+impl Change {
+    /// Returns the value of the `event` field.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Modified => "modified",
+            Self::Deleted => "deleted",
+        }
+    }
+}
+
+/// The entries of the watched paths, compared against after every change.
+pub struct Events {
+    /// Command-line arguments (the watched paths and listing filters).
+    args: Args,
+    /// The entries last listed for each watched path, by their own path.
+    listed: HashMap<PathBuf, HashMap<PathBuf, Entry>>,
+}
+
+impl Events {
+    /// Lists the watched paths, so later changes can be told apart.
+    ///
+    /// # Parameters
+    /// - `args`: Command-line arguments naming the watched paths.
+    pub fn new(args: &Args) -> Self {
+        let listed = args
+            .paths
+            .iter()
+            .map(|root| (root.clone(), Self::list(root, args)))
+            .collect();
+
+        Self {
+            args: args.clone(),
+            listed,
+        }
+    }
+
+    /// Prints an event for each changed path and remembers the new entries.
+    ///
+    /// # Parameters
+    /// - `changed`: The paths reported by [`Watch::wait`](crate::fs::watch::Watch::wait).
+    pub fn record(&mut self, changed: &[PathBuf]) {
+        let mut out = io::stdout().lock();
+
+        for (root, paths) in self.by_root(changed) {
+            let now = Self::list(&root, &self.args);
+            let before = self.listed.insert(root.clone(), now).unwrap_or_default();
+            let now = &self.listed[&root];
+
+            // A watched directory reported as a whole (kqueue) may hide any change inside it
+            let whole = paths.contains(&root) && root.is_dir();
+            let candidates: Vec<&PathBuf> = if whole {
+                let mut all: Vec<&PathBuf> = before.keys().chain(now.keys()).collect();
+                all.sort();
+                all.dedup();
+                all
+            } else {
+                paths.iter().collect()
+            };
+
+            for path in candidates {
+                let event = match (before.get(path), now.get(path)) {
+                    (None, Some(entry)) => Some((Change::Created, entry)),
+                    (Some(entry), None) => Some((Change::Deleted, entry)),
+                    // A path named by the backend changed; others only if their metadata moved
+                    (Some(old), Some(entry)) => {
+                        (!whole || Self::differs(old, entry)).then_some((Change::Modified, entry))
+                    }
+                    (None, None) => None,
+                };
+
+                if let Some((change, entry)) = event
+                    && writeln!(out, "{}", Self::line(change, entry)).is_err()
+                {
+                    return;
+                }
+            }
+        }
+
+        let _ = out.flush();
+    }
+
+    /// Encodes one event as a JSON line.
+    ///
+    /// # Parameters
+    /// - `change`: What happened.
+    /// - `entry`: The entry it happened to (as last seen, for deletions).
+    ///
+    /// # Returns
+    /// The entry's JSON object with an `event` field first.
+    pub fn line(change: Change, entry: &Entry) -> String {
+        let object = Json::entry(entry);
+        format!("{{\"event\":\"{}\",{}", change.label(), &object[1..])
+    }
+
+    /// Groups changed paths by the watched path they belong to.
+    ///
+    /// # Parameters
+    /// - `changed`: The changed paths.
+    ///
+    /// # Returns
+    /// Each affected watched path with its changed paths, in the order given.
+    fn by_root(&self, changed: &[PathBuf]) -> Vec<(PathBuf, Vec<PathBuf>)> {
+        let mut groups: Vec<(PathBuf, Vec<PathBuf>)> = Vec::new();
+
+        for path in changed {
+            let root = if self.listed.contains_key(path) {
+                path.as_path()
+            } else {
+                match path.parent() {
+                    Some(parent) if self.listed.contains_key(parent) => parent,
+                    _ => continue,
+                }
+            };
+
+            match groups.iter_mut().find(|(known, _)| known == root) {
+                Some((_, paths)) => paths.push(path.clone()),
+                None => groups.push((root.to_path_buf(), vec![path.clone()])),
+            }
+        }
+
+        groups
+    }
+
+    /// Lists a watched path with metadata: a directory's entries, or a file itself.
+    ///
+    /// # Parameters
+    /// - `root`: The watched path.
+    /// - `args`: Command-line arguments controlling visibility.
+    ///
+    /// # Returns
+    /// The entries, by path; empty if `root` is gone.
+    fn list(root: &Path, args: &Args) -> HashMap<PathBuf, Entry> {
+        if root.symlink_metadata().is_err() {
+            return HashMap::new();
+        }
+
+        DirReader::from(root.to_path_buf())
+            .list(args)
+            .into_iter()
+            .map(|mut entry| {
+                entry.unconditional_metadata(args.dereference);
+                (entry.path().to_path_buf(), entry)
+            })
+            .collect()
+    }
+
+    /// Checks whether an entry changed between two listings.
+    ///
+    /// # Parameters
+    /// - `old`: The entry as listed before.
+    /// - `new`: The entry as listed now.
+    ///
+    /// # Returns
+    /// `true` if its inode, size, or modification or change time moved.
+    fn differs(old: &Entry, new: &Entry) -> bool {
+        match (old.metadata(), new.metadata()) {
+            (Some(old), Some(new)) => {
+                (old.ino, old.size, old.mtime, old.ctime)
+                    != (new.ino, new.size, new.mtime, new.ctime)
+            }
+            _ => true,
+        }
+    }
+}
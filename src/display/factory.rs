@@ -23,14 +23,28 @@ SOFTWARE.
 */
 
 use crate::cli::args::Args;
+use crate::cli::flags::OutputFormat;
 use crate::display::grid::Grid;
+use crate::display::html::HtmlPage;
+use crate::display::links::LinkMap;
 use crate::display::list::List;
 use crate::display::mode::DisplayMode;
+use crate::display::narrow::Narrow;
+use crate::display::ndjson::Ndjson;
+use crate::display::stream::LongStream;
 use crate::display::styles::element::ElementStyle;
+use crate::display::tally::Tally;
 use crate::display::tree::Tree;
+use crate::display::usage::InodeUsage;
+use crate::fs::cache::CacheSettings;
+use crate::fs::dedupe::Dedupe;
 use crate::fs::dir::DirReader;
+use crate::fs::git::Git;
+#[cfg(feature = "oci")]
+use crate::fs::oci::Image;
 use crate::fs::search::Search;
 use crate::fs::tree::TreeBuilder;
+use std::path::PathBuf;
 
 /// Selects and creates the appropriate display mode based on CLI arguments.
 pub struct DisplayFactory;
@@ -45,6 +59,19 @@ impl DisplayFactory {
     /// # Returns
     /// A boxed [`DisplayMode`] trait object ready to produce output.
     pub fn create(dir_reader: &DirReader, args: Args) -> Box<dyn DisplayMode> {
+        // Image tarball: the merged layers, always as a tree
+        #[cfg(feature = "oci")]
+        if args.oci {
+            let args = Args { tree: true, ..args };
+            return match Image::open(dir_reader.path()) {
+                Ok(image) => Box::new(Tree::new_table(image.tree(&args), args)),
+                Err(e) => {
+                    eprintln!("Cannot read image '{}': {}", dir_reader.path().display(), e);
+                    Box::new(List::new(Vec::new(), args))
+                }
+            };
+        }
+
         // Find/Search mode
         if !args.find.is_empty() {
             let search = match Search::new(&args.find, dir_reader.path().clone()) {
@@ -55,13 +82,45 @@ impl DisplayFactory {
                 }
             };
             let matches = search.find(&args);
-            return if Self::needs_list_renderer(&args) {
+            return if args.output.is_json() {
+                Box::new(Ndjson::new(matches, args))
+            } else if args.output == OutputFormat::Html {
+                Box::new(HtmlPage::new(matches, args))
+            } else if Self::needs_list_renderer(&args) {
                 Box::new(List::new(matches, args))
             } else {
                 Box::new(Grid::new(matches, args))
             };
         }
 
+        // A standalone page, as a table (descends on its own for --recursive/--tree)
+        if args.output == OutputFormat::Html {
+            let entries = dir_reader.list(&args);
+            return Box::new(HtmlPage::new(entries, args));
+        }
+
+        // Inode usage report
+        if args.inode_usage {
+            let entries = dir_reader.list(&args);
+            return Box::new(InodeUsage::new(dir_reader.path().clone(), entries, args));
+        }
+
+        // Symlink audit table
+        if args.link_map {
+            return Box::new(LinkMap::new(dir_reader.path().clone(), args));
+        }
+
+        // Unaligned long format, printed as each entry is statted
+        if args.long_stream {
+            return Box::new(LongStream::new(dir_reader.path().clone(), args));
+        }
+
+        // Machine-readable output (descends on its own for --recursive/--tree)
+        if args.output.is_json() {
+            let entries = dir_reader.list(&args);
+            return Box::new(Ndjson::new(entries, args));
+        }
+
         // Tree mode
         if args.tree {
             // Use streaming mode for instant output when no table columns are needed
@@ -80,18 +139,161 @@ impl DisplayFactory {
         // Print directory title for recursive mode
         if args.recursive {
             println!(
-                "{}:",
-                ElementStyle::path_header(dir_reader.path().display())
+                "{}: {}",
+                ElementStyle::path_header(dir_reader.path().display()),
+                ElementStyle::entry_count(entries.len())
             );
         }
 
-        if Self::needs_list_renderer(&args) {
+        let args = Self::spread_oneline(args, entries.len());
+        if Self::needs_narrow_renderer(&args) {
+            Box::new(Narrow::new(entries, args))
+        } else if Self::needs_list_renderer(&args) {
             Box::new(List::new(entries, args))
         } else {
             Box::new(Grid::new(entries, args))
         }
     }
 
+    /// Prints a listing for every path operand, like `ls dir1 dir2 file.txt`.
+    ///
+    /// A single path is listed exactly as [`DisplayFactory::create`] would. With
+    /// several, files are listed together first and each directory follows under a
+    /// `path:` header; recursive and tree listings already name their roots, and
+    /// NDJSON stays one object per line. `--output json` wraps it all in one
+    /// document with the collected errors, and `--output html` in one page with
+    /// a heading row per directory. `--grand-total` follows text output with one
+    /// footer covering every path.
+    ///
+    /// # Parameters
+    /// - `paths`: The existing paths given on the command line.
+    /// - `args`: Command-line arguments controlling display options.
+    ///
+    /// # Returns
+    /// The [`Tally`] of everything listed, holding its paths for `--copy-paths`.
+    pub fn print_all(paths: &[PathBuf], args: &Args) -> Tally {
+        let mut tally = Tally::new(args);
+
+        // `git status` still runs once per repository, but never for two listings
+        if CacheSettings::is_disabled() {
+            Git::refresh();
+        }
+
+        // `--dedupe` lists a file once across every operand and section
+        Dedupe::across(|| match args.output {
+            OutputFormat::Json => Ndjson::document(|| Self::print_each(paths, args, &mut tally)),
+            OutputFormat::Html => HtmlPage::document(paths, args, &mut tally, |tally| {
+                Self::print_each(paths, args, tally)
+            }),
+            OutputFormat::Text | OutputFormat::Ndjson => Self::print_each(paths, args, &mut tally),
+        });
+
+        if args.grand_total && args.output == OutputFormat::Text {
+            tally.print(args);
+        }
+        tally
+    }
+
+    /// Prints the listing for each path operand, as described on [`DisplayFactory::print_all`].
+    ///
+    /// # Parameters
+    /// - `paths`: The existing paths given on the command line.
+    /// - `args`: Command-line arguments controlling display options.
+    /// - `tally`: Counts every entry listed, for the `--grand-total` footer.
+    fn print_each(paths: &[PathBuf], args: &Args, tally: &mut Tally) {
+        if let [path] = paths {
+            Self::create(&DirReader::from(path.clone()), args.clone()).print(tally);
+            return;
+        }
+
+        let groups_files = args.find.is_empty()
+            && !(args.tree || args.inode_usage || args.link_map || args.long_stream);
+        let (files, dirs): (Vec<PathBuf>, Vec<PathBuf>) = paths
+            .iter()
+            .cloned()
+            .partition(|path| groups_files && !path.is_dir());
+
+        let is_text = args.output == OutputFormat::Text;
+        let headed = !(args.recursive || args.tree) && is_text;
+
+        if !files.is_empty() {
+            Self::create_for_operands(&files, args.clone()).print(tally);
+        }
+
+        for (index, dir) in dirs.iter().enumerate() {
+            if is_text && (index > 0 || !files.is_empty()) {
+                println!();
+            }
+            if headed {
+                println!("{}:", ElementStyle::path_header(dir.display()));
+            } else if args.output == OutputFormat::Html {
+                HtmlPage::heading(dir, args);
+            }
+
+            let dir_args = Args {
+                paths: vec![dir.clone()],
+                ..args.clone()
+            };
+            Self::create(&DirReader::from(dir.clone()), dir_args).print(tally);
+        }
+    }
+
+    /// Creates the display for the file operands of a multi-path listing.
+    ///
+    /// # Parameters
+    /// - `paths`: The non-directory paths given on the command line.
+    /// - `args`: Command-line arguments controlling display options.
+    ///
+    /// # Returns
+    /// A boxed [`DisplayMode`] listing the files as one group.
+    fn create_for_operands(paths: &[PathBuf], args: Args) -> Box<dyn DisplayMode> {
+        let entries = DirReader::list_operands(paths, &args);
+
+        if args.output.is_json() {
+            Box::new(Ndjson::new(entries, args))
+        } else if args.output == OutputFormat::Html {
+            Box::new(HtmlPage::new(entries, args))
+        } else {
+            let args = Self::spread_oneline(args, entries.len());
+            if Self::needs_narrow_renderer(&args) {
+                Box::new(Narrow::new(entries, args))
+            } else if Self::needs_list_renderer(&args) {
+                Box::new(List::new(entries, args))
+            } else {
+                Box::new(Grid::new(entries, args))
+            }
+        }
+    }
+
+    /// Lays a `-1` listing out as a grid when `--fit-screen` finds it taller than the screen.
+    ///
+    /// Only applies when `-1` is all that asks for one name per line; long
+    /// listings and table columns keep their rows.
+    ///
+    /// # Parameters
+    /// - `args`: Command-line arguments controlling display options.
+    /// - `entries`: The number of entries to list.
+    ///
+    /// # Returns
+    /// `args`, with `-1` cleared if the listing should spread into a grid.
+    fn spread_oneline(args: Args, entries: usize) -> Args {
+        let spreads = args.oneline
+            && Grid::screen_rows(&args).is_some_and(|rows| entries > rows)
+            && !Self::needs_list_renderer(&Args {
+                oneline: false,
+                ..args.clone()
+            });
+
+        if spreads {
+            Args {
+                oneline: false,
+                ..args
+            }
+        } else {
+            args
+        }
+    }
+
     /// Checks whether the List renderer should be used instead of Grid.
     ///
     /// # Parameters
@@ -102,4 +304,23 @@ impl DisplayFactory {
     fn needs_list_renderer(args: &Args) -> bool {
         Args::is_args_requesting_metadata(args) || Args::is_args_requesting_table_column(args)
     }
+
+    /// Checks whether the stacked Narrow renderer should be used instead of List or Grid.
+    ///
+    /// `--narrow` asks for it outright; otherwise a table picks it on its own when
+    /// it would be laid out narrower than [`NARROW_WIDTH`](crate::display::narrow::NARROW_WIDTH) columns. A `--format`
+    /// template keeps its own layout.
+    ///
+    /// # Parameters
+    /// - `args`: Command-line arguments to examine.
+    ///
+    /// # Returns
+    /// `true` if the entries should be stacked under their names.
+    fn needs_narrow_renderer(args: &Args) -> bool {
+        if args.format.is_some() {
+            return false;
+        }
+
+        args.narrow || (Self::needs_list_renderer(args) && Narrow::is_too_narrow(args))
+    }
 }
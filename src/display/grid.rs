@@ -23,21 +23,35 @@ SOFTWARE.
 */
 
 use crate::cli::args::Args;
+use crate::display::group::Sections;
 use crate::display::layout::alignment::Alignment;
 use crate::display::layout::column::Column;
 use crate::display::layout::term_grid::{
-    Cell as GridCell, Direction, Filling, GridOptions, TermGrid,
+    Cell as GridCell, Direction, Filling, GridDisplay, GridOptions, TermGrid,
 };
 use crate::display::layout::width::Width;
+use crate::display::limit::Limit;
 use crate::display::mode::DisplayMode;
 use crate::display::output::quotes::Quotes;
 use crate::display::styles::column::ColumnStyle;
+#[cfg(feature = "volumes")]
+use crate::display::styles::value::ValueStyle;
 use crate::display::summary;
 use crate::display::summary::Summary;
+use crate::display::tally::Tally;
 use crate::display::traversal::RecursiveTraversal;
 use crate::fs::entry::Entry;
+#[cfg(feature = "volumes")]
+use crate::fs::mountpoint::Mountpoint;
 use std::cell::Cell;
 
+/// Lines kept free under a `--fit-screen` grid: the blank line and summary
+/// after it, and the shell prompt.
+const RESERVED_ROWS: usize = 3;
+
+/// `--fit-screen` never shortens names below this many columns, ellipsis included.
+const MIN_NAME_WIDTH: usize = 8;
+
 impl DisplayMode for Grid {
     /// Prints the grid output, either recursively or non-recursively based on args.
     ///
@@ -46,11 +60,14 @@ impl DisplayMode for Grid {
     /// * If `args.recursive` is true, displays entries in a hierarchical format
     ///   with directory titles, recursing into subdirectories
     /// * Otherwise, displays entries in a compact grid layout
-    fn print(&self) {
+    fn print(&self, tally: &mut Tally) {
         if self.args.recursive {
-            self.render_recursive(&self.entries, None);
+            self.render_recursive(&self.entries, None, tally);
         } else {
-            self.nonrecursive(&self.entries);
+            tally.extend(&self.entries);
+            Limit::render(&self.entries, &self.args, |shown| {
+                Sections::render(shown, &self.args, |section| self.nonrecursive(section))
+            });
         }
 
         self.print_summary();
@@ -61,9 +78,12 @@ impl RecursiveTraversal for Grid {
     /// Renders entries at a single directory level in grid format.
     ///
     /// This implementation delegates to the existing `nonrecursive()` method
-    /// which handles grid layout calculation and multi-column rendering.
-    fn render_level(&self, entries: &[Entry], _args: &Args) {
-        self.nonrecursive(entries);
+    /// which handles grid layout calculation and multi-column rendering,
+    /// once per section when `--group-by` is set and capped by `--max-entries`.
+    fn render_level(&self, entries: &[Entry], args: &Args) {
+        Limit::render(entries, args, |shown| {
+            Sections::render(shown, args, |section| self.nonrecursive(section))
+        });
     }
 
     /// Returns a reference to the Args for this renderer.
@@ -145,8 +165,24 @@ impl Grid {
         let cells: Vec<GridCell> = entries
             .iter()
             .map(|entry| {
-                let styled_column =
+                let mut styled_column =
                     ColumnStyle::get(entry, &Column::Name, &self.args, add_alignment_space);
+
+                // `--git` keeps the grid, prefixing each name with its status
+                if self.args.git {
+                    let status = ColumnStyle::get(entry, &Column::Git, &self.args, false);
+                    styled_column = format!("{status} {styled_column}");
+                }
+
+                // `--volumes` badges subvolume and dataset roots after their name
+                #[cfg(feature = "volumes")]
+                if self.args.volumes
+                    && entry.is_dir()
+                    && let Some(volume) = Mountpoint::volume(entry.path())
+                {
+                    let badge = ValueStyle::volume(volume.label());
+                    styled_column = format!("{styled_column} [{badge}]");
+                }
                 let entry_width = Width::measure_ansi_text(&styled_column);
                 GridCell {
                     width: entry_width,
@@ -156,30 +192,119 @@ impl Grid {
             })
             .collect();
 
-        // Create the grid
+        // Each level of a recursive listing is fitted on its own, so only flat ones fit the screen
+        let layout = match Self::screen_rows(&self.args) {
+            Some(rows) if !self.args.recursive => Self::fit_screen(&cells, terminal_width, rows),
+            _ => Self::fit_grid(&Self::grid(&cells, None), terminal_width, entries.len()),
+        };
+
+        print!("{layout}");
+    }
+
+    /// Returns the rows a `--fit-screen` listing may fill.
+    ///
+    /// # Parameters
+    /// - `args`: Command-line arguments (`--fit-screen`).
+    ///
+    /// # Returns
+    /// The terminal height less the lines printed after the listing, or `None`
+    /// without `--fit-screen` or a known terminal height.
+    pub(crate) fn screen_rows(args: &Args) -> Option<usize> {
+        if !args.fit_screen {
+            return None;
+        }
+
+        Width::terminal_height().map(|height| height.saturating_sub(RESERVED_ROWS).max(1))
+    }
+
+    /// Builds the grid for a set of cells.
+    ///
+    /// # Parameters
+    /// - `cells`: The styled names.
+    /// - `name_width`: The width to shorten longer names to, if any.
+    fn grid(cells: &[GridCell], name_width: Option<usize>) -> TermGrid {
         let mut grid = TermGrid::new(GridOptions {
             filling: Filling::Spaces(2),
             direction: Direction::TopToBottom, // column-first layout
         });
 
-        for cell in &cells {
-            grid.add(cell.clone());
+        for cell in cells {
+            match name_width {
+                Some(width) if cell.width > width => {
+                    let contents = Width::truncate_ansi(&cell.contents, width);
+                    grid.add(GridCell {
+                        width: Width::measure_ansi_text(&contents),
+                        contents,
+                        alignment: cell.alignment,
+                    });
+                }
+                _ => grid.add(cell.clone()),
+            }
+        }
+
+        grid
+    }
+
+    /// Shortens the longest names until the grid fits in `rows` lines (`--fit-screen`).
+    ///
+    /// Narrower names leave room for more columns, and so fewer rows. The widest
+    /// name width that still fits is searched for, so names are only cut as far as
+    /// needed; if names as narrow as [`MIN_NAME_WIDTH`] still don't fit, the grid
+    /// uses that width and scrolls.
+    ///
+    /// # Parameters
+    /// - `cells`: The styled names.
+    /// - `terminal_width`: The visible width of the terminal in characters.
+    /// - `rows`: The lines the grid may fill.
+    ///
+    /// # Returns
+    /// The laid out grid.
+    fn fit_screen(cells: &[GridCell], terminal_width: usize, rows: usize) -> GridDisplay {
+        let layout = |name_width: Option<usize>| {
+            Self::fit_grid(&Self::grid(cells, name_width), terminal_width, cells.len())
+        };
+
+        let full = layout(None);
+        let widest = cells.iter().map(|cell| cell.width).max().unwrap_or(0);
+        if full.row_count() <= rows || widest <= MIN_NAME_WIDTH {
+            return full;
         }
 
-        Self::fit_grid(grid, terminal_width, entries.len())
+        let narrowest = layout(Some(MIN_NAME_WIDTH));
+        if narrowest.row_count() > rows {
+            return narrowest;
+        }
+
+        // `low` always fits and `high` is the widest width left to try
+        let (mut low, mut high) = (MIN_NAME_WIDTH, widest - 1);
+        let mut best = narrowest;
+        while low < high {
+            let mid = low + (high - low).div_ceil(2);
+            let fitted = layout(Some(mid));
+            if fitted.row_count() <= rows {
+                best = fitted;
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+
+        best
     }
 
-    /// Fits the grid into the terminal width and prints it.
+    /// Fits the grid into the terminal width.
     ///
     /// # Parameters
-    /// - `grid`: The fully populated [`TermGrid`] to print.
+    /// - `grid`: The fully populated [`TermGrid`] to lay out.
     /// - `terminal_width`: The visible width of the terminal in characters.
     /// - `entries_length`: The number of entries (caps the column count).
-    fn fit_grid(grid: TermGrid, terminal_width: usize, entries_length: usize) {
+    ///
+    /// # Returns
+    /// The layout with the most columns that fit.
+    fn fit_grid(grid: &TermGrid, terminal_width: usize, entries_length: usize) -> GridDisplay {
         // Try the easy fit first
         if let Some(fit) = grid.fit_into_width(terminal_width) {
-            print!("{fit}");
-            return;
+            return fit;
         }
 
         // Fallback: binary search for maximum columns that fit
@@ -210,12 +335,7 @@ impl Grid {
             }
         }
 
-        // Print best fit or fall back to single column
-        if let Some(best) = best_fit {
-            print!("{best}");
-        } else {
-            let single = grid.fit_into_columns(1);
-            print!("{single}");
-        }
+        // Best fit or fall back to single column
+        best_fit.unwrap_or_else(|| grid.fit_into_columns(1))
     }
 }
@@ -0,0 +1,206 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Sectioned rendering for `--group-by`.
+
+use crate::cli::args::Args;
+use crate::cli::flags::GroupBy;
+use crate::display::output::escape::Escape;
+use crate::display::output::formats::ownership::Ownership;
+use crate::display::styles::element::ElementStyle;
+use crate::fs::cache::Cache;
+use crate::fs::clock::{Clock, ClockSettings};
+use crate::fs::dir::DirReader;
+use crate::fs::entry::Entry;
+use libc::{S_IFBLK, S_IFCHR, S_IFIFO, S_IFMT, S_IFSOCK};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::UNIX_EPOCH;
+
+const DAY: i64 = 24 * 60 * 60;
+
+/// Splits a listing into titled sections and renders each one separately.
+pub(crate) struct Sections;
+
+impl Sections {
+    /// Renders entries, split into headed sections when `--group-by` is set.
+    ///
+    /// # Parameters
+    /// - `entries`: The (already sorted) entries to render.
+    /// - `args`: Command-line arguments carrying the grouping key.
+    /// - `render`: Renderer-specific closure that prints one section's entries.
+    pub(crate) fn render(entries: &[Entry], args: &Args, render: impl Fn(&[Entry])) {
+        let Some(group_by) = args.group_by else {
+            render(entries);
+            return;
+        };
+
+        for (index, (title, section)) in Self::split(entries, group_by, args).iter().enumerate() {
+            if index > 0 {
+                println!();
+            }
+            println!("{}", ElementStyle::section_header(title));
+            render(section);
+        }
+    }
+
+    /// Splits entries into titled sections, keeping the existing sort order within each.
+    ///
+    /// Sections are ordered by [`DirReader::sort_sections`], so `--reverse`,
+    /// `--sort-key` and `--sort none` apply to them as they do to entries.
+    ///
+    /// # Parameters
+    /// - `entries`: The entries to split.
+    /// - `group_by`: The grouping key.
+    /// - `args`: Command-line arguments (used for dereferencing, ownership format and sorting).
+    ///
+    /// # Returns
+    /// `(title, entries)` pairs in display order.
+    pub(crate) fn split(
+        entries: &[Entry],
+        group_by: GroupBy,
+        args: &Args,
+    ) -> Vec<(Arc<str>, Vec<Entry>)> {
+        // The leading rank pins fixed buckets (e.g. "Directories" first); the
+        // title then orders sections within a rank.
+        let mut sections: Vec<(u8, Arc<str>, Vec<Entry>)> = Vec::new();
+        let mut indices: HashMap<Arc<str>, usize> = HashMap::new();
+        let clock = ClockSettings::clock();
+
+        for entry in entries {
+            let mut entry = entry.clone();
+            if group_by != GroupBy::Ext {
+                entry.unconditional_metadata(args.dereference);
+            }
+
+            let (rank, title) = match group_by {
+                GroupBy::Ext => Self::by_extension(&entry),
+                GroupBy::Type => Self::by_type(&entry),
+                GroupBy::Owner => Self::by_owner(&entry, args),
+                GroupBy::Date => Self::by_date(&entry, clock.as_ref()),
+            };
+
+            let index = *indices.entry(title.clone()).or_insert_with(|| {
+                sections.push((rank, title, Vec::new()));
+                sections.len() - 1
+            });
+            sections[index].2.push(entry);
+        }
+
+        DirReader::sort_sections(&mut sections, args, |(rank, title, _)| (*rank, title));
+
+        sections
+            .into_iter()
+            .map(|(_, title, section)| (title, section))
+            .collect()
+    }
+
+    /// Groups directories first, then files by lowercase extension, then extensionless files.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry to classify.
+    ///
+    /// # Returns
+    /// The section's `(rank, title)` key.
+    fn by_extension(entry: &Entry) -> (u8, Arc<str>) {
+        if entry.is_dir_like() {
+            return (0, "Directories".into());
+        }
+
+        let extension = entry.extension();
+        if extension.is_empty() {
+            (2, "No extension".into())
+        } else {
+            (1, format!("*.{}", extension.to_lowercase()).into())
+        }
+    }
+
+    /// Groups entries by file type (directory, symlink, file, pipe, socket, device).
+    ///
+    /// # Parameters
+    /// - `entry`: The entry to classify (metadata loaded).
+    ///
+    /// # Returns
+    /// The section's `(rank, title)` key.
+    fn by_type(entry: &Entry) -> (u8, Arc<str>) {
+        if entry.is_dir() {
+            return (0, "Directories".into());
+        }
+        if entry.is_symlink() {
+            return (1, "Symlinks".into());
+        }
+
+        match entry.metadata().map(|meta| meta.mode & S_IFMT) {
+            Some(S_IFIFO) => (3, "Pipes".into()),
+            Some(S_IFSOCK) => (4, "Sockets".into()),
+            Some(S_IFBLK | S_IFCHR) => (5, "Devices".into()),
+            _ => (2, "Files".into()),
+        }
+    }
+
+    /// Groups entries by owning user, alphabetically.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry to classify (metadata loaded).
+    /// - `args`: Command-line arguments selecting name or numeric ownership.
+    ///
+    /// # Returns
+    /// The section's `(rank, title)` key.
+    fn by_owner(entry: &Entry, args: &Args) -> (u8, Arc<str>) {
+        let ownership = Ownership::new(args.ownership_format);
+        let uid = entry.metadata().map(|meta| meta.uid).unwrap_or_default();
+
+        (
+            0,
+            Cache::owner(uid, args.ownership_format, |uid| {
+                Escape::value(ownership.format_user(uid))
+            }),
+        )
+    }
+
+    /// Groups entries into modification-age buckets, newest first.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry to classify (metadata loaded).
+    /// - `clock`: The source of the current time.
+    ///
+    /// # Returns
+    /// The section's `(rank, title)` key.
+    fn by_date(entry: &Entry, clock: &dyn Clock) -> (u8, Arc<str>) {
+        let now = clock
+            .now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs() as i64)
+            .unwrap_or_default();
+        let age = now - entry.metadata().map(|meta| meta.mtime).unwrap_or_default();
+
+        match age {
+            age if age < DAY => (0, "Last 24 hours".into()),
+            age if age < 7 * DAY => (1, "Last 7 days".into()),
+            age if age < 30 * DAY => (2, "Last 30 days".into()),
+            age if age < 365 * DAY => (3, "Last year".into()),
+            _ => (4, "Older".into()),
+        }
+    }
+}
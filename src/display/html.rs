@@ -0,0 +1,258 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! `--output html`: the listing as a standalone HTML page.
+
+use crate::cli::args::Args;
+use crate::cli::flags::{ShowColour, StyleCaps};
+use crate::cli::settings::Settings;
+use crate::display::layout::alignment::Alignment;
+use crate::display::layout::column;
+use crate::display::layout::column::Column;
+use crate::display::mode::DisplayMode;
+use crate::display::output::html::Html;
+use crate::display::styles::column::ColumnStyle;
+use crate::display::styles::element::ElementStyle;
+use crate::display::styles::entry::StyledEntry;
+use crate::display::styles::value::ValueStyle;
+use crate::display::tally::Tally;
+use crate::display::tree::Tree;
+use crate::fs::dir::DirReader;
+use crate::fs::entry::Entry;
+use crate::fs::tree::Visited;
+use crate::fs::vfs::RealFs;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Page styles: a dark background for the theme colours, which are chosen for terminals.
+const STYLE: &str = "\
+body { background: #1d1f21; color: #c5c8c6; margin: 2em; \
+font-family: ui-monospace, \"JetBrains Mono\", \"Fira Code\", Menlo, monospace; }
+h1 { font-size: 1.1em; font-weight: normal; }
+table { border-collapse: collapse; }
+th { text-align: left; border-bottom: 1px solid #4d4d4d; padding: 0 1em 0.2em 0; }
+td { white-space: pre; padding: 0 1em 0 0; }
+.right { text-align: right; }
+tr.heading th { border-bottom: none; padding-top: 1em; }
+footer { margin-top: 1em; opacity: 0.6; }";
+
+/// HTML renderer: one table row per entry, descending into directories
+/// with `--recursive` or `--tree`.
+///
+/// Cells are styled as in the terminal and their colours written as inline
+/// CSS. Descended entries are drawn beneath their directory with tree
+/// connectors in the name cell, whichever of the two flags asked for them.
+pub(crate) struct HtmlPage {
+    entries: Vec<Entry>,
+    args: Args,
+}
+
+impl DisplayMode for HtmlPage {
+    /// Prints one `<tr>` per entry.
+    fn print(&self, tally: &mut Tally) {
+        let columns = Self::columns(&self.args);
+        let mut visited = Visited::new(&RealFs, self.args.root(), &self.args);
+        let mut out = BufWriter::new(io::stdout().lock());
+
+        self.emit(&self.entries, &columns, &[], &mut visited, tally, &mut out);
+        let _ = out.flush();
+    }
+}
+
+impl HtmlPage {
+    /// Creates a new [`HtmlPage`] renderer.
+    ///
+    /// # Parameters
+    /// - `entries`: The top-level entries to draw.
+    /// - `args`: Command-line arguments controlling columns, traversal and styling.
+    pub(crate) fn new(entries: Vec<Entry>, args: Args) -> Self {
+        Self { entries, args }
+    }
+
+    /// Wraps everything `print_entries` draws in a page, with a table header
+    /// for the selected columns and a footer counting what was listed.
+    ///
+    /// Colours are kept unless `--colours never` was given, even though the
+    /// page is usually written to a file rather than a terminal.
+    ///
+    /// # Parameters
+    /// - `paths`: The listed paths, for the title.
+    /// - `args`: Command-line arguments controlling columns and styling.
+    /// - `tally`: Counts every entry drawn.
+    /// - `print_entries`: Prints the listing(s) through [`HtmlPage`] renderers.
+    pub(crate) fn document(
+        paths: &[PathBuf],
+        args: &Args,
+        tally: &mut Tally,
+        print_entries: impl FnOnce(&mut Tally),
+    ) {
+        let mut settings = Settings::current();
+        settings.colours = !matches!(args.colours, ShowColour::Never);
+        settings.style_caps = StyleCaps::Full;
+
+        Arc::new(settings).scope(|| {
+            let title = paths
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(" ");
+            let headers: String = Self::columns(args)
+                .iter()
+                .map(|column| {
+                    format!(
+                        "<th{}>{}</th>",
+                        Self::class(column),
+                        Html::escape(column.header())
+                    )
+                })
+                .collect();
+
+            println!("<!DOCTYPE html>");
+            println!("<html>\n<head>\n<meta charset=\"utf-8\">");
+            println!("<title>{}</title>", Html::escape(&title));
+            println!("<style>\n{STYLE}\n</style>\n</head>\n<body>");
+            println!("<h1>{}</h1>", Html::escape(&title));
+            println!("<table>\n<thead><tr>{headers}</tr></thead>\n<tbody>");
+            print_entries(tally);
+            println!("</tbody>\n</table>");
+            println!("<footer>{}</footer>", Html::escape(&tally.format(args)));
+            println!("</body>\n</html>");
+        });
+    }
+
+    /// Prints a row naming one directory of a multi-path listing.
+    ///
+    /// # Parameters
+    /// - `path`: The directory about to be listed.
+    /// - `args`: Command-line arguments (the heading spans every column).
+    pub(crate) fn heading(path: &Path, args: &Args) {
+        println!(
+            "<tr class=\"heading\"><th colspan=\"{}\">{}:</th></tr>",
+            Self::columns(args).len(),
+            Html::from_ansi(&ElementStyle::path_header(path.display()))
+        );
+    }
+
+    /// Prints a level of entries, recursing into subdirectories when requested.
+    ///
+    /// # Parameters
+    /// - `entries`: The entries to print.
+    /// - `columns`: The columns of each row.
+    /// - `parents_last`: Whether each directory above this level was the last of its own.
+    /// - `visited`: The directories open above this one.
+    /// - `tally`: Counts every entry printed.
+    /// - `out`: Where the rows are written.
+    fn emit(
+        &self,
+        entries: &[Entry],
+        columns: &[Column],
+        parents_last: &[bool],
+        visited: &mut Visited,
+        tally: &mut Tally,
+        out: &mut impl Write,
+    ) {
+        let descend = self.args.recursive || self.args.tree;
+
+        for (index, entry) in entries.iter().enumerate() {
+            let mut entry = entry.clone();
+            entry.conditional_metadata(&self.args);
+            tally.add(&entry);
+
+            let mut position = parents_last.to_vec();
+            if descend {
+                position.push(index + 1 == entries.len());
+            }
+            if writeln!(out, "{}", self.row(&entry, columns, &position)).is_err() {
+                return;
+            }
+
+            if descend && visited.enter(&RealFs, &entry, &self.args) {
+                let children = DirReader::from(entry.path().clone()).list(&self.args);
+                self.emit(&children, columns, &position, visited, tally, out);
+                visited.leave();
+            }
+        }
+    }
+
+    /// Renders one entry as a `<tr>`.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry to render.
+    /// - `columns`: The columns of the row.
+    /// - `position`: The entry's place in the tree, for its connector (empty when not descending).
+    fn row(&self, entry: &Entry, columns: &[Column], position: &[bool]) -> String {
+        let mut row = String::from("<tr>");
+        let mut cell = String::new();
+
+        for column in columns {
+            cell.clear();
+            if *column == Column::Name && !position.is_empty() {
+                let connector = Tree::draw_connector(position);
+                cell.push_str(&ElementStyle::tree_connector(&connector));
+            }
+
+            // A tree draws names unquoted, as `Tree` does
+            if *column == Column::Name && self.args.tree {
+                let view = StyledEntry::new(entry, &self.args).load(&self.args, false);
+                cell.push_str(&ValueStyle::name(&view.name, view.colour));
+            } else {
+                ColumnStyle::write(&mut cell, entry, column, &self.args, false);
+            }
+
+            row.push_str(&format!(
+                "<td{}>{}</td>",
+                Self::class(column),
+                Html::from_ansi(&cell)
+            ));
+        }
+
+        row.push_str("</tr>");
+        row
+    }
+
+    /// Returns the columns of each row: those the terminal listing would show,
+    /// with the name last where a tree draws it after the table.
+    ///
+    /// # Parameters
+    /// - `args`: Command-line arguments selecting the columns.
+    fn columns(args: &Args) -> Vec<Column> {
+        let mut columns = column::Selector::select(args);
+        if !columns.contains(&Column::Name) {
+            columns.push(Column::Name);
+        }
+        columns
+    }
+
+    /// Returns the `class` attribute aligning a column's cells.
+    ///
+    /// # Parameters
+    /// - `column`: The column.
+    fn class(column: &Column) -> &'static str {
+        match column.alignment() {
+            Alignment::Right => " class=\"right\"",
+            Alignment::Left => "",
+        }
+    }
+}
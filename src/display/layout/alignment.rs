@@ -23,6 +23,7 @@ SOFTWARE.
 */
 
 use crate::display::layout::width::Width;
+use std::iter;
 
 /// Text alignment direction within a column.
 #[derive(Debug, Copy, Clone)]
@@ -44,12 +45,31 @@ impl Align {
     ///
     /// # Returns
     /// The padded string.
-    pub fn pad(value: &String, width: usize, alignment: Alignment) -> String {
+    pub fn pad(value: &str, width: usize, alignment: Alignment) -> String {
+        let mut padded = String::with_capacity(value.len().max(width));
+        Self::pad_into(&mut padded, value, width, alignment);
+        padded
+    }
+
+    /// Appends a string onto `out`, padded to the target width using the given alignment.
+    ///
+    /// # Parameters
+    /// - `out`: The buffer to append to.
+    /// - `value`: The string to pad (may contain ANSI codes).
+    /// - `width`: The target display width.
+    /// - `alignment`: Whether to left- or right-align the value.
+    pub fn pad_into(out: &mut String, value: &str, width: usize, alignment: Alignment) {
         let visible = Width::measure_ansi_text(value);
-        let padding = width.saturating_sub(visible);
+        let padding = iter::repeat_n(' ', width.saturating_sub(visible));
         match alignment {
-            Alignment::Right => format!("{}{}", " ".repeat(padding), value),
-            Alignment::Left => format!("{}{}", value, " ".repeat(padding)),
+            Alignment::Right => {
+                out.extend(padding);
+                out.push_str(value);
+            }
+            Alignment::Left => {
+                out.push_str(value);
+                out.extend(padding);
+            }
         }
     }
 }
@@ -0,0 +1,75 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+//! Isolation of right-to-left names.
+//!
+//! Terminals that implement the Unicode bidirectional algorithm reorder each
+//! line as a whole, so a Hebrew or Arabic name can trade places with the
+//! columns next to it. Wrapping such names in FIRST STRONG ISOLATE (U+2068) and
+//! POP DIRECTIONAL ISOLATE (U+2069) keeps the reordering inside the name. Both
+//! marks are zero width, so column widths and alignment don't change.
+
+use std::borrow::Cow;
+
+/// Starts an isolated run whose direction comes from its first strong character.
+pub const FIRST_STRONG_ISOLATE: char = '\u{2068}';
+
+/// Ends the run started by [`FIRST_STRONG_ISOLATE`].
+pub const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
+
+/// Detects right-to-left text and isolates it.
+pub struct Bidi;
+
+impl Bidi {
+    /// Checks whether a character belongs to a right-to-left script.
+    ///
+    /// # Parameters
+    /// - `character`: The character to check.
+    ///
+    /// # Returns
+    /// `true` for Hebrew, Arabic, Syriac, Thaana, NKo and the other scripts
+    /// Unicode writes right to left, including their presentation forms.
+    pub fn is_rtl(character: char) -> bool {
+        matches!(
+            character as u32,
+            0x0590..=0x08FF | 0xFB1D..=0xFDFF | 0xFE70..=0xFEFE | 0x10800..=0x10FFF | 0x1E800..=0x1EFFF
+        )
+    }
+
+    /// Wraps a name in isolation marks if it holds right-to-left text.
+    ///
+    /// # Parameters
+    /// - `name`: The name to isolate.
+    ///
+    /// # Returns
+    /// The isolated name, or `name` unchanged if it's written left to right.
+    pub fn isolate(name: &str) -> Cow<'_, str> {
+        if name.chars().any(Self::is_rtl) {
+            Cow::Owned(format!(
+                "{FIRST_STRONG_ISOLATE}{name}{POP_DIRECTIONAL_ISOLATE}"
+            ))
+        } else {
+            Cow::Borrowed(name)
+        }
+    }
+}
@@ -28,23 +28,43 @@ use crate::cli::args::Args;
 use crate::cli::flags::HashAlgorithm;
 
 use crate::display::layout::alignment::{Align, Alignment};
+use crate::display::layout::locale::HeaderLocale;
 use crate::display::layout::width::Width;
 use crate::display::styles::element::ElementStyle;
+use crate::fs::trash::Trash;
 use std::collections::HashMap;
+use std::str::FromStr;
 
 /// Identifies a data column in the tabular output.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Column {
-    #[cfg(all(feature = "magic", not(target_os = "android")))]
+    #[cfg(feature = "magic")]
     Magic,
 
     #[cfg(feature = "checksum")]
     Checksum(HashAlgorithm),
 
+    #[cfg(feature = "checksum")]
+    Verify,
+
+    #[cfg(feature = "apps")]
+    App,
+
+    #[cfg(feature = "volumes")]
+    Volume,
+
     Xattr,
     Acl,
     Context,
     Mountpoint,
+    NameCheck,
+    Note,
+    Description,
+    TrashOrigin,
+    TrashDeleted,
+    Head,
+    Tail,
+    Git,
     Permissions,
     HardLinks,
     User,
@@ -52,20 +72,147 @@ pub enum Column {
     Blocks,
     BlockSize,
     Created,
+    Changed,
     Accessed,
     Modified,
     Size,
+    SizeGraph,
+    Allocated,
+    Ratio,
     Name,
     Inode,
 }
 
 impl Column {
-    /// Returns the display header label for this column.
-    pub(crate) fn header(&self) -> &str {
+    /// Returns every column this build supports, in the order the selector adds them.
+    pub(crate) fn all() -> Vec<Column> {
+        let mut columns = vec![
+            Column::Permissions,
+            Column::User,
+            Column::Group,
+            Column::Size,
+            Column::SizeGraph,
+            Column::Allocated,
+            Column::Ratio,
+        ];
+
+        #[cfg(feature = "magic")]
+        columns.push(Column::Magic);
+
+        #[cfg(feature = "checksum")]
+        {
+            use clap::ValueEnum;
+            columns.extend(
+                HashAlgorithm::value_variants()
+                    .iter()
+                    .map(|algo| Column::Checksum(*algo)),
+            );
+            columns.push(Column::Verify);
+        }
+
+        #[cfg(feature = "apps")]
+        columns.push(Column::App);
+
+        columns.extend([
+            Column::Xattr,
+            Column::Acl,
+            Column::Context,
+            Column::Mountpoint,
+        ]);
+
+        #[cfg(feature = "volumes")]
+        columns.push(Column::Volume);
+
+        columns.extend([
+            Column::NameCheck,
+            Column::Note,
+            Column::Description,
+            Column::TrashOrigin,
+            Column::TrashDeleted,
+            Column::Head,
+            Column::Tail,
+            Column::Inode,
+            Column::Blocks,
+            Column::HardLinks,
+            Column::BlockSize,
+            Column::Created,
+            Column::Changed,
+            Column::Modified,
+            Column::Accessed,
+            Column::Git,
+            Column::Name,
+        ]);
+        columns
+    }
+
+    /// Returns the name `--columns` and `--format` know this column by.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Self::Name => "name",
+
+            #[cfg(feature = "magic")]
+            Self::Magic => "magic",
+
+            #[cfg(feature = "checksum")]
+            Column::Checksum(algo) => match algo {
+                HashAlgorithm::Md5 => "md5",
+                HashAlgorithm::Crc32 => "crc32",
+                HashAlgorithm::Sha224 => "sha224",
+                HashAlgorithm::Sha256 => "sha256",
+                HashAlgorithm::Sha384 => "sha384",
+                HashAlgorithm::Sha512 => "sha512",
+            },
+
+            #[cfg(feature = "checksum")]
+            Self::Verify => "verify",
+
+            #[cfg(feature = "apps")]
+            Self::App => "app",
+
+            #[cfg(feature = "volumes")]
+            Self::Volume => "volume",
+
+            Self::Xattr => "xattr",
+            Self::Acl => "acl",
+            Self::Context => "context",
+            Self::Mountpoint => "mountpoint",
+            Self::NameCheck => "namecheck",
+            Self::Note => "note",
+            Self::Description => "description",
+            Self::TrashOrigin => "origin",
+            Self::TrashDeleted => "deleted",
+            Self::Head => "head",
+            Self::Tail => "tail",
+            Self::Git => "git",
+            Self::Inode => "inode",
+            Self::Permissions => "perm",
+            Self::HardLinks => "links",
+            Self::User => "user",
+            Self::Group => "group",
+            Self::Blocks => "blocks",
+            Self::BlockSize => "blocksize",
+            Self::Size => "size",
+            Self::SizeGraph => "graph",
+            Self::Allocated => "allocated",
+            Self::Ratio => "ratio",
+            Self::Created => "created",
+            Self::Changed => "ctime",
+            Self::Accessed => "atime",
+            Self::Modified => "mtime",
+        }
+    }
+
+    /// Returns the display header label for this column, in the configured language.
+    pub(crate) fn header(&self) -> &'static str {
+        HeaderLocale::translate(self)
+    }
+
+    /// Returns the English header label, used when a language has no translation.
+    pub(crate) fn english_header(&self) -> &'static str {
         match self {
             Self::Name => "Name",
 
-            #[cfg(all(feature = "magic", not(target_os = "android")))]
+            #[cfg(feature = "magic")]
             Self::Magic => "Magic",
 
             #[cfg(feature = "checksum")]
@@ -78,10 +225,27 @@ impl Column {
                 HashAlgorithm::Sha512 => "SHA-512",
             },
 
+            #[cfg(feature = "checksum")]
+            Self::Verify => "Verify",
+
+            #[cfg(feature = "apps")]
+            Self::App => "App",
+
+            #[cfg(feature = "volumes")]
+            Self::Volume => "Volume",
+
             Self::Xattr => "Xattr",
             Self::Acl => "ACL",
             Self::Context => "Context",
             Self::Mountpoint => "Mountpoint",
+            Self::NameCheck => "Name Check",
+            Self::Note => "Note",
+            Self::Description => "Description",
+            Self::TrashOrigin => "Original Path",
+            Self::TrashDeleted => "Deleted",
+            Self::Head => "Head",
+            Self::Tail => "Tail",
+            Self::Git => "Git",
             Self::Inode => "inode",
             Self::Permissions => "Permissions",
             Self::HardLinks => "HardLinks",
@@ -90,18 +254,65 @@ impl Column {
             Self::Blocks => "Blocks",
             Self::BlockSize => "Block Size",
             Self::Size => "Size",
+            Self::SizeGraph => "Share",
+            Self::Allocated => "Allocated",
+            Self::Ratio => "Ratio",
             Self::Created => "Created",
+            Self::Changed => "Changed",
             Self::Accessed => "Accessed",
             Self::Modified => "Modified",
         }
     }
 
+    /// Checks whether the column's value comes from the entry's metadata, so it
+    /// can't be shown when the metadata couldn't be read.
+    ///
+    /// Directory sizes are item counts and don't count.
+    pub(crate) fn reads_metadata(&self) -> bool {
+        matches!(
+            self,
+            Self::Inode
+                | Self::Permissions
+                | Self::HardLinks
+                | Self::User
+                | Self::Group
+                | Self::Blocks
+                | Self::BlockSize
+                | Self::Size
+                | Self::Allocated
+                | Self::Ratio
+                | Self::Created
+                | Self::Changed
+                | Self::Accessed
+                | Self::Modified
+        )
+    }
+
+    /// Checks whether the column's value is read from the file's contents, so
+    /// files over `--content-limit` are skipped.
+    pub(crate) fn reads_contents(&self) -> bool {
+        match self {
+            #[cfg(feature = "magic")]
+            Self::Magic => true,
+
+            #[cfg(feature = "checksum")]
+            Self::Checksum(_) | Self::Verify => true,
+
+            Self::Head | Self::Tail | Self::Description => true,
+            _ => false,
+        }
+    }
+
     /// Returns the text alignment for this column.
     pub(crate) fn alignment(&self) -> Alignment {
         match self {
             Self::Size
+            | Self::Allocated
+            | Self::Ratio
             | Self::Modified
             | Self::Created
+            | Self::Changed
+            | Self::TrashDeleted
             | Self::Accessed
             | Self::Inode
             | Self::HardLinks
@@ -138,6 +349,75 @@ impl Column {
 }
 
 /// Builds the ordered list of columns to display based on CLI arguments.
+impl FromStr for Column {
+    type Err = String;
+
+    /// Parses a column name as used by `--columns` and `--format`, e.g. `perm` or `mtime`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let column = match name {
+            "name" => Column::Name,
+            "perm" | "permissions" => Column::Permissions,
+            "links" | "nlink" => Column::HardLinks,
+            "user" => Column::User,
+            "group" => Column::Group,
+            "size" => Column::Size,
+            "graph" => Column::SizeGraph,
+            "allocated" => Column::Allocated,
+            "ratio" => Column::Ratio,
+            "blocks" => Column::Blocks,
+            "blocksize" => Column::BlockSize,
+            "inode" => Column::Inode,
+            "mtime" | "modified" => Column::Modified,
+            "created" => Column::Created,
+            "ctime" | "changed" => Column::Changed,
+            "atime" | "accessed" => Column::Accessed,
+            "git" => Column::Git,
+            "xattr" => Column::Xattr,
+            "acl" => Column::Acl,
+            "context" => Column::Context,
+            "mountpoint" => Column::Mountpoint,
+            "note" => Column::Note,
+            "description" => Column::Description,
+            "origin" => Column::TrashOrigin,
+            "deleted" => Column::TrashDeleted,
+            "head" => Column::Head,
+            "tail" => Column::Tail,
+            "namecheck" => Column::NameCheck,
+
+            #[cfg(feature = "magic")]
+            "magic" => Column::Magic,
+
+            #[cfg(feature = "apps")]
+            "app" => Column::App,
+
+            #[cfg(feature = "volumes")]
+            "volume" => Column::Volume,
+
+            #[cfg(feature = "checksum")]
+            "crc32" => Column::Checksum(HashAlgorithm::Crc32),
+            #[cfg(feature = "checksum")]
+            "md5" => Column::Checksum(HashAlgorithm::Md5),
+            #[cfg(feature = "checksum")]
+            "sha224" => Column::Checksum(HashAlgorithm::Sha224),
+            #[cfg(feature = "checksum")]
+            "sha256" => Column::Checksum(HashAlgorithm::Sha256),
+            #[cfg(feature = "checksum")]
+            "sha384" => Column::Checksum(HashAlgorithm::Sha384),
+            #[cfg(feature = "checksum")]
+            "sha512" => Column::Checksum(HashAlgorithm::Sha512),
+            #[cfg(feature = "checksum")]
+            "verify" => Column::Verify,
+
+            _ => {
+                return Err(format!(
+                    "unknown column '{name}' (expected e.g. perm, user, group, size, mtime, git or name)"
+                ));
+            }
+        };
+        Ok(column)
+    }
+}
+
 pub(crate) struct Selector;
 
 impl Selector {
@@ -149,6 +429,16 @@ impl Selector {
     /// # Returns
     /// An ordered vector of [`Column`] variants to display.
     pub(crate) fn select(args: &Args) -> Vec<Column> {
+        // `--columns` picks the set and order outright; a tree draws names itself
+        if !args.columns.is_empty() {
+            return args
+                .columns
+                .iter()
+                .copied()
+                .filter(|column| !(args.tree && *column == Column::Name))
+                .collect();
+        }
+
         let mut columns = Vec::new();
 
         if args.long {
@@ -168,6 +458,10 @@ impl Selector {
         if args.size && !columns.contains(&Column::Size) {
             columns.push(Column::Size);
         }
+        if args.compression && !columns.contains(&Column::Allocated) {
+            columns.push(Column::Allocated);
+            columns.push(Column::Ratio);
+        }
         if args.permissions && !columns.contains(&Column::Permissions) {
             columns.push(Column::Permissions);
         }
@@ -178,7 +472,7 @@ impl Selector {
             columns.push(Column::Group);
         }
 
-        #[cfg(all(feature = "magic", not(target_os = "android")))]
+        #[cfg(feature = "magic")]
         if args.magic && !columns.contains(&Column::Magic) {
             columns.push(Column::Magic);
         }
@@ -191,6 +485,16 @@ impl Selector {
             }
         }
 
+        #[cfg(feature = "checksum")]
+        if args.checksum_verify.is_some() && !columns.contains(&Column::Verify) {
+            columns.push(Column::Verify);
+        }
+
+        #[cfg(feature = "apps")]
+        if args.apps && !columns.contains(&Column::App) {
+            columns.push(Column::App);
+        }
+
         if args.xattr && !columns.contains(&Column::Xattr) {
             columns.push(Column::Xattr);
         }
@@ -203,6 +507,25 @@ impl Selector {
         if args.mountpoint && !columns.contains(&Column::Mountpoint) {
             columns.push(Column::Mountpoint);
         }
+        #[cfg(feature = "volumes")]
+        if args.volumes && !columns.contains(&Column::Volume) {
+            columns.push(Column::Volume);
+        }
+        if args.validate_names && !columns.contains(&Column::NameCheck) {
+            columns.push(Column::NameCheck);
+        }
+        if args.notes && !columns.contains(&Column::Note) {
+            columns.push(Column::Note);
+        }
+        if args.describe && !columns.contains(&Column::Description) {
+            columns.push(Column::Description);
+        }
+        if args.head.is_some() && !columns.contains(&Column::Head) {
+            columns.push(Column::Head);
+        }
+        if args.tail.is_some() && !columns.contains(&Column::Tail) {
+            columns.push(Column::Tail);
+        }
         if args.inode && !columns.contains(&Column::Inode) {
             columns.push(Column::Inode);
         }
@@ -218,12 +541,35 @@ impl Selector {
         if args.created && !columns.contains(&Column::Created) {
             columns.push(Column::Created);
         }
+        if args.changed && !columns.contains(&Column::Changed) {
+            columns.push(Column::Changed);
+        }
         if args.modified && !columns.contains(&Column::Modified) {
             columns.push(Column::Modified);
         }
         if args.accessed && !columns.contains(&Column::Accessed) {
             columns.push(Column::Accessed);
         }
+        // A trash can's files show where they came from and when they were deleted
+        if !columns.is_empty() && args.paths.iter().any(|path| Trash::is_files_dir(path)) {
+            for column in [Column::TrashOrigin, Column::TrashDeleted] {
+                if !columns.contains(&column) {
+                    columns.push(column);
+                }
+            }
+        }
+        // The size graph sits right after the sizes it draws
+        if args.size_graph && !columns.contains(&Column::SizeGraph) {
+            let after_size = columns
+                .iter()
+                .position(|column| *column == Column::Size)
+                .map_or(columns.len(), |index| index + 1);
+            columns.insert(after_size, Column::SizeGraph);
+        }
+        // Git status sits right before the name it describes
+        if args.git && !columns.contains(&Column::Git) {
+            columns.push(Column::Git);
+        }
         // Name and Separator are always last if not tree
         if !args.tree && !columns.contains(&Column::Name) {
             columns.push(Column::Name);
@@ -0,0 +1,139 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! `--size-graph`: a bar showing each entry's share of its directory's size.
+//!
+//! A cell can't see its siblings, so the table renderers open a
+//! [`SizeGraph::scope`] over the entries being laid out and the cells read the
+//! total from there. Outside a scope (`--long-stream` prints rows before the
+//! total is known) the column shows the placeholder.
+
+use crate::cli::args::Args;
+use crate::display::layout::column::Column;
+use crate::fs::cache::Cache;
+use crate::fs::dir::DirReader;
+use crate::fs::entry::Entry;
+use std::cell::Cell;
+use std::sync::Arc;
+
+/// Number of character cells a full bar takes up.
+pub const WIDTH: usize = 10;
+
+thread_local! {
+    /// Total bytes of the entries in the table being rendered.
+    static TOTAL: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Proportional size bars drawn with `█` (full), `▓` (half) and `░` (empty) cells.
+pub struct SizeGraph;
+
+impl SizeGraph {
+    /// Runs `render` with the bars measured against the combined size of `entries`.
+    ///
+    /// # Parameters
+    /// - `entries`: The entries whose sizes make up the whole.
+    /// - `args`: Command-line arguments deciding whether a graph is shown at all.
+    /// - `render`: Lays out and prints the table.
+    pub(crate) fn scope<T>(entries: &[Entry], args: &Args, render: impl FnOnce() -> T) -> T {
+        if !Self::is_shown(args) {
+            return render();
+        }
+
+        let total = entries.iter().map(|entry| Self::bytes(entry, args)).sum();
+        let outer = TOTAL.replace(Some(total));
+        let result = render();
+        TOTAL.set(outer);
+        result
+    }
+
+    /// Checks whether the graph is drawn, by `--size-graph`, `--columns` or a `--format` template.
+    ///
+    /// # Parameters
+    /// - `args`: Command-line arguments to check.
+    pub(crate) fn is_shown(args: &Args) -> bool {
+        args.size_graph
+            || args.columns.contains(&Column::SizeGraph)
+            || args
+                .format
+                .as_ref()
+                .is_some_and(|template| template.columns().contains(&Column::SizeGraph))
+    }
+
+    /// Returns the bytes an entry counts for: its recursive size for a directory,
+    /// otherwise its own size.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry to measure.
+    /// - `args`: Command-line arguments controlling hidden files and traversal.
+    pub(crate) fn bytes(entry: &Entry, args: &Args) -> u64 {
+        if entry.is_dir() {
+            Cache::dir_size(entry.path(), args, || {
+                DirReader::from(entry.path().to_owned()).dir_size(args)
+            })
+        } else {
+            entry.metadata().map(|meta| meta.size).unwrap_or_default()
+        }
+    }
+
+    /// Draws the bar for an entry's share of the current scope's total.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry to draw.
+    /// - `args`: Command-line arguments controlling hidden files and traversal.
+    ///
+    /// # Returns
+    /// A [`WIDTH`]-cell bar, or `"-"` outside a scope.
+    pub(crate) fn bar(entry: &Entry, args: &Args) -> Arc<str> {
+        match TOTAL.get() {
+            Some(total) => Self::draw(Self::bytes(entry, args), total).into(),
+            None => "-".into(),
+        }
+    }
+
+    /// Draws a bar for `part` out of `total`, rounded to half a cell.
+    ///
+    /// # Parameters
+    /// - `part`: The entry's bytes.
+    /// - `total`: The bytes of every entry in the table.
+    ///
+    /// # Returns
+    /// The bar, e.g. `"███▓░░░░░░"` for 35%.
+    pub fn draw(part: u64, total: u64) -> String {
+        let halves = if total == 0 {
+            0
+        } else {
+            ((part as f64 / total as f64) * (WIDTH * 2) as f64).round() as usize
+        }
+        .min(WIDTH * 2);
+        let full = halves / 2;
+        let half = halves % 2;
+
+        format!(
+            "{}{}{}",
+            "█".repeat(full),
+            "▓".repeat(half),
+            "░".repeat(WIDTH - full - half)
+        )
+    }
+}
@@ -0,0 +1,99 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! A global string interner for cell values that repeat from row to row.
+//!
+//! Owners, groups and permission strings take a handful of distinct values
+//! even in a listing of millions of entries, but each cache that produces them
+//! holds its own copies. Interning them through [`Intern`] leaves one shared
+//! `Arc<str>` per distinct string, so rows point at the same allocation and
+//! the width cache finds them without copying. `--no-cache` skips the pool
+//! and gives every cell its own copy.
+
+use crate::fs::cache::CacheSettings;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, OnceLock};
+
+static STRINGS: OnceLock<Mutex<HashSet<Arc<str>>>> = OnceLock::new();
+
+/// Process-wide interner for low-cardinality cell strings.
+pub struct Intern;
+
+impl Intern {
+    /// Returns the shared copy of `text`, allocating it on first sight.
+    ///
+    /// # Parameters
+    /// - `text`: The string to intern.
+    pub fn get(text: &str) -> Arc<str> {
+        if CacheSettings::is_disabled() {
+            return Arc::from(text);
+        }
+
+        let Ok(mut strings) = Self::strings().lock() else {
+            return Arc::from(text);
+        };
+
+        if let Some(interned) = strings.get(text) {
+            return interned.clone();
+        }
+
+        let interned: Arc<str> = Arc::from(text);
+        strings.insert(interned.clone());
+        interned
+    }
+
+    /// Returns the shared copy of an already allocated string, keeping
+    /// `value` itself as that copy if the string is new.
+    ///
+    /// # Parameters
+    /// - `value`: The string to intern.
+    pub fn shared(value: Arc<str>) -> Arc<str> {
+        if CacheSettings::is_disabled() {
+            return value;
+        }
+
+        let Ok(mut strings) = Self::strings().lock() else {
+            return value;
+        };
+
+        if let Some(interned) = strings.get(&value) {
+            return interned.clone();
+        }
+
+        strings.insert(value.clone());
+        value
+    }
+
+    /// Returns the number of distinct strings interned so far.
+    pub fn len() -> usize {
+        Self::strings()
+            .lock()
+            .map(|strings| strings.len())
+            .unwrap_or_default()
+    }
+
+    fn strings() -> &'static Mutex<HashSet<Arc<str>>> {
+        STRINGS.get_or_init(|| Mutex::new(HashSet::new()))
+    }
+}
@@ -0,0 +1,234 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Localised column headers.
+//!
+//! Each language maps [`Column`]s to their translated headers, and a column
+//! without a translation simply keeps its English header. The language comes
+//! from `language = "de"` in `cerium.toml`, falling back to `LC_ALL`,
+//! `LC_MESSAGES`, and `LANG`.
+
+use crate::cli::settings::Settings;
+use crate::display::layout::column::Column;
+use std::env;
+use std::sync::OnceLock;
+
+static LANGUAGE: OnceLock<Language> = OnceLock::new();
+
+/// A language with translated column headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    English,
+    German,
+    French,
+    Spanish,
+    Portuguese,
+}
+
+impl Language {
+    /// Parses a language code or locale name such as `de`, `fr_FR.UTF-8`, or `pt-BR`.
+    ///
+    /// # Parameters
+    /// - `code`: The language code or locale string.
+    ///
+    /// # Returns
+    /// The matching [`Language`], or `None` for unknown codes and the `C`/`POSIX` locales.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let language = code
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match language.as_str() {
+            "en" => Some(Self::English),
+            "de" => Some(Self::German),
+            "fr" => Some(Self::French),
+            "es" => Some(Self::Spanish),
+            "pt" => Some(Self::Portuguese),
+            _ => None,
+        }
+    }
+
+    /// Translates a column's header into this language.
+    ///
+    /// # Parameters
+    /// - `column`: The column whose header to translate.
+    ///
+    /// # Returns
+    /// The translated header, or the English one when there is no translation.
+    pub fn translate(&self, column: &Column) -> &'static str {
+        let translated = match self {
+            Self::English => None,
+            Self::German => match column {
+                #[cfg(feature = "apps")]
+                Column::App => Some("Programm"),
+                Column::Context => Some("Kontext"),
+                Column::Mountpoint => Some("Einhängepunkt"),
+                Column::NameCheck => Some("Namensprüfung"),
+                Column::TrashOrigin => Some("Ursprünglicher Pfad"),
+                Column::TrashDeleted => Some("Gelöscht"),
+                Column::Note => Some("Notiz"),
+                Column::Description => Some("Beschreibung"),
+                Column::Permissions => Some("Rechte"),
+                Column::HardLinks => Some("Harte Links"),
+                Column::User => Some("Benutzer"),
+                Column::Group => Some("Gruppe"),
+                Column::Blocks => Some("Blöcke"),
+                Column::BlockSize => Some("Blockgröße"),
+                Column::Size => Some("Größe"),
+                Column::Allocated => Some("Belegt"),
+                Column::SizeGraph => Some("Anteil"),
+                Column::Ratio => Some("Verhältnis"),
+                Column::Created => Some("Erstellt"),
+                Column::Changed => Some("Statusänderung"),
+                Column::Accessed => Some("Zugriff"),
+                Column::Modified => Some("Geändert"),
+                _ => None,
+            },
+            Self::French => match column {
+                Column::Name => Some("Nom"),
+                #[cfg(feature = "apps")]
+                Column::App => Some("Appli"),
+                Column::Context => Some("Contexte"),
+                Column::Mountpoint => Some("Point de montage"),
+                Column::NameCheck => Some("Vérif. nom"),
+                Column::TrashOrigin => Some("Chemin d'origine"),
+                Column::TrashDeleted => Some("Supprimé"),
+                Column::HardLinks => Some("Liens physiques"),
+                Column::User => Some("Utilisateur"),
+                Column::Group => Some("Groupe"),
+                Column::Blocks => Some("Blocs"),
+                Column::BlockSize => Some("Taille de bloc"),
+                Column::Size => Some("Taille"),
+                Column::Allocated => Some("Alloué"),
+                Column::SizeGraph => Some("Part"),
+                Column::Created => Some("Créé"),
+                Column::Changed => Some("Changé"),
+                Column::Accessed => Some("Consulté"),
+                Column::Modified => Some("Modifié"),
+                _ => None,
+            },
+            Self::Spanish => match column {
+                Column::Name => Some("Nombre"),
+                #[cfg(feature = "apps")]
+                Column::App => Some("Aplicación"),
+                Column::Context => Some("Contexto"),
+                Column::Mountpoint => Some("Punto de montaje"),
+                Column::NameCheck => Some("Verif. nombre"),
+                Column::Description => Some("Descripción"),
+                Column::TrashOrigin => Some("Ruta original"),
+                Column::TrashDeleted => Some("Eliminado"),
+                Column::Note => Some("Nota"),
+                Column::Permissions => Some("Permisos"),
+                Column::HardLinks => Some("Enlaces duros"),
+                Column::User => Some("Usuario"),
+                Column::Group => Some("Grupo"),
+                Column::Blocks => Some("Bloques"),
+                Column::BlockSize => Some("Tamaño de bloque"),
+                Column::Size => Some("Tamaño"),
+                Column::Allocated => Some("Asignado"),
+                Column::SizeGraph => Some("Parte"),
+                Column::Ratio => Some("Proporción"),
+                Column::Created => Some("Creado"),
+                Column::Changed => Some("Cambiado"),
+                Column::Accessed => Some("Accedido"),
+                Column::Modified => Some("Modificado"),
+                Column::Inode => Some("inodo"),
+                _ => None,
+            },
+            Self::Portuguese => match column {
+                Column::Name => Some("Nome"),
+                #[cfg(feature = "apps")]
+                Column::App => Some("Aplicativo"),
+                Column::Context => Some("Contexto"),
+                Column::Mountpoint => Some("Ponto de montagem"),
+                Column::NameCheck => Some("Verif. nome"),
+                Column::Description => Some("Descrição"),
+                Column::TrashOrigin => Some("Caminho original"),
+                Column::TrashDeleted => Some("Excluído"),
+                Column::Note => Some("Nota"),
+                Column::Permissions => Some("Permissões"),
+                Column::HardLinks => Some("Links físicos"),
+                Column::User => Some("Usuário"),
+                Column::Group => Some("Grupo"),
+                Column::Blocks => Some("Blocos"),
+                Column::BlockSize => Some("Tamanho do bloco"),
+                Column::Size => Some("Tamanho"),
+                Column::Allocated => Some("Alocado"),
+                Column::SizeGraph => Some("Parte"),
+                Column::Ratio => Some("Proporção"),
+                Column::Created => Some("Criado"),
+                Column::Changed => Some("Alterado"),
+                Column::Accessed => Some("Acessado"),
+                Column::Modified => Some("Modificado"),
+                _ => None,
+            },
+        };
+
+        translated.unwrap_or_else(|| column.english_header())
+    }
+}
+
+/// Global header language, chosen once at startup.
+pub struct HeaderLocale;
+
+impl HeaderLocale {
+    /// Selects the header language from the config file or the locale environment.
+    ///
+    /// # Parameters
+    /// - `configured`: The `language` value from `cerium.toml`, if any; it takes
+    ///   precedence over `LC_ALL`, `LC_MESSAGES`, and `LANG`.
+    pub fn setup(configured: Option<String>) {
+        // Like any POSIX program, the first locale variable that is set wins
+        let environment = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.is_empty());
+
+        let language = configured
+            .and_then(|code| Language::from_code(&code))
+            .or_else(|| environment.and_then(|code| Language::from_code(&code)))
+            .unwrap_or(Language::English);
+
+        LANGUAGE.set(language).ok();
+    }
+
+    /// Translates a column's header into the selected language.
+    ///
+    /// # Parameters
+    /// - `column`: The column whose header to translate.
+    ///
+    /// # Returns
+    /// The localised header (English until [`HeaderLocale::setup`] has run).
+    pub(crate) fn translate(column: &Column) -> &'static str {
+        Self::language().translate(column)
+    }
+
+    /// Returns the header language in effect (English until [`HeaderLocale::setup`] has run).
+    pub(crate) fn language() -> Language {
+        Settings::get(|settings| settings.language)
+            .unwrap_or_else(|| LANGUAGE.get().copied().unwrap_or(Language::English))
+    }
+}
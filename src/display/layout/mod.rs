@@ -23,8 +23,15 @@ SOFTWARE.
 */
 
 pub mod alignment;
+pub mod bidi;
 pub mod column;
+pub mod graph;
+pub mod intern;
+pub mod locale;
+pub mod pending;
 pub mod row;
+pub mod template;
 pub mod term_grid;
+pub(crate) mod totals;
 pub mod unicode_width;
 pub mod width;
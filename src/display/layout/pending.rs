@@ -0,0 +1,324 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Deferred values for the slow columns of `--watch` listings.
+//!
+//! Checksums, magic descriptions and recursive directory sizes can take
+//! seconds per entry. While watching, only the first screenful of rows
+//! computes them on demand; the rest show [`PENDING`] and are handed to a
+//! background worker, which asks for a redraw once it has filled them in.
+//! Values are kept between redraws until a change under their path drops them.
+//!
+//! Columns whose values other parts of the listing need up front (a
+//! `--sort-column`, or directory sizes feeding `--sort size`, `--totals` or
+//! the size graph) are never deferred.
+
+use crate::cli::args::Args;
+use crate::cli::flags::SortBy;
+use crate::cli::settings::Settings;
+use crate::display::layout::column::Column;
+use crate::display::layout::graph::SizeGraph;
+use crate::display::output::populate::Populate;
+use crate::fs::entry::Entry;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+
+/// Shown in place of a value the background worker hasn't filled in yet.
+pub const PENDING: &str = "…";
+
+static PENDING_VALUES: OnceLock<Pending> = OnceLock::new();
+
+/// A value waiting for the background worker, tagged with the generation it was queued in.
+type Job = (Entry, Column, u64);
+
+/// What has been computed so far, and what is still queued.
+#[derive(Default)]
+struct State {
+    /// Entries met during the current draw, and whether they were within the
+    /// first screenful.
+    seen: HashMap<PathBuf, bool>,
+    /// Values sent to the worker and not yet filled in.
+    queued: HashSet<(PathBuf, Column)>,
+    /// Values computed on demand or by the worker, kept across redraws.
+    filled: HashMap<(PathBuf, Column), Arc<str>>,
+}
+
+/// Process-wide registry of deferred column values for `--watch`.
+pub struct Pending {
+    /// How many entries per draw compute their values on demand.
+    rows: usize,
+    /// Bumped whenever values are invalidated, so the worker drops results
+    /// computed from a tree that has since changed.
+    generation: AtomicU64,
+    /// Set by the worker once its queue runs dry.
+    refresh: AtomicBool,
+    state: Mutex<State>,
+    jobs: Mutex<Sender<Job>>,
+}
+
+impl Pending {
+    /// Starts deferring slow values and the worker that fills them in (`--watch`).
+    ///
+    /// The worker runs under the caller's [`Settings`] scope, if any.
+    ///
+    /// # Parameters
+    /// - `args`: The arguments the listing is drawn with.
+    /// - `rows`: How many entries per draw compute their values on demand,
+    ///   typically the terminal height.
+    pub fn setup(args: &Args, rows: usize) {
+        if PENDING_VALUES.get().is_some() {
+            return;
+        }
+
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let pending = Self {
+            rows,
+            generation: AtomicU64::new(0),
+            refresh: AtomicBool::new(false),
+            state: Mutex::new(State::default()),
+            jobs: Mutex::new(sender),
+        };
+        if PENDING_VALUES.set(pending).is_err() {
+            return;
+        }
+
+        let args = args.clone();
+        let settings = Settings::scoped();
+        thread::spawn(move || {
+            Settings::within(settings, || {
+                while let Ok(job) = receiver.recv() {
+                    let mut next = Some(job);
+                    while let Some((entry, column, generation)) = next {
+                        let value = Populate::new(&entry, &column, &args).computed();
+                        Self::fill(entry.path(), column, value, generation);
+                        next = receiver.try_recv().ok();
+                    }
+
+                    if let Some(pending) = PENDING_VALUES.get() {
+                        pending.refresh.store(true, Ordering::Relaxed);
+                    }
+                }
+            })
+        });
+    }
+
+    /// Starts a new draw, giving the next screenful of entries their on-demand values.
+    pub fn begin_draw() {
+        if let Some(pending) = PENDING_VALUES.get()
+            && let Ok(mut state) = pending.state.lock()
+        {
+            state.seen.clear();
+        }
+    }
+
+    /// Checks whether the worker has filled in values since the last call,
+    /// so the listing should be redrawn.
+    pub fn take_refresh() -> bool {
+        PENDING_VALUES
+            .get()
+            .is_some_and(|pending| pending.refresh.swap(false, Ordering::Relaxed))
+    }
+
+    /// Drops every value that a change under `root` may have made stale: those
+    /// of `root` and the paths beneath it, and the sizes of its ancestors.
+    ///
+    /// # Parameters
+    /// - `root`: The changed file or directory.
+    pub fn invalidate(root: &Path) {
+        let Some(pending) = PENDING_VALUES.get() else {
+            return;
+        };
+
+        pending.generation.fetch_add(1, Ordering::Relaxed);
+        if let Ok(mut state) = pending.state.lock() {
+            let stale = |(path, column): &(PathBuf, Column)| {
+                path.starts_with(root) || (*column == Column::Size && root.starts_with(path))
+            };
+            state.filled.retain(|key, _| !stale(key));
+            state.queued.retain(|key| !stale(key));
+        }
+    }
+
+    /// Returns a column value, deferring it to the background worker if it is
+    /// slow and its entry falls below the first screenful of this draw.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry the value belongs to.
+    /// - `column`: The column to fill.
+    /// - `args`: Command-line arguments controlling which values are needed up front.
+    /// - `compute`: Closure producing the value on demand.
+    ///
+    /// # Returns
+    /// The value, a kept earlier one, or [`PENDING`].
+    pub(crate) fn value(
+        entry: &Entry,
+        column: &Column,
+        args: &Args,
+        compute: impl FnOnce() -> Arc<str>,
+    ) -> Arc<str> {
+        let Some(pending) = PENDING_VALUES
+            .get()
+            .filter(|_| Self::defers(entry, column, args))
+        else {
+            return compute();
+        };
+
+        let path = entry.path();
+        let key = (path.to_path_buf(), *column);
+        let generation = pending.generation.load(Ordering::Relaxed);
+        {
+            let Ok(mut state) = pending.state.lock() else {
+                return compute();
+            };
+            let visible = match state.seen.get(path) {
+                Some(&visible) => visible,
+                None => {
+                    let visible = state.seen.len() < pending.rows;
+                    state.seen.insert(path.to_path_buf(), visible);
+                    visible
+                }
+            };
+
+            if let Some(value) = state.filled.get(&key) {
+                return value.clone();
+            }
+            if !visible {
+                if state.queued.insert(key)
+                    && let Ok(jobs) = pending.jobs.lock()
+                {
+                    let _ = jobs.send((entry.clone(), *column, generation));
+                }
+                return PENDING.into();
+            }
+        }
+
+        let value = compute();
+        Self::fill(path, *column, value.clone(), generation);
+        value
+    }
+
+    /// Keeps a computed value, unless the tree changed since it was requested.
+    ///
+    /// # Parameters
+    /// - `path`: The entry's path.
+    /// - `column`: The column the value belongs to.
+    /// - `value`: The computed value.
+    /// - `generation`: The generation the value was requested in.
+    fn fill(path: &Path, column: Column, value: Arc<str>, generation: u64) {
+        let Some(pending) = PENDING_VALUES.get() else {
+            return;
+        };
+
+        if let Ok(mut state) = pending.state.lock() {
+            let key = (path.to_path_buf(), column);
+            state.queued.remove(&key);
+            if pending.generation.load(Ordering::Relaxed) == generation {
+                state.filled.insert(key, value);
+            }
+        }
+    }
+
+    /// Checks whether a column's value is slow to compute and not needed
+    /// before the listing is drawn.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry the value belongs to.
+    /// - `column`: The column to check.
+    /// - `args`: Command-line arguments (sorting, totals and the size graph need sizes up front).
+    fn defers(entry: &Entry, column: &Column, args: &Args) -> bool {
+        if args.sort_column == Some(*column) {
+            return false;
+        }
+
+        match column {
+            #[cfg(feature = "magic")]
+            Column::Magic => true,
+            #[cfg(feature = "checksum")]
+            Column::Checksum(_) => true,
+            Column::Size => {
+                entry.is_dir()
+                    && args.dir_size
+                    && !args.sort.contains(&SortBy::Size)
+                    && !args.totals
+                    && !args.subtotals
+                    && !SizeGraph::is_shown(args)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use std::fs;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn rows_below_the_budget_are_filled_in_the_background() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let args = Args::parse_from(["ce", "--size", "--dir-size"]);
+        let entries: Vec<Entry> = ["a", "b"]
+            .into_iter()
+            .map(|name| {
+                let dir = temp_dir.path().join(name);
+                fs::create_dir(&dir).unwrap();
+                fs::write(dir.join("file"), "contents").unwrap();
+                Entry::from_path(dir, false)
+            })
+            .collect();
+        let value = |entry: &Entry| {
+            Pending::value(entry, &Column::Size, &args, || {
+                Populate::new(entry, &Column::Size, &args).computed()
+            })
+        };
+        let expected = Populate::new(&entries[1], &Column::Size, &args).computed();
+
+        Pending::setup(&args, 1);
+        Pending::begin_draw();
+        assert_ne!(&*value(&entries[0]), PENDING);
+        assert_eq!(&*value(&entries[1]), PENDING);
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while !Pending::take_refresh() {
+            assert!(
+                Instant::now() < deadline,
+                "the worker never asked for a redraw"
+            );
+            thread::sleep(Duration::from_millis(10));
+        }
+        Pending::begin_draw();
+        assert_eq!(value(&entries[1]), expected);
+
+        Pending::invalidate(&entries[1].path().join("file"));
+        Pending::begin_draw();
+        assert_ne!(&*value(&entries[0]), PENDING);
+        assert_eq!(&*value(&entries[1]), PENDING);
+    }
+}
@@ -23,10 +23,35 @@ SOFTWARE.
 */
 
 use crate::cli::args::Args;
+use crate::cli::flags::SortKey;
 use crate::display::layout::column::Column;
+use crate::display::layout::intern::Intern;
 use crate::display::output::populate::Populate;
+use crate::display::output::secret::{self, Secret};
+use crate::fs::cache::Cache;
+#[cfg(feature = "collate")]
+use crate::fs::collate::CollationKey;
+use crate::fs::dir::DirReader;
 use crate::fs::entry::Entry;
+use crate::fs::metadata::Metadata;
+use crate::fs::trash::Trash;
+use std::cmp::Reverse;
 use std::sync::Arc;
+use std::time::UNIX_EPOCH;
+
+/// The value a `--sort-column` ordering compares for one row.
+///
+/// Numeric columns compare their raw values, so `9 KB` sorts before `10 KB`
+/// and dates sort by time rather than by how they are spelled. Everything
+/// else compares the text the column shows. Rows without a value (metadata
+/// that couldn't be read) sort first.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum CellKey {
+    Number(Option<i128>),
+    Text(String),
+    #[cfg(feature = "collate")]
+    Collated(CollationKey),
+}
 
 /// Extracts and formats column values from a filesystem entry.
 pub(crate) struct Row<'a> {
@@ -51,8 +76,100 @@ impl<'a> Row<'a> {
     ///
     /// # Returns
     /// An `Arc<str>` containing the formatted column value (without styling).
+    /// Owners, groups and permissions come from the shared [`Intern`] pool.
     pub(crate) fn value(&self, column: &Column) -> Arc<str> {
         let populate = Populate::new(self.entry, column, self.args);
-        populate.value()
+        let value = populate.value();
+
+        match column {
+            Column::User | Column::Group | Column::Permissions => Intern::shared(value),
+            _ => value,
+        }
+    }
+
+    /// Returns the warning marker drawn after a column's value, if any.
+    ///
+    /// The permissions of a secret-looking file that others can read get
+    /// [`secret::EXPOSED_MARKER`]. It isn't part of [`Row::value`], so sorting
+    /// and structured output see the permissions alone.
+    ///
+    /// # Parameters
+    /// - `column`: The column being drawn.
+    pub(crate) fn marker(&self, column: &Column) -> Option<char> {
+        (*column == Column::Permissions && Secret::is_exposed(self.entry))
+            .then_some(secret::EXPOSED_MARKER)
+    }
+
+    /// Returns the key this row sorts by in a column.
+    ///
+    /// # Parameters
+    /// - `column`: The column to sort by.
+    ///
+    /// # Returns
+    /// The raw number for numeric columns, otherwise the shown text (lowercased
+    /// unless `--sort-key raw`).
+    pub(crate) fn key(&self, column: &Column) -> CellKey {
+        let metadata = self.entry.metadata();
+        let number = |field: fn(&Metadata) -> i128| CellKey::Number(metadata.map(field));
+
+        match column {
+            Column::Size if self.entry.is_dir() => {
+                let path = self.entry.path();
+                let size = if self.args.dir_size {
+                    Cache::dir_size(path, self.args, || {
+                        DirReader::from(path.to_owned()).dir_size(self.args)
+                    })
+                } else {
+                    DirReader::from(path.to_owned()).item_count(self.args.all) as u64
+                };
+                CellKey::Number(Some(size.into()))
+            }
+            Column::Size => number(|meta| meta.size.into()),
+            Column::Allocated => number(|meta| meta.allocated().into()),
+            Column::Blocks => number(|meta| meta.blocks.into()),
+            Column::BlockSize => number(|meta| meta.blksize.into()),
+            Column::Inode => number(|meta| meta.ino.into()),
+            Column::HardLinks => number(|meta| meta.nlink.into()),
+            Column::Created => number(|meta| meta.created().into()),
+            Column::Changed => number(|meta| meta.ctime.into()),
+            Column::Accessed => number(|meta| meta.atime.into()),
+            Column::Modified => number(|meta| meta.mtime.into()),
+            Column::Ratio => CellKey::Number(
+                metadata
+                    .and_then(|meta| meta.compression_ratio())
+                    .map(|ratio| (ratio * 1_000_000.0) as i128),
+            ),
+            Column::TrashDeleted => CellKey::Number(
+                Trash::info(self.entry.path())
+                    .and_then(|info| info.deleted?.duration_since(UNIX_EPOCH).ok())
+                    .map(|since_epoch| since_epoch.as_secs().into()),
+            ),
+            _ => {
+                let value = self.value(column);
+                match self.args.sort_key {
+                    SortKey::Display => CellKey::Text(value.to_lowercase()),
+                    SortKey::Raw => CellKey::Text(value.to_string()),
+                    #[cfg(feature = "collate")]
+                    SortKey::Locale => CellKey::Collated(CollationKey::new(&value)),
+                }
+            }
+        }
+    }
+
+    /// Reorders entries by a column's values for `--sort-column`.
+    ///
+    /// The sort is stable, so entries with equal values keep the order `--sort`
+    /// gave them; `--reverse` flips the column order as well.
+    ///
+    /// # Parameters
+    /// - `entries`: The entries to reorder, with metadata loaded if the column reads it.
+    /// - `column`: The column to sort by.
+    /// - `args`: Command-line arguments controlling formatting and direction.
+    pub(crate) fn sort(entries: &mut [Entry], column: &Column, args: &Args) {
+        if args.reverse {
+            entries.sort_by_cached_key(|entry| Reverse(Row::new(entry, args).key(column)));
+        } else {
+            entries.sort_by_cached_key(|entry| Row::new(entry, args).key(column));
+        }
     }
 }
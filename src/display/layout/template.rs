@@ -0,0 +1,177 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! `--format` templates for long output, such as `"{perm} {size:>8} {mtime} {name}"`.
+//!
+//! A template is literal text with `{field}` placeholders, so columns can be
+//! picked and ordered freely instead of coming from [`Selector`]'s fixed set.
+//! A placeholder may carry a minimum width, `{size:8}`, aligned the way the
+//! column normally is, or forced with `{name:<20}` / `{size:>8}`. Without a
+//! width the value is printed as is. `{{` and `}}` print literal braces.
+//!
+//! [`Selector`]: crate::display::layout::column::Selector
+
+use crate::cli::args::Args;
+
+use crate::display::layout::alignment::{Align, Alignment};
+use crate::display::layout::column::Column;
+use crate::display::styles::column::ColumnStyle;
+use crate::fs::entry::Entry;
+use std::str::FromStr;
+
+/// A parsed `--format` template.
+#[derive(Debug, Clone)]
+pub struct Template {
+    pieces: Vec<Piece>,
+}
+
+/// One part of a [`Template`]: literal text or a column placeholder.
+#[derive(Debug, Clone)]
+enum Piece {
+    Text(String),
+    Field {
+        column: Column,
+        width: usize,
+        alignment: Option<Alignment>,
+    },
+}
+
+impl Template {
+    /// Returns the columns the template refers to, in order.
+    ///
+    /// # Returns
+    /// One [`Column`] per placeholder (repeats included).
+    pub fn columns(&self) -> Vec<Column> {
+        self.pieces
+            .iter()
+            .filter_map(|piece| match piece {
+                Piece::Field { column, .. } => Some(*column),
+                Piece::Text(_) => None,
+            })
+            .collect()
+    }
+
+    /// Renders one entry through the template.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry to render.
+    /// - `args`: Command-line arguments controlling how values are formatted.
+    ///
+    /// # Returns
+    /// The styled line, without a trailing newline.
+    pub(crate) fn render(&self, entry: &Entry, args: &Args) -> String {
+        self.pieces
+            .iter()
+            .map(|piece| match piece {
+                Piece::Text(text) => text.clone(),
+                Piece::Field {
+                    column,
+                    width,
+                    alignment,
+                } => {
+                    let value = ColumnStyle::get(entry, column, args, false);
+                    Align::pad(&value, *width, alignment.unwrap_or(column.alignment()))
+                }
+            })
+            .collect()
+    }
+
+    /// Parses the inside of a placeholder: a field name and an optional
+    /// `:[<|>]width` spec.
+    ///
+    /// # Parameters
+    /// - `placeholder`: The text between the braces.
+    ///
+    /// # Returns
+    /// The field piece, or an error naming the unknown field or bad width.
+    fn field(placeholder: &str) -> Result<Piece, String> {
+        let (name, spec) = placeholder.split_once(':').unwrap_or((placeholder, ""));
+        let name = name.trim();
+        let column = name.parse::<Column>().map_err(|_| {
+            format!("unknown field '{{{name}}}' in format (expected e.g. {{name}}, {{perm}}, {{size}} or {{mtime}})")
+        })?;
+
+        let (alignment, width) = match spec.split_at_checked(1) {
+            Some(("<", width)) => (Some(Alignment::Left), width),
+            Some((">", width)) => (Some(Alignment::Right), width),
+            _ => (None, spec),
+        };
+        let width = if width.is_empty() && alignment.is_none() {
+            0
+        } else {
+            width.parse().map_err(|_| {
+                format!(
+                    "invalid width '{spec}' for '{{{name}}}' in format (expected e.g. 8, <20 or >8)"
+                )
+            })?
+        };
+
+        Ok(Piece::Field {
+            column,
+            width,
+            alignment,
+        })
+    }
+}
+
+impl FromStr for Template {
+    type Err = String;
+
+    fn from_str(template: &str) -> Result<Self, Self::Err> {
+        let mut pieces = Vec::new();
+        let mut text = String::new();
+        let mut chars = template.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' | '}' if chars.as_str().starts_with(c) => {
+                    chars.next();
+                    text.push(c);
+                }
+                '{' => {
+                    let rest = chars.as_str();
+                    let end = rest
+                        .find('}')
+                        .ok_or_else(|| format!("unclosed '{{' in format '{template}'"))?;
+                    if !text.is_empty() {
+                        pieces.push(Piece::Text(std::mem::take(&mut text)));
+                    }
+                    pieces.push(Self::field(&rest[..end])?);
+                    chars = rest[end + 1..].chars();
+                }
+                '}' => {
+                    return Err(format!(
+                        "unmatched '}}' in format '{template}' (write '}}}}' for a literal brace)"
+                    ));
+                }
+                c => text.push(c),
+            }
+        }
+
+        if !text.is_empty() {
+            pieces.push(Piece::Text(text));
+        }
+        Ok(Self { pieces })
+    }
+}
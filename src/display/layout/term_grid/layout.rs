@@ -164,6 +164,15 @@ pub struct GridDisplay {
 }
 
 impl GridDisplay {
+    /// Returns the number of lines the grid prints.
+    pub fn row_count(&self) -> usize {
+        if self.num_columns == 0 {
+            return 0;
+        }
+
+        self.cells.len().div_ceil(self.num_columns)
+    }
+
     /// Returns the total width of the grid including separators.
     fn total_width(&self) -> usize {
         if self.column_widths.is_empty() {
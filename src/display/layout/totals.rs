@@ -0,0 +1,181 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+use crate::cli::args::Args;
+use crate::display::layout::alignment::Align;
+use crate::display::layout::column::Column;
+use crate::display::output::formats::format::Format;
+use crate::display::output::formats::number::Number;
+use crate::display::output::formats::size::Size;
+use crate::display::styles::element::ElementStyle;
+use crate::display::theme::colours::{Colour, ColourPaint};
+use crate::fs::cache::Cache;
+use crate::fs::dir::DirReader;
+use crate::fs::entry::Entry;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Footer row summing the size and block columns and counting the entries in a table.
+pub(crate) struct Totals {
+    count: usize,
+    size: u64,
+    blocks: u64,
+}
+
+impl Totals {
+    /// Accumulates totals over a set of entries.
+    ///
+    /// Directories contribute to the size total only with `--dir-size`, since
+    /// their size column otherwise shows an item count rather than bytes.
+    ///
+    /// # Parameters
+    /// - `entries`: The entries shown in the table.
+    /// - `args`: Command-line arguments (checked for `dir_size` and `all`).
+    ///
+    /// # Returns
+    /// The accumulated [`Totals`].
+    pub(crate) fn compute(entries: &[Entry], args: &Args) -> Self {
+        let mut size = 0;
+        let mut blocks = 0;
+
+        for entry in entries {
+            let metadata = entry.metadata();
+
+            if entry.is_dir() {
+                if args.dir_size {
+                    size += Cache::dir_size(entry.path(), args, || {
+                        DirReader::from(entry.path().to_owned()).dir_size(args)
+                    });
+                }
+            } else {
+                size += metadata.map(|meta| meta.size).unwrap_or_default();
+            }
+
+            blocks += metadata.map(|meta| meta.blocks).unwrap_or_default();
+        }
+
+        Self {
+            count: entries.len(),
+            size,
+            blocks,
+        }
+    }
+
+    /// Returns the formatted footer value for a column, if it has one.
+    ///
+    /// # Parameters
+    /// - `column`: The column to summarise.
+    /// - `args`: Command-line arguments controlling size and number formatting.
+    ///
+    /// # Returns
+    /// The total for summable columns, the entry count for the name column, otherwise `None`.
+    pub(crate) fn value(&self, column: &Column, args: &Args) -> Option<Arc<str>> {
+        match column {
+            Column::Size => Some(Size::new(args.size_format).format(self.size)),
+            Column::Blocks => Some(Number::new(args.number_format).format(self.blocks)),
+            Column::Name => Some(self.label()),
+            _ => None,
+        }
+    }
+
+    /// Prints the footer row aligned to the given column widths.
+    ///
+    /// When the name column isn't part of the table (tree mode), the entry
+    /// count is appended after the last column instead.
+    ///
+    /// # Parameters
+    /// - `widths`: Pre-calculated column widths.
+    /// - `columns`: The columns shown in the table.
+    /// - `args`: Command-line arguments controlling formatting.
+    pub(crate) fn print(&self, widths: &HashMap<Column, usize>, columns: &[Column], args: &Args) {
+        let mut parts: Vec<String> = columns
+            .iter()
+            .map(|column| {
+                let value = self
+                    .value(column, args)
+                    .map(|value| ElementStyle::table_footer(&value))
+                    .unwrap_or_default();
+                let width = *widths.get(column).unwrap_or(&0);
+                Align::pad(&value, width, column.alignment())
+            })
+            .collect();
+
+        if !columns.contains(&Column::Name) {
+            parts.push(ElementStyle::table_footer(&self.label()));
+        }
+
+        println!("{}", parts.join(" "));
+    }
+
+    /// Prints a dimmed subtotal line for one extension's entries (`--subtotals`).
+    ///
+    /// The line starts under the name column and reads e.g. `*.rs: 3 entries, 12 KB`.
+    ///
+    /// # Parameters
+    /// - `extension`: The extension shared by the entries (empty for none).
+    /// - `widths`: Pre-calculated column widths.
+    /// - `columns`: The columns shown in the table.
+    /// - `args`: Command-line arguments controlling size formatting.
+    pub(crate) fn print_subtotal(
+        &self,
+        extension: &str,
+        widths: &HashMap<Column, usize>,
+        columns: &[Column],
+        args: &Args,
+    ) {
+        let title = if extension.is_empty() {
+            "No extension".to_string()
+        } else {
+            format!("*.{}", extension.to_lowercase())
+        };
+        let text = format!(
+            "{}: {}, {}",
+            title,
+            self.label(),
+            Size::new(args.size_format).format(self.size)
+        );
+
+        // Blank out the columns before the name, keeping their separators
+        let indent: usize = columns
+            .iter()
+            .take_while(|column| **column != Column::Name)
+            .map(|column| widths.get(column).unwrap_or(&0) + 1)
+            .sum();
+
+        println!(
+            "{}{}",
+            " ".repeat(indent),
+            Colour::DarkGray.normal().apply_to(&text)
+        );
+    }
+
+    /// Returns the entry count label, e.g. `"1 entry"` or `"42 entries"`.
+    fn label(&self) -> Arc<str> {
+        if self.count == 1 {
+            "1 entry".into()
+        } else {
+            format!("{} entries", self.count).into()
+        }
+    }
+}
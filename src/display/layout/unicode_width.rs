@@ -44,6 +44,19 @@ fn init_locale() {
     });
 }
 
+/// Measures with the `C.UTF-8` locale instead of the environment's, so widths
+/// don't depend on `LANG` (used by `--deterministic`).
+///
+/// Must run before the first [`char_width`] call; falls back to the
+/// environment's locale where `C.UTF-8` isn't installed.
+pub fn pin_utf8_locale() {
+    LOCALE_INIT.call_once(|| unsafe {
+        if libc::setlocale(libc::LC_CTYPE, c"C.UTF-8".as_ptr()).is_null() {
+            libc::setlocale(libc::LC_CTYPE, c"".as_ptr());
+        }
+    });
+}
+
 /// Returns the display width of a Unicode character using libc's `wcwidth()`.
 ///
 /// # Parameters
@@ -23,16 +23,26 @@ SOFTWARE.
 */
 
 use crate::cli::args::Args;
+use crate::cli::settings::Settings;
 use crate::display::layout::column::Column;
 use crate::display::layout::row::Row;
+use crate::display::layout::totals::Totals;
 use crate::display::layout::unicode_width::char_width;
 use crate::fs::entry::Entry;
 use libc::{TIOCGWINSZ, ioctl, winsize};
 use std::collections::HashMap;
-use std::os::fd::AsRawFd;
-use std::sync::Arc;
+use std::fs::File;
+use std::os::fd::{AsRawFd, RawFd};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
 use std::{io, mem};
 
+/// Terminal width pinned with `--assume-width`, overriding detection.
+static ASSUMED_WIDTH: OnceLock<usize> = OnceLock::new();
+
+/// Whether width detection may query `/dev/tty` when stdout is redirected (`--force-grid`).
+static FORCE_GRID: AtomicBool = AtomicBool::new(false);
+
 /// Centralised width calculator with caching for ANSI-aware text measurement.
 pub struct Width {
     width_cache: HashMap<Arc<str>, usize>,
@@ -81,7 +91,8 @@ impl Width {
 
             for column in columns {
                 let value = row.value(column);
-                let width = self.measure_text_cached(&value);
+                let marker = row.marker(column).map_or(0, char_width);
+                let width = self.measure_text_cached(&value) + marker;
 
                 let current = *widths.get(column).unwrap_or(&0);
                 if width > current {
@@ -93,24 +104,150 @@ impl Width {
         widths
     }
 
-    /// Returns the current terminal width in columns via `TIOCGWINSZ` ioctl.
+    /// Widens column widths so the `--totals` footer fits in them too.
+    ///
+    /// # Parameters
+    /// - `widths`: Widths from [`Width::calculate`], widened in place.
+    /// - `totals`: The footer the table ends with.
+    /// - `columns`: The columns shown in the table.
+    /// - `args`: Command-line arguments controlling size and number formatting.
+    pub(crate) fn fit_totals(
+        &mut self,
+        widths: &mut HashMap<Column, usize>,
+        totals: &Totals,
+        columns: &[Column],
+        args: &Args,
+    ) {
+        for column in columns {
+            if let Some(value) = totals.value(column, args) {
+                let width = self.measure_text_cached(&value);
+                let current = widths.entry(*column).or_insert(0);
+                *current = (*current).max(width);
+            }
+        }
+    }
+
+    /// Pins the terminal width used for layout, bypassing detection (`--assume-width`).
+    ///
+    /// # Parameters
+    /// - `columns`: The width to assume, or `None` to keep detecting it.
+    pub fn assume(columns: Option<usize>) {
+        if let Some(columns) = columns {
+            ASSUMED_WIDTH.set(columns).ok();
+        }
+    }
+
+    /// Lets width detection fall back to the controlling terminal (`--force-grid`).
+    ///
+    /// # Parameters
+    /// - `enabled`: Whether to query `/dev/tty` when stdout is not a terminal.
+    pub fn force_grid(enabled: bool) {
+        FORCE_GRID.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Returns the width pinned by [`Width::assume`], if any.
+    pub(crate) fn assumed() -> Option<usize> {
+        Settings::get(|settings| settings.assumed_width)
+            .unwrap_or_else(|| ASSUMED_WIDTH.get().copied())
+    }
+
+    /// Checks whether [`Width::force_grid`] is on.
+    pub(crate) fn is_grid_forced() -> bool {
+        Settings::get(|settings| settings.force_grid)
+            .unwrap_or_else(|| FORCE_GRID.load(Ordering::Relaxed))
+    }
+
+    /// Returns the terminal width in columns.
+    ///
+    /// Uses the width pinned by [`Width::assume`] if any, then the `TIOCGWINSZ`
+    /// ioctl on stdout, then the `COLUMNS` environment variable, then (with
+    /// `--force-grid`) the controlling terminal `/dev/tty`.
     ///
     /// # Returns
-    /// The terminal width, or `80` if the query fails.
+    /// The terminal width, or `80` if none of those are available.
     pub fn terminal_width() -> usize {
-        {
-            let fd = io::stdout().as_raw_fd();
-            let mut winsize: winsize = unsafe { mem::zeroed() };
+        if let Some(columns) = Self::assumed() {
+            crate::debug!(
+                "terminal width",
+                columns = columns,
+                source = "--assume-width"
+            );
+            return columns;
+        }
+
+        if let Some(columns) = Self::window_columns(io::stdout().as_raw_fd()) {
+            crate::debug!("terminal width", columns = columns, source = "stdout");
+            return columns;
+        }
 
-            let result = unsafe { ioctl(fd, TIOCGWINSZ, &mut winsize as *mut _) };
+        // Not a terminal (piped or redirected): honour COLUMNS first
+        let columns = std::env::var("COLUMNS")
+            .ok()
+            .and_then(|columns| columns.trim().parse::<usize>().ok())
+            .filter(|&columns| columns > 0);
 
-            if result == 0 && winsize.ws_col > 0 {
-                winsize.ws_col as usize
-            } else {
-                // Fallback to 80 columns if ioctl fails
-                80
-            }
+        if let Some(columns) = columns {
+            crate::debug!("terminal width", columns = columns, source = "COLUMNS");
+            return columns;
+        }
+
+        // `ce --force-grid | tee file` keeps the layout of the terminal it was run from
+        if Self::is_grid_forced()
+            && let Ok(tty) = File::open("/dev/tty")
+            && let Some(columns) = Self::window_columns(tty.as_raw_fd())
+        {
+            crate::debug!("terminal width", columns = columns, source = "/dev/tty");
+            return columns;
         }
+
+        crate::debug!("terminal width", columns = 80, source = "default");
+        80
+    }
+
+    /// Returns the height of the terminal stdout is connected to.
+    ///
+    /// Uses the `TIOCGWINSZ` ioctl on stdout, then the `LINES` environment variable.
+    ///
+    /// # Returns
+    /// The row count, or `None` if stdout is not a terminal and `LINES` isn't set.
+    pub fn terminal_height() -> Option<usize> {
+        Self::window_size(io::stdout().as_raw_fd())
+            .filter(|winsize| winsize.ws_row > 0)
+            .map(|winsize| winsize.ws_row as usize)
+            .or_else(|| {
+                std::env::var("LINES")
+                    .ok()
+                    .and_then(|lines| lines.parse().ok())
+                    .filter(|&lines| lines > 0)
+            })
+    }
+
+    /// Queries the width of a terminal file descriptor.
+    ///
+    /// # Parameters
+    /// - `fd`: The file descriptor to query.
+    ///
+    /// # Returns
+    /// The column count, or `None` if `fd` is not a terminal.
+    fn window_columns(fd: RawFd) -> Option<usize> {
+        Self::window_size(fd)
+            .filter(|winsize| winsize.ws_col > 0)
+            .map(|winsize| winsize.ws_col as usize)
+    }
+
+    /// Queries the window size of a terminal file descriptor via `TIOCGWINSZ`.
+    ///
+    /// # Parameters
+    /// - `fd`: The file descriptor to query.
+    ///
+    /// # Returns
+    /// The window size, or `None` if `fd` is not a terminal.
+    fn window_size(fd: RawFd) -> Option<winsize> {
+        let mut winsize: winsize = unsafe { mem::zeroed() };
+
+        let result = unsafe { ioctl(fd, TIOCGWINSZ, &mut winsize as *mut _) };
+
+        (result == 0).then_some(winsize)
     }
 
     /// Measures the display width of text with caching.
@@ -121,16 +258,14 @@ impl Width {
     /// # Returns
     /// The display width in characters (excluding ANSI codes).
     pub fn measure_text_cached(&mut self, text: &str) -> usize {
-        // Try to get from cache first
-        let text_arc = Arc::<str>::from(text);
-
-        if let Some(&width) = self.width_cache.get(&text_arc) {
+        // Look up by `&str`, so a hit doesn't copy the text
+        if let Some(&width) = self.width_cache.get(text) {
             return width;
         }
 
         // Measure and cache
         let width = Self::measure_ansi_text(text);
-        self.width_cache.insert(text_arc, width);
+        self.width_cache.insert(Arc::from(text), width);
         width
     }
 
@@ -150,13 +285,19 @@ impl Width {
                 // Check what type of escape sequence
                 match chars.peek() {
                     Some(&'[') => {
-                        // CSI sequence (colours, styling)
+                        // CSI sequence (colours, styling): parameter and intermediate
+                        // bytes (0x20-0x3F), then one final byte (0x40-0x7E)
                         chars.next(); // consume '['
                         while let Some(&next_ch) = chars.peek() {
-                            chars.next();
-                            if next_ch.is_ascii_alphabetic() {
+                            if ('\x40'..='\x7e').contains(&next_ch) {
+                                chars.next();
+                                break;
+                            }
+                            if !(' '..='?').contains(&next_ch) {
+                                // Malformed: stop here so the rest of the text is still measured
                                 break;
                             }
+                            chars.next();
                         }
                     }
                     Some(&']') => {
@@ -190,6 +331,95 @@ impl Width {
         width
     }
 
+    /// Shortens text to a visual width, ending it with an ellipsis.
+    ///
+    /// Escape sequences are all kept, so colours are still reset and hyperlinks
+    /// closed after the cut, and so are bidi isolation marks (U+2066 to U+2069),
+    /// so an isolated name stays isolated.
+    ///
+    /// # Parameters
+    /// - `text`: The text to shorten (may contain ANSI escape codes).
+    /// - `width`: The widest the visible text may be, ellipsis included.
+    ///
+    /// # Returns
+    /// `text` unchanged if it already fits, otherwise its leading characters and `…`.
+    pub fn truncate_ansi(text: &str, width: usize) -> String {
+        if Self::measure_ansi_text(text) <= width {
+            return text.to_owned();
+        }
+
+        let mut shortened = String::with_capacity(text.len());
+        let mut used = 0;
+        let mut cut = false;
+        let mut rest = text;
+
+        while let Some(ch) = rest.chars().next() {
+            let length = if ch == '\x1b' {
+                Self::escape_length(rest)
+            } else {
+                ch.len_utf8()
+            };
+            let (piece, tail) = rest.split_at(length);
+
+            if ch == '\x1b' || ('\u{2066}'..='\u{2069}').contains(&ch) {
+                shortened.push_str(piece);
+            } else if !cut {
+                let ch_width = char_width(ch);
+                // Leave a column for the ellipsis
+                if used + ch_width < width {
+                    shortened.push(ch);
+                    used += ch_width;
+                } else {
+                    shortened.push('…');
+                    cut = true;
+                }
+            }
+
+            rest = tail;
+        }
+
+        shortened
+    }
+
+    /// Returns the byte length of the escape sequence `text` starts with.
+    ///
+    /// Recognises the same CSI and OSC sequences as [`Width::measure_ansi_text`].
+    ///
+    /// # Parameters
+    /// - `text`: Text starting with `ESC`.
+    ///
+    /// # Returns
+    /// The length of the sequence, or `1` for an unknown escape.
+    fn escape_length(text: &str) -> usize {
+        let bytes = text.as_bytes();
+
+        match bytes.get(1) {
+            Some(b'[') => {
+                for (offset, byte) in bytes.iter().enumerate().skip(2) {
+                    if (0x40..=0x7e).contains(byte) {
+                        return offset + 1;
+                    }
+                    if !(0x20..=0x3f).contains(byte) {
+                        return offset;
+                    }
+                }
+                bytes.len()
+            }
+            Some(b']') => {
+                for (offset, byte) in bytes.iter().enumerate().skip(2) {
+                    if *byte == 0x07 {
+                        return offset + 1;
+                    }
+                    if *byte == 0x1b && bytes.get(offset + 1) == Some(&b'\\') {
+                        return offset + 2;
+                    }
+                }
+                bytes.len()
+            }
+            _ => 1,
+        }
+    }
+
     /// Returns the number of cached measurements.
     ///
     /// Useful for debugging and performance analysis.
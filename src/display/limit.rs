@@ -0,0 +1,82 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Per-directory entry cap for `--max-entries`.
+
+use crate::cli::args::Args;
+use crate::display::styles::element::ElementStyle;
+use crate::fs::entry::Entry;
+use humanly::HumanNumber;
+
+/// Caps how many entries are rendered for a single directory.
+///
+/// The cap is applied after sorting, so the first N entries in display order
+/// are kept and the rest are collapsed into an "… and N more" trailer. Summary
+/// counts still cover every entry.
+pub(crate) struct Limit;
+
+impl Limit {
+    /// Returns the entries that fit under `--max-entries`.
+    ///
+    /// # Parameters
+    /// - `entries`: The (already sorted) entries of one directory.
+    /// - `args`: Command-line arguments carrying the cap.
+    ///
+    /// # Returns
+    /// The leading slice of at most `--max-entries` entries, or all of them when unset.
+    pub(crate) fn shown<'a>(entries: &'a [Entry], args: &Args) -> &'a [Entry] {
+        match args.max_entries {
+            Some(max) if entries.len() > max => &entries[..max],
+            _ => entries,
+        }
+    }
+
+    /// Renders the capped entries followed by a trailer naming how many were left out.
+    ///
+    /// # Parameters
+    /// - `entries`: The (already sorted) entries of one directory.
+    /// - `args`: Command-line arguments carrying the cap.
+    /// - `render`: Renderer-specific closure that prints the kept entries.
+    pub(crate) fn render(entries: &[Entry], args: &Args, render: impl Fn(&[Entry])) {
+        let shown = Self::shown(entries, args);
+        render(shown);
+
+        let omitted = entries.len() - shown.len();
+        if omitted > 0 {
+            println!("{}", Self::trailer(omitted));
+        }
+    }
+
+    /// Formats the trailer line for omitted entries.
+    ///
+    /// # Parameters
+    /// - `omitted`: The number of entries that were not rendered.
+    ///
+    /// # Returns
+    /// Styled text such as `… and 3,201 more`.
+    fn trailer(omitted: usize) -> String {
+        let text = format!("… and {} more", HumanNumber::from(omitted as f64));
+        ElementStyle::summary(&text)
+    }
+}
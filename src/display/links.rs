@@ -0,0 +1,235 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Symlink audit table for `--link-map`.
+
+use crate::cli::args::Args;
+use crate::display::layout::alignment::{Align, Alignment};
+use crate::display::layout::width::Width;
+use crate::display::mode::DisplayMode;
+use crate::display::output::escape::Escape;
+use crate::display::styles::element::ElementStyle;
+use crate::display::styles::entry::EntryStyle;
+use crate::display::styles::value::ValueStyle;
+use crate::display::tally::Tally;
+use crate::fs::dir::DirReader;
+use crate::fs::entry::Entry;
+use crate::fs::symlink;
+use crate::fs::tree::Visited;
+use crate::fs::vfs::RealFs;
+use std::fs;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+
+/// The arrow separating a link from its fully resolved target.
+const RESOLVED_ARROW: &str = "⇒";
+
+/// A single symlink found under the listing root.
+struct Link {
+    /// The symlink entry itself.
+    entry: Entry,
+    /// The link's path relative to the listing root.
+    source: String,
+    /// The canonical target, or `None` if the link is broken.
+    resolved: Option<PathBuf>,
+    /// Whether the target lives outside the listing root.
+    out_of_tree: bool,
+    /// Whether the target lives on a different device than the link.
+    cross_device: bool,
+}
+
+/// Lists every symlink beneath a directory next to its resolved target.
+///
+/// Meant for auditing stow- or nix-style symlink farms: each row shows the
+/// link (relative to the root) and where it finally points, flagging broken
+/// links, targets outside the listed tree, and targets on another device.
+/// Symlinked directories are reported but not descended into.
+pub(crate) struct LinkMap {
+    root: PathBuf,
+    args: Args,
+}
+
+impl DisplayMode for LinkMap {
+    /// Prints the link table followed by a summary of flagged links.
+    fn print(&self, tally: &mut Tally) {
+        let canonical_root = fs::canonicalize(&self.root).unwrap_or_else(|_| self.root.clone());
+
+        let mut links = Vec::new();
+        let mut visited = Visited::new(&RealFs, &self.root, &self.args);
+        self.collect(&self.root, &canonical_root, &mut links, &mut visited);
+
+        let width = links
+            .iter()
+            .map(|link| Width::measure_ansi_text(&Escape::text(&link.source)))
+            .max()
+            .unwrap_or(0);
+
+        for link in &links {
+            tally.add(&link.entry);
+            let colour = EntryStyle::from(&link.entry, &self.args).colour;
+            let source = Align::pad(
+                &ValueStyle::name(&Escape::text(&link.source), colour),
+                width,
+                Alignment::Left,
+            );
+
+            let target = match &link.resolved {
+                Some(resolved) => resolved.display().to_string(),
+                None => symlink::read_symlink_target(link.entry.path()),
+            };
+            let target = Escape::text(&target);
+
+            let flags: Vec<&str> = [
+                (link.resolved.is_none(), "broken"),
+                (link.out_of_tree, "out-of-tree"),
+                (link.cross_device, "cross-device"),
+            ]
+            .into_iter()
+            .filter_map(|(flagged, label)| flagged.then_some(label))
+            .collect();
+
+            if flags.is_empty() {
+                println!("{} {} {}", source, RESOLVED_ARROW, target);
+            } else {
+                println!(
+                    "{} {} {} {}",
+                    source,
+                    RESOLVED_ARROW,
+                    target,
+                    ElementStyle::warning(&format!("[{}]", flags.join(", ")))
+                );
+            }
+        }
+
+        self.print_summary(&links);
+    }
+}
+
+impl LinkMap {
+    /// Creates a new [`LinkMap`] for the given root directory.
+    ///
+    /// # Parameters
+    /// - `root`: The directory whose tree is scanned for symlinks.
+    /// - `args`: Command-line arguments controlling visibility and traversal.
+    pub(crate) fn new(root: PathBuf, args: Args) -> Self {
+        Self { root, args }
+    }
+
+    /// Walks a directory, collecting every symlink and descending into real subdirectories.
+    ///
+    /// # Parameters
+    /// - `dir`: The directory to scan.
+    /// - `canonical_root`: The canonicalised listing root, for out-of-tree checks.
+    /// - `links`: Accumulator for the symlinks found.
+    /// - `visited`: The directories open above this one.
+    fn collect(
+        &self,
+        dir: &Path,
+        canonical_root: &Path,
+        links: &mut Vec<Link>,
+        visited: &mut Visited,
+    ) {
+        for entry in DirReader::from(dir.to_path_buf()).list(&self.args) {
+            if entry.is_symlink() {
+                links.push(self.resolve(entry, canonical_root));
+            } else if visited.enter(&RealFs, &entry, &self.args) {
+                self.collect(entry.path(), canonical_root, links, visited);
+                visited.leave();
+            }
+        }
+    }
+
+    /// Resolves a symlink and works out which flags apply to it.
+    ///
+    /// # Parameters
+    /// - `entry`: The symlink entry.
+    /// - `canonical_root`: The canonicalised listing root.
+    ///
+    /// # Returns
+    /// The [`Link`] row for this symlink.
+    fn resolve(&self, entry: Entry, canonical_root: &Path) -> Link {
+        let path = entry.path();
+        let source = path
+            .strip_prefix(&self.root)
+            .unwrap_or(path)
+            .display()
+            .to_string();
+
+        let resolved = fs::canonicalize(path).ok();
+
+        let out_of_tree = resolved
+            .as_ref()
+            .is_some_and(|target| !target.starts_with(canonical_root));
+
+        // Compare against the directory holding the link, since the link itself
+        // always lives on the same device as its parent
+        let cross_device = resolved.as_ref().is_some_and(|target| {
+            let link_device = path
+                .parent()
+                .and_then(|parent| fs::metadata(parent).ok())
+                .map(|metadata| metadata.dev());
+            let target_device = fs::metadata(target).ok().map(|metadata| metadata.dev());
+            link_device.is_some() && target_device.is_some() && link_device != target_device
+        });
+
+        Link {
+            entry,
+            source,
+            resolved,
+            out_of_tree,
+            cross_device,
+        }
+    }
+
+    /// Prints the number of links found and how many carry each flag.
+    ///
+    /// # Parameters
+    /// - `links`: The collected links.
+    fn print_summary(&self, links: &[Link]) {
+        let broken = links.iter().filter(|link| link.resolved.is_none()).count();
+        let out_of_tree = links.iter().filter(|link| link.out_of_tree).count();
+        let cross_device = links.iter().filter(|link| link.cross_device).count();
+
+        let mut text = match links.len() {
+            1 => "1 symlink".to_string(),
+            count => format!("{} symlinks", count),
+        };
+
+        let details: Vec<String> = [
+            (broken, "broken"),
+            (out_of_tree, "out-of-tree"),
+            (cross_device, "cross-device"),
+        ]
+        .into_iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, label)| format!("{} {}", count, label))
+        .collect();
+
+        if !details.is_empty() {
+            text.push_str(&format!(" ({})", details.join(", ")));
+        }
+
+        println!("\n{}.", ElementStyle::summary(&text));
+    }
+}
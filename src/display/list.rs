@@ -23,19 +23,27 @@ SOFTWARE.
 */
 
 use crate::cli::args::Args;
+use crate::cli::flags::SortBy;
+use crate::display::group::Sections;
 use crate::display::layout::alignment::Align;
 use crate::display::layout::column;
 use crate::display::layout::column::Column;
+use crate::display::layout::graph::SizeGraph;
+use crate::display::layout::totals::Totals;
 use crate::display::layout::width::Width;
+use crate::display::limit::Limit;
 use crate::display::mode::DisplayMode;
 use crate::display::output::quotes::Quotes;
 use crate::display::styles::column::ColumnStyle;
+use crate::display::styles::entry::StyledEntry;
 use crate::display::summary;
 use crate::display::summary::Summary;
+use crate::display::tally::Tally;
 use crate::display::traversal::RecursiveTraversal;
 use crate::fs::entry::Entry;
 use std::cell::Cell;
 use std::collections::HashMap;
+use std::io::{self, BufWriter, Write};
 
 impl DisplayMode for List {
     /// Prints the table output, either recursively or non-recursively based on args.
@@ -45,11 +53,18 @@ impl DisplayMode for List {
     /// * If `args.recursive` is true, displays entries in a hierarchical format
     ///   with directory titles, recursing into subdirectories
     /// * Otherwise, displays a single table with properly aligned columns
-    fn print(&self) {
+    fn print(&self, tally: &mut Tally) {
         if self.args.recursive {
-            self.render_recursive(&self.entries, None);
+            self.render_recursive(&self.entries, None, tally);
         } else {
-            Self::nonrecursive(&self.entries, &self.args);
+            tally.extend(&self.entries);
+            SizeGraph::scope(&self.entries, &self.args, || {
+                Limit::render(&self.entries, &self.args, |shown| {
+                    Sections::render(shown, &self.args, |section| {
+                        Self::nonrecursive(section, &self.args)
+                    })
+                })
+            });
         }
 
         self.print_summary();
@@ -60,9 +75,15 @@ impl RecursiveTraversal for List {
     /// Renders entries at a single directory level in list format.
     ///
     /// This implementation delegates to the existing `nonrecursive()` method
-    /// which handles column width calculation and formatted table output.
+    /// which handles column width calculation and formatted table output,
+    /// once per section when `--group-by` is set and capped by `--max-entries`.
+    /// Size graphs are drawn against the whole level.
     fn render_level(&self, entries: &[Entry], args: &Args) {
-        Self::nonrecursive(entries, args);
+        SizeGraph::scope(entries, args, || {
+            Limit::render(entries, args, |shown| {
+                Sections::render(shown, args, |section| Self::nonrecursive(section, args))
+            })
+        });
     }
 
     /// Returns a reference to the Args for this renderer.
@@ -130,50 +151,146 @@ impl List {
             return;
         }
 
+        // A `--format` template picks its own columns and skips the table layout
+        if let Some(template) = &args.format {
+            for entry in entries {
+                println!("{}", template.render(entry, args));
+            }
+            return;
+        }
+
         let columns = column::Selector::select(args);
-        let mut width_calc = Width::new();
-        let widths = width_calc.calculate(entries, &columns, args);
 
         // Add an alignment space in any entries in have got special characters and will get quoted
         let add_alignment_space = entries
             .iter()
             .any(|entry| Quotes::is_quotable(entry.name()));
 
+        if Self::is_names_only(&columns, args) {
+            Self::stream_names(entries, args, add_alignment_space);
+            return;
+        }
+
+        // The footer is summed once, and the columns widened so it fits
+        let totals = args.totals.then(|| Totals::compute(entries, args));
+        let mut width_calc = Width::new();
+        let mut widths = width_calc.calculate(entries, &columns, args);
+        if let Some(totals) = &totals {
+            width_calc.fit_totals(&mut widths, totals, &columns, args);
+        }
+
         if args.headers {
             Column::headers(&widths, args);
         }
 
+        let subtotals = args.subtotals && matches!(args.sort.first(), Some(SortBy::Extension));
+        let mut run_start = 0;
+
+        // Rows are rendered into one reused buffer and written through a locked,
+        // buffered stdout rather than a `println!` (and its flush) per row
+        let mut out = BufWriter::new(io::stdout().lock());
+        let mut line = String::new();
+        let mut cell = String::new();
+
+        for (index, entry) in entries.iter().enumerate() {
+            line.clear();
+            Self::render_row(
+                &mut line,
+                &mut cell,
+                entry,
+                &widths,
+                &columns,
+                args,
+                add_alignment_space,
+            );
+            if writeln!(out, "{line}").is_err() {
+                return;
+            }
+
+            if !subtotals {
+                continue;
+            }
+
+            // Close an extension's run when the next entry starts another (or there is none)
+            let extension = entry.extension().to_lowercase();
+            if entries
+                .get(index + 1)
+                .is_none_or(|next| next.extension().to_lowercase() != extension)
+            {
+                let _ = out.flush();
+                Totals::compute(&entries[run_start..=index], args)
+                    .print_subtotal(&extension, &widths, &columns, args);
+                run_start = index + 1;
+            }
+        }
+
+        let _ = out.flush();
+
+        if let Some(totals) = totals {
+            totals.print(&widths, &columns, args);
+        }
+    }
+
+    /// Checks whether a table would hold nothing but names (e.g. plain `-1`).
+    ///
+    /// Such a table has no columns to align, so it can skip width
+    /// measurement and the per-cell row machinery.
+    ///
+    /// # Parameters
+    /// - `columns`: The selected columns.
+    /// - `args`: Command-line arguments (checked for header and subtotal rows).
+    fn is_names_only(columns: &[Column], args: &Args) -> bool {
+        columns == [Column::Name] && !args.headers && !args.subtotals
+    }
+
+    /// Prints one styled name per line, without padding them to a common width.
+    ///
+    /// # Parameters
+    /// - `entries`: The entries to print.
+    /// - `args`: Command-line arguments controlling name styling.
+    /// - `add_alignment_space`: Whether to add a space for quote-alignment.
+    fn stream_names(entries: &[Entry], args: &Args, add_alignment_space: bool) {
+        let mut out = BufWriter::new(io::stdout().lock());
+
         for entry in entries {
-            Self::render_row(entry, &widths, &columns, args, add_alignment_space);
+            let styled_entry = StyledEntry::new(entry, args);
+            let name = styled_entry.load(args, add_alignment_space).name;
+            if writeln!(out, "{name}").is_err() {
+                return;
+            }
         }
+
+        let _ = out.flush();
     }
 
     /// Renders a single row in list format with styled and aligned columns.
     ///
     /// # Parameters
+    /// - `line`: The buffer the row is appended to.
+    /// - `cell`: Scratch buffer each cell is styled into before it is padded.
     /// - `entry`: The entry to render.
     /// - `widths`: Pre-calculated column widths for alignment.
     /// - `columns`: The columns to display.
     /// - `args`: Command-line arguments controlling display options.
     /// - `add_alignment_space`: Whether to add a space for quote-alignment.
     fn render_row(
+        line: &mut String,
+        cell: &mut String,
         entry: &Entry,
         widths: &HashMap<Column, usize>,
         columns: &[Column],
         args: &Args,
         add_alignment_space: bool,
     ) {
-        let mut parts = Vec::new();
-
-        for column in columns {
-            let styled_column = ColumnStyle::get(entry, column, args, add_alignment_space);
-            let width = *widths
-                .get(column)
-                .unwrap_or(&Width::measure_ansi_text(&styled_column));
-            let padded = Align::pad(&styled_column, width, column.alignment());
-            parts.push(padded);
-        }
+        for (index, column) in columns.iter().enumerate() {
+            if index > 0 {
+                line.push(' ');
+            }
 
-        println!("{}", parts.join(" "));
+            cell.clear();
+            ColumnStyle::write(cell, entry, column, args, add_alignment_space);
+            let width = widths.get(column).copied().unwrap_or_default();
+            Align::pad_into(line, cell, width, column.alignment());
+        }
     }
 }
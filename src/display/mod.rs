@@ -46,15 +46,26 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
+pub mod catalog;
 pub(crate) mod classify;
+pub mod events;
 pub mod factory;
 pub(crate) mod grid;
+pub(crate) mod group;
+pub(crate) mod html;
 pub mod layout;
+pub(crate) mod limit;
+pub(crate) mod links;
 pub(crate) mod list;
 pub(crate) mod mode;
+pub(crate) mod narrow;
+pub(crate) mod ndjson;
 pub mod output;
+pub(crate) mod stream;
 pub mod styles;
 pub(crate) mod summary;
+pub mod tally;
 pub mod theme;
 pub(crate) mod traversal;
 pub(crate) mod tree;
+pub(crate) mod usage;
@@ -22,8 +22,13 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
+use crate::display::tally::Tally;
+
 /// Trait implemented by all output renderers (grid, list, tree).
 pub trait DisplayMode {
     /// Prints the formatted output to stdout.
-    fn print(&self);
+    ///
+    /// # Parameters
+    /// - `tally`: Counts every entry printed, for the `--grand-total` footer.
+    fn print(&self, tally: &mut Tally);
 }
@@ -0,0 +1,288 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+//! Stacked renderer for very narrow terminals.
+//!
+//! Below [`NARROW_WIDTH`] columns a table wraps every row. Instead, each entry
+//! gets its name on a line of its own, with its other columns stacked under it
+//! as labelled, indented lines:
+//!
+//! ```text
+//! notes.txt
+//!   Permissions  .rw-r--r--
+//!   Size         1.2 kB
+//! ```
+
+use crate::cli::args::Args;
+use crate::cli::flags::SortBy;
+use crate::display::group::Sections;
+use crate::display::layout::alignment::{Align, Alignment};
+use crate::display::layout::column;
+use crate::display::layout::column::Column;
+use crate::display::layout::graph::SizeGraph;
+use crate::display::layout::totals::Totals;
+use crate::display::layout::width::Width;
+use crate::display::limit::Limit;
+use crate::display::mode::DisplayMode;
+use crate::display::styles::column::ColumnStyle;
+use crate::display::styles::element::ElementStyle;
+use crate::display::summary;
+use crate::display::summary::Summary;
+use crate::display::tally::Tally;
+use crate::display::theme::colours::{Colour, ColourPaint};
+use crate::display::traversal::RecursiveTraversal;
+use crate::fs::entry::Entry;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::io::{self, BufWriter, Write};
+
+/// Terminals narrower than this many columns get the stacked layout for tables.
+pub const NARROW_WIDTH: usize = 40;
+
+/// Indent of the labelled lines under each name.
+const INDENT: &str = "  ";
+
+impl DisplayMode for Narrow {
+    /// Prints the stacked output, either recursively or non-recursively based on args.
+    fn print(&self, tally: &mut Tally) {
+        if self.args.recursive {
+            self.render_recursive(&self.entries, None, tally);
+        } else {
+            tally.extend(&self.entries);
+            self.render_level(&self.entries, &self.args);
+        }
+
+        self.print_summary();
+    }
+}
+
+impl RecursiveTraversal for Narrow {
+    /// Renders entries at a single directory level as stacked blocks, once per
+    /// section when `--group-by` is set and capped by `--max-entries`.
+    fn render_level(&self, entries: &[Entry], args: &Args) {
+        SizeGraph::scope(entries, args, || {
+            Limit::render(entries, args, |shown| {
+                Sections::render(shown, args, |section| Self::nonrecursive(section, args))
+            })
+        });
+    }
+
+    /// Returns a reference to the Args for this renderer.
+    fn get_args(&self) -> &Args {
+        &self.args
+    }
+
+    fn dir_count(&self) -> &Cell<usize> {
+        &self.dir_count
+    }
+
+    fn file_count(&self) -> &Cell<usize> {
+        &self.file_count
+    }
+}
+
+/// Renderer that stacks each entry's columns under its name.
+pub(crate) struct Narrow {
+    /// The filesystem entries to display
+    entries: Vec<Entry>,
+    /// Command-line arguments controlling display options
+    args: Args,
+    /// Accumulated directory count during recursive traversal
+    dir_count: Cell<usize>,
+    /// Accumulated file count during recursive traversal
+    file_count: Cell<usize>,
+}
+
+impl Summary for Narrow {
+    /// Returns directory and file counts, accumulated during traversal in recursive mode.
+    fn counts(&self) -> (usize, usize) {
+        if self.args.recursive {
+            (self.dir_count.get(), self.file_count.get())
+        } else {
+            summary::count_entries(&self.entries)
+        }
+    }
+}
+
+impl Narrow {
+    /// Creates a new [`Narrow`] renderer.
+    ///
+    /// # Parameters
+    /// - `entries`: The filesystem entries to display.
+    /// - `args`: Command-line arguments controlling columns and formatting.
+    pub(crate) fn new(entries: Vec<Entry>, args: Args) -> Self {
+        Self {
+            entries,
+            args,
+            dir_count: Cell::new(0),
+            file_count: Cell::new(0),
+        }
+    }
+
+    /// Checks whether a table would be laid out narrower than [`NARROW_WIDTH`].
+    ///
+    /// Tables of names alone (plain `-1`) don't count, since they never wrap.
+    ///
+    /// # Parameters
+    /// - `args`: Command-line arguments (`--width` and the selected columns).
+    ///
+    /// # Returns
+    /// `true` if the stacked layout should replace the table.
+    pub(crate) fn is_too_narrow(args: &Args) -> bool {
+        let width = match args.width {
+            None => Width::terminal_width(),
+            Some(0) => usize::MAX, // 0 means no limit
+            Some(w) => w,
+        };
+
+        width < NARROW_WIDTH
+            && column::Selector::select(args)
+                .iter()
+                .any(|column| *column != Column::Name)
+    }
+
+    /// Prints one block per entry, followed by the `--subtotals` and `--totals` lines.
+    ///
+    /// # Parameters
+    /// - `entries`: The entries to display.
+    /// - `args`: Command-line arguments controlling column selection and formatting.
+    fn nonrecursive(entries: &[Entry], args: &Args) {
+        if entries.is_empty() {
+            return;
+        }
+
+        let columns: Vec<Column> = column::Selector::select(args)
+            .into_iter()
+            .filter(|column| *column != Column::Name)
+            .collect();
+        let label_width = columns
+            .iter()
+            .map(|column| Width::measure_ansi_text(column.header()))
+            .max()
+            .unwrap_or(0);
+
+        let subtotals = args.subtotals && matches!(args.sort.first(), Some(SortBy::Extension));
+        let mut run_start = 0;
+
+        let mut out = BufWriter::new(io::stdout().lock());
+        let mut block = String::new();
+
+        for (index, entry) in entries.iter().enumerate() {
+            block.clear();
+            Self::render_block(&mut block, entry, &columns, label_width, args);
+            if write!(out, "{block}").is_err() {
+                return;
+            }
+
+            if !subtotals {
+                continue;
+            }
+
+            // Close an extension's run when the next entry starts another (or there is none)
+            let extension = entry.extension().to_lowercase();
+            if entries
+                .get(index + 1)
+                .is_none_or(|next| next.extension().to_lowercase() != extension)
+            {
+                let _ = out.flush();
+                Totals::compute(&entries[run_start..=index], args).print_subtotal(
+                    &extension,
+                    &HashMap::new(),
+                    &[Column::Name],
+                    args,
+                );
+                run_start = index + 1;
+            }
+        }
+
+        let _ = out.flush();
+
+        if args.totals {
+            Self::print_totals(&Totals::compute(entries, args), &columns, label_width, args);
+        }
+    }
+
+    /// Renders one entry: its name, then a labelled line per other column.
+    ///
+    /// # Parameters
+    /// - `block`: The buffer the block is appended to.
+    /// - `entry`: The entry to render.
+    /// - `columns`: The columns to stack under the name.
+    /// - `label_width`: The width labels are padded to.
+    /// - `args`: Command-line arguments controlling display options.
+    fn render_block(
+        block: &mut String,
+        entry: &Entry,
+        columns: &[Column],
+        label_width: usize,
+        args: &Args,
+    ) {
+        ColumnStyle::write(block, entry, &Column::Name, args, false);
+        block.push('\n');
+
+        for column in columns {
+            Self::push_label(block, column, label_width);
+            ColumnStyle::write(block, entry, column, args, false);
+            block.push('\n');
+        }
+    }
+
+    /// Prints the `--totals` block: the entry count, then the summed columns.
+    ///
+    /// # Parameters
+    /// - `totals`: The totals of the listed entries.
+    /// - `columns`: The columns stacked under each name.
+    /// - `label_width`: The width labels are padded to.
+    /// - `args`: Command-line arguments controlling formatting.
+    fn print_totals(totals: &Totals, columns: &[Column], label_width: usize, args: &Args) {
+        let mut block = String::new();
+
+        if let Some(count) = totals.value(&Column::Name, args) {
+            block.push_str(&ElementStyle::table_footer(&count));
+            block.push('\n');
+        }
+
+        for column in columns {
+            if let Some(value) = totals.value(column, args) {
+                Self::push_label(&mut block, column, label_width);
+                block.push_str(&ElementStyle::table_footer(&value));
+                block.push('\n');
+            }
+        }
+
+        print!("{block}");
+    }
+
+    /// Appends the indent and a column's padded, dimmed label.
+    ///
+    /// # Parameters
+    /// - `block`: The buffer to append to.
+    /// - `column`: The column whose header labels the line.
+    /// - `label_width`: The width to pad the label to.
+    fn push_label(block: &mut String, column: &Column, label_width: usize) {
+        let label = Colour::DarkGray.normal().apply_to(column.header());
+        block.push_str(INDENT);
+        Align::pad_into(block, &label, label_width, Alignment::Left);
+        block.push(' ');
+    }
+}
@@ -0,0 +1,111 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+use crate::cli::args::Args;
+use crate::cli::flags::OutputFormat;
+use crate::display::mode::DisplayMode;
+use crate::display::output::errors::Errors;
+use crate::display::output::json::Json;
+use crate::display::tally::Tally;
+use crate::fs::dir::DirReader;
+use crate::fs::entry::Entry;
+use crate::fs::tree::Visited;
+use crate::fs::vfs::RealFs;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether an `--output json` document has had its first entry, so the rest get a comma.
+static DOCUMENT_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Newline-delimited JSON renderer: one object per entry, printed as soon as it's read.
+///
+/// Each line is self-contained, so the output can be consumed incrementally
+/// (`jq -c`, log shippers, and eventually a watch-mode event stream). With
+/// `--output json` the same objects become the `entries` of one document.
+pub(crate) struct Ndjson {
+    entries: Vec<Entry>,
+    args: Args,
+}
+
+impl DisplayMode for Ndjson {
+    /// Prints one JSON object per entry, descending into directories with `--recursive` or `--tree`.
+    fn print(&self, tally: &mut Tally) {
+        let mut visited = Visited::new(&RealFs, self.args.root(), &self.args);
+        self.emit(&self.entries, &mut visited, tally);
+    }
+}
+
+impl Ndjson {
+    /// Creates a new [`Ndjson`] renderer.
+    ///
+    /// # Parameters
+    /// - `entries`: The top-level entries to emit.
+    /// - `args`: Command-line arguments controlling traversal and metadata.
+    pub(crate) fn new(entries: Vec<Entry>, args: Args) -> Self {
+        Self { entries, args }
+    }
+
+    /// Wraps everything `print_entries` emits in an `--output json` document,
+    /// followed by the diagnostics collected along the way.
+    ///
+    /// # Parameters
+    /// - `print_entries`: Prints the listing(s) through [`Ndjson`] renderers.
+    pub(crate) fn document(print_entries: impl FnOnce()) {
+        println!("{{\"entries\":[");
+        print_entries();
+        println!("\n],\"errors\":[{}]}}", Errors::take().join(",\n"));
+    }
+
+    /// Emits a level of entries, recursing into subdirectories when requested.
+    ///
+    /// # Parameters
+    /// - `entries`: The entries to emit.
+    /// - `visited`: The directories open above this one.
+    /// - `tally`: Counts every entry emitted.
+    fn emit(&self, entries: &[Entry], visited: &mut Visited, tally: &mut Tally) {
+        let descend = self.args.recursive || self.args.tree;
+
+        for entry in entries {
+            let mut entry = entry.clone();
+            entry.unconditional_metadata(self.args.dereference);
+            tally.add(&entry);
+            let object = Json::entry(&entry);
+            if self.args.output == OutputFormat::Json {
+                let separator = if DOCUMENT_STARTED.swap(true, Ordering::SeqCst) {
+                    ",\n"
+                } else {
+                    ""
+                };
+                print!("{separator}{object}");
+            } else {
+                println!("{object}");
+            }
+
+            if descend && visited.enter(&RealFs, &entry, &self.args) {
+                let children = DirReader::from(entry.path().clone()).list(&self.args);
+                self.emit(&children, visited, tally);
+                visited.leave();
+            }
+        }
+    }
+}
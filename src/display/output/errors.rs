@@ -0,0 +1,226 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Structured diagnostics for entries that couldn't be listed.
+//!
+//! With `--output json` they are gathered into the document's `errors`
+//! array; with `--errors json` (and any other output) each is written to
+//! stderr as a one-line JSON object. Otherwise the usual human-readable
+//! messages are printed.
+//!
+//! Whatever the channel, failures to read an entry's own data (metadata,
+//! xattrs, ACL) are also kept by path, so the listing can badge the entry.
+
+use crate::cli::flags::{ErrorFormat, OutputFormat};
+use crate::display::output::json::Json;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+/// Marks an entry whose data couldn't all be read.
+const BADGE: char = '⚠';
+
+/// Where structured diagnostics go.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Channel {
+    /// Not structured: callers print their own messages.
+    Text,
+    /// One JSON object per line on stderr.
+    Stderr,
+    /// Held for the `errors` array of an `--output json` document.
+    Document,
+}
+
+static CHANNEL: OnceLock<Channel> = OnceLock::new();
+static REPORTED: OnceLock<Mutex<Reported>> = OnceLock::new();
+
+/// Set once any entry has a failure, so listings without any skip the lock.
+static ENTRY_FAILED: AtomicBool = AtomicBool::new(false);
+
+/// Diagnostics reported so far.
+#[derive(Default)]
+struct Reported {
+    /// Encoded objects awaiting an `--output json` document.
+    collected: Vec<String>,
+    /// What has been reported, so each failure appears once.
+    seen: HashSet<(ErrorKind, PathBuf)>,
+    /// Entry-level failures and their messages, by path.
+    entries: HashMap<PathBuf, Vec<(ErrorKind, String)>>,
+}
+
+/// What went wrong with an entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// A path operand doesn't exist.
+    NotFound,
+    /// A directory couldn't be read.
+    UnreadableDirectory,
+    /// An entry's metadata couldn't be read, so its fields are missing.
+    StatFailed,
+    /// An entry's extended attributes couldn't be listed.
+    XattrFailed,
+    /// An entry's ACL couldn't be checked.
+    AclFailed,
+}
+
+impl ErrorKind {
+    /// Returns the kind as it appears in the `error` field.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::NotFound => "not_found",
+            Self::UnreadableDirectory => "unreadable_directory",
+            Self::StatFailed => "stat_failed",
+            Self::XattrFailed => "xattr_failed",
+            Self::AclFailed => "acl_failed",
+        }
+    }
+
+    /// Returns what couldn't be read, for a failure on the entry itself.
+    ///
+    /// # Returns
+    /// `None` for failures that aren't about one entry's data.
+    fn subject(self) -> Option<&'static str> {
+        match self {
+            Self::StatFailed => Some("metadata"),
+            Self::XattrFailed => Some("xattrs"),
+            Self::AclFailed => Some("ACL"),
+            Self::NotFound | Self::UnreadableDirectory => None,
+        }
+    }
+}
+
+/// Global sink for structured diagnostics.
+pub struct Errors;
+
+impl Errors {
+    /// Chooses where diagnostics go. Only the first call takes effect.
+    ///
+    /// # Parameters
+    /// - `errors`: The `--errors` format.
+    /// - `output`: The `--output` format; `json` always collects diagnostics.
+    pub fn setup(errors: ErrorFormat, output: OutputFormat) {
+        let channel = match (output, errors) {
+            (OutputFormat::Json, _) => Channel::Document,
+            (_, ErrorFormat::Json) => Channel::Stderr,
+            (_, ErrorFormat::Text) => Channel::Text,
+        };
+        let _ = CHANNEL.set(channel);
+    }
+
+    /// Checks whether diagnostics are structured, so callers should report
+    /// through [`Errors::report`] instead of printing text.
+    pub fn is_structured() -> bool {
+        Self::channel() != Channel::Text
+    }
+
+    /// Records a diagnostic, once per kind and path.
+    ///
+    /// Failures on an entry's own data are always kept for its badge; other
+    /// than that, does nothing unless diagnostics are structured.
+    ///
+    /// # Parameters
+    /// - `kind`: What went wrong.
+    /// - `path`: The entry it happened to.
+    /// - `message`: The underlying error, e.g. `Permission denied (os error 13)`.
+    pub fn report(kind: ErrorKind, path: &Path, message: &str) {
+        let channel = Self::channel();
+        if channel == Channel::Text && kind.subject().is_none() {
+            return;
+        }
+
+        let Ok(mut reported) = Self::reported().lock() else {
+            return;
+        };
+
+        // Metadata is retried when it failed before, so the same failure can come up again
+        if !reported.seen.insert((kind, path.to_path_buf())) {
+            return;
+        }
+
+        if kind.subject().is_some() {
+            reported
+                .entries
+                .entry(path.to_path_buf())
+                .or_default()
+                .push((kind, message.to_string()));
+            ENTRY_FAILED.store(true, Ordering::Relaxed);
+        }
+        if channel == Channel::Text {
+            return;
+        }
+
+        let object = Json::error(kind.as_str(), path, message);
+        match channel {
+            Channel::Document => reported.collected.push(object),
+            _ => eprintln!("{object}"),
+        }
+    }
+
+    /// Takes the diagnostics collected for an `--output json` document.
+    ///
+    /// # Returns
+    /// One encoded JSON object per diagnostic, in the order they were reported.
+    pub fn take() -> Vec<String> {
+        Self::reported()
+            .lock()
+            .map(|mut reported| std::mem::take(&mut reported.collected))
+            .unwrap_or_default()
+    }
+
+    /// Returns the badge for an entry whose metadata, xattrs or ACL couldn't be read.
+    ///
+    /// # Parameters
+    /// - `path`: The entry's path.
+    /// - `verbose`: If `true`, follows the badge with what failed and why.
+    ///
+    /// # Returns
+    /// The badge text (e.g. `⚠ metadata: Permission denied (os error 13)`), or
+    /// `None` if nothing failed for this entry.
+    pub fn badge(path: &Path, verbose: bool) -> Option<String> {
+        if !ENTRY_FAILED.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let reported = Self::reported().lock().ok()?;
+        let failures = reported.entries.get(path)?;
+        if !verbose {
+            return Some(BADGE.to_string());
+        }
+
+        let reasons: Vec<String> = failures
+            .iter()
+            .filter_map(|(kind, message)| Some(format!("{}: {}", kind.subject()?, message)))
+            .collect();
+        Some(format!("{} {}", BADGE, reasons.join("; ")))
+    }
+
+    fn reported() -> &'static Mutex<Reported> {
+        REPORTED.get_or_init(Mutex::default)
+    }
+
+    fn channel() -> Channel {
+        CHANNEL.get().copied().unwrap_or(Channel::Text)
+    }
+}
@@ -0,0 +1,97 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Escaping of control characters in text that reaches the terminal.
+//!
+//! File names, libmagic descriptions, extended attributes, SELinux contexts
+//! and the like come from the filesystem or file contents, not from cerium.
+//! Printed raw, an embedded `ESC` could recolour the rest of the listing, move
+//! the cursor or set the window title, and a bidi override could reorder what
+//! the reader sees. [`Escape`] replaces each such character with a visible
+//! escape: `\x1b` for ASCII controls and `\u{202e}` for the rest.
+
+use crate::fs::names::NameIssue;
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::sync::Arc;
+
+/// Makes externally derived text safe to print.
+pub struct Escape;
+
+impl Escape {
+    /// Checks whether a character must be escaped before printing.
+    ///
+    /// # Parameters
+    /// - `character`: The character to check.
+    pub fn is_unsafe(character: char) -> bool {
+        character.is_control() || NameIssue::is_bidi_control(character)
+    }
+
+    /// Escapes control and bidi characters in text.
+    ///
+    /// # Parameters
+    /// - `text`: The text to escape.
+    ///
+    /// # Returns
+    /// The text unchanged (and unallocated) when nothing needed escaping.
+    ///
+    /// # Examples
+    ///
+    /// ```text
+    /// Escape::text("report.pdf")         // => "report.pdf"
+    /// Escape::text("a\x1b[31mb")         // => "a\\x1b[31mb"
+    /// Escape::text("evil\u{202e}fdp.exe") // => "evil\\u{202e}fdp.exe"
+    /// ```
+    pub fn text(text: &str) -> Cow<'_, str> {
+        if !text.chars().any(Self::is_unsafe) {
+            return Cow::Borrowed(text);
+        }
+
+        let mut escaped = String::with_capacity(text.len() + 8);
+        for character in text.chars() {
+            if !Self::is_unsafe(character) {
+                escaped.push(character);
+            } else if character.is_ascii() {
+                let _ = write!(escaped, "\\x{:02x}", character as u32);
+            } else {
+                let _ = write!(escaped, "\\u{{{:x}}}", character as u32);
+            }
+        }
+        Cow::Owned(escaped)
+    }
+
+    /// Escapes a cell value, reusing it when nothing needed escaping.
+    ///
+    /// # Parameters
+    /// - `value`: The value to escape.
+    ///
+    /// # Returns
+    /// The same [`Arc`] for safe values, otherwise a new escaped one.
+    pub fn value(value: Arc<str>) -> Arc<str> {
+        match Self::text(&value) {
+            Cow::Borrowed(_) => value,
+            Cow::Owned(escaped) => escaped.into(),
+        }
+    }
+}
@@ -24,7 +24,8 @@ SOFTWARE.
 
 use crate::cli::flags::DateFormat;
 use crate::display::output::formats::format::Format;
-use chrono::{DateTime, Local};
+use crate::fs::clock::{Clock, ClockSettings};
+use chrono::{DateTime, Local, Utc};
 use humanly::HumanDuration;
 use std::sync::Arc;
 use std::time::SystemTime;
@@ -37,17 +38,29 @@ impl Format<Option<SystemTime>> for Date {
 }
 
 /// Formats timestamps according to the selected [`DateFormat`].
-pub(crate) struct Date {
+pub struct Date {
     date_format: DateFormat,
+    /// Reference point for relative dates
+    clock: Box<dyn Clock>,
 }
 
 impl Date {
-    /// Creates a new [`Date`] formatter.
+    /// Creates a new [`Date`] formatter measuring relative dates against the process clock
+    /// (the system clock, or a pinned one under `--deterministic`).
     ///
     /// # Parameters
     /// - `date_format`: The display format to use.
-    pub(crate) fn new(date_format: DateFormat) -> Self {
-        Self { date_format }
+    pub fn new(date_format: DateFormat) -> Self {
+        Self::with_clock(date_format, ClockSettings::clock())
+    }
+
+    /// Creates a new [`Date`] formatter measuring relative dates against `clock`.
+    ///
+    /// # Parameters
+    /// - `date_format`: The display format to use.
+    /// - `clock`: The source of "now" for the humanised format.
+    pub fn with_clock(date_format: DateFormat, clock: Box<dyn Clock>) -> Self {
+        Self { date_format, clock }
     }
 
     /// Dispatches to the appropriate date formatting method.
@@ -55,9 +68,10 @@ impl Date {
     /// # Parameters
     /// - `system_time`: The timestamp to format, or `None` for a placeholder.
     fn format_date(&self, system_time: Option<SystemTime>) -> Arc<str> {
-        match self.date_format {
+        match &self.date_format {
             DateFormat::Humanly => self.humanised(system_time),
-            DateFormat::Locale => Self::locale(system_time),
+            DateFormat::Locale => Self::strftime(system_time, "%b %d %H:%M"),
+            DateFormat::Custom(pattern) => Self::strftime(system_time, pattern),
             DateFormat::Timestamp => match system_time {
                 Some(st) => match st.duration_since(SystemTime::UNIX_EPOCH) {
                     Ok(dur) => dur.as_secs().to_string().into(),
@@ -73,18 +87,30 @@ impl Date {
     /// # Parameters
     /// - `system_time`: The timestamp to format.
     fn humanised(&self, system_time: Option<SystemTime>) -> Arc<str> {
-        Arc::from(HumanDuration::from(system_time).to_string())
+        // HumanDuration measures against the wall clock, so re-anchor the age from our clock on it
+        let now = SystemTime::now();
+        let anchored = system_time.map(|st| match self.clock.now().duration_since(st) {
+            Ok(age) => now.checked_sub(age).unwrap_or(st),
+            Err(ahead) => now.checked_add(ahead.duration()).unwrap_or(st),
+        });
+
+        Arc::from(HumanDuration::from(anchored).to_string())
     }
 
-    /// Formats the timestamp using the locale date format.
+    /// Formats the timestamp with a strftime pattern in local time, or in UTC under `--deterministic`.
     ///
     /// # Parameters
     /// - `system_time`: The timestamp to format, or `None` for `"-"`.
-    fn locale(system_time: Option<SystemTime>) -> Arc<str> {
+    /// - `pattern`: The strftime pattern, validated when it was parsed.
+    fn strftime(system_time: Option<SystemTime>, pattern: &str) -> Arc<str> {
         match system_time {
+            Some(st) if ClockSettings::is_deterministic() => {
+                let datetime: DateTime<Utc> = st.into();
+                datetime.format(pattern).to_string().into()
+            }
             Some(st) => {
                 let datetime: DateTime<Local> = st.into();
-                datetime.format("%b %d %H:%M").to_string().into()
+                datetime.format(pattern).to_string().into()
             }
             None => "-".into(),
         }
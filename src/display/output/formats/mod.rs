@@ -22,7 +22,7 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
-pub(crate) mod date;
+pub mod date;
 pub mod format;
 pub(crate) mod number;
 pub(crate) mod ownership;
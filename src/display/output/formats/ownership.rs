@@ -29,6 +29,12 @@ use std::mem;
 use std::ptr;
 use std::sync::Arc;
 
+/// Starting size of the `getpwuid_r`/`getgrgid_r` scratch buffer.
+const INITIAL_BUFFER_LEN: usize = 16 * 1024;
+
+/// Largest scratch buffer tried before falling back to the numeric ID.
+const MAX_BUFFER_LEN: usize = 1024 * 1024;
+
 /// Resolved user identity with name and UID.
 #[derive(Debug)]
 struct User {
@@ -68,10 +74,10 @@ impl Ownership {
         unsafe {
             let mut passwd_entry: passwd = mem::zeroed();
             let mut passwd_result: *mut passwd = ptr::null_mut();
-            let mut buffer = vec![0u8; 16 * 1024];
+            let mut buffer = vec![0u8; INITIAL_BUFFER_LEN];
 
             // c_char is i8 on most platforms but u8 on Android
-            let status = getpwuid_r(
+            let mut status = getpwuid_r(
                 user_id,
                 &mut passwd_entry,
                 buffer.as_mut_ptr() as *mut c_char,
@@ -79,6 +85,18 @@ impl Ownership {
                 &mut passwd_result,
             );
 
+            // Entries with huge member lists (or Android's synthesised ones) may need more room
+            while status == libc::ERANGE && buffer.len() < MAX_BUFFER_LEN {
+                buffer.resize(buffer.len() * 2, 0);
+                status = getpwuid_r(
+                    user_id,
+                    &mut passwd_entry,
+                    buffer.as_mut_ptr() as *mut c_char,
+                    buffer.len(),
+                    &mut passwd_result,
+                );
+            }
+
             if status == 0 && !passwd_result.is_null() && !passwd_entry.pw_name.is_null() {
                 return User {
                     name: CStr::from_ptr(passwd_entry.pw_name)
@@ -106,10 +124,10 @@ impl Ownership {
         unsafe {
             let mut group_entry: group = mem::zeroed();
             let mut group_result: *mut group = ptr::null_mut();
-            let mut buffer = vec![0u8; 16 * 1024];
+            let mut buffer = vec![0u8; INITIAL_BUFFER_LEN];
 
             // c_char is i8 on most platforms but u8 on Android
-            let status = getgrgid_r(
+            let mut status = getgrgid_r(
                 group_id,
                 &mut group_entry,
                 buffer.as_mut_ptr() as *mut c_char,
@@ -117,6 +135,18 @@ impl Ownership {
                 &mut group_result,
             );
 
+            // Entries with huge member lists (or Android's synthesised ones) may need more room
+            while status == libc::ERANGE && buffer.len() < MAX_BUFFER_LEN {
+                buffer.resize(buffer.len() * 2, 0);
+                status = getgrgid_r(
+                    group_id,
+                    &mut group_entry,
+                    buffer.as_mut_ptr() as *mut c_char,
+                    buffer.len(),
+                    &mut group_result,
+                );
+            }
+
             if status == 0 && !group_result.is_null() && !group_entry.gr_name.is_null() {
                 return Group {
                     name: CStr::from_ptr(group_entry.gr_name)
@@ -0,0 +1,338 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! HTML encoding for `--output html`.
+//!
+//! Cells are styled by the same code as the terminal listing, so rather than
+//! style them twice, the ANSI escapes they carry are turned into inline CSS:
+//! SGR colours and attributes become `<span style>`, OSC 8 hyperlinks become
+//! `<a href>`. Indexed colours use the xterm palette.
+
+use std::fmt::Write;
+
+/// The 16 basic colours as xterm draws them.
+const BASIC: [(u8, u8, u8); 16] = [
+    (0x00, 0x00, 0x00),
+    (0xcd, 0x00, 0x00),
+    (0x00, 0xcd, 0x00),
+    (0xcd, 0xcd, 0x00),
+    (0x00, 0x00, 0xee),
+    (0xcd, 0x00, 0xcd),
+    (0x00, 0xcd, 0xcd),
+    (0xe5, 0xe5, 0xe5),
+    (0x7f, 0x7f, 0x7f),
+    (0xff, 0x00, 0x00),
+    (0x00, 0xff, 0x00),
+    (0xff, 0xff, 0x00),
+    (0x5c, 0x5c, 0xff),
+    (0xff, 0x00, 0xff),
+    (0x00, 0xff, 0xff),
+    (0xff, 0xff, 0xff),
+];
+
+/// The SGR state in effect at a point in the text.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Pen {
+    foreground: Option<(u8, u8, u8)>,
+    background: Option<(u8, u8, u8)>,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+    reverse: bool,
+    strikethrough: bool,
+}
+
+impl Pen {
+    /// Applies the parameters of one SGR sequence (`\x1b[...m`).
+    ///
+    /// # Parameters
+    /// - `params`: The numeric parameters; empty means reset.
+    fn apply(&mut self, params: &[u16]) {
+        if params.is_empty() {
+            *self = Self::default();
+            return;
+        }
+
+        let mut params = params.iter().copied();
+        while let Some(param) = params.next() {
+            match param {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                7 => self.reverse = true,
+                9 => self.strikethrough = true,
+                22 => (self.bold, self.dim) = (false, false),
+                23 => self.italic = false,
+                24 => self.underline = false,
+                27 => self.reverse = false,
+                29 => self.strikethrough = false,
+                30..=37 => self.foreground = Some(BASIC[usize::from(param - 30)]),
+                90..=97 => self.foreground = Some(BASIC[usize::from(param - 90 + 8)]),
+                40..=47 => self.background = Some(BASIC[usize::from(param - 40)]),
+                100..=107 => self.background = Some(BASIC[usize::from(param - 100 + 8)]),
+                38 => self.foreground = Self::extended(&mut params),
+                48 => self.background = Self::extended(&mut params),
+                39 => self.foreground = None,
+                49 => self.background = None,
+                _ => {}
+            }
+        }
+    }
+
+    /// Reads the colour of a `38`/`48` parameter: `5;n` (palette) or `2;r;g;b`.
+    ///
+    /// # Parameters
+    /// - `params`: The parameters following the `38` or `48`.
+    fn extended(params: &mut impl Iterator<Item = u16>) -> Option<(u8, u8, u8)> {
+        let mut channel = || params.next().map(|value| value.min(255) as u8);
+
+        match channel()? {
+            5 => Some(Self::indexed(channel()?)),
+            2 => Some((channel()?, channel()?, channel()?)),
+            _ => None,
+        }
+    }
+
+    /// Returns the RGB value of an xterm 256-colour palette index.
+    ///
+    /// # Parameters
+    /// - `index`: The palette index.
+    fn indexed(index: u8) -> (u8, u8, u8) {
+        match index {
+            0..=15 => BASIC[usize::from(index)],
+            16..=231 => {
+                let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+                let cube = index - 16;
+                (level(cube / 36), level(cube / 6 % 6), level(cube % 6))
+            }
+            _ => {
+                let grey = 8 + (index - 232) * 10;
+                (grey, grey, grey)
+            }
+        }
+    }
+
+    /// Returns the inline CSS for this state, empty for plain text.
+    fn css(&self) -> String {
+        let (foreground, background) = if self.reverse {
+            (
+                self.background.or(Some(BASIC[0])),
+                self.foreground.or(Some(BASIC[7])),
+            )
+        } else {
+            (self.foreground, self.background)
+        };
+
+        let mut css = String::new();
+        if let Some((r, g, b)) = foreground {
+            let _ = write!(css, "color:#{r:02x}{g:02x}{b:02x};");
+        }
+        if let Some((r, g, b)) = background {
+            let _ = write!(css, "background-color:#{r:02x}{g:02x}{b:02x};");
+        }
+        if self.bold {
+            css.push_str("font-weight:bold;");
+        }
+        if self.dim {
+            css.push_str("opacity:0.6;");
+        }
+        if self.italic {
+            css.push_str("font-style:italic;");
+        }
+        match (self.underline, self.strikethrough) {
+            (true, true) => css.push_str("text-decoration:underline line-through;"),
+            (true, false) => css.push_str("text-decoration:underline;"),
+            (false, true) => css.push_str("text-decoration:line-through;"),
+            (false, false) => {}
+        }
+        css.pop();
+        css
+    }
+}
+
+/// Encodes text and ANSI-styled text as HTML.
+pub struct Html;
+
+impl Html {
+    /// Escapes text for use in HTML content or a quoted attribute.
+    ///
+    /// # Parameters
+    /// - `text`: The text to escape.
+    ///
+    /// # Returns
+    /// The text with `&`, `<`, `>`, `"` and `'` replaced by entities.
+    pub fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for character in text.chars() {
+            Self::push_escaped(&mut escaped, character);
+        }
+        escaped
+    }
+
+    /// Converts ANSI-styled text to HTML.
+    ///
+    /// SGR sequences become `<span>`s with inline CSS and OSC 8 hyperlinks to
+    /// `file`, `http` and `https` URLs become links; other escape sequences
+    /// and control characters are dropped.
+    ///
+    /// # Parameters
+    /// - `text`: The styled text, e.g. a cell as the terminal listing draws it.
+    ///
+    /// # Returns
+    /// Escaped HTML with every tag it opens closed.
+    pub fn from_ansi(text: &str) -> String {
+        let mut html = String::with_capacity(text.len());
+        let mut pen = Pen::default();
+        let mut span = false;
+        let mut link = false;
+        let mut chars = text.chars().peekable();
+
+        while let Some(character) = chars.next() {
+            if character != '\x1b' {
+                if !character.is_control() {
+                    Self::push_escaped(&mut html, character);
+                }
+                continue;
+            }
+
+            match chars.next() {
+                // CSI: parameters up to a final byte; only SGR (`m`) matters
+                Some('[') => {
+                    let mut sequence = String::new();
+                    let mut last = None;
+                    for next in chars.by_ref() {
+                        if ('@'..='~').contains(&next) {
+                            last = Some(next);
+                            break;
+                        }
+                        sequence.push(next);
+                    }
+                    if last != Some('m') {
+                        continue;
+                    }
+
+                    let params: Vec<u16> = sequence
+                        .split([';', ':'])
+                        .filter(|param| !param.is_empty())
+                        .filter_map(|param| param.parse().ok())
+                        .collect();
+                    let mut next = pen.clone();
+                    next.apply(&params);
+                    if next != pen {
+                        pen = next;
+                        Self::close(&mut html, &mut span);
+                        Self::open(&mut html, &mut span, &pen);
+                    }
+                }
+                // OSC: up to BEL or ST (`ESC \`); only hyperlinks (`8;params;url`) matter
+                Some(']') => {
+                    let mut sequence = String::new();
+                    while let Some(next) = chars.next() {
+                        match next {
+                            '\x07' => break,
+                            '\x1b' if chars.peek() == Some(&'\\') => {
+                                chars.next();
+                                break;
+                            }
+                            _ => sequence.push(next),
+                        }
+                    }
+                    let Some(url) = sequence
+                        .strip_prefix("8;")
+                        .and_then(|rest| rest.split_once(';'))
+                        .map(|(_, url)| url)
+                    else {
+                        continue;
+                    };
+
+                    Self::close(&mut html, &mut span);
+                    if link {
+                        html.push_str("</a>");
+                        link = false;
+                    }
+                    if Self::is_linkable(url) {
+                        let _ = write!(html, "<a href=\"{}\">", Self::escape(url));
+                        link = true;
+                    }
+                    Self::open(&mut html, &mut span, &pen);
+                }
+                _ => {}
+            }
+        }
+
+        Self::close(&mut html, &mut span);
+        if link {
+            html.push_str("</a>");
+        }
+        html
+    }
+
+    /// Checks whether a hyperlink target may become an `href`.
+    ///
+    /// Only `file://`, `http://` and `https://` URLs are linked, so a crafted
+    /// `javascript:` or `data:` target can't run in the page; the text is kept
+    /// without a link.
+    ///
+    /// # Parameters
+    /// - `url`: The OSC 8 target.
+    fn is_linkable(url: &str) -> bool {
+        url.split_once("://").is_some_and(|(scheme, _)| {
+            ["file", "http", "https"]
+                .iter()
+                .any(|allowed| scheme.eq_ignore_ascii_case(allowed))
+        })
+    }
+
+    /// Opens a `<span>` for a pen, unless it draws plain text.
+    fn open(html: &mut String, span: &mut bool, pen: &Pen) {
+        let css = pen.css();
+        if !css.is_empty() {
+            let _ = write!(html, "<span style=\"{css}\">");
+            *span = true;
+        }
+    }
+
+    /// Closes the open `<span>`, if any.
+    fn close(html: &mut String, span: &mut bool) {
+        if *span {
+            html.push_str("</span>");
+            *span = false;
+        }
+    }
+
+    /// Appends one character, escaped.
+    fn push_escaped(html: &mut String, character: char) {
+        match character {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            other => html.push(other),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Minimal JSON encoding for machine-readable output.
+//!
+//! Cerium only ever writes flat objects of strings and integers, so a tiny
+//! hand-rolled encoder keeps `serde_json` out of the dependency tree.
+
+use crate::fs::entry::Entry;
+use crate::fs::symlink;
+use std::fmt::Write;
+use std::path::Path;
+
+/// Encodes entries and strings as JSON.
+pub struct Json;
+
+impl Json {
+    /// Encodes text as a quoted JSON string, escaping quotes, backslashes, and control characters.
+    ///
+    /// # Parameters
+    /// - `text`: The text to encode.
+    ///
+    /// # Returns
+    /// The quoted and escaped JSON string.
+    pub fn string(text: &str) -> String {
+        let mut encoded = String::with_capacity(text.len() + 2);
+        encoded.push('"');
+
+        for character in text.chars() {
+            match character {
+                '"' => encoded.push_str("\\\""),
+                '\\' => encoded.push_str("\\\\"),
+                '\n' => encoded.push_str("\\n"),
+                '\r' => encoded.push_str("\\r"),
+                '\t' => encoded.push_str("\\t"),
+                control if control.is_control() => {
+                    let _ = write!(encoded, "\\u{:04x}", control as u32);
+                }
+                other => encoded.push(other),
+            }
+        }
+
+        encoded.push('"');
+        encoded
+    }
+
+    /// Encodes an entry and its metadata as a single-line JSON object.
+    ///
+    /// Metadata fields are omitted when the entry's metadata isn't loaded.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry to encode.
+    ///
+    /// # Returns
+    /// The JSON object as a string (no trailing newline).
+    pub fn entry(entry: &Entry) -> String {
+        let path = entry.path();
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_else(|| path.to_string_lossy());
+        let kind = if entry.is_dir() {
+            "directory"
+        } else if entry.is_symlink() {
+            "symlink"
+        } else {
+            "file"
+        };
+
+        let mut object = format!(
+            "{{\"name\":{},\"path\":{},\"type\":\"{}\"",
+            Self::string(&name),
+            Self::string(&path.to_string_lossy()),
+            kind
+        );
+
+        if entry.is_symlink() {
+            let target = symlink::read_symlink_target(path);
+            let _ = write!(object, ",\"target\":{}", Self::string(&target));
+        }
+
+        if let Some(meta) = entry.metadata() {
+            let _ = write!(
+                object,
+                ",\"size\":{},\"mode\":{},\"inode\":{},\"links\":{},\"uid\":{},\"gid\":{},\"accessed\":{},\"modified\":{},\"changed\":{}",
+                meta.size,
+                meta.mode,
+                meta.ino,
+                meta.nlink,
+                meta.uid,
+                meta.gid,
+                meta.atime,
+                meta.mtime,
+                meta.ctime
+            );
+            if let Some(btime) = meta.btime {
+                let _ = write!(object, ",\"created\":{btime}");
+            }
+        }
+
+        object.push('}');
+        object
+    }
+
+    /// Encodes a diagnostic as a single-line JSON object.
+    ///
+    /// # Parameters
+    /// - `kind`: What went wrong, e.g. `unreadable_directory`.
+    /// - `path`: The entry it happened to.
+    /// - `message`: The underlying error message.
+    ///
+    /// # Returns
+    /// The JSON object as a string (no trailing newline).
+    pub fn error(kind: &str, path: &Path, message: &str) -> String {
+        format!(
+            "{{\"error\":{},\"path\":{},\"message\":{}}}",
+            Self::string(kind),
+            Self::string(&path.to_string_lossy()),
+            Self::string(message)
+        )
+    }
+}
@@ -0,0 +1,176 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Debug logging for `--debug`, by module.
+//!
+//! Each message belongs to the module that logs it, named as in the source
+//! tree (`fs::glob`, `display::layout::width`). `--debug` picks which ones are
+//! written: a name enables that module and everything beneath it, and `all`
+//! enables every module. Lines go to stderr, or to the `--debug-file`, in
+//! logfmt so they can be filtered with the usual tools:
+//!
+//! ```text
+//! t=0.000232 target=fs::glob msg="compiling pattern" pattern=*.txt regex=^.*\.txt$
+//! t=0.000284 target=fs::dir msg="hid entries" dir=. patterns=*.txt removed=1
+//! ```
+//!
+//! Without `--debug`, [`debug!`](crate::debug) costs one atomic load.
+
+use std::borrow::Cow;
+use std::fmt::{Display, Write as _};
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+
+static LOG: OnceLock<Log> = OnceLock::new();
+
+/// Logs a message with `key = value` fields, if the calling module is enabled with `--debug`.
+///
+/// ```ignore
+/// crate::debug!("skipping invalid hide pattern", pattern = pattern, error = error);
+/// ```
+#[macro_export]
+macro_rules! debug {
+    ($message:literal $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::display::output::log::Log::is_enabled(module_path!()) {
+            $crate::display::output::log::Log::write(
+                module_path!(),
+                $message,
+                &[$((stringify!($key), &$value as &dyn ::std::fmt::Display)),*],
+            );
+        }
+    };
+}
+
+/// Process-wide sink for `--debug` messages.
+pub struct Log {
+    /// The enabled module names, e.g. `fs::glob`, or `all`.
+    targets: Vec<String>,
+    sink: Mutex<Box<dyn Write + Send>>,
+    /// When logging started, for the `t` field.
+    start: Instant,
+}
+
+impl Log {
+    /// Starts logging the given modules. Only the first call takes effect.
+    ///
+    /// # Parameters
+    /// - `targets`: Module names to log (`fs`, `fs::glob`, ...), or `all`. Nothing is logged if empty.
+    /// - `file`: A file to append to instead of writing to stderr.
+    ///
+    /// # Returns
+    /// An error if `file` can't be opened.
+    pub fn setup(targets: &[String], file: Option<&Path>) -> io::Result<()> {
+        if targets.is_empty() {
+            return Ok(());
+        }
+
+        let sink: Box<dyn Write + Send> = match file {
+            Some(file) => Box::new(OpenOptions::new().create(true).append(true).open(file)?),
+            None => Box::new(io::stderr()),
+        };
+        let _ = LOG.set(Self {
+            targets: targets
+                .iter()
+                .map(|target| target.trim().to_string())
+                .collect(),
+            sink: Mutex::new(sink),
+            start: Instant::now(),
+        });
+        Ok(())
+    }
+
+    /// Checks whether messages from a module are logged.
+    ///
+    /// # Parameters
+    /// - `module`: The module's path as given by `module_path!()`.
+    pub fn is_enabled(module: &str) -> bool {
+        let Some(log) = LOG.get() else {
+            return false;
+        };
+
+        let target = Self::target(module);
+        log.targets.iter().any(|filter| {
+            filter == "all"
+                || target
+                    .strip_prefix(filter.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+        })
+    }
+
+    /// Writes one message as a logfmt line. Use [`debug!`](crate::debug) instead,
+    /// which checks [`Log::is_enabled`] before formatting anything.
+    ///
+    /// # Parameters
+    /// - `module`: The logging module's path as given by `module_path!()`.
+    /// - `message`: What happened.
+    /// - `fields`: Named values describing it.
+    pub fn write(module: &str, message: &str, fields: &[(&str, &dyn Display)]) {
+        let Some(log) = LOG.get() else {
+            return;
+        };
+
+        let mut line = format!(
+            "t={:.6} target={} msg={}",
+            log.start.elapsed().as_secs_f64(),
+            Self::target(module),
+            Self::quote(message)
+        );
+        for (key, value) in fields {
+            let _ = write!(line, " {key}={}", Self::quote(&value.to_string()));
+        }
+        line.push('\n');
+
+        if let Ok(mut sink) = log.sink.lock() {
+            let _ = sink.write_all(line.as_bytes());
+        }
+    }
+
+    /// Names a module as `--debug` does, without the crate name (`fs::glob`).
+    ///
+    /// # Parameters
+    /// - `module`: The module's path as given by `module_path!()`.
+    fn target(module: &str) -> &str {
+        module.split_once("::").map_or(module, |(_, target)| target)
+    }
+
+    /// Quotes a logfmt value if it is empty or holds spaces, quotes, `=` or control characters.
+    ///
+    /// # Parameters
+    /// - `value`: The value to write.
+    fn quote(value: &str) -> Cow<'_, str> {
+        let plain = !value.is_empty()
+            && !value
+                .chars()
+                .any(|c| c.is_whitespace() || c.is_control() || c == '"' || c == '=');
+
+        if plain {
+            Cow::Borrowed(value)
+        } else {
+            Cow::Owned(format!("{value:?}"))
+        }
+    }
+}
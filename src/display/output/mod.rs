@@ -46,7 +46,13 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
+pub mod errors;
+pub mod escape;
 pub mod formats;
+pub mod html;
+pub mod json;
+pub mod log;
 pub(crate) mod populate;
 pub mod quotes;
+pub(crate) mod secret;
 pub mod terminal;
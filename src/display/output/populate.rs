@@ -26,23 +26,39 @@ use crate::cli::args::Args;
 use crate::fs::acl::Acl;
 use crate::fs::cache::Cache;
 use crate::fs::context::Context;
+use crate::fs::describe::Describe;
 use crate::fs::dir::DirReader;
 use crate::fs::entry::Entry;
+use crate::fs::git::Git;
 
+#[cfg(feature = "apps")]
+use crate::fs::feature::apps::Apps;
 #[cfg(feature = "checksum")]
 use crate::fs::feature::checksum::Checksum;
+#[cfg(feature = "checksum")]
+use crate::fs::feature::verify::Manifest;
 
-#[cfg(all(feature = "magic", not(target_os = "android")))]
+#[cfg(feature = "magic")]
 use crate::fs::feature::magic::Magic;
 
 use crate::display::layout::column::Column;
+use crate::display::layout::graph::SizeGraph;
+use crate::display::layout::intern::Intern;
+use crate::display::layout::pending::Pending;
+use crate::display::output::escape::Escape;
 use crate::display::output::formats::date::Date;
 use crate::display::output::formats::format::Format;
 use crate::display::output::formats::number::Number;
 use crate::display::output::formats::ownership::Ownership;
 use crate::display::output::formats::permission::Permission;
 use crate::display::output::formats::size::Size;
+use crate::fs::metadata;
 use crate::fs::mountpoint::Mountpoint;
+use crate::fs::names::NameIssue;
+use crate::fs::notes::Notes;
+use crate::fs::permissions::Permissions;
+use crate::fs::preview::{self, Preview};
+use crate::fs::trash::Trash;
 use crate::fs::xattr::Xattr;
 use std::sync::Arc;
 use std::time;
@@ -70,92 +86,201 @@ impl<'a> Populate<'a> {
     }
 
     /// Returns the formatted value for this column.
+    ///
+    /// Unavailable values (metadata that couldn't be read, or a column that
+    /// doesn't apply to the entry) come back as the `--placeholder` glyph.
+    /// While watching, slow values below the first screenful come back as
+    /// [`PENDING`](crate::display::layout::pending::PENDING) until they are filled in.
     pub(crate) fn value(&self) -> Arc<str> {
+        Pending::value(self.entry, self.column, self.args, || self.computed())
+    }
+
+    /// Computes the formatted value for this column, as [`Populate::value`]
+    /// does but without deferring it.
+    pub(crate) fn computed(&self) -> Arc<str> {
+        if *self.column == Column::Name {
+            return self.formatted();
+        }
+
+        let unavailable = self.entry.metadata().is_none()
+            && self.column.reads_metadata()
+            && !(*self.column == Column::Size && self.entry.is_dir());
+        if unavailable {
+            return Intern::get(&self.args.placeholder);
+        }
+
+        let value = self.formatted();
+        if &*value == "-" {
+            Intern::get(&self.args.placeholder)
+        } else {
+            value
+        }
+    }
+
+    /// Formats this column's value, with `"-"` for values that don't apply.
+    fn formatted(&self) -> Arc<str> {
         let path = self.entry.path();
 
-        let date = Date::new(self.args.date_format);
+        let date = Date::new(self.args.date_format.clone());
         let permission = Permission::new(self.args.permission_format, path.to_owned());
         let number = Number::new(self.args.number_format);
         let size = Size::new(self.args.size_format);
         let ownership = Ownership::new(self.args.ownership_format);
         let metadata = self.entry.metadata();
 
+        if self.column.reads_contents() && !self.args.content_limit.admits(path) {
+            return self.args.content_limit.placeholder();
+        }
+
         match self.column {
             Column::Name => self.entry.name().clone(),
 
-            #[cfg(all(feature = "magic", not(target_os = "android")))]
-            Column::Magic => Magic::file(path),
+            #[cfg(feature = "magic")]
+            Column::Magic => Escape::value(Magic::file(path)),
+
+            #[cfg(feature = "checksum")]
+            Column::Checksum(algo) => {
+                Cache::checksum(path, *algo, || Checksum::new(path, *algo).compute())
+            }
 
             #[cfg(feature = "checksum")]
-            Column::Checksum(algo) => Checksum::new(path, *algo).compute(),
+            Column::Verify => Manifest::status(path),
+
+            #[cfg(feature = "apps")]
+            Column::App => Escape::value(Apps::default_for(path)),
+
+            #[cfg(feature = "volumes")]
+            Column::Volume if self.entry.is_dir() => match Mountpoint::volume(path) {
+                Some(volume) => volume.label().into(),
+                None => "-".into(),
+            },
+            #[cfg(feature = "volumes")]
+            Column::Volume => "-".into(),
 
-            Column::Xattr => Xattr::list(path),
+            Column::Xattr => Escape::value(Xattr::list(path)),
             Column::Acl => Acl::check(path),
-            Column::Context => Context::get(path),
-            Column::Mountpoint => Mountpoint::get(path),
+            Column::Context => Escape::value(Context::get(path)),
+            Column::Mountpoint => Escape::value(Mountpoint::get(path)),
+            Column::Note => Escape::value(Notes::get(path)),
+            Column::Description => Escape::value(Describe::get(path)),
+            Column::TrashOrigin => match Trash::info(path).and_then(|info| info.origin) {
+                Some(origin) => Escape::value(origin.display().to_string().into()),
+                None => "-".into(),
+            },
+            Column::TrashDeleted => Cache::date(
+                Trash::info(path).and_then(|info| info.deleted),
+                &self.args.date_format,
+                |ts| date.format(ts),
+            ),
+            Column::Head => Escape::value(Preview::head(
+                path,
+                self.args.head.unwrap_or(preview::DEFAULT_BYTES),
+            )),
+            Column::Tail => Escape::value(Preview::tail(
+                path,
+                self.args.tail.unwrap_or(preview::DEFAULT_BYTES),
+            )),
+            Column::Git => match Git::status(path, self.entry.is_dir()) {
+                Some(status) => status.to_string().into(),
+                None => "-".into(),
+            },
+            Column::NameCheck => {
+                let issues = NameIssue::check(self.entry.name());
+                if issues.is_empty() {
+                    "-".into()
+                } else {
+                    let labels: Vec<&str> = issues.iter().map(NameIssue::label).collect();
+                    labels.join(", ").into()
+                }
+            }
             Column::Inode => metadata
                 .map(|meta| meta.ino.to_string())
                 .unwrap_or_default()
                 .into(),
-            Column::Permissions => {
-                Cache::permissions(metadata.map(|meta| meta.mode).unwrap_or_default(), |meta| {
-                    permission.format(meta)
-                })
-            }
-            Column::HardLinks => {
-                Cache::number(metadata.map(|meta| meta.nlink).unwrap_or_default(), |n| {
-                    number.format(n)
-                })
-            }
-            Column::User => {
-                Cache::owner(metadata.map(|meta| meta.uid).unwrap_or_default(), |uid| {
-                    ownership.format_user(uid)
-                })
-            }
-            Column::Group => {
-                Cache::group(metadata.map(|meta| meta.gid).unwrap_or_default(), |gid| {
-                    ownership.format_group(gid)
-                })
-            }
-            Column::Blocks => {
-                Cache::number(metadata.map(|meta| meta.blocks).unwrap_or_default(), |b| {
-                    number.format(b)
-                })
-            }
-            Column::BlockSize => {
-                Cache::size(metadata.map(|meta| meta.blksize).unwrap_or_default(), |b| {
-                    size.format(b)
-                })
-            }
+            Column::Permissions => Cache::permissions(
+                metadata.map(|meta| meta.mode).unwrap_or_default(),
+                self.args.permission_format,
+                Permissions::check_xattr(path),
+                |meta| permission.format(meta),
+            ),
+            Column::HardLinks => Cache::number(
+                metadata.map(|meta| meta.nlink).unwrap_or_default(),
+                self.args.number_format,
+                |n| number.format(n),
+            ),
+            Column::User => Cache::owner(
+                metadata.map(|meta| meta.uid).unwrap_or_default(),
+                self.args.ownership_format,
+                |uid| Escape::value(ownership.format_user(uid)),
+            ),
+            Column::Group => Cache::group(
+                metadata.map(|meta| meta.gid).unwrap_or_default(),
+                self.args.ownership_format,
+                |gid| Escape::value(ownership.format_group(gid)),
+            ),
+            Column::Blocks => Cache::number(
+                metadata.map(|meta| meta.blocks).unwrap_or_default(),
+                self.args.number_format,
+                |b| number.format(b),
+            ),
+            Column::BlockSize => Cache::size(
+                metadata.map(|meta| meta.blksize).unwrap_or_default(),
+                self.args.size_format,
+                |b| size.format(b),
+            ),
             Column::Size => {
                 if self.entry.is_dir() {
                     if self.args.dir_size {
-                        let size_bytes = Cache::dir_size(self.entry.path(), self.args.all, || {
-                            DirReader::from(path.to_owned()).dir_size(self.args.all)
+                        let size_bytes = Cache::dir_size(self.entry.path(), self.args, || {
+                            DirReader::from(path.to_owned()).dir_size(self.args)
                         });
-                        Cache::size(size_bytes, |s| size.format(s))
+                        Cache::size(size_bytes, self.args.size_format, |s| size.format(s))
                     } else {
                         let count = DirReader::from(path.to_owned()).item_count(self.args.all);
                         Size::format_item_count(count)
                     }
                 } else {
                     let size_bytes = metadata.map(|meta| meta.size).unwrap_or_default();
-                    Cache::size(size_bytes, |s| size.format(s))
+                    Cache::size(size_bytes, self.args.size_format, |s| size.format(s))
                 }
             }
+            Column::SizeGraph => SizeGraph::bar(self.entry, self.args),
+            Column::Allocated => Cache::size(
+                metadata.map(|meta| meta.allocated()).unwrap_or_default(),
+                self.args.size_format,
+                |a| size.format(a),
+            ),
+            Column::Ratio => match metadata.and_then(|meta| meta.compression_ratio()) {
+                // btrfs counts compressed extents at full size, so only FIEMAP gives them away
+                Some(ratio) if ratio <= 1.0 && metadata::has_compressed_extents(path) => {
+                    "compressed".into()
+                }
+                Some(ratio) => format!("{ratio:.2}x").into(),
+                None => "-".into(),
+            },
             Column::Created => Cache::date(
+                metadata.map(|meta| {
+                    time::UNIX_EPOCH + time::Duration::from_secs(meta.created() as u64)
+                }),
+                &self.args.date_format,
+                |ts| date.format(ts),
+            ),
+            Column::Changed => Cache::date(
                 metadata
                     .map(|meta| time::UNIX_EPOCH + time::Duration::from_secs(meta.ctime as u64)),
+                &self.args.date_format,
                 |ts| date.format(ts),
             ),
             Column::Accessed => Cache::date(
                 metadata
                     .map(|meta| time::UNIX_EPOCH + time::Duration::from_secs(meta.atime as u64)),
+                &self.args.date_format,
                 |ts| date.format(ts),
             ),
             Column::Modified => Cache::date(
                 metadata
                     .map(|meta| time::UNIX_EPOCH + time::Duration::from_secs(meta.mtime as u64)),
+                &self.args.date_format,
                 |ts| date.format(ts),
             ),
         }
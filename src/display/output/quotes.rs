@@ -114,7 +114,7 @@ impl<'a> Quotes<'a> {
     ///
     /// A `String` with the text wrapped in single quotes. For symlinks, both the
     /// link name and target are quoted separately with the arrow between them.
-    /// Single quotes within the text are escaped as `\'`.
+    /// Single quotes within the text are written as `'\''`.
     ///
     /// # Examples
     ///
@@ -138,6 +138,46 @@ impl<'a> Quotes<'a> {
         }
     }
 
+    /// Wraps the whole text in single quotes as one shell word.
+    ///
+    /// Unlike [`Quotes::single_quote_always`], a ` -> ` inside the text is not
+    /// taken for a symlink arrow, so a file named `x -> y` stays one word
+    /// instead of turning into a redirect when pasted into a shell.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the text wrapped in single quotes.
+    /// Single quotes within the text are written as `'\''`.
+    ///
+    /// # Examples
+    ///
+    /// ```text
+    /// Quotes::new("file name").single_quote_word()      // => "'file name'"
+    /// Quotes::new("x -> y.log").single_quote_word()     // => "'x -> y.log'"
+    /// ```
+    pub fn single_quote_word(&self) -> String {
+        Self::add_single_quotes(self.text)
+    }
+
+    /// Wraps the whole text in single quotes as one shell word, if it needs quoting.
+    ///
+    /// Like [`Quotes::single_quote_conditional`], but without the symlink split
+    /// (see [`Quotes::single_quote_word`]).
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the text quoted if necessary. Unquoted text is returned as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```text
+    /// Quotes::new("normal").single_quote_word_conditional()      // => "normal"
+    /// Quotes::new("/tmp/x -> y").single_quote_word_conditional() // => "'/tmp/x -> y'"
+    /// ```
+    pub fn single_quote_word_conditional(&self) -> String {
+        Self::quote_if_quotable(self.text)
+    }
+
     /// Wraps text in double quotes unconditionally.
     ///
     /// Always adds double quotes around the input text. For symlinks (indicated by the -> arrow),
@@ -236,7 +276,7 @@ impl<'a> Quotes<'a> {
     ///
     /// The text wrapped in single quotes if it contains special characters,
     /// or the original text if no quoting is necessary. Single quotes within
-    /// the text are escaped as `\'`.
+    /// the text are written as `'\''`.
     fn quote_if_quotable(text: &str) -> String {
         if Self::has_special_chars(text) {
             Self::add_single_quotes(text)
@@ -247,21 +287,23 @@ impl<'a> Quotes<'a> {
 
     /// Adds single quotes around text, escaping any single quotes within.
     ///
+    /// Nothing can be escaped inside single quotes in a POSIX shell, so an
+    /// embedded quote closes the string, adds an escaped quote, and reopens it.
+    ///
     /// # Parameters
     ///
     /// - `text`: The text to wrap in single quotes.
     ///
     /// # Returns
     ///
-    /// The text wrapped in single quotes with internal single quotes escaped as `\'`
+    /// The text wrapped in single quotes with internal single quotes written as `'\''`
     fn add_single_quotes(text: &str) -> String {
         let mut quoted = String::with_capacity(text.len() + 2);
         quoted.push('\'');
 
         for character in text.chars() {
             if character == '\'' {
-                quoted.push('\\');
-                quoted.push('\'');
+                quoted.push_str("'\\''");
             } else {
                 quoted.push(character);
             }
@@ -271,7 +313,8 @@ impl<'a> Quotes<'a> {
         quoted
     }
 
-    /// Adds double quotes around text, escaping any double quotes within.
+    /// Adds double quotes around text, escaping the characters a shell treats
+    /// specially inside them.
     ///
     /// # Parameters
     ///
@@ -279,15 +322,15 @@ impl<'a> Quotes<'a> {
     ///
     /// # Returns
     ///
-    /// The text wrapped in double quotes with internal double quotes escaped as `\"`
+    /// The text wrapped in double quotes with internal `"`, `\`, `$` and `` ` `` backslash-escaped
     fn add_double_quotes(text: &str) -> String {
         let mut quoted = String::with_capacity(text.len() + 2);
         quoted.push('"');
 
         for character in text.chars() {
-            if character == '"' {
+            if matches!(character, '"' | '\\' | '$' | '`') {
                 quoted.push('\\');
-                quoted.push('"');
+                quoted.push(character);
             } else {
                 quoted.push(character);
             }
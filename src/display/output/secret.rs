@@ -0,0 +1,58 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Heuristic for secret-looking files that others can read.
+
+use crate::display::theme::icons;
+use crate::fs::entry::Entry;
+use libc::{S_IRGRP, S_IROTH};
+
+/// The marker appended to the permissions of an exposed secret.
+pub(crate) const EXPOSED_MARKER: char = '!';
+
+/// Spots files that look like secrets (keys, keystores, `.env`) but are group- or world-readable.
+///
+/// This is a name-based heuristic, so it can't know whether `server.pem` is a
+/// private key or a public certificate; it errs on the side of warning so that
+/// `ce -l ~/.ssh` shows a misconfigured key at a glance.
+pub(crate) struct Secret;
+
+impl Secret {
+    /// Checks whether an entry looks like a secret and is readable by its group or by others.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry to check. Its metadata must already be loaded.
+    ///
+    /// # Returns
+    /// `true` for regular files with a secret-looking name and a group/other read bit set.
+    pub(crate) fn is_exposed(entry: &Entry) -> bool {
+        let Some(metadata) = entry.metadata() else {
+            return false;
+        };
+
+        entry.is_file()
+            && metadata.mode & (S_IRGRP | S_IROTH) != 0
+            && icons::is_secret(entry.name(), entry.extension())
+    }
+}
@@ -22,6 +22,7 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
+use crate::cli::flags::StyleCaps;
 use std::os::unix::io::AsRawFd;
 use std::{env, io};
 
@@ -84,6 +85,46 @@ pub fn colours_enabled() -> bool {
     is_tty()
 }
 
+/// Guesses which text attributes the terminal renders from `TERM`.
+///
+/// The Linux console, old hardware terminals, and `screen*` entries (also
+/// tmux's default) draw italics and strikethrough as reverse video or colour
+/// changes, or not at all, so they get the basic set. Everything else is assumed to be a modern emulator.
+///
+/// # Returns
+/// [`StyleCaps::Basic`] for limited terminals, [`StyleCaps::None`] for dumb
+/// ones, and [`StyleCaps::Full`] otherwise.
+pub fn style_caps() -> StyleCaps {
+    let term = env::var("TERM").unwrap_or_default();
+
+    if term == "dumb" {
+        return StyleCaps::None;
+    }
+
+    let limited = term == "linux"
+        || term == "ansi"
+        || term == "sun"
+        || term.starts_with("vt")
+        || term.starts_with("cons")
+        || term.starts_with("screen");
+
+    if limited {
+        StyleCaps::Basic
+    } else {
+        StyleCaps::Full
+    }
+}
+
+/// Clears the terminal and homes the cursor before a redraw (`--watch`).
+///
+/// Does nothing when standard output isn't a TTY, so redirected output just
+/// gets one listing after another.
+pub fn clear_screen() {
+    if is_tty() {
+        print!("\x1b[H\x1b[2J");
+    }
+}
+
 /// Checks if standard output is connected to a TTY.
 pub fn is_tty() -> bool {
     {
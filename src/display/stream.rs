@@ -0,0 +1,131 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+use crate::cli::args::Args;
+use crate::display::layout::column::{self, Column};
+use crate::display::mode::DisplayMode;
+use crate::display::styles::column::ColumnStyle;
+use crate::display::styles::element::ElementStyle;
+use crate::display::summary::Summary;
+use crate::display::tally::Tally;
+use crate::fs::dir::DirReader;
+use crate::fs::tree::Visited;
+use crate::fs::vfs::RealFs;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Unaligned long-format renderer that prints each entry as soon as it is statted.
+///
+/// Skips the global width pass (and sorting) that [`List`](crate::display::list::List)
+/// needs, trading ragged columns for immediate output on very slow filesystems.
+pub(crate) struct LongStream {
+    /// The directory (or file) being listed
+    path: PathBuf,
+    /// Command-line arguments controlling columns and traversal
+    args: Args,
+    /// Directories printed so far
+    dir_count: Cell<usize>,
+    /// Files printed so far
+    file_count: Cell<usize>,
+}
+
+impl DisplayMode for LongStream {
+    /// Streams rows for the listed path, descending into subdirectories with `--recursive`.
+    fn print(&self, tally: &mut Tally) {
+        Column::headers(&HashMap::new(), &self.args);
+        let mut visited = Visited::new(&RealFs, &self.path, &self.args);
+        self.stream(
+            &self.path,
+            &column::Selector::select(&self.args),
+            &mut visited,
+            tally,
+        );
+        self.print_summary();
+    }
+}
+
+impl Summary for LongStream {
+    /// Returns the counts accumulated while streaming.
+    fn counts(&self) -> (usize, usize) {
+        (self.dir_count.get(), self.file_count.get())
+    }
+}
+
+impl LongStream {
+    /// Creates a new [`LongStream`] renderer.
+    ///
+    /// # Parameters
+    /// - `path`: The directory (or file) to list.
+    /// - `args`: Command-line arguments; the long-format columns are always shown.
+    pub(crate) fn new(path: PathBuf, mut args: Args) -> Self {
+        args.long = true;
+
+        Self {
+            path,
+            args,
+            dir_count: Cell::new(0),
+            file_count: Cell::new(0),
+        }
+    }
+
+    /// Prints one directory's rows as they are read, then descends if recursive.
+    ///
+    /// # Parameters
+    /// - `path`: The directory to stream.
+    /// - `columns`: The columns to print for each row.
+    /// - `visited`: The directories open above this one.
+    /// - `tally`: Counts every row printed, for the `--grand-total` footer.
+    fn stream(&self, path: &Path, columns: &[Column], visited: &mut Visited, tally: &mut Tally) {
+        let mut subdirs = Vec::new();
+
+        DirReader::from(path.to_path_buf()).stream(&self.args, |entry| {
+            let row: Vec<String> = columns
+                .iter()
+                .map(|column| ColumnStyle::get(&entry, column, &self.args, false))
+                .collect();
+            println!("{}", row.join(" "));
+            tally.add(&entry);
+
+            if entry.is_dir() {
+                self.dir_count.set(self.dir_count.get() + 1);
+            } else {
+                self.file_count.set(self.file_count.get() + 1);
+            }
+            // Only the rows are streamed; directories wait until this one is done
+            if self.args.recursive && entry.is_dir_like() {
+                subdirs.push(entry);
+            }
+        });
+
+        for subdir in subdirs {
+            if !visited.enter(&RealFs, &subdir, &self.args) {
+                continue;
+            }
+            println!("\n{}:", ElementStyle::path_header(subdir.path().display()));
+            self.stream(subdir.path(), columns, visited, tally);
+            visited.leave();
+        }
+    }
+}
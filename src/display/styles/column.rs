@@ -24,12 +24,16 @@ SOFTWARE.
 
 use crate::cli::args::Args;
 use crate::display::layout::column::Column;
+use crate::display::layout::pending;
 use crate::display::layout::row::Row;
 use crate::display::styles::element::ElementStyle;
-use crate::display::styles::entry::StyledEntry;
+use crate::display::styles::entry::{EntryStyle, StyledEntry};
 use crate::display::styles::value::ValueStyle;
 use crate::display::theme::colours::{Colour, ColourPaint, RgbColours};
 use crate::fs::entry::Entry;
+#[cfg(feature = "checksum")]
+use crate::fs::feature::verify::Verdict;
+use crate::fs::preview;
 
 /// Provides styling logic for individual columns in the output display.
 pub(crate) struct ColumnStyle;
@@ -51,52 +55,113 @@ impl ColumnStyle {
         args: &Args,
         add_alignment_space: bool,
     ) -> String {
-        let styled_entry = StyledEntry::new(entry);
-        let style = styled_entry.load(args, add_alignment_space);
+        let mut out = String::new();
+        Self::write(&mut out, entry, column, args, add_alignment_space);
+        out
+    }
 
+    /// Appends the styled value for a given column and entry onto `out`.
+    ///
+    /// Row renderers call this with one reused buffer, so a cell costs no
+    /// allocations beyond its raw value. The entry's name is only styled for
+    /// the name column, and its colour only resolved for columns painted in it.
+    ///
+    /// # Parameters
+    /// - `out`: The buffer to append to.
+    /// - `entry`: The filesystem entry to display.
+    /// - `column`: The column type to render.
+    /// - `args`: Command-line arguments controlling display options.
+    /// - `add_alignment_space`: Whether to add alignment spacing for the name column.
+    pub(crate) fn write(
+        out: &mut String,
+        entry: &Entry,
+        column: &Column,
+        args: &Args,
+        add_alignment_space: bool,
+    ) {
         if *column == Column::Name {
-            style.name.to_string()
+            let styled_entry = StyledEntry::new(entry, args);
+            out.push_str(&styled_entry.load(args, add_alignment_space).name);
         } else {
             let row = Row::new(entry, args);
-            let row_value = row.value(column);
-            Self::column_value(column, row_value.to_string(), style.colour)
+            Self::write_value(
+                out,
+                column,
+                &row.value(column),
+                || EntryStyle::from(entry, args).colour,
+                &args.placeholder,
+            );
+
+            if let Some(marker) = row.marker(column) {
+                let mut buffer = [0; 4];
+                ElementStyle::write_warning(out, marker.encode_utf8(&mut buffer));
+            }
         }
     }
 
-    /// Applies appropriate styling to a column value based on the column type and content.
+    /// Appends a column value onto `out`, styled by the column type and content.
     ///
     /// # Parameters
+    /// - `out`: The buffer to append to.
     /// - `column`: The column type, which determines the styling rules.
     /// - `value`: The raw text value to style.
-    /// - `colour`: The base colour to use (typically from entry type).
-    ///
-    /// # Returns
-    /// A string with ANSI colour codes applied for terminal display.
-    fn column_value(column: &Column, value: String, colour: Colour) -> String {
-        if value == "-" {
-            Colour::DarkGray.normal().apply_to(&value) // DarkGray for values that are "-"
+    /// - `colour`: Resolves the base colour (typically from entry type), for columns painted in it.
+    /// - `placeholder`: The `--placeholder` glyph shown for unavailable values.
+    fn write_value(
+        out: &mut String,
+        column: &Column,
+        value: &str,
+        colour: impl FnOnce() -> Colour,
+        placeholder: &str,
+    ) {
+        if value == placeholder || value == pending::PENDING {
+            ElementStyle::write_placeholder(out, value);
         } else if value.parse::<f64>().is_ok() {
-            ElementStyle::numeric(&value)
+            ElementStyle::write_numeric(out, value);
         } else {
             match column {
-                Column::Name => ValueStyle::name(&value, colour),
-                #[cfg(all(feature = "magic", not(target_os = "android")))]
-                Column::Magic => colour.bold().apply_to(&value),
+                Column::Name => out.push_str(&ValueStyle::name(value, colour())),
+                #[cfg(feature = "magic")]
+                Column::Magic => colour().bold().write_to(out, value),
+
+                #[cfg(feature = "checksum")]
+                Column::Checksum(_) => Colour::White.italic().write_to(out, value),
 
                 #[cfg(feature = "checksum")]
-                Column::Checksum(_) => Colour::White.italic().apply_to(&value),
+                Column::Verify if value == Verdict::Ok.as_str() => {
+                    Colour::Green.bold().write_to(out, value)
+                }
+                #[cfg(feature = "checksum")]
+                Column::Verify => ElementStyle::write_warning(out, value),
 
-                Column::Xattr => Colour::Cyan.normal().apply_to(&value),
-                Column::Acl => Colour::Green.normal().apply_to(&value),
-                Column::Mountpoint => Colour::Magenta.normal().apply_to(&value),
-                Column::Permissions => ValueStyle::permissions(&value),
-                Column::BlockSize | Column::Size => ValueStyle::size(&value),
-                Column::User => RgbColours::hen_of_the_day().normal().apply_to(&value),
-                Column::Group => RgbColours::hen_of_the_night().normal().apply_to(&value),
-                Column::Created | Column::Modified | Column::Accessed => {
-                    ValueStyle::datetime(&value)
+                #[cfg(feature = "apps")]
+                Column::App => Colour::Yellow.normal().write_to(out, value),
+
+                #[cfg(feature = "volumes")]
+                Column::Volume => out.push_str(&ValueStyle::volume(value)),
+
+                Column::Xattr => Colour::Cyan.normal().write_to(out, value),
+                Column::Acl => Colour::Green.normal().write_to(out, value),
+                Column::Mountpoint => Colour::Magenta.normal().write_to(out, value),
+                Column::NameCheck => ElementStyle::write_warning(out, value),
+                Column::Note => Colour::White.italic().write_to(out, value),
+                Column::Head | Column::Tail if value == preview::BINARY => {
+                    Colour::DarkGray.italic().write_to(out, value)
+                }
+                Column::Head | Column::Tail => Colour::White.normal().write_to(out, value),
+                Column::Git => ValueStyle::write_git(out, value),
+                Column::Permissions => ValueStyle::write_permissions(out, value),
+                Column::BlockSize | Column::Size | Column::SizeGraph | Column::Allocated => {
+                    ValueStyle::write_size(out, value)
                 }
-                _ => ElementStyle::text(&value, None),
+                Column::User => RgbColours::hen_of_the_day().normal().write_to(out, value),
+                Column::Group => RgbColours::hen_of_the_night().normal().write_to(out, value),
+                Column::Created
+                | Column::Changed
+                | Column::Modified
+                | Column::Accessed
+                | Column::TrashDeleted => ValueStyle::write_datetime(out, value),
+                _ => ElementStyle::write_text(out, value, None),
             }
         }
     }
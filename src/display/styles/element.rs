@@ -54,6 +54,28 @@ impl ElementStyle {
         style.underline().bold().apply_to(name)
     }
 
+    /// Styles table footer values (the `--totals` row) like the summary line.
+    ///
+    /// # Parameters
+    /// - `text`: The footer value (e.g. `"4.2 MB"` or `"12 entries"`).
+    ///
+    /// # Returns
+    /// Styled text with numbers in bold cyan and labels in the summary colour.
+    pub(crate) fn table_footer(text: &str) -> String {
+        Self::summary(text)
+    }
+
+    /// Styles `--group-by` section titles in the bold, underlined summary colour.
+    ///
+    /// # Parameters
+    /// - `title`: The section title (e.g. `"Directories"` or `"*.rs"`).
+    ///
+    /// # Returns
+    /// Styled section title.
+    pub(crate) fn section_header(title: &str) -> String {
+        RgbColours::summary().bold().underline().apply_to(title)
+    }
+
     /// Styles directory path titles for recursive mode output.
     ///
     /// # Parameters
@@ -68,6 +90,53 @@ impl ElementStyle {
             .apply_to(path_display.to_string().as_str())
     }
 
+    /// Styles the entry count shown after a recursive section title, e.g. `(3 entries)`.
+    ///
+    /// # Parameters
+    /// - `count`: The number of entries in the section.
+    ///
+    /// # Returns
+    /// The parenthesised count in dark grey.
+    pub(crate) fn entry_count(count: usize) -> String {
+        let label = if count == 1 {
+            "(1 entry)".to_string()
+        } else {
+            format!("({} entries)", count)
+        };
+        Colour::DarkGray.normal().apply_to(&label)
+    }
+
+    /// Styles a warning label (e.g. `broken`, `out-of-tree`) in the bold theme warning colour.
+    ///
+    /// # Parameters
+    /// - `text`: The warning text.
+    ///
+    /// # Returns
+    /// Styled warning text.
+    pub(crate) fn warning(text: &str) -> String {
+        let mut out = String::new();
+        Self::write_warning(&mut out, text);
+        out
+    }
+
+    /// Appends a warning label onto `out`, styled as by [`ElementStyle::warning`].
+    ///
+    /// # Parameters
+    /// - `out`: The buffer to append to.
+    /// - `text`: The warning text.
+    pub(crate) fn write_warning(out: &mut String, text: &str) {
+        RgbColours::warning().bold().write_to(out, text);
+    }
+
+    /// Appends the glyph shown for an unavailable value onto `out`, in the themed placeholder colour.
+    ///
+    /// # Parameters
+    /// - `out`: The buffer to append to.
+    /// - `text`: The placeholder glyph.
+    pub(crate) fn write_placeholder(out: &mut String, text: &str) {
+        RgbColours::placeholder().normal().write_to(out, text);
+    }
+
     /// Styles a summary string with bold themed numbers and italic themed labels.
     ///
     /// # Parameters
@@ -89,39 +158,48 @@ impl ElementStyle {
     /// # Returns
     /// Styled text with numeric segments in bold cyan and the remainder in the provided colour.
     pub(crate) fn text(text: &str, colour: Option<Colour>) -> String {
-        let style = Style::new();
-        let colour = colour.unwrap_or_default();
-
         let mut result = String::new();
-        let mut chunk = String::new();
+        Self::write_text(&mut result, text, colour);
+        result
+    }
+
+    /// Appends mixed text onto `out`, styled as by [`ElementStyle::text`].
+    ///
+    /// Runs are painted straight from slices of `text`, so no per-run `String` is built.
+    ///
+    /// # Parameters
+    /// - `out`: The buffer to append to.
+    /// - `text`: The text to style.
+    /// - `colour`: The colour to apply to non-numeric segments.
+    pub(crate) fn write_text(out: &mut String, text: &str, colour: Option<Colour>) {
+        let style = Style::new().fg(colour.unwrap_or_default());
+
+        let mut start = 0;
         let mut in_digits = text.starts_with(|character: char| character.is_ascii_digit());
 
-        for character in text.chars() {
+        for (index, character) in text.char_indices() {
             // Keep '.' and ',' attached to an active digit run so decimals and thousands
             // separators (e.g. "25.9", "1,024") render as a single numeric chunk.
-            let is_digit = character.is_ascii_digit()
-                || (in_digits && (character == '.' || character == ','));
-            if is_digit != in_digits && !chunk.is_empty() {
+            let is_digit =
+                character.is_ascii_digit() || (in_digits && (character == '.' || character == ','));
+            if is_digit != in_digits && index > start {
                 if in_digits {
-                    result.push_str(&Self::numeric(&chunk));
+                    Self::write_numeric(out, &text[start..index]);
                 } else {
-                    result.push_str(&style.fg(colour).apply_to(&chunk));
+                    style.write_to(out, &text[start..index]);
                 }
-                chunk.clear();
+                start = index;
                 in_digits = is_digit;
             }
-            chunk.push(character);
         }
 
-        if !chunk.is_empty() {
+        if start < text.len() {
             if in_digits {
-                result.push_str(&Self::numeric(&chunk));
+                Self::write_numeric(out, &text[start..]);
             } else {
-                result.push_str(&style.fg(colour).apply_to(&chunk));
+                style.write_to(out, &text[start..]);
             }
         }
-
-        result
     }
 
     /// Styles numeric text as bold cyan.
@@ -134,4 +212,13 @@ impl ElementStyle {
     pub(crate) fn numeric(text: &str) -> String {
         Colour::Cyan.bold().apply_to(text)
     }
+
+    /// Appends numeric text onto `out` as bold cyan.
+    ///
+    /// # Parameters
+    /// - `out`: The buffer to append to.
+    /// - `text`: The numeric text to style.
+    pub(crate) fn write_numeric(out: &mut String, text: &str) {
+        Colour::Cyan.bold().write_to(out, text);
+    }
 }
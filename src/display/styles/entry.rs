@@ -24,12 +24,19 @@ SOFTWARE.
 
 use crate::cli::args::Args;
 use crate::display::classify;
+use crate::display::output::errors::Errors;
+use crate::display::output::escape::Escape;
 use crate::display::output::quotes::Quotes;
+use crate::display::styles::element::ElementStyle;
 use crate::display::styles::value::ValueStyle;
-use crate::display::theme::colours::{Colour, ColourPaint};
+use crate::display::theme::colours::{Colour, ColourPaint, ColourSettings};
 use crate::display::theme::icons::{self, IconSettings};
 use crate::fs::entry::Entry;
+#[cfg(feature = "magic")]
+use crate::fs::feature::magic::Magic;
+use crate::fs::highlight::Highlight;
 use crate::fs::hyperlink::{self, HyperlinkSettings};
+use crate::fs::metadata::Overlay;
 use std::sync::Arc;
 
 /// Represents the final visual presentation of an entry, ready for display.
@@ -48,26 +55,66 @@ pub(crate) struct EntryStyle {
 impl EntryStyle {
     /// Resolves the appropriate icon and colour for a filesystem entry using PHF maps.
     ///
+    /// Extensionless files that match no known filename fall back to their
+    /// libmagic description when `--magic` is active.
+    ///
     /// # Parameters
     /// - `entry`: The filesystem entry to resolve styling for.
+    /// - `args`: Command-line arguments (used to check whether magic detection is enabled).
     ///
     /// # Returns
     /// An [`EntryStyle`] with the resolved icon and colour.
-    pub(crate) fn from(entry: &Entry) -> Self {
+    pub(crate) fn from(entry: &Entry, args: &Args) -> Self {
         let name = entry.name().as_ref();
-        let extension = entry.extension().as_ref();
+        let mut extension = entry.extension().as_ref();
 
-        let icon = icons::icon_for_entry(
+        let mut icon = icons::icon_for_entry(
             name,
             extension,
             entry.is_dir(),
             entry.has_children(),
             entry.is_symlink(),
         );
+
+        // Nothing in the name to go on, so let the file's content decide
+        if icon == icons::default_file_icon()
+            && extension.is_empty()
+            && entry.is_file()
+            && let Some(magic_extension) = Self::magic_extension(entry, args)
+        {
+            extension = magic_extension;
+            icon = icons::icon_for_entry(name, extension, false, false, false);
+        }
+
         let colour = icons::colour_for_entry(name, extension, entry.is_dir(), entry.is_symlink());
 
         Self { icon, colour }
     }
+
+    /// Derives a representative extension from the entry's libmagic description.
+    ///
+    /// # Parameters
+    /// - `entry`: The file entry to identify.
+    /// - `args`: Command-line arguments (magic is only consulted with `--magic`).
+    ///
+    /// # Returns
+    /// An extension to style the entry by, or `None` if magic is disabled or inconclusive.
+    #[cfg(feature = "magic")]
+    fn magic_extension(entry: &Entry, args: &Args) -> Option<&'static str> {
+        if !args.magic || !args.content_limit.admits(entry.path()) {
+            return None;
+        }
+
+        icons::extension_for_magic(&Magic::file(entry.path()))
+    }
+
+    /// Derives a representative extension from the entry's libmagic description.
+    ///
+    /// Always `None` when cerium is built without the `magic` feature.
+    #[cfg(not(feature = "magic"))]
+    fn magic_extension(_entry: &Entry, _args: &Args) -> Option<&'static str> {
+        None
+    }
 }
 
 /// A filesystem entry paired with its resolved styling information
@@ -81,11 +128,12 @@ impl<'a> StyledEntry<'a> {
     ///
     /// # Parameters
     /// - `entry`: The filesystem entry to style.
+    /// - `args`: Command-line arguments controlling style resolution.
     ///
     /// # Returns
     /// A [`StyledEntry`] pairing the entry with its resolved styling.
-    pub(crate) fn new(entry: &'a Entry) -> Self {
-        let style = EntryStyle::from(entry);
+    pub(crate) fn new(entry: &'a Entry, args: &Args) -> Self {
+        let style = EntryStyle::from(entry, args);
         Self { entry, style }
     }
 
@@ -107,32 +155,43 @@ impl<'a> StyledEntry<'a> {
             name.push(' ');
         }
 
+        // Control characters in a name would reach the terminal as escape sequences
+        let escaped_name = Escape::text(self.entry.name());
+
         let entry_name = if args.tree {
             // Tree mode skips quoting to match traditional `tree` command behavior.
             // Filenames display as-is without quotes, prioritizing clean hierarchical display.
             if HyperlinkSettings::is_enabled() {
-                hyperlink::wrap_hyperlink(self.entry.name(), self.entry.path())
+                hyperlink::wrap_hyperlink(&escaped_name, self.entry.path())
             } else {
-                self.entry.name().to_string()
+                escaped_name.to_string()
             }
         } else {
             // Determine quoting based on the ORIGINAL filename (not hyperlinked)
-            let quotes = Quotes::new(self.entry.name());
+            let quotes = Quotes::new(&escaped_name);
             let quoted = quotes.apply(args.quote_name, add_alignment_space);
 
             // Then apply hyperlink to just the filename part if enabled
             if HyperlinkSettings::is_enabled() {
                 // Hyperlink the original name, then insert it into the quoted result
-                let hyperlinked_name =
-                    hyperlink::wrap_hyperlink(self.entry.name(), self.entry.path());
-                quoted.replace(self.entry.name().to_string().as_str(), &hyperlinked_name)
+                let hyperlinked_name = hyperlink::wrap_hyperlink(&escaped_name, self.entry.path());
+                quoted.replace(&*escaped_name, &hyperlinked_name)
             } else {
                 quoted
             }
         };
 
-        // Apply text style to the entry name (without icon)
-        let styled_entry_name = ValueStyle::name(&entry_name, self.style.colour);
+        // Apply text style to the entry name (without icon); whiteouts in an
+        // overlayfs layer read as deleted rather than as stray devices
+        let overlay = self.entry.overlay();
+        let highlighted = Highlight::contains(self.entry.path());
+        let styled_entry_name = if overlay == Some(Overlay::Whiteout) {
+            Colour::DarkGray.strikethrough().apply_to(&entry_name)
+        } else if highlighted {
+            self.style.colour.bold().reverse().apply_to(&entry_name)
+        } else {
+            ValueStyle::name(&entry_name, self.style.colour)
+        };
         name.push_str(&styled_entry_name);
 
         // Append the `-F`/`--file-type`/`--slash` indicator last and
@@ -143,6 +202,23 @@ impl<'a> StyledEntry<'a> {
             name.push(symbol);
         }
 
+        // An opaque directory hides everything beneath it in the lower layers
+        if overlay == Some(Overlay::Opaque) {
+            name.push_str(&Colour::DarkGray.normal().apply_to(" (opaque)"));
+        }
+
+        // Flag entries whose metadata, xattrs or ACL couldn't be read, rather than
+        // letting their missing fields pass for real values
+        if let Some(badge) = Errors::badge(self.entry.path(), args.verbose) {
+            name.push(' ');
+            name.push_str(&ElementStyle::warning(&badge));
+        }
+
+        // Reverse video is lost without colours, so point at the file instead
+        if highlighted && !ColourSettings::is_enabled() {
+            name.push_str(" <");
+        }
+
         EntryView {
             name: Arc::from(name.as_str()),
             colour: self.style.colour,
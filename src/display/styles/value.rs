@@ -22,9 +22,11 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
+use crate::display::layout::bidi::Bidi;
 use crate::display::styles::element::ElementStyle;
-use crate::display::theme::colours::{Colour, ColourPaint, RgbColours};
+use crate::display::theme::colours::{Colour, ColourPaint, ColourSettings, RgbColours};
 use crate::fs::symlink;
+use std::borrow::Cow;
 
 /// Applies colour styling and formatting to data values based on their content.
 ///
@@ -34,14 +36,12 @@ use crate::fs::symlink;
 pub(crate) struct ValueStyle;
 
 impl ValueStyle {
-    /// Styles entry size with colours based on magnitude.
+    /// Appends entry size onto `out`, coloured by magnitude.
     ///
     /// # Parameters
+    /// - `out`: The buffer to append to.
     /// - `size`: The formatted size string (e.g., "1.2 MB", "45 KB").
-    ///
-    /// # Returns
-    /// Bold-styled text with magnitude-appropriate colour.
-    pub(crate) fn size(size: &str) -> String {
+    pub(crate) fn write_size(out: &mut String, size: &str) {
         let colour = if size.ends_with(" kB") || size.ends_with("KiB") {
             RgbColours::leaf_green()
         } else if size.ends_with(" MB") || size.ends_with("MiB") {
@@ -52,11 +52,15 @@ impl ValueStyle {
             RgbColours::pine_glade()
         };
 
-        ElementStyle::text(size, Some(colour))
+        ElementStyle::write_text(out, size, Some(colour))
     }
 
     /// Styles entry names with special handling for symlinks and ignored files.
     ///
+    /// In styled output, right-to-left names (and symlink targets) are wrapped in
+    /// bidi isolation marks so terminals don't reorder them with the columns around
+    /// them; plain output keeps names as stored.
+    ///
     /// # Parameters
     /// - `name`: The entry name (may contain symlink arrow `->`).
     /// - `colour`: The base colour for the entry.
@@ -69,11 +73,14 @@ impl ValueStyle {
             // Style the link name
             let styled_link = Colour::Blue
                 .italic()
-                .apply_to(link_part.trim_end())
+                .apply_to(&Self::isolate(link_part.trim_end()))
                 .to_string();
 
             // Style the target name
-            let styled_target = colour.bold().apply_to(target.trim_start()).to_string();
+            let styled_target = colour
+                .bold()
+                .apply_to(&Self::isolate(target.trim_start()))
+                .to_string();
 
             return format!(
                 "{}{}{}",
@@ -90,17 +97,30 @@ impl ValueStyle {
             colour.bold()
         };
 
-        styled.apply_to(name).to_string()
+        styled.apply_to(&Self::isolate(name)).to_string()
     }
 
-    /// Styles dates with colours indicating recency.
+    /// Isolates right-to-left text in styled output, keeping plain output as stored.
     ///
     /// # Parameters
-    /// - `datetime`: The formatted timestamp string (e.g., "2 hours ago", "Jan 15").
+    /// - `text`: A name or symlink target.
     ///
     /// # Returns
-    /// Bold-styled text with recency-appropriate colour.
-    pub(crate) fn datetime(datetime: &str) -> String {
+    /// The text to style.
+    fn isolate(text: &str) -> Cow<'_, str> {
+        if ColourSettings::is_enabled() {
+            Bidi::isolate(text)
+        } else {
+            Cow::Borrowed(text)
+        }
+    }
+
+    /// Appends a date onto `out`, coloured by recency.
+    ///
+    /// # Parameters
+    /// - `out`: The buffer to append to.
+    /// - `datetime`: The formatted timestamp string (e.g., "2 hours ago", "Jan 15").
+    pub(crate) fn write_datetime(out: &mut String, datetime: &str) {
         let colour = if datetime.contains("second") {
             RgbColours::frost_glimmer()
         } else if datetime.contains("minute") {
@@ -141,44 +161,75 @@ impl ValueStyle {
             RgbColours::frost_glimmer()
         };
 
-        ElementStyle::text(datetime, Some(colour))
+        ElementStyle::write_text(out, datetime, Some(colour))
     }
 
-    /// Styles Unix permission strings with character-by-character colour coding.
+    /// Appends a two-letter git status onto `out`, each code in its own colour.
     ///
     /// # Parameters
-    /// - `permissions`: The permission string (e.g., "rwxr-xr-x", "drwxr-xr-x", ".rwxr-xr-x").
+    /// - `out`: The buffer to append to.
+    /// - `status`: The status (e.g. `"-M"`, `"A-"`, `"??"`).
+    pub(crate) fn write_git(out: &mut String, status: &str) {
+        for (index, code) in status.char_indices() {
+            let style = match code {
+                'M' => Colour::Blue.bold(),
+                'A' | '?' => Colour::Green.bold(),
+                'D' => Colour::Red.bold(),
+                'R' | 'C' => Colour::Yellow.bold(),
+                'T' => Colour::Magenta.bold(),
+                'U' => Colour::Red.bold().underline(),
+                _ => Colour::DarkGray.normal(),
+            };
+            style.write_to(out, &status[index..index + code.len_utf8()]);
+        }
+    }
+
+    /// Styles a btrfs/ZFS volume label, setting snapshots apart from live volumes.
+    ///
+    /// # Parameters
+    /// - `label`: The volume label (e.g. `"btrfs subvol"`, `"zfs snapshot"`).
     ///
     /// # Returns
-    /// String with each character individually styled.
-    pub(crate) fn permissions(permissions: &str) -> String {
-        permissions
-            .chars()
-            .map(|character| match character.to_ascii_lowercase() {
+    /// The label in cyan for snapshots, or magenta (as mountpoints) otherwise.
+    #[cfg(feature = "volumes")]
+    pub(crate) fn volume(label: &str) -> String {
+        if label.ends_with("snapshot") {
+            Colour::Cyan.bold().apply_to(label)
+        } else {
+            Colour::Magenta.bold().apply_to(label)
+        }
+    }
+
+    /// Appends a Unix permission string onto `out` with character-by-character colour coding.
+    ///
+    /// # Parameters
+    /// - `out`: The buffer to append to.
+    /// - `permissions`: The permission string (e.g., "rwxr-xr-x", "drwxr-xr-x", ".rwxr-xr-x").
+    pub(crate) fn write_permissions(out: &mut String, permissions: &str) {
+        for (index, character) in permissions.char_indices() {
+            let style = match character.to_ascii_lowercase() {
                 // File type or "dot" prefix
-                '.' => Colour::White.bold().apply_to("."),
+                '.' => Colour::White.bold(),
 
                 // Standard permissions
-                'r' => Colour::Yellow.bold().apply_to("r"),
-                'w' => Colour::Red.bold().apply_to("w"),
-                'x' => Colour::Green.bold().apply_to("x"),
-                '-' => Colour::DarkGray.normal().apply_to("-"),
+                'r' => Colour::Yellow.bold(),
+                'w' => Colour::Red.bold(),
+                'x' => Colour::Green.bold(),
+                '-' => Colour::DarkGray.normal(),
 
                 // File type indicators
-                'd' | 'l' | 'b' | 'c' | 'p' | 's' => {
-                    Colour::Blue.bold().apply_to(&character.to_string())
-                }
+                'd' | 'l' | 'b' | 'c' | 'p' | 's' => Colour::Blue.bold(),
 
                 // Special permission bits
-                'S' | 'T' | 't' => Colour::Magenta.bold().apply_to(&character.to_string()),
+                'S' | 'T' | 't' => Colour::Magenta.bold(),
 
                 // Numeric characters (for octal/hex)
-                '0'..='9' => ElementStyle::numeric(&character.to_string()),
+                '0'..='9' => Colour::Cyan.bold(),
 
                 // Anything else (just in case)
-                other => Colour::White.bold().apply_to(&other.to_string()),
-            })
-            .map(|s| s.to_string())
-            .collect::<String>()
+                _ => Colour::White.bold(),
+            };
+            style.write_to(out, &permissions[index..index + character.len_utf8()]);
+        }
     }
 }
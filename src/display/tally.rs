@@ -0,0 +1,149 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! `--grand-total`: the `42 files, 7 dirs, 3 symlinks, 1.2 GB total` footer.
+//!
+//! Every [`DisplayMode`](crate::display::mode::DisplayMode) adds the entries it
+//! lists to a [`Tally`] handed down from the factory, so one footer can cover
+//! several path operands and whichever renderer drew them. With `--copy-paths`
+//! the tally also keeps the listed paths, so the clipboard gets exactly what
+//! was printed.
+
+use crate::cli::args::Args;
+use crate::display::output::formats::format::Format;
+use crate::display::output::formats::size::Size;
+use crate::display::styles::element::ElementStyle;
+use crate::fs::entry::Entry;
+use std::path::PathBuf;
+
+/// Running counts of the entries listed so far and the bytes their files hold.
+#[derive(Debug, Default)]
+pub struct Tally {
+    files: usize,
+    dirs: usize,
+    symlinks: usize,
+    bytes: u64,
+    /// Absolute paths of the listed entries, kept only for `--copy-paths`
+    paths: Option<Vec<PathBuf>>,
+}
+
+impl Tally {
+    /// Starts an empty tally for a listing.
+    ///
+    /// # Parameters
+    /// - `args`: Command-line arguments (checked for `copy_paths`).
+    pub(crate) fn new(args: &Args) -> Self {
+        Self {
+            paths: args.copy_paths.then(Vec::new),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the absolute paths of the entries listed so far, in listing order.
+    ///
+    /// # Returns
+    /// The paths, or an empty slice unless `--copy-paths` was given.
+    pub fn paths(&self) -> &[PathBuf] {
+        self.paths.as_deref().unwrap_or_default()
+    }
+
+    /// Counts one listed entry.
+    ///
+    /// Only regular files add to the byte total; a directory's own size is
+    /// filesystem bookkeeping and its contents are counted as they are listed.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry that was listed.
+    pub(crate) fn add(&mut self, entry: &Entry) {
+        if let Some(paths) = &mut self.paths
+            && let Ok(path) = std::path::absolute(entry.path())
+        {
+            paths.push(path);
+        }
+
+        if entry.is_dir() {
+            self.dirs += 1;
+        } else if entry.is_symlink() {
+            self.symlinks += 1;
+        } else {
+            self.files += 1;
+            self.bytes += match entry.metadata() {
+                Some(metadata) => metadata.size,
+                None => std::fs::symlink_metadata(entry.path())
+                    .map(|metadata| metadata.len())
+                    .unwrap_or_default(),
+            };
+        }
+    }
+
+    /// Counts every entry in a slice.
+    ///
+    /// # Parameters
+    /// - `entries`: The entries that were listed.
+    pub(crate) fn extend(&mut self, entries: &[Entry]) {
+        for entry in entries {
+            self.add(entry);
+        }
+    }
+
+    /// Formats the footer, leaving out kinds of entry that weren't listed.
+    ///
+    /// # Parameters
+    /// - `args`: Command-line arguments (checked for `size_format`).
+    ///
+    /// # Returns
+    /// Text like `"42 files, 7 dirs, 3 symlinks, 1.2 GB total"`.
+    pub(crate) fn format(&self, args: &Args) -> String {
+        let mut parts: Vec<String> = [
+            (self.files, "file", "files"),
+            (self.dirs, "dir", "dirs"),
+            (self.symlinks, "symlink", "symlinks"),
+        ]
+        .into_iter()
+        .filter(|(count, _, _)| *count > 0)
+        .map(|(count, one, many)| match count {
+            1 => format!("1 {one}"),
+            count => format!("{count} {many}"),
+        })
+        .collect();
+
+        if parts.is_empty() {
+            parts.push("0 files".to_string());
+        }
+        parts.push(format!(
+            "{} total",
+            Size::new(args.size_format).format(self.bytes)
+        ));
+
+        parts.join(", ")
+    }
+
+    /// Prints the styled footer on its own line.
+    ///
+    /// # Parameters
+    /// - `args`: Command-line arguments controlling size formatting.
+    pub(crate) fn print(&self, args: &Args) {
+        println!("\n{}", ElementStyle::summary(&self.format(args)));
+    }
+}
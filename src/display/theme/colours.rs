@@ -22,17 +22,20 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
-use crate::cli::flags::ShowColour;
+use crate::cli::flags::{ShowColour, StyleCaps};
+use crate::cli::settings::Settings;
 use crate::display::output::terminal;
 use crate::display::theme::config::Theme;
 use nu_ansi_term::{Color, Style};
-use std::sync::OnceLock;
+use std::fmt::Write;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
 
 pub(crate) type Colour = Color;
 
 static COLOURS_ENABLED: AtomicBool = AtomicBool::new(true);
-static THEME: OnceLock<Theme> = OnceLock::new();
+static THEME: OnceLock<Arc<Theme>> = OnceLock::new();
+static STYLE_CAPS: OnceLock<StyleCaps> = OnceLock::new();
 
 /// Global colour toggle controlling whether ANSI colour codes are emitted.
 pub struct ColourSettings;
@@ -50,7 +53,8 @@ impl ColourSettings {
 
     /// Checks whether colour output is currently enabled.
     pub(crate) fn is_enabled() -> bool {
-        COLOURS_ENABLED.load(Ordering::SeqCst)
+        Settings::get(|settings| settings.colours)
+            .unwrap_or_else(|| COLOURS_ENABLED.load(Ordering::SeqCst))
     }
 
     /// Configures colour output at startup based on the CLI flag and terminal detection.
@@ -72,12 +76,66 @@ impl ColourSettings {
     }
 }
 
+/// Global text-attribute capability level, used to downgrade styles the terminal can't draw.
+pub struct StyleCapsSettings;
+
+impl StyleCapsSettings {
+    /// Configures the capability level at startup from the CLI flag or terminal detection.
+    ///
+    /// # Parameters
+    /// - `style_caps`: The user's `--style-caps` choice (`auto` probes `TERM`).
+    pub fn setup(style_caps: StyleCaps) {
+        let resolved = match style_caps {
+            StyleCaps::Auto => terminal::style_caps(),
+            caps => caps,
+        };
+        STYLE_CAPS.set(resolved).ok();
+    }
+
+    /// Returns the capability level in effect (`full` until [`StyleCapsSettings::setup`] has run).
+    pub(crate) fn caps() -> StyleCaps {
+        Settings::get(|settings| settings.style_caps)
+            .unwrap_or_else(|| STYLE_CAPS.get().copied().unwrap_or(StyleCaps::Full))
+    }
+
+    /// Downgrades a style to the attributes the terminal supports.
+    ///
+    /// With `basic`, italic, strikethrough and blink are dropped and underline
+    /// becomes bold; with `none`, only colours are kept.
+    ///
+    /// # Parameters
+    /// - `style`: The requested style.
+    ///
+    /// # Returns
+    /// The style to actually emit.
+    pub(crate) fn adapt(style: Style) -> Style {
+        match Self::caps() {
+            StyleCaps::Auto | StyleCaps::Full => style,
+            StyleCaps::Basic => Style {
+                is_bold: style.is_bold || style.is_underline,
+                is_underline: false,
+                is_italic: false,
+                is_strikethrough: false,
+                is_blink: false,
+                ..style
+            },
+            StyleCaps::None => Style {
+                foreground: style.foreground,
+                background: style.background,
+                ..Style::new()
+            },
+        }
+    }
+}
+
 /// Trait for applying colour styles to text, respecting the global colour toggle.
 pub(crate) trait ColourPaint {
     /// Applies this style to a string slice, returning plain text when colours are disabled.
     fn apply_to(&self, text: &str) -> String;
     /// Applies this style to a single character, returning plain text when colours are disabled.
     fn apply_to_char(&self, c: char) -> String;
+    /// Appends this style applied to a string slice onto `out`, without allocating.
+    fn write_to(&self, out: &mut String, text: &str);
 }
 
 impl ColourPaint for Style {
@@ -89,11 +147,9 @@ impl ColourPaint for Style {
     /// # Returns
     /// The styled string, or the original text if colours are disabled.
     fn apply_to(&self, text: &str) -> String {
-        if ColourSettings::is_enabled() {
-            self.paint(text).to_string()
-        } else {
-            text.to_string()
-        }
+        let mut out = String::new();
+        self.write_to(&mut out, text);
+        out
     }
 
     /// Applies this style to a single character, returning plain text when colours are disabled.
@@ -104,10 +160,24 @@ impl ColourPaint for Style {
     /// # Returns
     /// The styled character as a string, or the plain character if colours are disabled.
     fn apply_to_char(&self, c: char) -> String {
+        let mut out = String::new();
+        self.write_to(&mut out, c.encode_utf8(&mut [0; 4]));
+        out
+    }
+
+    /// Appends this style applied to a string slice onto `out`.
+    ///
+    /// Renderers reuse one buffer per row, so painting straight into it avoids
+    /// an intermediate `String` for every styled fragment.
+    ///
+    /// # Parameters
+    /// - `out`: The buffer to append to.
+    /// - `text`: The text to style.
+    fn write_to(&self, out: &mut String, text: &str) {
         if ColourSettings::is_enabled() {
-            self.paint(c.to_string()).to_string()
+            let _ = write!(out, "{}", StyleCapsSettings::adapt(*self).paint(text));
         } else {
-            c.to_string()
+            out.push_str(text);
         }
     }
 }
@@ -123,12 +193,14 @@ impl RgbColours {
     /// # Parameters
     /// - `theme`: The theme to store globally.
     pub fn init(theme: Theme) {
-        THEME.set(theme).ok();
+        THEME.set(Arc::new(theme)).ok();
     }
 
     /// Returns the current theme.
-    pub(crate) fn theme() -> &'static Theme {
-        THEME.get().expect("Theme not initialised - call RgbColours::init() first")
+    pub(crate) fn theme() -> Arc<Theme> {
+        Settings::get(|settings| settings.theme.clone()).unwrap_or_else(|| {
+            THEME.get().expect("Theme not initialised - call RgbColours::init() first").clone()
+        })
     }
 
     /// Returns the theme colour for byte-sized files.
@@ -276,6 +348,16 @@ impl RgbColours {
         Self::theme().entry_directory.colour
     }
 
+    /// Returns the theme colour for warnings about suspicious entries.
+    pub(crate) fn warning() -> Colour {
+        Self::theme().warning.colour
+    }
+
+    /// Returns the theme colour for placeholders of unavailable values.
+    pub(crate) fn placeholder() -> Colour {
+        Self::theme().placeholder.colour
+    }
+
     /// Returns the theme colour for summary text.
     pub(crate) fn summary() -> Colour {
         Self::theme().summary.colour
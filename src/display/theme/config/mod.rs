@@ -28,7 +28,8 @@ SOFTWARE.
 //! `$XDG_CONFIG_HOME/cerium.toml`). Every key is optional and falls back
 //! per-field to the built-in Catppuccin Mocha theme, so partial overrides
 //! work. A missing config file is silent; a config that exists but can't be
-//! read or parsed produces a non-fatal warning on stderr.
+//! read or parsed produces a non-fatal warning on stderr. The file is read
+//! once and shared by everything that takes settings from it ([`table`]).
 //!
 //! # Config File Format
 //!
@@ -50,15 +51,70 @@ SOFTWARE.
 //!
 //! Semantic keys may also be placed at the top level (flat form) without a
 //! `[colors]` table.
+//!
+//! A top-level `theme = "nord"` starts from one of the bundled themes
+//! ([`Theme::BUILTIN`]) instead of Catppuccin Mocha; any other keys still
+//! override it per field.
+//!
+//! A top-level `version = 1` records the schema the file was written for
+//! ([`schema::VERSION`]). Renamed keys are migrated as the file loads, keys
+//! this build doesn't know are ignored, and `ce --check-config` lists both.
 
 pub mod colour;
+pub mod schema;
 mod theme;
 
 pub use theme::Theme;
 
 use crate::display::output::terminal;
+use crate::display::theme::icons::FontHint;
+use schema::{Issue, Schema};
 use std::fs;
+use std::io::ErrorKind;
 use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// The config file, read and migrated once per run.
+static DOCUMENT: OnceLock<Option<Document>> = OnceLock::new();
+
+/// A config file that exists, with what was learned while loading it.
+struct Document {
+    path: PathBuf,
+    /// The migrated contents, or `None` if the file couldn't be read or parsed.
+    table: Option<toml::Table>,
+    /// Problems and notices found while loading.
+    issues: Vec<Issue>,
+}
+
+/// Reads, parses, and migrates the config file on first use.
+///
+/// # Returns
+/// The loaded document, or `None` if there is no config file.
+fn document() -> Option<&'static Document> {
+    DOCUMENT
+        .get_or_init(|| {
+            let path = get_config_path().ok()?;
+            let (table, issues) = match fs::read_to_string(&path) {
+                Ok(contents) => Schema::parse(&contents),
+                Err(error) if error.kind() == ErrorKind::NotFound => return None,
+                Err(error) => (None, vec![Issue::Unreadable(error.to_string())]),
+            };
+            Some(Document {
+                path,
+                table,
+                issues,
+            })
+        })
+        .as_ref()
+}
+
+/// Returns the top-level table of the config file, with renamed keys migrated.
+///
+/// # Returns
+/// The table, or `None` if the config file is missing, unreadable, or invalid.
+pub fn table() -> Option<&'static toml::Table> {
+    document()?.table.as_ref()
+}
 
 /// Loads the theme from the config file, falling back to the built-in
 /// Catppuccin Mocha theme.
@@ -67,48 +123,110 @@ use std::path::PathBuf;
 /// - **No config file** (or no resolvable config dir): use the built-in
 ///   default, silently.
 /// - **Config exists but can't be read or is invalid TOML**: use the
-///   built-in default, and print a non-fatal warning to stderr when stdout
-///   is an interactive terminal.
+///   built-in default; [`report`] says so.
 /// - **Config exists and parses**: per-field resolution is handled by
 ///   [`Theme::from_value`]; absent or unresolvable keys use their default.
+///   An unknown `theme` name falls back to Catppuccin Mocha.
 ///
 /// # Returns
 ///
 /// The resolved [`Theme`].
 pub fn load_theme() -> Theme {
-    let Ok(config_path) = get_config_path() else {
-        return Theme::default();
+    match table() {
+        Some(table) => Theme::from_value(&toml::Value::Table(table.clone())),
+        None => Theme::default(),
+    }
+}
+
+/// Reads the top-level `language` key used for localised column headers.
+///
+/// # Returns
+/// The configured language code (e.g. `"de"`), or `None` if the config file
+/// is missing, unreadable, or doesn't set one.
+pub fn load_language() -> Option<String> {
+    table()?.get("language")?.as_str().map(String::from)
+}
+
+/// Reads the top-level `nerd_font` key used to pick the icon set.
+///
+/// # Returns
+/// [`FontHint::Yes`] or [`FontHint::No`] for `true`/`false`, or `None` if the
+/// config file is missing, unreadable, or doesn't set it.
+pub fn load_font_hint() -> Option<FontHint> {
+    match table()?.get("nerd_font")?.as_bool()? {
+        true => Some(FontHint::Yes),
+        false => Some(FontHint::No),
+    }
+}
+
+/// Warns on stderr about problems found while loading the config file.
+///
+/// A file that couldn't be read or parsed is always reported, since none of
+/// it is used. Migration notices and an unknown `theme` name are reported
+/// only when stdout is an interactive terminal, to stay quiet for pipes,
+/// scripts, and command substitution. Unknown keys are left to
+/// `--check-config`.
+pub fn report() {
+    let Some(document) = document() else {
+        return;
     };
 
-    if !config_path.exists() {
-        return Theme::default();
+    let unknown_theme = document
+        .table
+        .as_ref()
+        .and_then(|table| table.get("theme")?.as_str())
+        .filter(|name| Theme::builtin(name).is_none())
+        .map(|name| Issue::UnknownTheme(name.to_string()));
+
+    for issue in document.issues.iter().chain(&unknown_theme) {
+        let fatal = matches!(issue, Issue::Syntax { .. } | Issue::Unreadable(_));
+        if !fatal && !terminal::is_tty() {
+            continue;
+        }
+        let fallback = if fatal {
+            "; using built-in defaults"
+        } else {
+            ""
+        };
+        eprintln!("cerium: {}: {issue}{fallback}.", document.path.display());
     }
+}
 
-    let parsed = fs::read_to_string(&config_path)
-        .map_err(|e| e.to_string())
-        .and_then(|contents| toml::from_str::<Theme>(&contents).map_err(|e| e.to_string()));
-
-    match parsed {
-        Ok(theme) => theme,
-        Err(error) => {
-            // Warn only on an interactive terminal; stay silent for pipes,
-            // scripts, and command substitution.
-            if terminal::is_tty() {
-                eprintln!(
-                    "cerium: could not load theme from {} ({error}); using built-in theme.",
-                    config_path.display()
-                );
-            }
-            Theme::default()
+/// Prints every problem with the config file, for `--check-config`.
+///
+/// # Returns
+/// `true` if the file is missing or has nothing to report beyond notices,
+/// `false` if any key or value would be ignored.
+pub fn print_check() -> bool {
+    let Some(document) = document() else {
+        match get_config_path() {
+            Ok(path) => println!("{}: not found (using built-in defaults)", path.display()),
+            Err(error) => println!("cerium: {error}"),
         }
+        return true;
+    };
+
+    let mut issues = document.issues.clone();
+    if let Some(table) = &document.table {
+        issues.extend(Schema::check(table));
     }
+
+    let path = document.path.display();
+    if issues.is_empty() {
+        println!("{path}: ok");
+    }
+    for issue in &issues {
+        println!("{path}: {issue}");
+    }
+
+    issues.iter().all(Issue::is_notice)
 }
 
 /// Returns the path to the config file (`~/.config/cerium.toml`).
 ///
 /// # Returns
 /// The config file path, or an error if the home directory cannot be determined.
-fn get_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+pub fn get_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
     let config_dir = std::env::var_os("XDG_CONFIG_HOME")
         .map(PathBuf::from)
         .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
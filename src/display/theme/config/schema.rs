@@ -0,0 +1,318 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! The keys `cerium.toml` understands, and checks against them.
+//!
+//! A top-level `version = 1` names the schema a file was written for. Files
+//! without one are read as the current version, and files from a newer
+//! version are still read, with anything this build doesn't know ignored.
+//! Keys that were renamed are moved to their new name when the file is
+//! loaded ([`Schema::migrate`]), and `--check-config` lists everything else
+//! that would be ignored ([`Schema::check`]).
+
+use super::colour::colour_from_value;
+use super::theme::Theme;
+use nu_ansi_term::Color as Colour;
+use std::collections::HashMap;
+use std::fmt;
+
+/// The schema version this build reads and writes.
+pub const VERSION: i64 = 1;
+
+/// Keys that moved, as `(old, new)`, migrated on load.
+const RENAMED: &[(&str, &str)] = &[("colours", "colors")];
+
+/// A problem with a config file, or a notice about how it was read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    /// The file exists but couldn't be read.
+    Unreadable(String),
+    /// The file isn't valid TOML, so none of it is used.
+    Syntax {
+        /// The 1-based line of the error, when known.
+        line: Option<usize>,
+        message: String,
+    },
+    /// A renamed key was moved to its new name.
+    Renamed {
+        from: &'static str,
+        to: &'static str,
+    },
+    /// A renamed key was dropped because its new name is also set.
+    Shadowed {
+        from: &'static str,
+        to: &'static str,
+    },
+    /// `version` names a schema newer than [`VERSION`].
+    NewerVersion(i64),
+    /// `version` isn't a positive integer.
+    InvalidVersion,
+    /// A key this build doesn't know (tables as `colors.key`).
+    UnknownKey(String),
+    /// A colour key whose value isn't a colour.
+    InvalidColour(String),
+    /// `theme` names no built-in theme.
+    UnknownTheme(String),
+    /// A key whose value has the wrong type.
+    InvalidValue {
+        key: &'static str,
+        expected: &'static str,
+    },
+}
+
+impl Issue {
+    /// Checks whether this is only a notice: the setting still took effect.
+    pub fn is_notice(&self) -> bool {
+        matches!(self, Self::Renamed { .. } | Self::NewerVersion(_))
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unreadable(error) => write!(f, "can't be read ({error})"),
+            Self::Syntax {
+                line: Some(line),
+                message,
+            } => write!(f, "invalid TOML on line {line}: {message}"),
+            Self::Syntax {
+                line: None,
+                message,
+            } => write!(f, "invalid TOML: {message}"),
+            Self::Renamed { from, to } => write!(f, "`{from}` has been renamed to `{to}`"),
+            Self::Shadowed { from, to } => {
+                write!(
+                    f,
+                    "`{from}` is ignored: it was renamed to `{to}`, which is also set"
+                )
+            }
+            Self::NewerVersion(version) => write!(
+                f,
+                "written for version {version}, newer than this cerium reads ({VERSION}); keys it doesn't know are ignored"
+            ),
+            Self::InvalidVersion => write!(f, "`version` should be a positive integer"),
+            Self::UnknownKey(key) => write!(f, "unknown key `{key}`"),
+            Self::InvalidColour(key) => write!(
+                f,
+                "`{key}` isn't a colour (expected a hex string, RGB table, palette name or colour name)"
+            ),
+            Self::UnknownTheme(name) => write!(
+                f,
+                "unknown theme `{name}` (available: {})",
+                Theme::BUILTIN.join(", ")
+            ),
+            Self::InvalidValue { key, expected } => write!(f, "`{key}` should be {expected}"),
+        }
+    }
+}
+
+/// Reads and validates the layout of `cerium.toml`.
+pub struct Schema;
+
+impl Schema {
+    /// Parses a config file's contents and migrates renamed keys.
+    ///
+    /// # Parameters
+    /// - `contents`: The TOML text of the config file.
+    ///
+    /// # Returns
+    /// The migrated table (or `None` if the text isn't valid TOML), and the
+    /// notices and problems found on the way.
+    pub fn parse(contents: &str) -> (Option<toml::Table>, Vec<Issue>) {
+        match toml::from_str::<toml::Table>(contents) {
+            Ok(mut table) => {
+                let mut issues = Self::migrate(&mut table);
+                if let Some(issue) = Self::newer_version(&table) {
+                    issues.push(issue);
+                }
+                (Some(table), issues)
+            }
+            Err(error) => {
+                let line = error
+                    .span()
+                    .map(|span| contents[..span.start].matches('\n').count() + 1);
+                let issue = Issue::Syntax {
+                    line,
+                    message: error.message().trim().to_string(),
+                };
+                (None, vec![issue])
+            }
+        }
+    }
+
+    /// Moves renamed keys to their new names.
+    ///
+    /// When both names are set, the new one wins and the old one is dropped.
+    ///
+    /// # Parameters
+    /// - `table`: The top-level config table, modified in place.
+    ///
+    /// # Returns
+    /// A notice for every key that was moved or dropped.
+    pub fn migrate(table: &mut toml::Table) -> Vec<Issue> {
+        let mut notices = Vec::new();
+
+        for &(from, to) in RENAMED {
+            let Some(value) = table.remove(from) else {
+                continue;
+            };
+            if table.contains_key(to) {
+                notices.push(Issue::Shadowed { from, to });
+            } else {
+                table.insert(to.to_string(), value);
+                notices.push(Issue::Renamed { from, to });
+            }
+        }
+
+        notices
+    }
+
+    /// Lists every key and value in a (migrated) config that this build won't use.
+    ///
+    /// # Parameters
+    /// - `table`: The top-level config table.
+    ///
+    /// # Returns
+    /// The problems found, by key.
+    pub fn check(table: &toml::Table) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let empty = HashMap::new();
+
+        // Palette entries don't reference each other, so they resolve against
+        // an empty map, as in `Theme::from_value`.
+        let palette: HashMap<String, Colour> = table
+            .get("palette")
+            .and_then(toml::Value::as_table)
+            .map(|palette| {
+                palette
+                    .iter()
+                    .filter_map(|(name, v)| colour_from_value(v, &empty).map(|c| (name.clone(), c)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        for (key, value) in table {
+            match key.as_str() {
+                "version" => {
+                    if value.as_integer().is_none_or(|version| version < 1) {
+                        issues.push(Issue::InvalidVersion);
+                    }
+                }
+                "theme" => match value.as_str() {
+                    Some(name) if Theme::builtin(name).is_none() => {
+                        issues.push(Issue::UnknownTheme(name.to_string()));
+                    }
+                    Some(_) => {}
+                    None => issues.push(Issue::InvalidValue {
+                        key: "theme",
+                        expected: "a theme name",
+                    }),
+                },
+                "palette" => match value.as_table() {
+                    Some(entries) => {
+                        for (name, value) in entries {
+                            Self::colour(&mut issues, format!("palette.{name}"), value, &empty);
+                        }
+                    }
+                    None => issues.push(Issue::InvalidValue {
+                        key: "palette",
+                        expected: "a table",
+                    }),
+                },
+                "colors" => match value.as_table() {
+                    Some(entries) => {
+                        for (name, value) in entries {
+                            let qualified = format!("colors.{name}");
+                            if Theme::KEYS.contains(&name.as_str()) {
+                                Self::colour(&mut issues, qualified, value, &palette);
+                            } else {
+                                issues.push(Issue::UnknownKey(qualified));
+                            }
+                        }
+                    }
+                    None => issues.push(Issue::InvalidValue {
+                        key: "colors",
+                        expected: "a table",
+                    }),
+                },
+                "language" if !value.is_str() => issues.push(Issue::InvalidValue {
+                    key: "language",
+                    expected: "a language code such as \"de\"",
+                }),
+                "nerd_font" if !value.is_bool() => issues.push(Issue::InvalidValue {
+                    key: "nerd_font",
+                    expected: "true or false",
+                }),
+                "bookmarks" if !value.is_table() => issues.push(Issue::InvalidValue {
+                    key: "bookmarks",
+                    expected: "a table",
+                }),
+                "profile"
+                    if !value
+                        .as_table()
+                        .is_some_and(|profiles| profiles.values().all(toml::Value::is_table)) =>
+                {
+                    issues.push(Issue::InvalidValue {
+                        key: "profile",
+                        expected: "a set of [profile.NAME] tables",
+                    });
+                }
+                "language" | "nerd_font" | "bookmarks" | "profile" => {}
+                name if Theme::KEYS.contains(&name) => {
+                    Self::colour(&mut issues, key.clone(), value, &palette);
+                }
+                _ => issues.push(Issue::UnknownKey(key.clone())),
+            }
+        }
+
+        issues
+    }
+
+    /// Records an issue if a colour key's value doesn't resolve.
+    ///
+    /// # Parameters
+    /// - `issues`: Where to record the issue.
+    /// - `key`: The key, qualified with its table.
+    /// - `value`: The key's value.
+    /// - `palette`: The named colours it may reference.
+    fn colour(
+        issues: &mut Vec<Issue>,
+        key: String,
+        value: &toml::Value,
+        palette: &HashMap<String, Colour>,
+    ) {
+        if colour_from_value(value, palette).is_none() {
+            issues.push(Issue::InvalidColour(key));
+        }
+    }
+
+    /// Notices a `version` newer than this build reads.
+    ///
+    /// # Parameters
+    /// - `table`: The top-level config table.
+    fn newer_version(table: &toml::Table) -> Option<Issue> {
+        let version = table.get("version")?.as_integer()?;
+        (version > VERSION).then_some(Issue::NewerVersion(version))
+    }
+}
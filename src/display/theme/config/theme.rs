@@ -112,6 +112,7 @@ pub struct Theme {
     pub mountpoint: ThemeColour,
     pub numeric: ThemeColour,
     pub placeholder: ThemeColour,
+    pub warning: ThemeColour,
     pub summary: ThemeColour,
 
     // CLI help colours
@@ -126,7 +127,7 @@ impl<'de> Deserialize<'de> for Theme {
     ///
     /// The whole document is read into a [`toml::Value`], then
     /// [`Theme::from_value`] applies the palette layer and per-field
-    /// fallbacks to the built-in Catppuccin Mocha default.
+    /// fallbacks to the selected built-in theme.
     ///
     /// # Parameters
     /// - `deserializer`: The serde deserialiser to read from.
@@ -143,15 +144,121 @@ impl<'de> Deserialize<'de> for Theme {
 }
 
 impl Theme {
+    /// Names of the themes bundled with Cerium, selectable with `--theme` or
+    /// a top-level `theme = "<name>"` key in the config.
+    pub const BUILTIN: [&'static str; 9] = [
+        "catppuccin-mocha",
+        "gruvbox",
+        "dracula",
+        "nord",
+        "solarized-dark",
+        "solarized-light",
+        "tokyo-night",
+        "one-dark",
+        "rose-pine",
+    ];
+
+    /// Semantic colour keys, accepted under `[colors]` or at the top level.
+    pub const KEYS: [&'static str; 58] = [
+        "size_bytes",
+        "size_kb",
+        "size_mb",
+        "size_gb",
+        "date_recent",
+        "date_hours",
+        "date_days",
+        "date_weeks",
+        "date_months",
+        "date_old",
+        "perm_read",
+        "perm_write",
+        "perm_execute",
+        "perm_none",
+        "perm_special",
+        "perm_filetype",
+        "entry_directory",
+        "entry_symlink",
+        "entry_file",
+        "user",
+        "group",
+        "code_rust",
+        "code_python",
+        "code_javascript",
+        "code_c",
+        "code_go",
+        "code_java",
+        "code_ruby",
+        "code_php",
+        "code_lua",
+        "web_html",
+        "web_css",
+        "web_json",
+        "web_xml",
+        "web_yaml",
+        "doc_text",
+        "doc_markdown",
+        "doc_pdf",
+        "media_image",
+        "media_video",
+        "media_audio",
+        "archive",
+        "tree_connector",
+        "table_header",
+        "path_display",
+        "checksum",
+        "magic",
+        "xattr",
+        "acl",
+        "mountpoint",
+        "numeric",
+        "placeholder",
+        "warning",
+        "summary",
+        "cli_help_header",
+        "cli_help_usage",
+        "cli_help_literal",
+        "cli_help_placeholder",
+    ];
+
+    /// Looks up a bundled theme by name.
+    ///
+    /// The themes are the files under `themes/`, embedded at compile time, so
+    /// they match what users would get by copying a file into their config.
+    ///
+    /// # Parameters
+    /// - `name`: One of [`Theme::BUILTIN`].
+    ///
+    /// # Returns
+    /// The complete theme, or `None` if no built-in theme has that name.
+    pub fn builtin(name: &str) -> Option<Self> {
+        let source = match name {
+            "catppuccin-mocha" => return Some(Theme::default()),
+            "gruvbox" => include_str!("../../../../themes/gruvbox.toml"),
+            "dracula" => include_str!("../../../../themes/dracula.toml"),
+            "nord" => include_str!("../../../../themes/nord.toml"),
+            "solarized-dark" => include_str!("../../../../themes/solarized-dark.toml"),
+            "solarized-light" => include_str!("../../../../themes/solarized-light.toml"),
+            "tokyo-night" => include_str!("../../../../themes/tokyo-night.toml"),
+            "one-dark" => include_str!("../../../../themes/one-dark.toml"),
+            "rose-pine" => include_str!("../../../../themes/rose-pine.toml"),
+            _ => return None,
+        };
+        toml::from_str::<toml::Value>(source)
+            .ok()
+            .map(|value| Theme::from_value(&value))
+    }
+
     /// Builds a theme from a parsed TOML value.
     ///
     /// Resolution per key:
-    /// 1. the `[palette]` table is resolved into named colours;
-    /// 2. each semantic key is looked up under `[colors]`, then at the top
+    /// 1. a top-level `theme = "<name>"` selects the built-in base theme
+    ///    (Catppuccin Mocha when absent or unknown);
+    /// 2. the `[palette]` table is resolved into named colours;
+    /// 3. each semantic key is looked up under `[colors]`, then at the top
     ///    level (flat form);
-    /// 3. its value is resolved (RGB / hex / palette reference / named);
-    /// 4. anything absent or unresolvable uses the built-in Catppuccin
-    ///    Mocha default for that key.
+    /// 4. its value is resolved (RGB / hex / palette reference / named);
+    /// 5. anything absent or unresolvable uses the base theme's colour for
+    ///    that key.
     ///
     /// Always returns a complete theme.
     ///
@@ -172,9 +279,7 @@ impl Theme {
             .map(|table| {
                 table
                     .iter()
-                    .filter_map(|(name, v)| {
-                        colour_from_value(v, &empty).map(|c| (name.clone(), c))
-                    })
+                    .filter_map(|(name, v)| colour_from_value(v, &empty).map(|c| (name.clone(), c)))
                     .collect()
             })
             .unwrap_or_default();
@@ -195,7 +300,11 @@ impl Theme {
             }
         };
 
-        let d = Theme::default();
+        let d = root
+            .and_then(|t| t.get("theme"))
+            .and_then(toml::Value::as_str)
+            .and_then(Theme::builtin)
+            .unwrap_or_default();
 
         Theme {
             size_bytes: pick("size_bytes", d.size_bytes),
@@ -260,6 +369,7 @@ impl Theme {
             mountpoint: pick("mountpoint", d.mountpoint),
             numeric: pick("numeric", d.numeric),
             placeholder: pick("placeholder", d.placeholder),
+            warning: pick("warning", d.warning),
             summary: pick("summary", d.summary),
 
             cli_help_header: pick("cli_help_header", d.cli_help_header),
@@ -368,6 +478,7 @@ impl Default for Theme {
             mountpoint: mauve.clone(),
             numeric: sky.clone(),
             placeholder: overlay0.clone(),
+            warning: red.clone(),
             summary: text.clone(),
 
             // CLI help colours
@@ -23,14 +23,29 @@ SOFTWARE.
 */
 
 use crate::cli::flags::ShowIcons;
+use crate::cli::settings::Settings;
+use crate::display::output::terminal;
 use crate::display::theme::colours::{Colour, RgbColours};
 use phf::{Map, phf_map};
+use std::env;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 // Global atomic: are icons enabled?
 static ICONS_ENABLED: AtomicBool = AtomicBool::new(true);
 
-/// Global icon toggle controlling whether Nerd Font icons are displayed.
+// Global atomic: can the terminal draw Nerd Font glyphs?
+static NERD_FONT: AtomicBool = AtomicBool::new(true);
+
+/// The glyphs entry icons are drawn from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IconSet {
+    /// Nerd Font glyphs from the Private Use Area.
+    NerdFont,
+    /// Standard Unicode emoji, for terminals whose font lacks Nerd Font glyphs.
+    Unicode,
+}
+
+/// Global icon toggle controlling whether icons are displayed, and which set.
 pub struct IconSettings;
 
 impl IconSettings {
@@ -46,28 +61,116 @@ impl IconSettings {
 
     /// Checks whether icon output is currently enabled.
     pub(crate) fn enabled() -> bool {
-        ICONS_ENABLED.load(Ordering::SeqCst)
+        Settings::get(|settings| settings.icons)
+            .unwrap_or_else(|| ICONS_ENABLED.load(Ordering::SeqCst))
+    }
+
+    /// Returns the icon set currently in use.
+    pub fn icon_set() -> IconSet {
+        Settings::get(|settings| settings.icon_set).unwrap_or_else(|| {
+            if NERD_FONT.load(Ordering::SeqCst) {
+                IconSet::NerdFont
+            } else {
+                IconSet::Unicode
+            }
+        })
+    }
+
+    /// Selects the icon set globally.
+    ///
+    /// # Parameters
+    /// - `set`: The icon set to draw from.
+    pub fn use_set(set: IconSet) {
+        NERD_FONT.store(set == IconSet::NerdFont, Ordering::SeqCst);
     }
 
     /// Configures icon output at startup based on the CLI flag and terminal detection.
     ///
+    /// `--icons auto` shows icons on any TTY. Whether they come from the Nerd
+    /// Font set or the Unicode fallback is decided by [`IconSettings::guess`];
+    /// when it can't tell, Nerd Font icons are assumed as before.
+    ///
     /// # Parameters
     /// - `show_icons`: The user's icon preference from the CLI.
-    pub fn setup(show_icons: ShowIcons) {
+    /// - `hint`: The `nerd_font` setting from `cerium.toml`, if any.
+    pub fn setup(show_icons: ShowIcons, hint: Option<FontHint>) {
         match show_icons {
             ShowIcons::Always => Self::enable(),
             ShowIcons::Never => Self::disable(),
             ShowIcons::Auto => {
-                if unsafe { libc::isatty(libc::STDOUT_FILENO) == 1 } {
+                if terminal::is_tty() {
                     Self::enable()
                 } else {
                     Self::disable()
                 }
             }
         }
+
+        let nerdfont = env::var("NERDFONT").ok();
+        let term_program = env::var("TERM_PROGRAM").unwrap_or_default();
+        let term = env::var("TERM").unwrap_or_default();
+
+        if Self::guess(nerdfont.as_deref(), hint, &term_program, &term).unwrap_or(true) {
+            Self::use_set(IconSet::NerdFont)
+        } else {
+            Self::use_set(IconSet::Unicode)
+        }
+    }
+
+    /// Guesses from the environment whether the terminal can draw Nerd Font icons.
+    ///
+    /// In order: `NERDFONT` (`1`/`true`/`yes` or `0`/`false`/`no`), then the
+    /// config's `nerd_font`, then the terminal itself. WezTerm, Ghostty and
+    /// kitty bundle the Nerd Font symbols, while the Linux console and dumb
+    /// terminals can't show them at all.
+    ///
+    /// # Parameters
+    /// - `nerdfont`: The `NERDFONT` environment variable, if set.
+    /// - `hint`: The `nerd_font` setting from `cerium.toml`, if any.
+    /// - `term_program`: The `TERM_PROGRAM` environment variable.
+    /// - `term`: The `TERM` environment variable.
+    ///
+    /// # Returns
+    /// Whether Nerd Font icons will render, or `None` when that can't be told.
+    pub fn guess(
+        nerdfont: Option<&str>,
+        hint: Option<FontHint>,
+        term_program: &str,
+        term: &str,
+    ) -> Option<bool> {
+        match nerdfont.map(str::to_ascii_lowercase).as_deref() {
+            Some("1" | "true" | "yes") => return Some(true),
+            Some("0" | "false" | "no") => return Some(false),
+            _ => {}
+        }
+
+        match hint {
+            Some(FontHint::Yes) => return Some(true),
+            Some(FontHint::No) => return Some(false),
+            None => {}
+        }
+
+        if matches!(term_program, "WezTerm" | "ghostty")
+            || matches!(term, "xterm-kitty" | "xterm-ghostty" | "wezterm")
+        {
+            Some(true)
+        } else if term == "linux" || term == "dumb" {
+            Some(false)
+        } else {
+            None
+        }
     }
 }
 
+/// What `cerium.toml` says about the terminal font, from its `nerd_font` key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FontHint {
+    /// `nerd_font = true`: the font has Nerd Font glyphs.
+    Yes,
+    /// `nerd_font = false`: it doesn't, so use the Unicode set.
+    No,
+}
+
 /// Nerd Font icon constants for filesystem entries.
 #[non_exhaustive]
 pub(crate) struct Icons;
@@ -737,11 +840,66 @@ pub(crate) static EXTENSION_COLOURS: Map<&'static str, Colour> = phf_map! {
     "log"   => Colour::White,
 };
 
+/// PHF map for extension icons in the Unicode set, used when the terminal
+/// font has no Nerd Font glyphs. Only broad file kinds get an icon.
+pub(crate) static UNICODE_EXTENSION_ICONS: Map<&'static str, char> = phf_map! {
+    // Images
+    "avif" => '🎨', "bmp" => '🎨', "gif" => '🎨', "heic" => '🎨', "ico" => '🎨',
+    "jpeg" => '🎨', "jpg" => '🎨', "png" => '🎨', "svg" => '🎨', "tiff" => '🎨',
+    "webp" => '🎨',
+    // Video
+    "avi" => '🎬', "flv" => '🎬', "m4v" => '🎬', "mkv" => '🎬', "mov" => '🎬',
+    "mp4" => '🎬', "webm" => '🎬', "wmv" => '🎬',
+    // Audio
+    "aac" => '🎵', "flac" => '🎵', "m4a" => '🎵', "mp3" => '🎵', "ogg" => '🎵',
+    "opus" => '🎵', "wav" => '🎵',
+    // Archives
+    "7z" => '📦', "bz2" => '📦', "deb" => '📦', "gz" => '📦', "jar" => '📦',
+    "rar" => '📦', "rpm" => '📦', "tar" => '📦', "tgz" => '📦', "xz" => '📦',
+    "zip" => '📦', "zst" => '📦',
+    // Disk images
+    "dmg" => '💿', "img" => '💿', "iso" => '💿',
+    // Source code
+    "c" => '📝', "cc" => '📝', "cpp" => '📝', "cs" => '📝', "go" => '📝',
+    "h" => '📝', "hpp" => '📝', "java" => '📝', "js" => '📝', "kt" => '📝',
+    "lua" => '📝', "php" => '📝', "py" => '📝', "rb" => '📝', "rs" => '📝',
+    "swift" => '📝', "ts" => '📝', "tsx" => '📝', "jsx" => '📝', "zig" => '📝',
+    // Scripts
+    "bash" => '📜', "fish" => '📜', "ps1" => '📜', "sh" => '📜', "zsh" => '📜',
+    // Configuration
+    "cfg" => '🔧', "conf" => '🔧', "ini" => '🔧', "json" => '🔧', "toml" => '🔧',
+    "xml" => '🔧', "yaml" => '🔧', "yml" => '🔧',
+    // Data
+    "db" => '💾', "sql" => '💾', "sqlite" => '💾', "sqlite3" => '💾',
+    "csv" => '📊', "ods" => '📊', "tsv" => '📊', "xls" => '📊', "xlsx" => '📊',
+    // Documents
+    "md" => '📖', "rst" => '📖', "pdf" => '📕', "epub" => '📕',
+    // Fonts
+    "otf" => '🔤', "ttf" => '🔤', "woff" => '🔤', "woff2" => '🔤',
+    // Keys
+    "gpg" => '🔑', "kdbx" => '🔑', "key" => '🔑', "p12" => '🔑', "pem" => '🔑',
+    "pfx" => '🔑',
+};
+
 /// Default fallback values
 pub(crate) const DEFAULT_FILE_ICON: char = Icons::FILE_UNKNOWN;
 pub(crate) const DEFAULT_DIR_ICON: char = Icons::FOLDER;
 pub(crate) const SYMLINK_ICON: char = Icons::FILE_SYMLINK;
 
+/// Default fallback values for the Unicode set
+pub(crate) const UNICODE_FILE_ICON: char = '📄';
+pub(crate) const UNICODE_DIR_ICON: char = '📁';
+pub(crate) const UNICODE_EMPTY_DIR_ICON: char = '📂';
+pub(crate) const UNICODE_SYMLINK_ICON: char = '🔗';
+
+/// Returns the fallback file icon of the active icon set.
+pub(crate) fn default_file_icon() -> char {
+    match IconSettings::icon_set() {
+        IconSet::NerdFont => DEFAULT_FILE_ICON,
+        IconSet::Unicode => UNICODE_FILE_ICON,
+    }
+}
+
 /// Returns the default file colour from the active theme.
 pub(crate) fn default_file_colour() -> Colour {
     RgbColours::theme().entry_file.colour
@@ -775,6 +933,20 @@ pub(crate) fn icon_for_entry(
     is_dir: bool,
     has_children: bool,
     is_symlink: bool,
+) -> char {
+    match IconSettings::icon_set() {
+        IconSet::NerdFont => nerd_icon_for_entry(name, extension, is_dir, has_children, is_symlink),
+        IconSet::Unicode => unicode_icon_for_entry(extension, is_dir, has_children, is_symlink),
+    }
+}
+
+/// Returns the Nerd Font icon for an entry, regardless of the active icon set.
+fn nerd_icon_for_entry(
+    name: &str,
+    extension: &str,
+    is_dir: bool,
+    has_children: bool,
+    is_symlink: bool,
 ) -> char {
     if is_symlink {
         return SYMLINK_ICON;
@@ -806,6 +978,55 @@ pub(crate) fn icon_for_entry(
     DEFAULT_FILE_ICON
 }
 
+/// Returns the Unicode icon for an entry, by kind and then by extension.
+fn unicode_icon_for_entry(
+    extension: &str,
+    is_dir: bool,
+    has_children: bool,
+    is_symlink: bool,
+) -> char {
+    if is_symlink {
+        return UNICODE_SYMLINK_ICON;
+    }
+
+    if is_dir {
+        return if has_children {
+            UNICODE_DIR_ICON
+        } else {
+            UNICODE_EMPTY_DIR_ICON
+        };
+    }
+
+    UNICODE_EXTENSION_ICONS
+        .get(extension.to_lowercase().as_str())
+        .copied()
+        .unwrap_or(UNICODE_FILE_ICON)
+}
+
+/// Checks whether a file looks like it holds secrets, based on the same
+/// filename and extension knowledge used to pick key and keystore icons.
+///
+/// Covers private keys (`id_rsa`, `*.pem`, `*.key`, `*.p12`), password stores
+/// (`*.kdbx`), and credential files with no dedicated icon (`.env`, `.netrc`, ...).
+///
+/// # Parameters
+/// - `name`: The entry filename.
+/// - `extension`: The file extension (empty string if none).
+///
+/// # Returns
+/// `true` if the name marks the file as sensitive.
+pub(crate) fn is_secret(name: &str, extension: &str) -> bool {
+    let name_lower = name.to_lowercase();
+
+    matches!(
+        nerd_icon_for_entry(name, extension, false, false, false),
+        Icons::PRIVATE_KEY | Icons::KEY | Icons::KEYPASS
+    ) || matches!(
+        name_lower.as_str(),
+        ".env" | ".htpasswd" | ".netrc" | ".pgpass" | "credentials" | "gshadow" | "shadow"
+    ) || name_lower.starts_with(".env.")
+}
+
 /// Looks up the colour for a filesystem entry by name, extension, and type.
 ///
 /// # Parameters
@@ -1100,3 +1321,48 @@ pub(crate) fn colour_for_entry(
 
     default_file_colour()
 }
+
+/// Maps a libmagic description onto an extension whose icon and colour best represent it.
+///
+/// Used as a fallback for extensionless files (`LICENSE-BSD`, shebang scripts,
+/// binary blobs) whose name alone gives no hint of their content. Categories are
+/// checked in order, so specific ones (scripts) win over generic ones (text).
+///
+/// # Parameters
+/// - `description`: The libmagic description of the file.
+///
+/// # Returns
+/// A representative extension, or `None` if the description isn't recognised.
+#[cfg(feature = "magic")]
+pub(crate) fn extension_for_magic(description: &str) -> Option<&'static str> {
+    const CATEGORIES: &[(&str, &str)] = &[
+        ("python script", "py"),
+        ("perl script", "pl"),
+        ("ruby script", "rb"),
+        ("node script", "js"),
+        ("javascript", "js"),
+        ("shell script", "sh"),
+        ("elf ", "elf"),
+        ("pe32", "exe"),
+        ("html document", "html"),
+        ("xml", "xml"),
+        ("json", "json"),
+        ("pdf document", "pdf"),
+        ("svg", "svg"),
+        ("image data", "png"),
+        ("audio", "mp3"),
+        ("video", "mp4"),
+        ("iso media", "mp4"),
+        ("sqlite", "sqlite"),
+        ("archive", "zip"),
+        ("compressed data", "gz"),
+        ("text", "txt"),
+    ];
+
+    let description = description.to_lowercase();
+
+    CATEGORIES
+        .iter()
+        .find(|(keyword, _)| description.contains(keyword))
+        .map(|(_, extension)| *extension)
+}
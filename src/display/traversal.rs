@@ -23,9 +23,13 @@ SOFTWARE.
 */
 
 use crate::cli::args::Args;
+use crate::display::limit::Limit;
 use crate::display::styles::element::ElementStyle;
+use crate::display::tally::Tally;
 use crate::fs::dir::DirReader;
 use crate::fs::entry::Entry;
+use crate::fs::tree::Visited;
+use crate::fs::vfs::RealFs;
 use std::cell::Cell;
 use std::path::Path;
 
@@ -73,23 +77,56 @@ pub(crate) trait RecursiveTraversal {
 
     /// Recursively renders entries with directory titles, descending into subdirectories.
     ///
+    /// Each title carries the number of entries in its section; empty sections
+    /// are left out entirely with `--skip-empty-sections`.
+    ///
     /// Accumulates directory and file counts during traversal so that
     /// the summary can be printed instantly without re-reading the filesystem.
     ///
     /// # Parameters
     /// - `entries`: The entries to display at the current level.
     /// - `title`: Optional path to display as a section header; `None` for the root call.
-    fn render_recursive(&self, entries: &[Entry], title: Option<&Path>) {
-        // Print section title if provided
+    /// - `tally`: Counts every entry rendered, for the `--grand-total` footer.
+    fn render_recursive(&self, entries: &[Entry], title: Option<&Path>, tally: &mut Tally) {
+        let mut visited = Visited::new(&RealFs, self.get_args().root(), self.get_args());
+        self.render_section(entries, title, &mut visited, tally);
+    }
+
+    /// Renders one section of [`RecursiveTraversal::render_recursive`] and the sections below it.
+    ///
+    /// # Parameters
+    /// - `entries`: The entries to display at the current level.
+    /// - `title`: Optional path to display as a section header; `None` for the root call.
+    /// - `visited`: The directories open above this one.
+    /// - `tally`: Counts every entry rendered, for the `--grand-total` footer.
+    fn render_section(
+        &self,
+        entries: &[Entry],
+        title: Option<&Path>,
+        visited: &mut Visited,
+        tally: &mut Tally,
+    ) {
+        let args = self.get_args();
+
+        // Print section title (with its entry count) if provided
         if let Some(path) = title {
-            println!("\n{}:", ElementStyle::path_header(path.display()));
+            // An empty section has nothing to render or descend into
+            if entries.is_empty() && args.skip_empty_sections {
+                return;
+            }
+
+            println!(
+                "\n{}: {}",
+                ElementStyle::path_header(path.display()),
+                ElementStyle::entry_count(entries.len())
+            );
         }
 
         // Render current level using renderer-specific logic
-        let args = self.get_args();
         self.render_level(entries, args);
 
         // Accumulate counts from this level
+        tally.extend(entries);
         for entry in entries {
             if entry.is_dir() {
                 self.dir_count().set(self.dir_count().get() + 1);
@@ -98,12 +135,16 @@ pub(crate) trait RecursiveTraversal {
             }
         }
 
-        // Descend into subdirectories
-        for entry in entries.iter().filter(|e| e.is_dir()) {
+        // Descend into the subdirectories that were shown, steering clear of system directories
+        for entry in Limit::shown(entries, args) {
+            if !visited.enter(&RealFs, entry, args) {
+                continue;
+            }
             let path = entry.path();
             let dir_reader = DirReader::from(path.to_path_buf());
             let children = dir_reader.list(args);
-            self.render_recursive(&children, Some(path));
+            self.render_section(&children, Some(path), visited, tally);
+            visited.leave();
         }
     }
 }
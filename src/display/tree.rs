@@ -26,6 +26,8 @@ use crate::cli::args::Args;
 use crate::display::layout::alignment::Align;
 use crate::display::layout::column;
 use crate::display::layout::column::Column;
+use crate::display::layout::graph::SizeGraph;
+use crate::display::layout::totals::Totals;
 use crate::display::layout::width::Width;
 use crate::display::mode::DisplayMode;
 use crate::display::output::quotes::Quotes;
@@ -34,9 +36,11 @@ use crate::display::styles::element::ElementStyle;
 use crate::display::styles::entry::StyledEntry;
 use crate::display::styles::value::ValueStyle;
 use crate::display::summary::Summary;
+use crate::display::tally::Tally;
 use crate::fs::dir::DirReader;
 use crate::fs::entry::Entry;
-use crate::fs::tree::TreeNode;
+use crate::fs::tree::{TreeNode, Visited};
+use crate::fs::vfs::RealFs;
 use std::cell::Cell;
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -65,13 +69,14 @@ impl DisplayMode for Tree {
     /// 2. Calculates optimal column widths based on all entries
     /// 3. Prints optional column headers
     /// 4. Recursively renders the tree with proper connectors
-    fn print(&self) {
+    fn print(&self, tally: &mut Tally) {
         match &self.data {
             TreeData::Streaming(path) => {
                 // Streaming mode: traverse and print on-demand
                 let mut parent_entry = Entry::from_path(path.clone(), self.args.long);
-                parent_entry.conditional_metadata(&self.args);
-                self.traverse_and_print(parent_entry, &Vec::new());
+                parent_entry.conditional_operand_metadata(&self.args);
+                let mut visited = Visited::new(&RealFs, path, &self.args);
+                self.traverse_and_print(parent_entry, &Vec::new(), &mut visited, tally);
             }
             TreeData::Table(node) => {
                 // Table mode: use pre-built tree with width calculations
@@ -82,14 +87,32 @@ impl DisplayMode for Tree {
                 let add_alignment_space = entries.iter().any(|e| Quotes::is_quotable(e.name()));
 
                 let columns = column::Selector::select(&self.args);
-                let mut width_calc = Width::new();
-                let widths = width_calc.calculate(&entries, &columns, &self.args);
-
-                if self.args.headers {
-                    Column::headers(&widths, &self.args);
+                // Every bar is a share of the root's contents
+                let children: Vec<Entry> = node.children.iter().map(|c| c.entry.clone()).collect();
+                // Like the summary, totals cover the root's descendants only
+                let totals = self
+                    .args
+                    .totals
+                    .then(|| Totals::compute(&entries[1..], &self.args));
+                let widths = SizeGraph::scope(&children, &self.args, || {
+                    let mut width_calc = Width::new();
+                    let mut widths = width_calc.calculate(&entries, &columns, &self.args);
+                    if let Some(totals) = &totals {
+                        width_calc.fit_totals(&mut widths, totals, &columns, &self.args);
+                    }
+
+                    if self.args.headers {
+                        Column::headers(&widths, &self.args);
+                    }
+
+                    Self::add_node(node, &widths, &Vec::new(), &self.args, add_alignment_space);
+                    widths
+                });
+                tally.extend(&entries[1..]);
+
+                if let Some(totals) = totals {
+                    totals.print(&widths, &columns, &self.args);
                 }
-
-                Self::add_node(node, &widths, &Vec::new(), &self.args, add_alignment_space);
             }
         }
 
@@ -170,18 +193,22 @@ impl Tree {
             || args.user
             || args.group
             || args.created
+            || args.changed
             || args.modified
             || args.accessed
             || args.inode
             || args.blocks
             || args.hard_links
             || args.block_size
+            || args.compression
+            || args.size_graph
+            || !args.columns.is_empty()
         {
             return true;
         }
 
         // Table-specific columns
-        #[cfg(all(feature = "magic", not(target_os = "android")))]
+        #[cfg(feature = "magic")]
         if args.magic {
             return true;
         }
@@ -191,7 +218,28 @@ impl Tree {
             return true;
         }
 
-        if args.xattr || args.acl || args.context || args.mountpoint || args.oneline {
+        #[cfg(feature = "apps")]
+        if args.apps {
+            return true;
+        }
+
+        #[cfg(feature = "volumes")]
+        if args.volumes {
+            return true;
+        }
+
+        if args.xattr
+            || args.acl
+            || args.context
+            || args.mountpoint
+            || args.git
+            || args.validate_names
+            || args.notes
+            || args.describe
+            || args.head.is_some()
+            || args.tail.is_some()
+            || args.oneline
+        {
             return true;
         }
 
@@ -206,11 +254,19 @@ impl Tree {
     /// # Parameters
     /// - `entry`: The current entry to render.
     /// - `parents_last`: Boolean flags indicating whether each ancestor is the last child.
-    fn traverse_and_print(&self, entry: Entry, parents_last: &[bool]) {
+    /// - `visited`: The directories open above this one.
+    /// - `tally`: Counts every entry below the root, for the `--grand-total` footer.
+    fn traverse_and_print(
+        &self,
+        entry: Entry,
+        parents_last: &[bool],
+        visited: &mut Visited,
+        tally: &mut Tally,
+    ) {
         let connector = Self::draw_connector(parents_last);
 
         // Get styled entry for name display (no alignment space for tree)
-        let styled_entry = StyledEntry::new(&entry);
+        let styled_entry = StyledEntry::new(&entry, &self.args);
         let entry_view = styled_entry.load(&self.args, false);
 
         // Print: [connector] [name]
@@ -222,6 +278,7 @@ impl Tree {
 
         // Count non-root entries (root has empty parents_last)
         if !parents_last.is_empty() {
+            tally.add(&entry);
             if entry.is_dir() {
                 self.dir_count.set(self.dir_count.get() + 1);
             } else {
@@ -229,8 +286,15 @@ impl Tree {
             }
         }
 
-        // If this is a directory, traverse and print its children
-        if entry.is_dir() {
+        // If this is a directory, traverse and print its children (the root
+        // is always entered; system directories and cycles below it are not)
+        let is_root = parents_last.is_empty();
+        let entered = if is_root {
+            entry.is_dir() || (self.args.dereference && entry.is_dir_like())
+        } else {
+            visited.enter(&RealFs, &entry, &self.args)
+        };
+        if entered {
             let dir_reader = DirReader::from(entry.path().clone());
             let children = dir_reader.list(&self.args);
 
@@ -239,7 +303,11 @@ impl Tree {
                 child_entry.conditional_metadata(&self.args);
                 let mut new_parents = parents_last.to_owned();
                 new_parents.push(i == count - 1);
-                self.traverse_and_print(child_entry, &new_parents);
+                self.traverse_and_print(child_entry, &new_parents, visited, tally);
+            }
+
+            if !is_root {
+                visited.leave();
             }
         }
     }
@@ -301,26 +369,26 @@ impl Tree {
         add_alignment_space: bool,
     ) {
         let columns = column::Selector::select(args);
-        let mut parts = Vec::new();
+        let mut line = String::new();
+        let mut cell = String::new();
 
-        // Build column data
+        // Build column data, each cell followed by its gap
         for column in &columns {
-            let styled_column = ColumnStyle::get(entry, column, args, add_alignment_space);
-            let width = *widths
-                .get(column)
-                .unwrap_or(&Width::measure_ansi_text(&styled_column));
-            let padded = Align::pad(&styled_column, width, column.alignment());
-            parts.push(padded);
+            cell.clear();
+            ColumnStyle::write(&mut cell, entry, column, args, add_alignment_space);
+            let width = widths.get(column).copied().unwrap_or_default();
+            Align::pad_into(&mut line, &cell, width, column.alignment());
+            line.push(' ');
         }
 
         // Get styled entry for name display (no alignment space for tree)
-        let styled_entry = StyledEntry::new(entry);
+        let styled_entry = StyledEntry::new(entry, args);
         let entry_view = styled_entry.load(args, false);
 
         // Print: [table columns] [connector] [name]
         println!(
-            "{} {}{}",
-            parts.join(" "),
+            "{}{}{}",
+            line,
             ElementStyle::tree_connector(connector),
             ValueStyle::name(&entry_view.name, entry_view.colour),
         );
@@ -333,7 +401,7 @@ impl Tree {
     ///
     /// # Returns
     /// A string of box-drawing characters representing the node's position in the tree.
-    fn draw_connector(parents_last: &[bool]) -> String {
+    pub(crate) fn draw_connector(parents_last: &[bool]) -> String {
         let mut connector = String::new();
         let depth = parents_last.len();
         if depth > 0 {
@@ -0,0 +1,107 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Inode usage report for `--inode-usage`.
+
+use crate::cli::args::Args;
+use crate::display::layout::alignment::{Align, Alignment};
+use crate::display::mode::DisplayMode;
+use crate::display::output::formats::format::Format;
+use crate::display::output::formats::number::Number;
+use crate::display::styles::element::ElementStyle;
+use crate::display::styles::entry::StyledEntry;
+use crate::display::tally::Tally;
+use crate::fs::dir::DirReader;
+use crate::fs::entry::Entry;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Reports how many inodes each entry of a directory consumes, recursively.
+///
+/// This is the inode counterpart of a disk-usage listing: when a filesystem
+/// runs out of inodes, the directory with millions of tiny files is rarely the
+/// one using the most bytes. Rows are ordered by inode count, largest first.
+pub(crate) struct InodeUsage {
+    root: PathBuf,
+    entries: Vec<Entry>,
+    args: Args,
+}
+
+impl DisplayMode for InodeUsage {
+    /// Prints one row per entry (inode count and name) followed by the overall total.
+    fn print(&self, tally: &mut Tally) {
+        let number = Number::new(self.args.number_format);
+        tally.extend(&self.entries);
+
+        // One seen-set for the whole report, so a file linked from two rows counts in the first
+        let seen = Mutex::new(HashSet::new());
+        let mut usage: Vec<(u64, &Entry)> = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let count =
+                    DirReader::from(entry.path().to_path_buf()).inode_count(&self.args, &seen);
+                (count, entry)
+            })
+            .collect();
+        usage.sort_by_key(|(count, _)| std::cmp::Reverse(*count));
+
+        let counts: Vec<String> = usage
+            .iter()
+            .map(|(count, _)| number.format(*count).to_string())
+            .collect();
+        let width = counts.iter().map(|count| count.len()).max().unwrap_or(0);
+
+        for ((_, entry), count) in usage.iter().zip(&counts) {
+            let padded = Align::pad(count, width, Alignment::Right);
+            let name = StyledEntry::new(entry, &self.args)
+                .load(&self.args, false)
+                .name;
+            println!("{} {}", ElementStyle::numeric(&padded), name);
+        }
+
+        // The rest is the listed directory itself and what `-a` or filters left out of the rows
+        let rest = DirReader::from(self.root.clone()).inode_count(&self.args, &seen);
+        let total: u64 = rest + usage.iter().map(|(count, _)| count).sum::<u64>();
+        let text = format!("{} inodes in total", number.format(total));
+        println!("\n{}.", ElementStyle::summary(&text));
+    }
+}
+
+impl InodeUsage {
+    /// Creates a new [`InodeUsage`] report.
+    ///
+    /// # Parameters
+    /// - `root`: The listed directory.
+    /// - `entries`: The entries of the listed directory.
+    /// - `args`: Command-line arguments controlling traversal and number formatting.
+    pub(crate) fn new(root: PathBuf, entries: Vec<Entry>, args: Args) -> Self {
+        Self {
+            root,
+            entries,
+            args,
+        }
+    }
+}
@@ -22,6 +22,8 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
+use crate::display::output::errors::{ErrorKind, Errors};
+use crate::fs::network;
 use std::ffi::CString;
 use std::os::unix::ffi::OsStrExt;
 use std::path::Path;
@@ -37,11 +39,14 @@ impl Acl {
     /// - `path`: Path to the file to inspect.
     ///
     /// # Returns
-    /// `"+"` if ACLs are present, `"-"` if none or on error.
+    /// `"+"` if ACLs are present, `"-"` if none, or `"?"` if the filesystem
+    /// (or a kernel without xattr support, or a network mount that is skipped
+    /// or not answering) can't say.
     pub fn check(path: &Path) -> Arc<str> {
-        match Self::has_acl(path) {
+        match network::bounded(path, |path| Ok(Self::has_acl(path))).unwrap_or(Err(())) {
             Ok(true) => "+".into(),
-            _ => "-".into(),
+            Ok(false) => "-".into(),
+            Err(()) => "?".into(),
         }
     }
 
@@ -52,7 +57,8 @@ impl Acl {
     ///
     /// # Returns
     /// `Ok(true)` if the file has a POSIX ACL, `Ok(false)` if not or on
-    /// a libc error, `Err(())` if the path contains a null byte.
+    /// a libc error (reported against the entry), `Err(())` if extended
+    /// attributes are unsupported or the path contains a null byte.
     fn has_acl(path: &Path) -> Result<bool, ()> {
         let path_c = CString::new(path.as_os_str().as_bytes()).map_err(|_| ())?;
 
@@ -60,7 +66,14 @@ impl Acl {
         let size = unsafe { libc::listxattr(path_c.as_ptr(), std::ptr::null_mut(), 0) };
 
         if size < 0 {
-            return Ok(false);
+            let error = std::io::Error::last_os_error();
+            return match error.raw_os_error() {
+                Some(libc::ENOTSUP | libc::ENOSYS) => Err(()),
+                _ => {
+                    Errors::report(ErrorKind::AclFailed, path, &error.to_string());
+                    Ok(false)
+                }
+            };
         }
 
         if size == 0 {
@@ -78,6 +91,8 @@ impl Acl {
         };
 
         if result < 0 {
+            let error = std::io::Error::last_os_error();
+            Errors::report(ErrorKind::AclFailed, path, &error.to_string());
             return Ok(false);
         }
 
@@ -22,34 +22,108 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
+use crate::cli::args::Args;
+#[cfg(feature = "checksum")]
+use crate::cli::flags::HashAlgorithm;
+use crate::cli::flags::{DateFormat, NumberFormat, OwnershipFormat, PermissionFormat, SizeFormat};
+use crate::cli::settings::Settings;
+use crate::fs::clock::ClockSettings;
 use crate::fs::metadata::Metadata;
+use crate::fs::network;
+#[cfg(any(feature = "magic", feature = "checksum"))]
+use crate::fs::store::Store;
 use std::collections::HashMap;
+#[cfg(any(feature = "magic", feature = "checksum"))]
+use std::fmt::Display;
 use std::io;
 use std::path::{Path, PathBuf};
+#[cfg(any(feature = "magic", feature = "checksum"))]
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
 use std::time::SystemTime;
 
-static DIR_SIZE_CACHE: OnceLock<Mutex<HashMap<(PathBuf, bool), u64>>> = OnceLock::new();
-static SIZE_DISPLAY_CACHE: OnceLock<Mutex<HashMap<u64, Arc<str>>>> = OnceLock::new();
-
-#[cfg(all(feature = "magic", not(target_os = "android")))]
-static MAGIC_CACHE: OnceLock<Mutex<HashMap<PathBuf, Arc<str>>>> = OnceLock::new();
-
-static NUMBER_DISPLAY_CACHE: OnceLock<Mutex<HashMap<u64, Arc<str>>>> = OnceLock::new();
-static DATE_DISPLAY_CACHE: OnceLock<Mutex<HashMap<Option<SystemTime>, Arc<str>>>> = OnceLock::new();
-static PERMISSIONS_CACHE: OnceLock<Mutex<HashMap<u32, Arc<str>>>> = OnceLock::new();
-static USER_CACHE: OnceLock<Mutex<HashMap<u32, Arc<str>>>> = OnceLock::new();
-static GROUP_CACHE: OnceLock<Mutex<HashMap<u32, Arc<str>>>> = OnceLock::new();
+/// Modification time and size of a path when a value derived from it was cached.
+///
+/// A cached value is only reused while the path's stamp is unchanged.
+type Stamp = Option<(SystemTime, u64)>;
+
+/// A formatted date's format, whether it was rendered in UTC (`--deterministic`),
+/// and the timestamp.
+type DateKey = (DateFormat, bool, Option<SystemTime>);
+
+/// Cache map of display strings, keyed by the format they were rendered with and the raw value.
+type DisplayMap<K> = Mutex<HashMap<K, Arc<str>>>;
+
+/// Cache map whose values are only valid while their path's [`Stamp`] holds.
+type StampedMap<K, V> = Mutex<HashMap<K, (Stamp, V)>>;
+
+static DIR_SIZE_CACHE: OnceLock<StampedMap<(PathBuf, SizeWalk), u64>> = OnceLock::new();
+static SIZE_DISPLAY_CACHE: OnceLock<DisplayMap<(SizeFormat, u64)>> = OnceLock::new();
+
+#[cfg(feature = "apps")]
+static APP_CACHE: OnceLock<Mutex<HashMap<String, Arc<str>>>> = OnceLock::new();
+
+#[cfg(feature = "magic")]
+static MAGIC_CACHE: OnceLock<StampedMap<PathBuf, Arc<str>>> = OnceLock::new();
+
+/// Filename-to-note map loaded from a directory's `.ce-notes.toml`.
+type NoteMap = HashMap<String, Arc<str>>;
+
+static NOTES_CACHE: OnceLock<Mutex<HashMap<PathBuf, Arc<NoteMap>>>> = OnceLock::new();
+static NUMBER_DISPLAY_CACHE: OnceLock<DisplayMap<(NumberFormat, u64)>> = OnceLock::new();
+static DATE_DISPLAY_CACHE: OnceLock<DisplayMap<DateKey>> = OnceLock::new();
+static PERMISSIONS_CACHE: OnceLock<DisplayMap<(PermissionFormat, u32, bool)>> = OnceLock::new();
+static USER_CACHE: OnceLock<DisplayMap<(OwnershipFormat, u32)>> = OnceLock::new();
+static GROUP_CACHE: OnceLock<DisplayMap<(OwnershipFormat, u32)>> = OnceLock::new();
+
+/// Whether caching is bypassed (`--no-cache`).
+static CACHE_DISABLED: AtomicBool = AtomicBool::new(false);
+
+static DIR_SIZE_COUNTER: Counter = Counter::new("dir size");
+static SIZE_COUNTER: Counter = Counter::new("size");
+#[cfg(feature = "apps")]
+static APP_COUNTER: Counter = Counter::new("apps");
+#[cfg(feature = "magic")]
+static MAGIC_COUNTER: Counter = Counter::new("magic");
+static NOTES_COUNTER: Counter = Counter::new("notes");
+static NUMBER_COUNTER: Counter = Counter::new("number");
+static DATE_COUNTER: Counter = Counter::new("date");
+static PERMISSIONS_COUNTER: Counter = Counter::new("permissions");
+static OWNER_COUNTER: Counter = Counter::new("owner");
+static GROUP_COUNTER: Counter = Counter::new("group");
+static STORE_COUNTER: Counter = Counter::new("persistent");
+
+/// Every cache's counter, in the order `--cache-stats` prints them.
+static COUNTERS: &[&Counter] = &[
+    &DIR_SIZE_COUNTER,
+    &SIZE_COUNTER,
+    #[cfg(feature = "apps")]
+    &APP_COUNTER,
+    #[cfg(feature = "magic")]
+    &MAGIC_COUNTER,
+    &NOTES_COUNTER,
+    &NUMBER_COUNTER,
+    &DATE_COUNTER,
+    &PERMISSIONS_COUNTER,
+    &OWNER_COUNTER,
+    &GROUP_COUNTER,
+    &STORE_COUNTER,
+];
 
 /// Thread-safe caching layer for formatted display strings and computed values.
 ///
 /// Each cache method checks a global `OnceLock<Mutex<HashMap>>` before calling
 /// the provided formatting/compute closure, ensuring each unique key is only
-/// computed once.
-pub(crate) struct Cache;
+/// computed once. Values derived from file contents (directory sizes, magic
+/// descriptions) are also keyed on the path's [`Stamp`], so they are recomputed
+/// once the path changes. Magic descriptions and checksums are kept between
+/// runs by the [`Store`](crate::fs::store::Store) when it is enabled.
+pub struct Cache;
 
 impl Cache {
-    /// Loads metadata for a path. Not cached — delegates directly to [`Metadata::load`].
+    /// Loads metadata for a path. Not cached — delegates to [`network::metadata`],
+    /// which bounds the time spent on network mounts.
     ///
     /// # Parameters
     /// - `path`: The filesystem path to query.
@@ -58,26 +132,32 @@ impl Cache {
     /// # Returns
     /// The loaded [`Metadata`], or an I/O error.
     pub(crate) fn metadata(path: &Path, dereference: bool) -> io::Result<Metadata> {
-        Metadata::load(path, dereference)
+        network::metadata(path, dereference)
     }
 
     /// Returns a cached formatted string for a number, computing it via `format` on a cache miss.
     ///
     /// # Parameters
     /// - `number`: The numeric key to look up or cache.
+    /// - `style`: The number format `format` renders with (part of the key).
     /// - `format`: Closure to produce the display string on a cache miss.
     ///
     /// # Returns
     /// The cached or freshly computed display string.
-    pub(crate) fn number(number: u64, format: impl Fn(u64) -> Arc<str>) -> Arc<str> {
+    pub(crate) fn number(
+        number: u64,
+        style: NumberFormat,
+        format: impl Fn(u64) -> Arc<str>,
+    ) -> Arc<str> {
         let cache = NUMBER_DISPLAY_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+        let key = (style, number);
 
-        if let Some(cached) = Self::getter(cache, &number) {
+        if let Some(cached) = Self::getter(cache, &key, &NUMBER_COUNTER) {
             return cached;
         }
 
         let formatted = format(number);
-        Self::setter(cache, number, formatted.clone());
+        Self::setter(cache, key, formatted.clone());
         formatted
     }
 
@@ -85,19 +165,27 @@ impl Cache {
     ///
     /// # Parameters
     /// - `mode`: The raw Unix permission bits.
+    /// - `style`: The permission format `format` renders with (part of the key).
+    /// - `has_xattr`: Whether the entry has extended attributes, shown as `@` (part of the key).
     /// - `format`: Closure to produce the display string (e.g. `"rwxr-xr-x"`) on a cache miss.
     ///
     /// # Returns
     /// The cached or freshly computed permission string.
-    pub(crate) fn permissions(mode: u32, format: impl Fn(u32) -> Arc<str>) -> Arc<str> {
+    pub(crate) fn permissions(
+        mode: u32,
+        style: PermissionFormat,
+        has_xattr: bool,
+        format: impl Fn(u32) -> Arc<str>,
+    ) -> Arc<str> {
         let cache = PERMISSIONS_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+        let key = (style, mode, has_xattr);
 
-        if let Some(cached) = Self::getter(cache, &mode) {
+        if let Some(cached) = Self::getter(cache, &key, &PERMISSIONS_COUNTER) {
             return cached;
         }
 
         let formatted = format(mode);
-        Self::setter(cache, mode, formatted.clone());
+        Self::setter(cache, key, formatted.clone());
         formatted
     }
 
@@ -105,44 +193,54 @@ impl Cache {
     ///
     /// # Parameters
     /// - `bytes`: The raw byte count.
+    /// - `style`: The size format `format` renders with (part of the key).
     /// - `format`: Closure to produce the display string (e.g. `"4.2 KiB"`) on a cache miss.
     ///
     /// # Returns
     /// The cached or freshly computed size string.
-    pub(crate) fn size(bytes: u64, format: impl Fn(u64) -> Arc<str>) -> Arc<str> {
+    pub(crate) fn size(
+        bytes: u64,
+        style: SizeFormat,
+        format: impl Fn(u64) -> Arc<str>,
+    ) -> Arc<str> {
         let cache = SIZE_DISPLAY_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+        let key = (style, bytes);
 
-        if let Some(cached) = Self::getter(cache, &bytes) {
+        if let Some(cached) = Self::getter(cache, &key, &SIZE_COUNTER) {
             return cached;
         }
 
         let formatted = format(bytes);
-        Self::setter(cache, bytes, formatted.clone());
+        Self::setter(cache, key, formatted.clone());
         formatted
     }
 
     /// Returns a cached recursive directory size, computing it via `compute` on a cache miss.
     ///
+    /// The cached size is reused only while the directory's own mtime and size
+    /// are unchanged. Changes deeper in the tree don't touch that stamp, so
+    /// whoever observes them must call [`Cache::invalidate_subtree`].
+    ///
     /// # Parameters
     /// - `path`: The directory path to compute size for.
-    /// - `include_hidden`: Whether hidden files are included (part of the cache key).
+    /// - `args`: The options the walk counts by (part of the cache key).
     /// - `compute`: Closure to calculate the total size on a cache miss.
     ///
     /// # Returns
     /// The cached or freshly computed total size in bytes.
-    pub(crate) fn dir_size(
-        path: &Path,
-        include_hidden: bool,
-        compute: impl FnOnce() -> u64,
-    ) -> u64 {
+    pub(crate) fn dir_size(path: &Path, args: &Args, compute: impl FnOnce() -> u64) -> u64 {
         let cache = DIR_SIZE_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+        let key = (path.to_path_buf(), SizeWalk::from(args));
+        let stamp = Self::stamp(path);
 
-        if let Some(cached) = Self::getter(cache, &(path.to_path_buf(), include_hidden)) {
+        if let Some(cached) = Self::stamped_getter(cache, &key, &stamp, &DIR_SIZE_COUNTER) {
             return cached;
         }
 
+        // Not persisted: only the directory's own stamp is checked, which edits
+        // deeper down leave alone, so a stored total could be stale for weeks
         let size = compute();
-        Self::setter(cache, (path.to_path_buf(), include_hidden), size);
+        Self::setter(cache, key, (stamp, size));
         size
     }
 
@@ -150,19 +248,25 @@ impl Cache {
     ///
     /// # Parameters
     /// - `uid`: The user ID to resolve.
+    /// - `style`: Whether `lookup` gives a name or the number (part of the key).
     /// - `lookup`: Closure to resolve the UID to a username string on a cache miss.
     ///
     /// # Returns
     /// The cached or freshly resolved username.
-    pub(crate) fn owner(uid: u32, lookup: impl Fn(u32) -> Arc<str>) -> Arc<str> {
+    pub(crate) fn owner(
+        uid: u32,
+        style: OwnershipFormat,
+        lookup: impl Fn(u32) -> Arc<str>,
+    ) -> Arc<str> {
         let cache = USER_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+        let key = (style, uid);
 
-        if let Some(cached) = Self::getter(cache, &uid) {
+        if let Some(cached) = Self::getter(cache, &key, &OWNER_COUNTER) {
             return cached;
         }
 
         let formatted = lookup(uid);
-        Self::setter(cache, uid, formatted.clone());
+        Self::setter(cache, key, formatted.clone());
         formatted
     }
 
@@ -170,78 +274,318 @@ impl Cache {
     ///
     /// # Parameters
     /// - `ts`: The optional timestamp to format. `None` represents an unavailable timestamp.
+    /// - `style`: The date format `format` renders with (part of the key, along with
+    ///   whether dates are in UTC for `--deterministic`).
     /// - `format`: Closure to produce the display string on a cache miss.
     ///
     /// # Returns
     /// The cached or freshly computed date string.
     pub(crate) fn date(
         ts: Option<SystemTime>,
+        style: &DateFormat,
         format: impl Fn(Option<SystemTime>) -> Arc<str>,
     ) -> Arc<str> {
         let cache = DATE_DISPLAY_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+        let key = (style.clone(), ClockSettings::is_deterministic(), ts);
 
-        if let Some(cached) = Self::getter(cache, &ts) {
+        if let Some(cached) = Self::getter(cache, &key, &DATE_COUNTER) {
             return cached;
         }
 
         let formatted = format(ts);
-        Self::setter(cache, ts, formatted.clone());
+        Self::setter(cache, key, formatted.clone());
         formatted
     }
 
     /// Returns a cached libmagic file description, computing it via `compute` on a cache miss.
     ///
+    /// The description is recomputed once the file's mtime or size changes.
+    ///
     /// # Parameters
     /// - `path`: The file path to identify.
     /// - `compute`: Closure to produce the magic description string on a cache miss.
     ///
     /// # Returns
     /// The cached or freshly computed file description.
-    #[cfg(all(feature = "magic", not(target_os = "android")))]
+    #[cfg(feature = "magic")]
     pub(crate) fn magic(path: &PathBuf, compute: impl FnOnce() -> Arc<str>) -> Arc<str> {
         let cache = MAGIC_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+        let stamp = Self::stamp(path);
 
-        if let Some(cached) = Self::getter(cache, path) {
+        if let Some(cached) = Self::stamped_getter(cache, path, &stamp, &MAGIC_COUNTER) {
             return cached;
         }
 
-        let description = compute();
-        Self::setter(cache, path.clone(), description.clone());
+        let description: Arc<str> =
+            Self::persisted("magic", path, stamp, || compute().to_string()).into();
+        Self::setter(cache, path.clone(), (stamp, description.clone()));
         description
     }
 
+    /// Returns a file's checksum, computing it via `compute` unless an earlier run
+    /// stored it in the [`Store`].
+    ///
+    /// Not cached in memory, as each file is hashed once per listing.
+    /// `--checksum-verify` doesn't come through here and always rehashes.
+    ///
+    /// # Parameters
+    /// - `path`: The file to hash.
+    /// - `algorithm`: The hash algorithm (part of the stored key).
+    /// - `compute`: Closure to hash the file on a miss.
+    ///
+    /// # Returns
+    /// The stored or freshly computed digest.
+    #[cfg(feature = "checksum")]
+    pub(crate) fn checksum(
+        path: &Path,
+        algorithm: HashAlgorithm,
+        compute: impl FnOnce() -> Arc<str>,
+    ) -> Arc<str> {
+        let kind = format!("{algorithm:?}").to_ascii_lowercase();
+        Self::persisted(&kind, path, Self::stamp(path), || compute().to_string()).into()
+    }
+
+    /// Returns the cached default application for a MIME type, resolving it via `lookup` on a cache miss.
+    ///
+    /// # Parameters
+    /// - `mime`: The MIME type.
+    /// - `lookup`: Closure to find the application name on a cache miss.
+    ///
+    /// # Returns
+    /// The cached or freshly resolved application name.
+    #[cfg(feature = "apps")]
+    pub(crate) fn app(mime: &str, lookup: impl FnOnce() -> Arc<str>) -> Arc<str> {
+        let cache = APP_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+
+        if let Some(cached) = Self::getter(cache, &mime.to_string(), &APP_COUNTER) {
+            return cached;
+        }
+
+        let app = lookup();
+        Self::setter(cache, mime.to_string(), app.clone());
+        app
+    }
+
+    /// Returns the cached notes for a directory, loading them via `load` on a cache miss.
+    ///
+    /// # Parameters
+    /// - `dir`: The directory whose `.ce-notes.toml` the notes come from.
+    /// - `load`: Closure to read and parse the notes file on a cache miss.
+    ///
+    /// # Returns
+    /// The cached or freshly loaded map of filename to note.
+    pub(crate) fn notes(dir: &Path, load: impl FnOnce() -> NoteMap) -> Arc<NoteMap> {
+        let cache = NOTES_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+
+        if let Some(cached) = Self::getter(cache, &dir.to_path_buf(), &NOTES_COUNTER) {
+            return cached;
+        }
+
+        let notes = Arc::new(load());
+        Self::setter(cache, dir.to_path_buf(), notes.clone());
+        notes
+    }
+
     /// Returns a cached group name for a GID, resolving it via `lookup` on a cache miss.
     ///
     /// # Parameters
     /// - `gid`: The group ID to resolve.
+    /// - `style`: Whether `lookup` gives a name or the number (part of the key).
     /// - `lookup`: Closure to resolve the GID to a group name string on a cache miss.
     ///
     /// # Returns
     /// The cached or freshly resolved group name.
-    pub(crate) fn group(gid: u32, lookup: impl Fn(u32) -> Arc<str>) -> Arc<str> {
+    pub(crate) fn group(
+        gid: u32,
+        style: OwnershipFormat,
+        lookup: impl Fn(u32) -> Arc<str>,
+    ) -> Arc<str> {
         let cache = GROUP_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+        let key = (style, gid);
 
-        if let Some(cached) = Self::getter(cache, &gid) {
+        if let Some(cached) = Self::getter(cache, &key, &GROUP_COUNTER) {
             return cached;
         }
 
         let formatted = lookup(gid);
-        Self::setter(cache, gid, formatted.clone());
+        Self::setter(cache, key, formatted.clone());
         formatted
     }
 
+    /// Drops every cached value that a change under `root` may have made stale.
+    ///
+    /// Called when a watcher reports changes below `root`. Directory sizes are
+    /// dropped for `root`, everything beneath it, and its ancestors (whose
+    /// totals include it); magic descriptions and notes for paths under `root`.
+    ///
+    /// # Parameters
+    /// - `root`: The changed file or directory.
+    pub fn invalidate_subtree(root: &Path) {
+        if let Some(cache) = DIR_SIZE_CACHE.get() {
+            Self::retain(cache, |(path, _)| {
+                !path.starts_with(root) && !root.starts_with(path)
+            });
+        }
+
+        #[cfg(feature = "magic")]
+        if let Some(cache) = MAGIC_CACHE.get() {
+            Self::retain(cache, |path| !path.starts_with(root));
+        }
+
+        // A directory's notes live in a file inside it, so its parent counts too
+        if let Some(cache) = NOTES_CACHE.get() {
+            Self::retain(cache, |dir| {
+                !dir.starts_with(root) && root.parent() != Some(dir.as_path())
+            });
+        }
+    }
+
+    /// Drops the formatted dates, which go stale as time passes when they are
+    /// relative ("5 minutes ago"). Called before each `--watch` redraw.
+    pub fn expire_dates() {
+        if let Some(cache) = DATE_DISPLAY_CACHE.get() {
+            Self::retain(cache, |_| false);
+        }
+    }
+
+    #[cfg(any(feature = "magic", feature = "checksum"))]
+    /// Looks a value up in the [`Store`], computing and storing it on a miss.
+    ///
+    /// The `-` placeholder for unreadable files isn't stored, as a change of
+    /// permissions doesn't touch the mtime that keys the record.
+    ///
+    /// # Parameters
+    /// - `kind`: What the value is (e.g. `"magic"`).
+    /// - `path`: The path the value is computed for.
+    /// - `stamp`: The path's current [`Stamp`]; values of unstattable paths aren't stored.
+    /// - `compute`: Closure to produce the value on a miss.
+    ///
+    /// # Returns
+    /// The stored or freshly computed value.
+    fn persisted<V: Display + FromStr>(
+        kind: &str,
+        path: &Path,
+        stamp: Stamp,
+        compute: impl FnOnce() -> V,
+    ) -> V {
+        let Some(stamp) = stamp.filter(|_| Store::is_enabled()) else {
+            return compute();
+        };
+
+        let stored = Store::get(kind, path, stamp).and_then(|value| value.parse().ok());
+        STORE_COUNTER.record(stored.is_some());
+        if let Some(value) = stored {
+            return value;
+        }
+
+        let value = compute();
+        let text = value.to_string();
+        if text != "-" {
+            Store::put(kind, path, stamp, text.into());
+        }
+        value
+    }
+
+    /// Returns the current modification time and size of a path.
+    ///
+    /// # Parameters
+    /// - `path`: The path to stat (symlinks are followed).
+    ///
+    /// # Returns
+    /// The path's [`Stamp`], or `None` if it cannot be statted.
+    fn stamp(path: &Path) -> Stamp {
+        let metadata = std::fs::metadata(path).ok()?;
+        Some((metadata.modified().ok()?, metadata.len()))
+    }
+
+    /// Removes the entries of a locked cache map whose keys fail `keep`. No-ops on a poisoned lock.
+    ///
+    /// # Parameters
+    /// - `cache`: The mutex-guarded hash map to prune.
+    /// - `keep`: Predicate returning `true` for keys to keep.
+    fn retain<K: Eq + std::hash::Hash, V>(cache: &Mutex<HashMap<K, V>>, keep: impl Fn(&K) -> bool) {
+        if let Ok(mut map) = cache.lock() {
+            map.retain(|key, _| keep(key));
+        }
+    }
+
+    /// Prints hit and miss counts for every cache to stderr (`--cache-stats`).
+    pub fn print_stats() {
+        let width = COUNTERS
+            .iter()
+            .map(|counter| counter.name.len())
+            .max()
+            .unwrap_or_default();
+
+        eprintln!("{:<width$} {:>8} {:>8}", "cache", "hits", "misses");
+        for counter in COUNTERS {
+            eprintln!(
+                "{:<width$} {:>8} {:>8}",
+                counter.name,
+                counter.hits.load(Ordering::Relaxed),
+                counter.misses.load(Ordering::Relaxed)
+            );
+        }
+    }
+
     /// Attempts to retrieve a cloned value from a locked cache map.
     ///
     /// # Parameters
     /// - `cache`: The mutex-guarded hash map to look up.
     /// - `key`: The key to search for.
+    /// - `counter`: The cache's hit/miss counter.
     ///
     /// # Returns
-    /// `Some(value)` on a cache hit, or `None` on a miss or poisoned lock.
+    /// `Some(value)` on a cache hit, or `None` on a miss, a poisoned lock, or with `--no-cache`.
     fn getter<K: Eq + std::hash::Hash, V: Clone>(
         cache: &Mutex<HashMap<K, V>>,
         key: &K,
+        counter: &Counter,
+    ) -> Option<V> {
+        let value = Self::lookup(cache, key);
+        counter.record(value.is_some());
+        value
+    }
+
+    /// Attempts to retrieve a cloned value from a stamped cache map, if its stamp still holds.
+    ///
+    /// # Parameters
+    /// - `cache`: The mutex-guarded stamped map to look up.
+    /// - `key`: The key to search for.
+    /// - `stamp`: The path's current [`Stamp`].
+    /// - `counter`: The cache's hit/miss counter.
+    ///
+    /// # Returns
+    /// `Some(value)` on a fresh hit, or `None` on a miss or stale entry.
+    fn stamped_getter<K: Eq + std::hash::Hash, V: Clone>(
+        cache: &StampedMap<K, V>,
+        key: &K,
+        stamp: &Stamp,
+        counter: &Counter,
     ) -> Option<V> {
+        let value = Self::lookup(cache, key)
+            .filter(|(cached_stamp, _)| cached_stamp == stamp)
+            .map(|(_, value)| value);
+        counter.record(value.is_some());
+        value
+    }
+
+    /// Looks up a cloned value in a locked cache map without counting it.
+    ///
+    /// # Parameters
+    /// - `cache`: The mutex-guarded hash map to look up.
+    /// - `key`: The key to search for.
+    ///
+    /// # Returns
+    /// The cached value, or `None` if absent, poisoned, or caching is disabled.
+    fn lookup<K: Eq + std::hash::Hash, V: Clone>(
+        cache: &Mutex<HashMap<K, V>>,
+        key: &K,
+    ) -> Option<V> {
+        if CacheSettings::is_disabled() {
+            return None;
+        }
+
         if let Ok(map) = cache.lock() {
             map.get(key).cloned()
         } else {
@@ -249,15 +593,172 @@ impl Cache {
         }
     }
 
-    /// Inserts a key-value pair into a locked cache map. Silently no-ops on a poisoned lock.
+    /// Inserts a key-value pair into a locked cache map. Silently no-ops on a poisoned lock
+    /// or with `--no-cache`.
     ///
     /// # Parameters
     /// - `cache`: The mutex-guarded hash map to insert into.
     /// - `key`: The cache key.
     /// - `value`: The value to store.
     fn setter<K: Eq + std::hash::Hash, V>(cache: &Mutex<HashMap<K, V>>, key: K, value: V) {
+        if CacheSettings::is_disabled() {
+            return;
+        }
+
         if let Ok(mut map) = cache.lock() {
             map.insert(key, value);
         }
     }
 }
+
+/// The options a recursive directory size depends on, keying [`Cache::dir_size`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct SizeWalk {
+    all: bool,
+    follow: bool,
+    count_links: bool,
+    one_file_system: bool,
+}
+
+impl SizeWalk {
+    /// Picks the walk options out of the arguments.
+    ///
+    /// # Parameters
+    /// - `args`: The listing's arguments.
+    fn from(args: &Args) -> Self {
+        Self {
+            all: args.all,
+            follow: args.dereference,
+            count_links: args.count_links,
+            one_file_system: args.one_file_system,
+        }
+    }
+}
+
+/// Hit and miss counts for one cache, reported by `--cache-stats`.
+struct Counter {
+    name: &'static str,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl Counter {
+    /// Creates a zeroed counter for the named cache.
+    const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Records one lookup.
+    ///
+    /// # Parameters
+    /// - `hit`: Whether the lookup was served from the cache.
+    fn record(&self, hit: bool) {
+        let count = if hit { &self.hits } else { &self.misses };
+        count.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Global cache switch, set up once from `--no-cache`.
+pub struct CacheSettings;
+
+impl CacheSettings {
+    /// Disables every caching layer when `--no-cache` is given.
+    ///
+    /// # Parameters
+    /// - `no_cache`: Whether to bypass the caches.
+    pub fn setup(no_cache: bool) {
+        CACHE_DISABLED.store(no_cache, Ordering::Relaxed);
+    }
+
+    /// Checks whether caching is bypassed for the current listing.
+    pub(crate) fn is_disabled() -> bool {
+        Settings::get(|settings| settings.no_cache)
+            .unwrap_or_else(|| CACHE_DISABLED.load(Ordering::Relaxed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use std::fs::{self, File};
+    use std::sync::Barrier;
+    use std::thread;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn display_strings_are_keyed_by_format_across_threads() {
+        let barrier = Arc::new(Barrier::new(3));
+        let workers: Vec<_> = [SizeFormat::Bytes, SizeFormat::Binary, SizeFormat::Decimal]
+            .into_iter()
+            .map(|style| {
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    // Far above any size other tests format through the shared cache
+                    for bytes in u64::MAX - 2_000..u64::MAX {
+                        let value = Cache::size(bytes, style, |b| format!("{style:?} {b}").into());
+                        assert_eq!(*value, *format!("{style:?} {bytes}"));
+                    }
+                })
+            })
+            .collect();
+
+        for worker in workers {
+            worker.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn dir_sizes_are_keyed_by_walk_options() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let visible = Args::parse_from(["ce"]);
+        let hidden = Args::parse_from(["ce", "--all"]);
+
+        assert_eq!(Cache::dir_size(temp_dir.path(), &visible, || 1), 1);
+        assert_eq!(Cache::dir_size(temp_dir.path(), &hidden, || 2), 2);
+        assert_eq!(Cache::dir_size(temp_dir.path(), &visible, || 3), 1);
+    }
+
+    #[test]
+    fn changed_mtime_drops_the_cached_value() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let args = Args::parse_from(["ce"]);
+
+        assert_eq!(Cache::dir_size(temp_dir.path(), &args, || 1), 1);
+        assert_eq!(Cache::dir_size(temp_dir.path(), &args, || 2), 1);
+
+        let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        File::open(temp_dir.path())
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+        assert_eq!(Cache::dir_size(temp_dir.path(), &args, || 3), 3);
+        assert_eq!(Cache::dir_size(temp_dir.path(), &args, || 4), 3);
+    }
+
+    #[test]
+    fn invalidate_subtree_keeps_siblings() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let changed = temp_dir.path().join("changed");
+        let sibling = temp_dir.path().join("sibling");
+        fs::create_dir(&changed).unwrap();
+        fs::create_dir(&sibling).unwrap();
+        let args = Args::parse_from(["ce"]);
+
+        assert_eq!(Cache::dir_size(&changed, &args, || 1), 1);
+        assert_eq!(Cache::dir_size(&sibling, &args, || 2), 2);
+        assert_eq!(Cache::dir_size(temp_dir.path(), &args, || 3), 3);
+
+        Cache::invalidate_subtree(&changed);
+
+        assert_eq!(Cache::dir_size(&changed, &args, || 4), 4);
+        assert_eq!(Cache::dir_size(&sibling, &args, || 5), 2);
+        // The parent's total includes the changed directory
+        assert_eq!(Cache::dir_size(temp_dir.path(), &args, || 6), 6);
+    }
+}
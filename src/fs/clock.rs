@@ -0,0 +1,108 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Time source abstraction.
+//!
+//! Relative dates ("3 minutes ago") and `--group-by date` buckets depend on
+//! the current time. Reading it through a [`Clock`] lets tests pin "now" and
+//! check those results deterministically. `--deterministic` pins it for the
+//! whole process via [`ClockSettings`].
+
+use crate::cli::settings::Settings;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime};
+
+/// Whether `--deterministic` pinned "now" to [`ClockSettings::EPOCH`].
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+/// A source of the current time.
+pub trait Clock {
+    /// Returns the current time.
+    fn now(&self) -> SystemTime;
+}
+
+/// The real wall clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock stopped at a fixed instant, for tests.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub SystemTime);
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+}
+
+/// Chooses the process-wide time source.
+pub struct ClockSettings;
+
+impl ClockSettings {
+    /// Seconds since the Unix epoch of the pinned "now" (2025-01-01 00:00:00 UTC).
+    pub const EPOCH_SECS: u64 = 1_735_689_600;
+
+    /// Pins "now" to [`ClockSettings::epoch`] and dates to UTC when `deterministic` is set.
+    ///
+    /// # Parameters
+    /// - `deterministic`: Whether `--deterministic` was passed.
+    pub fn setup(deterministic: bool) {
+        DETERMINISTIC.store(deterministic, Ordering::Relaxed);
+    }
+
+    /// Checks whether output must not depend on the wall clock or time zone.
+    ///
+    /// # Returns
+    /// `true` after `setup(true)`.
+    pub fn is_deterministic() -> bool {
+        Settings::get(|settings| settings.deterministic)
+            .unwrap_or_else(|| DETERMINISTIC.load(Ordering::Relaxed))
+    }
+
+    /// Returns the instant "now" is pinned to in deterministic mode.
+    ///
+    /// # Returns
+    /// 2025-01-01 00:00:00 UTC.
+    pub fn epoch() -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(Self::EPOCH_SECS)
+    }
+
+    /// Returns the clock the rest of the program should read "now" from.
+    ///
+    /// # Returns
+    /// A [`FixedClock`] at [`ClockSettings::epoch`] in deterministic mode, otherwise [`SystemClock`].
+    pub fn clock() -> Box<dyn Clock> {
+        if Self::is_deterministic() {
+            Box::new(FixedClock(Self::epoch()))
+        } else {
+            Box::new(SystemClock)
+        }
+    }
+}
@@ -0,0 +1,126 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+//! Locale-aware name collation for `--sort-key locale` (the `collate` feature).
+//!
+//! Compares names with the Unicode Collation Algorithm as tailored by CLDR for
+//! the locale in `LC_ALL`, `LC_COLLATE` or `LANG`, so `ё` sorts next to `е` in a
+//! Russian listing and accented Latin letters sit beside their base letter
+//! instead of after `z`. Without a usable locale (or with `--deterministic`),
+//! the CLDR root order applies, which still interleaves scripts sensibly.
+
+use crate::cli::settings::Settings;
+use crate::fs::clock::ClockSettings;
+use icu_collator::{Collator, CollatorOptions};
+use icu_locid::Locale;
+use std::cmp::Ordering;
+use std::env;
+use std::sync::OnceLock;
+
+/// The collator for the environment's locale, built on first use.
+static LOCAL: OnceLock<Option<Collator>> = OnceLock::new();
+
+/// The collator for the CLDR root order, used by `--deterministic`.
+static ROOT: OnceLock<Option<Collator>> = OnceLock::new();
+
+/// Compares names by the locale's collation rules.
+pub struct Collation;
+
+impl Collation {
+    /// Compares two names as a reader of the locale's language would order them.
+    ///
+    /// # Parameters
+    /// - `left`: The first name.
+    /// - `right`: The second name.
+    ///
+    /// # Returns
+    /// How `left` orders relative to `right`; names the collation ranks equal
+    /// fall back to their bytes, so distinct names never compare equal.
+    pub fn compare(left: &str, right: &str) -> Ordering {
+        let collated = match Self::collator() {
+            Some(collator) => collator.compare(left, right),
+            None => left.to_lowercase().cmp(&right.to_lowercase()),
+        };
+
+        collated.then_with(|| left.cmp(right))
+    }
+
+    /// Returns the collator for the current listing, building it on first use.
+    fn collator() -> Option<&'static Collator> {
+        let deterministic = Settings::get(|settings| settings.deterministic)
+            .unwrap_or_else(ClockSettings::is_deterministic);
+
+        let (cell, locale) = if deterministic {
+            (&ROOT, None)
+        } else {
+            (&LOCAL, Self::locale())
+        };
+
+        cell.get_or_init(|| {
+            let locale = locale.unwrap_or(Locale::UND);
+            Collator::try_new(&(&locale).into(), CollatorOptions::new()).ok()
+        })
+        .as_ref()
+    }
+
+    /// Reads the collation locale from the environment, as `setlocale` would.
+    ///
+    /// # Returns
+    /// The locale of the first non-empty variable (e.g. `ru_RU.UTF-8` as `ru-RU`),
+    /// or `None` if none is set or it doesn't parse (`C`, `POSIX`).
+    fn locale() -> Option<Locale> {
+        let value = ["LC_ALL", "LC_COLLATE", "LANG"]
+            .iter()
+            .find_map(|name| env::var(name).ok().filter(|value| !value.is_empty()))?;
+
+        // Drop the encoding and modifier: `sr_RS.UTF-8@latin` -> `sr-RS`
+        let tag = value.split(['.', '@']).next()?.replace('_', "-");
+        tag.parse().ok()
+    }
+}
+
+/// A name that sorts by [`Collation::compare`].
+///
+/// Stored in sort keys so cached sorts can compare names by collation without
+/// re-reading them from their entries.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CollationKey(String);
+
+impl CollationKey {
+    /// Creates the key for `name`.
+    pub fn new(name: &str) -> Self {
+        Self(name.to_owned())
+    }
+}
+
+impl Ord for CollationKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Collation::compare(&self.0, &other.0)
+    }
+}
+
+impl PartialOrd for CollationKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
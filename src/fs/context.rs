@@ -24,6 +24,7 @@ SOFTWARE.
 
 //! SELinux security context retrieval.
 
+use crate::fs::network;
 use std::ffi::CString;
 use std::os::unix::ffi::OsStrExt;
 use std::path::Path;
@@ -42,11 +43,12 @@ impl Context {
     ///
     /// # Returns
     /// The SELinux context string (e.g. `"system_u:object_r:usr_t:s0"`),
-    /// or `"?"` if SELinux is not enabled or the context cannot be retrieved.
+    /// or `"?"` if SELinux is not enabled or the context cannot be retrieved
+    /// (including from a network mount that is skipped or not answering).
     pub(crate) fn get(path: &Path) -> Arc<str> {
-        match Self::get_context(path) {
-            Ok(ctx) => ctx.into(),
-            Err(_) => "?".into(),
+        match network::bounded(path, |path| Ok(Self::get_context(path))) {
+            Ok(Ok(ctx)) => ctx.into(),
+            _ => "?".into(),
         }
     }
 
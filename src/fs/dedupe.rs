@@ -0,0 +1,145 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Hard link de-duplication for `--dedupe`.
+//!
+//! Several names can refer to the same file (hard links, or symlinks when
+//! dereferencing). Collapsing them into one entry keeps every name visible
+//! while counting the file, and its size, only once.
+//!
+//! Names in one directory fold into one entry. A file already listed under an
+//! earlier path operand or `-R` section can't take more names once printed, so
+//! inside [`Dedupe::across`] its later names are left out instead.
+
+use crate::fs::entry::Entry;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
+thread_local! {
+    /// The files listed so far by the listing running on this thread, inside [`Dedupe::across`].
+    static LISTED: RefCell<Option<HashSet<(u64, u64)>>> = const { RefCell::new(None) };
+}
+
+/// Separator between the names of a collapsed entry, e.g. `a.txt, b.txt`.
+pub const NAME_SEPARATOR: &str = ", ";
+
+/// Collapses entries that resolve to the same `(device, inode)` pair.
+pub struct Dedupe;
+
+impl Dedupe {
+    /// Runs a listing whose directories share one record of the files they listed.
+    ///
+    /// Scopes nest; the previous record is restored when `run` returns or panics.
+    ///
+    /// # Parameters
+    /// - `run`: The listing, e.g. every path operand with its `-R` sections.
+    ///
+    /// # Returns
+    /// Whatever `run` returns.
+    pub fn across<R>(run: impl FnOnce() -> R) -> R {
+        /// Puts the previous record back, even when unwinding.
+        struct Restore(Option<HashSet<(u64, u64)>>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                LISTED.with(|listed| *listed.borrow_mut() = previous);
+            }
+        }
+
+        let _restore = Restore(LISTED.with(|listed| listed.replace(Some(HashSet::new()))));
+        run()
+    }
+
+    /// Merges entries that refer to the same file into the first one seen.
+    ///
+    /// The surviving entry's name lists every name in listing order. Inside
+    /// [`Dedupe::across`], entries for a file an earlier call already listed are
+    /// dropped. Directories and entries whose metadata cannot be read are left
+    /// as they are.
+    ///
+    /// # Parameters
+    /// - `entries`: The entries to de-duplicate.
+    /// - `dereference`: If `true`, symlinks are identified by their target.
+    ///
+    /// # Returns
+    /// The entries with duplicates folded away, in their original order.
+    pub fn collapse(entries: Vec<Entry>, dereference: bool) -> Vec<Entry> {
+        let mut collapsed: Vec<Entry> = Vec::with_capacity(entries.len());
+        let mut names: Vec<Vec<String>> = Vec::with_capacity(entries.len());
+        let mut seen: HashMap<(u64, u64), usize> = HashMap::new();
+
+        for mut entry in entries {
+            if !entry.is_dir() {
+                entry.unconditional_metadata(dereference);
+
+                let key = entry
+                    .metadata()
+                    .filter(|meta| meta.ino != 0)
+                    .map(|meta| (meta.dev, meta.ino));
+
+                if let Some(key) = key {
+                    if let Some(&index) = seen.get(&key) {
+                        names[index].push(entry.name().to_string());
+                        continue;
+                    }
+                    if Self::was_listed(key) {
+                        continue;
+                    }
+                    seen.insert(key, collapsed.len());
+                }
+            }
+
+            names.push(vec![entry.name().to_string()]);
+            collapsed.push(entry);
+        }
+
+        for (entry, names) in collapsed.iter_mut().zip(names) {
+            if names.len() > 1 {
+                entry.set_name(names.join(NAME_SEPARATOR).into());
+            }
+        }
+
+        LISTED.with(|listed| {
+            if let Some(listed) = listed.borrow_mut().as_mut() {
+                listed.extend(seen.into_keys());
+            }
+        });
+
+        collapsed
+    }
+
+    /// Checks whether an earlier call inside [`Dedupe::across`] listed a file.
+    ///
+    /// # Parameters
+    /// - `key`: The file's `(device, inode)` pair.
+    fn was_listed(key: (u64, u64)) -> bool {
+        LISTED.with(|listed| {
+            listed
+                .borrow()
+                .as_ref()
+                .is_some_and(|listed| listed.contains(&key))
+        })
+    }
+}
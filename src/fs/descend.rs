@@ -0,0 +1,192 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Decides which directories recursive traversal may descend into.
+
+use crate::cli::args::Args;
+use crate::fs::glob::Glob;
+use crate::fs::mountpoint::Mountpoint;
+use std::path::Path;
+
+/// Guards recursive and tree traversal against system directories.
+///
+/// Listing `/` recursively would otherwise wander into `/proc`, `/sys`, and
+/// friends, which are huge, unstable, and never what the user meant. A
+/// directory is skipped if it appears in `--skip-dirs` or if it is the mount
+/// point of a kernel pseudo-filesystem. The root of a listing is never
+/// checked, so `ce -R /proc` still works when asked for explicitly.
+///
+/// `--enter` narrows traversal further: only directories matching one of its
+/// patterns (and everything beneath them) are descended into. This is separate
+/// from `--hide`, so non-matching directories are still listed, just not opened.
+/// `--level` caps how deep below the listing root traversal goes, in the same way.
+pub struct Descend;
+
+impl Descend {
+    /// Checks whether traversal should descend into a directory.
+    ///
+    /// # Parameters
+    /// - `path`: The directory about to be entered.
+    /// - `args`: CLI arguments carrying the `--skip-dirs` list (`none` disables skipping),
+    ///   the `--enter` patterns, and the `--level` limit.
+    ///
+    /// # Returns
+    /// `true` if the directory may be descended into.
+    pub fn allowed(path: &Path, args: &Args) -> bool {
+        let reason = if !Self::within_level(path, args) {
+            "--level"
+        } else if !Self::enters(path, args) {
+            "--enter"
+        } else if Self::skipped(path, args) {
+            "--skip-dirs"
+        } else {
+            return true;
+        };
+
+        crate::debug!("not descending", dir = path.display(), reason = reason);
+        false
+    }
+
+    /// Checks a directory against the `--level` limit.
+    ///
+    /// The root's own entries are level 1, so a directory `n` components below the
+    /// root holds level `n + 1` and may only be entered while that is within the limit.
+    ///
+    /// # Parameters
+    /// - `path`: The directory about to be entered.
+    /// - `args`: CLI arguments carrying the `--level` limit and the listing root.
+    ///
+    /// # Returns
+    /// `true` if no limit is set or the directory's entries are within it.
+    fn within_level(path: &Path, args: &Args) -> bool {
+        let Some(level) = args.level else {
+            return true;
+        };
+
+        match path.strip_prefix(args.root()) {
+            Ok(relative) => relative.components().count() < level as usize,
+            Err(_) => true,
+        }
+    }
+
+    /// Checks whether a directory is listed in `--skip-dirs` or is a pseudo-filesystem mount.
+    ///
+    /// Absolute entries name one directory. A bare name such as `target`
+    /// matches a directory of that name at any depth, and other relative
+    /// entries (`build/cache`) are resolved against the listing root.
+    ///
+    /// # Parameters
+    /// - `path`: The directory about to be entered.
+    /// - `args`: CLI arguments carrying the `--skip-dirs` list and the listing root.
+    ///
+    /// # Returns
+    /// `true` if the directory must be skipped.
+    fn skipped(path: &Path, args: &Args) -> bool {
+        let skip_dirs: Vec<&Path> = args
+            .skip_dirs
+            .iter()
+            .map(|dir| dir.as_path())
+            .filter(|dir| !dir.as_os_str().is_empty() && dir.as_os_str() != "none")
+            .collect();
+
+        if skip_dirs.is_empty() {
+            return false;
+        }
+
+        let Ok(absolute_path) = std::path::absolute(path) else {
+            return false;
+        };
+
+        let listed = skip_dirs.iter().any(|dir| {
+            if dir.is_absolute() {
+                *dir == absolute_path
+            } else if dir.components().count() == 1 {
+                path.file_name() == Some(dir.as_os_str())
+            } else {
+                std::path::absolute(args.root().join(dir))
+                    .is_ok_and(|resolved| resolved == absolute_path)
+            }
+        });
+
+        listed || Mountpoint::is_pseudo_mount(&absolute_path)
+    }
+
+    /// Checks a directory against the `--enter` patterns.
+    ///
+    /// Patterns without a `/` match any single directory name (`src*`); patterns
+    /// with one match the path relative to the listing root (`crates/*`), and the
+    /// directories leading up to such a pattern are entered so it can be reached.
+    /// Once a directory matches, its whole subtree is open.
+    ///
+    /// # Parameters
+    /// - `path`: The directory about to be entered.
+    /// - `args`: CLI arguments carrying the `--enter` patterns and the listing root.
+    ///
+    /// # Returns
+    /// `true` if no patterns are set or the directory is covered by one.
+    fn enters(path: &Path, args: &Args) -> bool {
+        if args.enter.is_empty() {
+            return true;
+        }
+
+        let Ok(relative) = path.strip_prefix(args.root()) else {
+            return true;
+        };
+
+        let components: Vec<String> = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().into_owned())
+            .collect();
+
+        args.enter.iter().any(|pattern| {
+            if pattern.contains('/') {
+                let segments: Vec<&str> = pattern.trim_matches('/').split('/').collect();
+                // Inside a matched subtree, or on the way down to one
+                (1..=components.len())
+                    .any(|depth| Self::matches(&segments.join("/"), &components[..depth].join("/")))
+                    || (components.len() < segments.len()
+                        && components
+                            .iter()
+                            .zip(&segments)
+                            .all(|(component, segment)| Self::matches(segment, component)))
+            } else {
+                components
+                    .iter()
+                    .any(|component| Self::matches(pattern, component))
+            }
+        })
+    }
+
+    /// Matches text against a glob pattern, treating invalid patterns as non-matching.
+    ///
+    /// # Parameters
+    /// - `pattern`: The glob pattern.
+    /// - `text`: The directory name or relative path to test.
+    ///
+    /// # Returns
+    /// `true` if the pattern compiles and matches.
+    fn matches(pattern: &str, text: &str) -> bool {
+        Glob::new(pattern).is_ok_and(|glob| glob.is_match(text))
+    }
+}
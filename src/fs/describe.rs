@@ -0,0 +1,126 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! One-line summaries of systemd units and desktop entries, for `--describe`.
+//!
+//! A unit file's `Description=` (from its `[Unit]` section) and a desktop
+//! entry's `Name=` (from `[Desktop Entry]`) say what the file is for, so
+//! listings of `/etc/systemd/system` or `~/.local/share/applications` read
+//! as an inventory. Only the start of each file is read.
+
+use crate::fs::metadata;
+use crate::fs::notes::Notes;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::sync::Arc;
+
+/// How many leading bytes are searched for the summary.
+const MAX_READ: u64 = 16 * 1024;
+
+/// systemd unit types, by extension.
+const UNIT_EXTENSIONS: &[&str] = &[
+    "service",
+    "socket",
+    "timer",
+    "target",
+    "mount",
+    "automount",
+    "path",
+    "slice",
+    "scope",
+    "swap",
+    "device",
+];
+
+/// Reads summaries from unit files and desktop entries.
+pub struct Describe;
+
+impl Describe {
+    /// Returns the summary of a unit file or desktop entry.
+    ///
+    /// # Parameters
+    /// - `path`: The file to summarise.
+    ///
+    /// # Returns
+    /// The (trimmed, width-capped) summary, or `"-"` for other files and
+    /// files without one. Control characters are left for
+    /// [`Escape`](crate::display::output::escape::Escape) to make visible.
+    pub fn get(path: &Path) -> Arc<str> {
+        let Some(extension) = path.extension().and_then(|ext| ext.to_str()) else {
+            return "-".into();
+        };
+        let (section, key) = if extension == "desktop" {
+            ("[Desktop Entry]", "Name")
+        } else if UNIT_EXTENSIONS.contains(&extension) {
+            ("[Unit]", "Description")
+        } else {
+            return "-".into();
+        };
+
+        // Masked units are symlinks to /dev/null, which has nothing to say
+        if !metadata::has_readable_contents(path) {
+            return "-".into();
+        }
+
+        let mut head = Vec::new();
+        if File::open(path)
+            .and_then(|file| file.take(MAX_READ).read_to_end(&mut head))
+            .is_err()
+        {
+            return "-".into();
+        }
+
+        match Self::find(&String::from_utf8_lossy(&head), section, key) {
+            Some(summary) if !summary.is_empty() => summary.into(),
+            _ => "-".into(),
+        }
+    }
+
+    /// Finds a key's value in an INI-style section.
+    ///
+    /// # Parameters
+    /// - `content`: The file's text.
+    /// - `section`: The section header, e.g. `[Unit]`.
+    /// - `key`: The key to look up, e.g. `Description`.
+    ///
+    /// # Returns
+    /// The first value for `key` in `section`, trimmed and width-capped for a table cell.
+    pub fn find(content: &str, section: &str, key: &str) -> Option<String> {
+        let mut in_section = false;
+
+        for line in content.lines().map(str::trim) {
+            if line.starts_with('[') {
+                in_section = line == section;
+            } else if in_section
+                && let Some((name, value)) = line.split_once('=')
+                && name.trim() == key
+            {
+                return Some(Notes::truncate(value.trim()));
+            }
+        }
+
+        None
+    }
+}
@@ -23,11 +23,86 @@ SOFTWARE.
 */
 
 use crate::cli::args::Args;
-use crate::cli::flags::SortBy;
+use crate::cli::flags::{SizeFilter, SortBy, SortKey};
+use crate::cli::settings::Settings;
+use crate::display::layout::row::Row;
+use crate::display::output::errors::{ErrorKind, Errors};
+use crate::fs::cache::Cache;
+use crate::fs::clock::ClockSettings;
+#[cfg(feature = "collate")]
+use crate::fs::collate::CollationKey;
+use crate::fs::dedupe::Dedupe;
+use crate::fs::descend::Descend;
 use crate::fs::entry::Entry;
+use crate::fs::gitignore::GitIgnore;
 use crate::fs::glob::Glob;
+use crate::fs::highlight::Highlight;
+use crate::fs::metadata;
+use crate::fs::vfs::{DirItem, Fs, RealFs};
+use rayon::prelude::*;
+use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Directories with fewer entries than this are read on the calling thread,
+/// where spreading the work would cost more than it saves.
+const PARALLEL_THRESHOLD: usize = 512;
+
+/// The `--size-filter`, `--newer` and `--older` bounds, with times resolved against the clock.
+struct MetadataFilter {
+    size: Option<SizeFilter>,
+    newer: Option<i64>,
+    older: Option<i64>,
+}
+
+impl MetadataFilter {
+    /// Resolves the metadata filters requested on the command line.
+    ///
+    /// # Parameters
+    /// - `args`: CLI arguments holding the filter expressions.
+    ///
+    /// # Returns
+    /// The filter, or `None` if no metadata filter was given.
+    fn from(args: &Args) -> Option<Self> {
+        if args.size_filter.is_none() && args.newer.is_none() && args.older.is_none() {
+            return None;
+        }
+
+        let clock = ClockSettings::clock();
+        Some(Self {
+            size: args.size_filter,
+            newer: args.newer.map(|time| time.cutoff(clock.as_ref())),
+            older: args.older.map(|time| time.cutoff(clock.as_ref())),
+        })
+    }
+
+    /// Checks an entry's size and modification time against the bounds.
+    ///
+    /// Directories always pass, so recursive and tree listings still reach
+    /// the matching files inside them.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry to check, with its metadata loaded.
+    ///
+    /// # Returns
+    /// `true` if the entry should be listed.
+    fn admits(&self, entry: &Entry) -> bool {
+        if entry.is_dir() {
+            return true;
+        }
+
+        let Some(metadata) = entry.metadata() else {
+            return false;
+        };
+
+        self.size.is_none_or(|size| size.matches(metadata.size))
+            && self.newer.is_none_or(|cutoff| metadata.mtime > cutoff)
+            && self.older.is_none_or(|cutoff| metadata.mtime < cutoff)
+    }
+}
 
 /// Reads and lists directory contents, applying filtering, hiding, and sorting
 /// based on CLI arguments.
@@ -60,55 +135,236 @@ impl DirReader {
     /// # Returns
     /// A `Vec<Entry>` of filtered and sorted directory entries.
     pub fn list(&self, args: &Args) -> Vec<Entry> {
+        self.list_in(&RealFs, args)
+    }
+
+    /// Lists directory entries like [`DirReader::list`], reading through the given filesystem.
+    ///
+    /// # Parameters
+    /// - `filesystem`: The filesystem to read (e.g. a [`MockFs`](crate::fs::vfs::MockFs) in tests).
+    /// - `args`: CLI arguments controlling visibility, hide patterns, and sort order.
+    ///
+    /// # Returns
+    /// A `Vec<Entry>` of filtered and sorted directory entries.
+    pub fn list_in(&self, filesystem: &dyn Fs, args: &Args) -> Vec<Entry> {
         let mut entries: Vec<Entry> = Vec::new();
 
+        if filesystem.is_dir(&self.path) {
+            let filter = MetadataFilter::from(args);
+            let needs_metadata = Args::is_args_requesting_metadata(args) || filter.is_some();
+
+            // readdir already reports the type, so no stat is needed to build the entry
+            let build = |item: DirItem| {
+                let mut entry = Entry::create(item.path, item.is_dir, item.is_symlink, args.long);
+
+                if !Self::admits(&entry, args, filesystem) {
+                    return None;
+                }
+
+                if needs_metadata {
+                    entry.metadata_from(filesystem, args.dereference);
+                }
+
+                if filter.as_ref().is_some_and(|filter| !filter.admits(&entry)) {
+                    return None;
+                }
+
+                Some(entry)
+            };
+
+            // The stats dominate in big directories, so spread them over threads there.
+            // Collecting keeps readdir order either way; sorting happens afterwards.
+            let items = filesystem.read_dir(&self.path).unwrap_or_else(|error| {
+                Errors::report(
+                    ErrorKind::UnreadableDirectory,
+                    &self.path,
+                    &error.to_string(),
+                );
+                Vec::new()
+            });
+            crate::debug!(
+                "read directory",
+                dir = self.path.display(),
+                items = items.len()
+            );
+            entries = if items.len() >= PARALLEL_THRESHOLD {
+                // Workers don't inherit this thread's settings scope, so hand it over
+                let settings = Settings::scoped();
+                items
+                    .into_par_iter()
+                    .filter_map(|item| Settings::within(settings.clone(), || build(item)))
+                    .collect()
+            } else {
+                items.into_iter().filter_map(build).collect()
+            };
+
+            if !args.hide.is_empty() {
+                self.hide_entries(&mut entries, &args.hide);
+            }
+
+            if args.git_ignore {
+                self.ignore_entries(&mut entries);
+            }
+
+            // Fold hard links to the same file into one entry listing every name
+            if args.dedupe {
+                entries = Dedupe::collapse(entries, args.dereference);
+            }
+        } else if let Ok(metadata) = filesystem.metadata(&self.path, false) {
+            // lstat() handles all file types including broken symlinks
+            let is_symlink = metadata.mode & libc::S_IFMT == libc::S_IFLNK;
+            let is_dir = !is_symlink && filesystem.is_dir(&self.path);
+            let mut entry = Entry::create(self.path.to_path_buf(), is_dir, is_symlink, args.long);
+
+            if Args::is_args_requesting_metadata(args) {
+                entry.metadata_from(filesystem, args.dereferences(true));
+            }
+
+            entries.push(entry);
+        }
+
+        Self::sort(&mut entries, args, filesystem);
+        entries
+    }
+
+    /// Lists file operands from the command line as one sorted group, the way `ls`
+    /// lists the files it was given before any directories.
+    ///
+    /// Each entry keeps the path as it was typed (e.g. `src/main.rs`) for its name.
+    ///
+    /// # Parameters
+    /// - `paths`: The non-directory paths given on the command line.
+    /// - `args`: CLI arguments controlling metadata loading and sort order.
+    ///
+    /// # Returns
+    /// One entry per path that could be statted, sorted like a directory listing.
+    pub fn list_operands(paths: &[PathBuf], args: &Args) -> Vec<Entry> {
+        let mut entries: Vec<Entry> = Vec::new();
+
+        for path in paths {
+            for mut entry in Self::from(path.clone()).list(args) {
+                // Swap the bare file name for the operand, keeping any ` -> target` suffix
+                let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+                let suffix = entry.name().strip_prefix(file_name.as_ref()).unwrap_or("");
+                let name = format!("{}{}", path.display(), suffix);
+                entry.set_name(name.into());
+                entries.push(entry);
+            }
+        }
+
+        // Operands naming the same file fold together like names in a directory
+        if args.dedupe {
+            entries = Dedupe::collapse(entries, args.dereference);
+        }
+
+        Self::sort(&mut entries, args, &RealFs);
+        entries
+    }
+
+    /// Streams directory entries to a callback as they are read, without sorting.
+    ///
+    /// Each entry is filtered and has its metadata loaded before it is handed
+    /// over, so the caller can print it straight away (`--long-stream`). Entries
+    /// arrive in `readdir` order.
+    ///
+    /// If the path is a single file (or broken symlink), streams just that entry.
+    ///
+    /// # Parameters
+    /// - `args`: CLI arguments controlling visibility, hide patterns, and metadata.
+    /// - `visit`: Called once per admitted entry.
+    pub fn stream(&self, args: &Args, mut visit: impl FnMut(Entry)) {
         if self.path.is_dir() {
+            let globs = Self::hide_globs(&args.hide);
+            let filter = MetadataFilter::from(args);
+            let ignore = if args.git_ignore {
+                GitIgnore::for_dir(&self.path)
+            } else {
+                GitIgnore::default()
+            };
+
             for mut entry in self
                 .path
                 .read_dir()
                 .into_iter()
                 .flatten()
-                .filter_map(|dir_entry| {
-                    let e = dir_entry.ok()?;
-                    // Use from_dir_entry to leverage d_type and avoid stat calls
-                    Some(Entry::from_dir_entry(&e, args.long))
-                })
+                .filter_map(|dir_entry| Some(Entry::from_dir_entry(&dir_entry.ok()?, args.long)))
             {
-                // Hidden files (use entry.name())
-                if !args.all && entry.name().starts_with('.') {
-                    continue;
-                }
-
-                // Explicit type filters (use is_dir_like for symlinks to directories)
-                if args.dirs && !entry.is_dir_like() {
-                    continue;
-                }
-                if args.files && entry.is_dir_like() {
-                    continue;
-                }
-
-                // Omit empty entries (childless directories and 0-byte files)
-                if args.prune && entry.is_empty() {
+                if !Self::admits(&entry, args, &RealFs)
+                    || globs.iter().any(|g| g.is_match(entry.name()))
+                    || Self::is_ignored(&entry, &ignore)
+                {
                     continue;
                 }
 
                 entry.conditional_metadata(args);
+                if let Some(filter) = &filter {
+                    entry.unconditional_metadata(args.dereference);
+                    if !filter.admits(&entry) {
+                        continue;
+                    }
+                }
 
-                entries.push(entry);
-            }
-
-            if !args.hide.is_empty() {
-                self.hide_entries(&mut entries, &args.hide, args.verbose);
+                visit(entry);
             }
         } else if fs::symlink_metadata(&self.path).is_ok() {
-            // lstat() handles all file types including broken symlinks
             let mut entry = Entry::from_path(self.path.to_path_buf(), args.long);
-            entry.conditional_metadata(args);
-            entries.push(entry);
+            entry.conditional_operand_metadata(args);
+            visit(entry);
         }
+    }
 
-        self.sort(&mut entries, args);
-        entries
+    /// Checks an entry against the visibility and type filters.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry to check.
+    /// - `args`: CLI arguments controlling hidden entries, dirs/files only, and pruning.
+    /// - `filesystem`: The filesystem used to check emptiness for `--prune`.
+    ///
+    /// # Returns
+    /// `true` if the entry should be listed.
+    fn admits(entry: &Entry, args: &Args, filesystem: &dyn Fs) -> bool {
+        // The file a `--context-listing` is about always shows
+        if Highlight::contains(entry.path()) {
+            return true;
+        }
+
+        // Hidden files (dot-prefixed or flagged hidden by the platform)
+        if !args.all && entry.is_hidden() {
+            return false;
+        }
+
+        // Explicit type filters (use is_dir_like for symlinks to directories)
+        if args.dirs && !entry.is_dir_like() {
+            return false;
+        }
+        if args.files && entry.is_dir_like() {
+            return false;
+        }
+
+        // Omit empty entries (childless directories and 0-byte files)
+        !(args.prune && Self::is_empty_in(entry, filesystem))
+    }
+
+    /// Checks whether an entry is a childless directory or a 0-byte file.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry to check.
+    /// - `filesystem`: The filesystem to query.
+    ///
+    /// # Returns
+    /// `true` if the entry is empty; symlinks never are.
+    fn is_empty_in(entry: &Entry, filesystem: &dyn Fs) -> bool {
+        if entry.is_dir() {
+            filesystem
+                .read_dir(entry.path())
+                .map_or(true, |children| children.is_empty())
+        } else if entry.is_file() {
+            filesystem
+                .metadata(entry.path(), false)
+                .is_ok_and(|metadata| metadata.size == 0)
+        } else {
+            false
+        }
     }
 
     /// Counts the entries directly contained in this directory (non-recursive).
@@ -119,31 +375,53 @@ impl DirReader {
     /// # Returns
     /// The number of immediate children, or `0` if the path is not a readable directory.
     pub fn item_count(&self, include_hidden: bool) -> usize {
-        let Ok(entries) = fs::read_dir(&self.path) else {
+        self.item_count_in(&RealFs, include_hidden)
+    }
+
+    /// Counts the immediate children of this directory, reading through the given filesystem.
+    ///
+    /// # Parameters
+    /// - `filesystem`: The filesystem to read.
+    /// - `include_hidden`: Whether to count hidden (dot-prefixed) entries.
+    ///
+    /// # Returns
+    /// The number of immediate children, or `0` if the path is not a readable directory.
+    fn item_count_in(&self, filesystem: &dyn Fs, include_hidden: bool) -> usize {
+        let Ok(items) = filesystem.read_dir(&self.path) else {
             return 0;
         };
 
-        entries
-            .filter_map(Result::ok)
-            .filter(|entry| {
-                include_hidden
-                    || !entry
-                        .file_name()
-                        .to_string_lossy()
-                        .starts_with('.')
+        items
+            .iter()
+            .filter(|item| {
+                let name = item.path.file_name().unwrap_or_default().to_string_lossy();
+                include_hidden || !metadata::is_hidden(&name, &item.path)
             })
             .count()
     }
 
     /// Computes the total size (in bytes) of all files under this directory, recursively.
     ///
+    /// Like `du`, a file with several hard links is counted once, unless
+    /// `--count-links` asks for every link to be counted. Symlinks are not
+    /// followed unless `--dereference` is set, in which case each directory is
+    /// entered at most once so link cycles terminate. With `--one-file-system`,
+    /// directories on another device than the listing root count as empty.
+    ///
     /// # Parameters
-    /// - `include_hidden`: Whether to count hidden (dot-prefixed) files and directories.
+    /// - `args`: CLI arguments controlling hidden files (`all`), hard link
+    ///   counting, symlink following, and staying on one filesystem.
     ///
     /// # Returns
     /// The cumulative file size in bytes, or `0` if the path is not a directory.
-    pub fn dir_size(&self, include_hidden: bool) -> u64 {
-        fn dir_size(path: &PathBuf, include_hidden: bool) -> u64 {
+    pub fn dir_size(&self, args: &Args) -> u64 {
+        fn dir_size(
+            path: &PathBuf,
+            args: &Args,
+            device: Option<u64>,
+            seen: &mut HashSet<(u64, u64)>,
+            entered: &mut HashSet<(u64, u64)>,
+        ) -> u64 {
             let mut size = 0;
 
             if let Ok(entries) = fs::read_dir(path) {
@@ -151,19 +429,36 @@ impl DirReader {
                     let path = entry.path();
 
                     // Skip hidden files if not including them
-                    if !include_hidden
+                    if !args.all
                         && let Some(name) = path.file_name()
-                        && name.to_string_lossy().starts_with('.')
+                        && metadata::is_hidden(&name.to_string_lossy(), &path)
                     {
                         continue;
                     }
 
-                    if let Ok(metadata) = entry.metadata() {
+                    // lstat, so a symlink to a huge external file doesn't inflate the total
+                    let metadata = match fs::symlink_metadata(&path) {
+                        Ok(metadata) if metadata.is_symlink() && args.dereference => {
+                            fs::metadata(&path)
+                        }
+                        other => other,
+                    };
+
+                    if let Ok(metadata) = metadata {
                         if metadata.is_file() {
-                            size += metadata.len();
-                        } else if metadata.is_dir() {
-                            // Recursive call for subdirectory
-                            size += dir_size(&path, include_hidden);
+                            // Further links (or followed symlinks) to an already counted file add nothing
+                            if args.count_links
+                                || (metadata.nlink() == 1 && !args.dereference)
+                                || seen.insert((metadata.dev(), metadata.ino()))
+                            {
+                                size += metadata.len();
+                            }
+                        } else if metadata.is_dir()
+                            && device.is_none_or(|device| metadata.dev() == device)
+                            && entered.insert((metadata.dev(), metadata.ino()))
+                        {
+                            // Recursive call for subdirectory (each directory once, against link cycles)
+                            size += dir_size(&path, args, device, seen, entered);
                         }
                     }
                 }
@@ -172,110 +467,493 @@ impl DirReader {
             size
         }
 
-        if !self.path.is_dir() {
-            0
+        let device = if args.one_file_system {
+            match fs::metadata(args.root()) {
+                Ok(root) => Some(root.dev()),
+                Err(_) => return 0,
+            }
         } else {
-            dir_size(&self.path, include_hidden)
+            None
+        };
+
+        match fs::metadata(&self.path) {
+            Ok(metadata) if metadata.is_dir() => {
+                if device.is_some_and(|device| metadata.dev() != device) {
+                    return 0;
+                }
+                let mut entered = HashSet::from([(metadata.dev(), metadata.ino())]);
+                dir_size(&self.path, args, device, &mut HashSet::new(), &mut entered)
+            }
+            _ => 0,
         }
     }
 
+    /// Counts the inodes used by this directory and everything beneath it.
+    ///
+    /// Every inode counts, hidden or not, and symlinks are not followed.
+    /// Recursion stays on the directory's own filesystem (inodes are a
+    /// per-filesystem resource) and fans subdirectories out over rayon.
+    /// Directories that traversal skips (see [`Descend`]) count as a single
+    /// inode.
+    ///
+    /// # Parameters
+    /// - `args`: CLI arguments controlling skipped directories.
+    /// - `seen`: The hard-linked inodes already counted; share one between
+    ///   calls so a file linked from several of them counts once overall.
+    ///
+    /// # Returns
+    /// The number of inodes not counted before, or `0` if the path cannot be read.
+    pub fn inode_count(&self, args: &Args, seen: &Mutex<HashSet<(u64, u64)>>) -> u64 {
+        fn inode_count(
+            path: &Path,
+            device: u64,
+            args: &Args,
+            seen: &Mutex<HashSet<(u64, u64)>>,
+            settings: &Option<Arc<Settings>>,
+        ) -> u64 {
+            let Ok(metadata) = fs::symlink_metadata(path) else {
+                return 0;
+            };
+
+            // Only an inode with several links (or a directory) can be reached twice
+            if metadata.nlink() > 1
+                && !seen
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .insert((metadata.dev(), metadata.ino()))
+            {
+                return 0;
+            }
+
+            if !(metadata.is_dir() && metadata.dev() == device && Descend::allowed(path, args)) {
+                return 1;
+            }
+
+            let children: Vec<PathBuf> = match fs::read_dir(path) {
+                Ok(entries) => entries
+                    .filter_map(Result::ok)
+                    .map(|entry| entry.path())
+                    .collect(),
+                Err(_) => return 1,
+            };
+
+            // Workers don't inherit this thread's settings scope, so hand it over
+            1 + children
+                .par_iter()
+                .map(|child| {
+                    Settings::within(settings.clone(), || {
+                        inode_count(child, device, args, seen, settings)
+                    })
+                })
+                .sum::<u64>()
+        }
+
+        let Ok(metadata) = fs::symlink_metadata(&self.path) else {
+            return 0;
+        };
+
+        inode_count(&self.path, metadata.dev(), args, seen, &Settings::scoped())
+    }
+
     /// Removes entries whose names match any of the given glob patterns.
     ///
     /// # Parameters
     /// - `entries`: The entry list to filter in place.
     /// - `hide_patterns`: Glob patterns to match against entry names (e.g. `"*.bak"`, `"._*"`).
-    /// - `verbose`: If `true`, logs invalid patterns and reports when nothing matched.
     ///
     /// # Returns
     /// The number of entries removed.
-    fn hide_entries(
-        &self,
-        entries: &mut Vec<Entry>,
-        hide_patterns: &[String],
-        verbose: bool,
-    ) -> usize {
+    fn hide_entries(&self, entries: &mut Vec<Entry>, hide_patterns: &[String]) -> usize {
         if hide_patterns.is_empty() {
             return 0;
         }
 
-        // Compile glob patterns
-        let globs: Vec<_> = hide_patterns
+        let globs = Self::hide_globs(hide_patterns);
+
+        let original_len = entries.len();
+
+        // Retain entries that don't match any hide pattern
+        entries.retain(|entry| !globs.iter().any(|g| g.is_match(entry.name())));
+
+        let removed = original_len - entries.len();
+
+        crate::debug!(
+            "hid entries",
+            dir = self.path.display(),
+            patterns = hide_patterns.join(","),
+            removed = removed,
+        );
+
+        removed
+    }
+
+    /// Removes entries matched by the `.gitignore` rules in effect for this directory.
+    ///
+    /// Ignored directories are dropped with their contents, so recursive and
+    /// tree listings never descend into them.
+    ///
+    /// # Parameters
+    /// - `entries`: The entry list to filter in place.
+    fn ignore_entries(&self, entries: &mut Vec<Entry>) {
+        let ignore = GitIgnore::for_dir(&self.path);
+        entries.retain(|entry| !Self::is_ignored(entry, &ignore));
+    }
+
+    /// Checks an entry against a directory's `.gitignore` rules.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry to check.
+    /// - `ignore`: The rules in effect for the entry's directory.
+    ///
+    /// # Returns
+    /// `true` if the entry is ignored.
+    fn is_ignored(entry: &Entry, ignore: &GitIgnore) -> bool {
+        let name = entry
+            .path()
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy();
+        ignore.is_ignored(&name, entry.is_dir())
+    }
+
+    /// Compiles `--hide` patterns, skipping (and logging) invalid ones.
+    ///
+    /// # Parameters
+    /// - `hide_patterns`: Glob patterns to compile.
+    ///
+    /// # Returns
+    /// The compiled globs.
+    fn hide_globs(hide_patterns: &[String]) -> Vec<Glob> {
+        hide_patterns
             .iter()
             .filter_map(|p| match Glob::new(p) {
                 Ok(g) => Some(g),
                 Err(e) => {
-                    if verbose {
-                        eprintln!("Invalid hide pattern '{}': {}", p, e);
-                    }
+                    crate::debug!("skipping invalid hide pattern", pattern = p, error = e);
                     None
                 }
             })
-            .collect();
-
-        let original_len = entries.len();
+            .collect()
+    }
 
-        // Retain entries that don't match any hide pattern
-        entries.retain(|entry| !globs.iter().any(|g| g.is_match(entry.name())));
+    /// Compares two names with runs of digits ordered by their numeric value, so
+    /// `file2` comes before `file10` and `v1.9` before `v1.10` (as `ls -v` does).
+    ///
+    /// Everything else compares case-insensitively, like `--sort name`. Names that
+    /// only differ in case or leading zeros fall back to a plain comparison, so
+    /// distinct names never compare equal.
+    ///
+    /// # Parameters
+    /// - `left`: The first name.
+    /// - `right`: The second name.
+    ///
+    /// # Returns
+    /// How `left` orders relative to `right`.
+    pub fn compare_versions(left: &str, right: &str) -> Ordering {
+        VersionKey::new(left, SortKey::Display).cmp(&VersionKey::new(right, SortKey::Display))
+    }
 
-        let removed = original_len - entries.len();
+    /// Builds the key a name (or extension) sorts by under `--sort-key`.
+    ///
+    /// `display` compares the lowercased name and only falls back to the name as
+    /// shown when two differ in case alone, so `README` and `readme` always land in
+    /// the same order. `raw` compares the name exactly as stored. `locale` names
+    /// are collated by [`DirReader::text_value`]; where a key is still needed (ties
+    /// of `--sort children`), they compare as `display` does.
+    ///
+    /// # Parameters
+    /// - `name`: The name or extension to build a key for.
+    /// - `sort_key`: How names compare.
+    ///
+    /// # Returns
+    /// The key, computed once per entry by the caller's cached sort.
+    fn name_key(name: &str, sort_key: SortKey) -> (String, String) {
+        match sort_key {
+            SortKey::Raw => (name.to_owned(), String::new()),
+            _ => (name.to_lowercase(), name.to_owned()),
+        }
+    }
 
-        if removed == 0 && verbose {
-            println!(
-                "Hide pattern(s) {:?} matched nothing in '{}'",
-                hide_patterns,
-                self.path.display()
-            );
+    /// Builds the sort value of a name (or extension) under `--sort-key`.
+    ///
+    /// # Parameters
+    /// - `name`: The name or extension.
+    /// - `sort_key`: How names compare.
+    ///
+    /// # Returns
+    /// A collated name for `locale`, otherwise the key from [`DirReader::name_key`].
+    fn text_value(name: &str, sort_key: SortKey) -> SortValue {
+        #[cfg(feature = "collate")]
+        if sort_key == SortKey::Locale {
+            return SortValue::Collated(CollationKey::new(name));
         }
 
-        removed
+        SortValue::Text(Self::name_key(name, sort_key))
+    }
+
+    /// Computes the value an entry sorts by for one `--sort` key.
+    ///
+    /// # Parameters
+    /// - `entry`: The entry, with metadata loaded for metadata keys.
+    /// - `by`: The sort key.
+    /// - `args`: CLI arguments (`--dir-size`, `--all` and `--sort-key` affect some keys).
+    /// - `filesystem`: The filesystem child counts are read from.
+    ///
+    /// # Returns
+    /// The value, compared only against the same key's values of other entries.
+    fn sort_value(entry: &Entry, by: SortBy, args: &Args, filesystem: &dyn Fs) -> SortValue {
+        let number = |field: fn(&metadata::Metadata) -> i128| {
+            SortValue::Number(entry.metadata().map(field).unwrap_or(0))
+        };
+
+        match by {
+            SortBy::Size if args.dir_size && entry.is_dir() => {
+                SortValue::Number(Cache::dir_size(entry.path(), args, || {
+                    DirReader::from(entry.path().to_path_buf()).dir_size(args)
+                }) as i128)
+            }
+            SortBy::Size => number(|meta| meta.size.into()),
+            SortBy::Modified => number(|meta| meta.mtime.into()),
+            SortBy::Created => number(|meta| meta.created().into()),
+            SortBy::Changed => number(|meta| meta.ctime.into()),
+            SortBy::Accessed => number(|meta| meta.atime.into()),
+            SortBy::Inode => number(|meta| meta.ino.into()),
+            SortBy::Extension => Self::text_value(entry.extension(), args.sort_key),
+            SortBy::Name => Self::text_value(entry.name(), args.sort_key),
+            SortBy::Children => {
+                // Files count as empty; ties fall back to name for a stable order
+                let children = if entry.is_dir() {
+                    DirReader::from(entry.path().to_path_buf()).item_count_in(filesystem, args.all)
+                } else {
+                    0
+                };
+                SortValue::Counted(children, Self::name_key(entry.name(), args.sort_key))
+            }
+            SortBy::Version => SortValue::Version(VersionKey::new(entry.name(), args.sort_key)),
+            // Everything ties, keeping the order of the keys before it
+            SortBy::None => SortValue::Number(0),
+        }
     }
 
     /// Sorts entries in place according to `args.sort`, reversing if `args.reverse` is set.
     ///
-    /// Loads metadata for all entries when sorting by size, timestamps, or inode.
+    /// Several `--sort` keys (e.g. `size,name`) compare in order, each later key
+    /// only ordering entries the ones before it tie on. `--sort none` (`-U`)
+    /// keeps the order `readdir` gave, skipping the sort, its stats and `--reverse`.
+    ///
+    /// `--sort-column` then reorders by a column's values, with the order above
+    /// breaking ties. With `--group-directories-first` (or `-last`), directories
+    /// and symlinks to them are then moved ahead of (or behind) files; `--reverse`
+    /// doesn't flip that.
+    ///
+    /// Loads metadata for all entries when sorting by size, timestamps, or inode, and
+    /// counts each directory's immediate children when sorting by `children`. With
+    /// `--dir-size` (or `--du`), directories sort by their recursive size, as shown.
     ///
     /// # Parameters
     /// - `entries`: The slice of entries to sort.
     /// - `args`: CLI arguments specifying the sort field and direction.
-    fn sort(&self, entries: &mut [Entry], args: &Args) {
+    /// - `filesystem`: The filesystem metadata and child counts are read from.
+    fn sort(entries: &mut [Entry], args: &Args, filesystem: &dyn Fs) {
+        if !args.is_unsorted() {
+            Self::sort_by_keys(entries, args, filesystem);
+        }
+
+        // `--sort-column` then orders by a column's values, keeping the order above for ties
+        if let Some(column) = &args.sort_column {
+            if column.reads_metadata() {
+                entries
+                    .iter_mut()
+                    .for_each(|entry| entry.metadata_from(filesystem, args.dereference));
+            }
+            Row::sort(entries, column, args);
+        }
+
+        // The sort is stable, so each group keeps the order above (reversed or not)
+        if args.group_directories_first {
+            entries.sort_by_key(|entry| !entry.is_dir_like());
+        } else if args.group_directories_last {
+            entries.sort_by_key(|entry| entry.is_dir_like());
+        }
+    }
+
+    /// Sorts entries by the `--sort` keys, reversing if `args.reverse` is set.
+    ///
+    /// # Parameters
+    /// - `entries`: The slice of entries to sort.
+    /// - `args`: CLI arguments specifying the sort keys and direction.
+    /// - `filesystem`: The filesystem metadata and child counts are read from.
+    fn sort_by_keys(entries: &mut [Entry], args: &Args, filesystem: &dyn Fs) {
         // Load metadata for all entries if we're sorting by metadata fields
-        let needs_metadata = matches!(
-            args.sort,
-            SortBy::Size | SortBy::Modified | SortBy::Created | SortBy::Accessed | SortBy::Inode
-        );
+        let needs_metadata = args.sort.iter().any(|by| {
+            matches!(
+                by,
+                SortBy::Size
+                    | SortBy::Modified
+                    | SortBy::Created
+                    | SortBy::Changed
+                    | SortBy::Accessed
+                    | SortBy::Inode
+            )
+        });
 
         if needs_metadata {
-            for entry in entries.iter_mut() {
-                entry.unconditional_metadata(args.dereference);
+            let load = |entry: &mut Entry| entry.metadata_from(filesystem, args.dereference);
+            if entries.len() >= PARALLEL_THRESHOLD {
+                let settings = Settings::scoped();
+                entries
+                    .par_iter_mut()
+                    .for_each(|entry| Settings::within(settings.clone(), || load(entry)));
+            } else {
+                entries.iter_mut().for_each(load);
             }
         }
 
-        match args.sort {
-            SortBy::Size => {
-                entries.sort_by_cached_key(|entry| entry.metadata().map(|m| m.size).unwrap_or(0));
-            }
-            SortBy::Modified => {
-                entries.sort_by_cached_key(|entry| entry.metadata().map(|m| m.mtime).unwrap_or(0));
-            }
-            SortBy::Created => {
-                entries.sort_by_cached_key(|entry| entry.metadata().map(|m| m.ctime).unwrap_or(0));
-            }
-            SortBy::Accessed => {
-                entries.sort_by_cached_key(|entry| entry.metadata().map(|m| m.atime).unwrap_or(0));
+        // Each key only breaks the ties of the ones before it
+        entries.sort_by_cached_key(|entry| {
+            args.sort
+                .iter()
+                .map(|by| Self::sort_value(entry, *by, args, filesystem))
+                .collect::<Vec<_>>()
+        });
+
+        if args.reverse {
+            entries.reverse();
+        }
+    }
+
+    /// Sorts `--group-by` sections by their rank, then by title as `--sort name`
+    /// compares names, reversing if `args.reverse` is set.
+    ///
+    /// Titles follow `--sort-key` and break ties the same way entry names do.
+    /// `--sort none` (`-U`) keeps the sections in the order they were first met.
+    ///
+    /// # Parameters
+    /// - `sections`: The sections to sort in place.
+    /// - `args`: CLI arguments specifying the sort keys and direction.
+    /// - `key`: Returns a section's rank and title.
+    pub(crate) fn sort_sections<T>(
+        sections: &mut [T],
+        args: &Args,
+        key: impl Fn(&T) -> (u8, &str),
+    ) {
+        if args.is_unsorted() {
+            return;
+        }
+
+        sections.sort_by_cached_key(|section| {
+            let (rank, title) = key(section);
+            (rank, Self::text_value(title, args.sort_key))
+        });
+
+        if args.reverse {
+            sections.reverse();
+        }
+    }
+}
+
+/// One entry's value for a single `--sort` key.
+///
+/// An entry sorts by one of these per key, so values only ever meet values of
+/// the same variant.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum SortValue {
+    /// A size, timestamp or inode number.
+    Number(i128),
+    /// A name or extension, as built by `DirReader::name_key`.
+    Text((String, String)),
+    /// A directory's child count, with the name for ties.
+    Counted(usize, (String, String)),
+    /// A name compared as a version.
+    Version(VersionKey),
+    /// A name or extension compared by the locale's collation (`--sort-key locale`).
+    #[cfg(feature = "collate")]
+    Collated(CollationKey),
+}
+
+/// A name split into runs of digits and single characters for `--sort version`.
+///
+/// Built once per entry so sorting doesn't re-scan (and re-lowercase) names on
+/// every comparison. Ties fall back to the name itself, so distinct names never
+/// compare equal.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct VersionKey {
+    parts: Vec<VersionPart>,
+    name: String,
+}
+
+impl VersionKey {
+    /// Splits `name` into its parts.
+    ///
+    /// # Parameters
+    /// - `name`: The entry name.
+    /// - `sort_key`: Whether characters are lowercased (`display`, `locale`) or kept as stored (`raw`).
+    ///
+    /// # Returns
+    /// The key for `name`.
+    fn new(name: &str, sort_key: SortKey) -> Self {
+        let mut chars = name.chars().peekable();
+        let mut parts = Vec::new();
+
+        while let Some(c) = chars.next() {
+            if c.is_ascii_digit() {
+                let mut digits = String::from(c);
+                digits.extend(std::iter::from_fn(|| chars.next_if(char::is_ascii_digit)));
+                parts.push(VersionPart::Number {
+                    first: c,
+                    digits: digits.trim_start_matches('0').to_owned(),
+                });
+            } else if sort_key != SortKey::Raw {
+                parts.push(VersionPart::Text(c.to_lowercase().collect()));
+            } else {
+                parts.push(VersionPart::Text(c.to_string()));
             }
-            SortBy::Inode => {
-                entries.sort_by_cached_key(|entry| entry.metadata().map(|m| m.ino).unwrap_or(0));
+        }
+
+        VersionKey {
+            parts,
+            name: name.to_owned(),
+        }
+    }
+}
+
+/// One piece of a [`VersionKey`].
+enum VersionPart {
+    /// A run of digits, without leading zeros, plus its first digit as written.
+    Number { first: char, digits: String },
+    /// A single character, lowercased unless sorting by the raw name.
+    Text(String),
+}
+
+impl Ord for VersionPart {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            // Without leading zeros, a longer run of digits is the larger number
+            (VersionPart::Number { digits: x, .. }, VersionPart::Number { digits: y, .. }) => {
+                x.len().cmp(&y.len()).then_with(|| x.cmp(y))
             }
-            SortBy::Extension => {
-                entries.sort_by_cached_key(|entry| entry.extension().to_lowercase());
+            // A digit against anything else compares as a character
+            (VersionPart::Number { first, .. }, VersionPart::Text(text)) => {
+                first.to_string().cmp(text)
             }
-            SortBy::Name => {
-                entries.sort_by_cached_key(|entry| entry.name().to_lowercase());
+            (VersionPart::Text(text), VersionPart::Number { first, .. }) => {
+                text.cmp(&first.to_string())
             }
+            (VersionPart::Text(x), VersionPart::Text(y)) => x.cmp(y),
         }
+    }
+}
 
-        if args.reverse {
-            entries.reverse();
-        }
+impl PartialOrd for VersionPart {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
+
+impl PartialEq for VersionPart {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for VersionPart {}
@@ -31,9 +31,10 @@ pub use file::FileEntry;
 pub use symlink::SymlinkEntry;
 
 use crate::cli::args::Args;
-use crate::fs::cache::Cache;
-use crate::fs::metadata::Metadata;
+use crate::display::output::errors::{ErrorKind, Errors};
+use crate::fs::metadata::{self, Metadata, Overlay};
 use crate::fs::symlink as symlink_utils;
+use crate::fs::vfs::{Fs, RealFs};
 use std::fs::DirEntry;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -84,7 +85,12 @@ impl Entry {
     /// - `is_dir`: Whether the path is a directory.
     /// - `is_symlink`: Whether the path is a symbolic link.
     /// - `show_link_target`: If `true`, includes the symlink target in the display name.
-    fn create(path: PathBuf, is_dir: bool, is_symlink: bool, show_link_target: bool) -> Self {
+    pub(crate) fn create(
+        path: PathBuf,
+        is_dir: bool,
+        is_symlink: bool,
+        show_link_target: bool,
+    ) -> Self {
         let name = Self::get_name(&path, is_symlink, show_link_target);
 
         if is_symlink {
@@ -110,7 +116,6 @@ impl Entry {
         matches!(self, Entry::Symlink(_))
     }
 
-    #[allow(dead_code)]
     /// Returns true only for regular files.
     pub fn is_file(&self) -> bool {
         matches!(self, Entry::File(_))
@@ -148,6 +153,30 @@ impl Entry {
         }
     }
 
+    /// Returns true if this entry is hidden (dot-prefixed or flagged hidden by the platform).
+    pub fn is_hidden(&self) -> bool {
+        let name = self
+            .path()
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default();
+
+        metadata::is_hidden(&name, self.path())
+    }
+
+    /// Returns the entry's role in an overlayfs layer, if any (see [`metadata::overlay`]).
+    ///
+    /// `.wh.` whiteout names are recognised from the name alone; device whiteouts
+    /// and opaque directories need the entry's metadata to have been loaded.
+    pub fn overlay(&self) -> Option<Overlay> {
+        let name = self.path().file_name()?.to_string_lossy();
+
+        match self.metadata() {
+            Some(metadata) if metadata.mode != 0 => metadata::overlay(&name, self.path(), metadata),
+            _ => metadata::is_whiteout_name(&name).then_some(Overlay::Whiteout),
+        }
+    }
+
     /// Returns a reference to the entry's path.
     pub fn path(&self) -> &PathBuf {
         match self {
@@ -222,11 +251,37 @@ impl Entry {
         self.unconditional_metadata(args.dereference);
     }
 
+    /// Loads metadata for a path given on the command line, only if the arguments request it.
+    ///
+    /// Unlike [`Entry::conditional_metadata`], a symlink is also followed with
+    /// `--dereference-command-line`.
+    ///
+    /// # Parameters
+    /// - `args`: Parsed command-line arguments that determine which metadata fields to populate.
+    pub fn conditional_operand_metadata(&mut self, args: &Args) {
+        if !Args::is_args_requesting_metadata(args) {
+            return;
+        }
+
+        self.unconditional_metadata(args.dereferences(true));
+    }
+
     /// Unconditionally loads metadata, bypassing display-flag checks.
     ///
     /// # Parameters
     /// - `dereference`: If `true`, follows symlinks so metadata reflects the target.
     pub fn unconditional_metadata(&mut self, dereference: bool) {
+        self.metadata_from(&RealFs, dereference);
+    }
+
+    /// Loads metadata through the given filesystem, unless it is already loaded.
+    ///
+    /// If the lookup fails the metadata stays unset (and is retried next time).
+    ///
+    /// # Parameters
+    /// - `fs`: The filesystem to query.
+    /// - `dereference`: If `true`, follows symlinks so metadata reflects the target.
+    pub fn metadata_from(&mut self, fs: &dyn Fs, dereference: bool) {
         // Skip if already loaded (check both size and ino for robustness)
         if let Some(meta) = self.metadata()
             && (meta.size != 0 || meta.ino != 0)
@@ -235,9 +290,13 @@ impl Entry {
         }
 
         let path = self.path().clone();
-        let metadata = match Cache::metadata(&path, dereference) {
+        let metadata = match fs.metadata(&path, dereference) {
             Ok(raw) => Some(raw.clone()),
-            Err(_) => Some(Metadata::empty()),
+            // Left unset, so the fields show as placeholders rather than zeros
+            Err(error) => {
+                Errors::report(ErrorKind::StatFailed, &path, &error.to_string());
+                None
+            }
         };
 
         match self {
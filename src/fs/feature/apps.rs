@@ -0,0 +1,521 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Default "open with" applications from the XDG MIME database (`--apps`).
+//!
+//! A file's MIME type is guessed from its name with the shared-mime-info
+//! `globs2` files, then mapped to a desktop entry through `mimeapps.list`
+//! (`[Default Applications]` first, then `[Added Associations]`) and the
+//! `mimeinfo.cache` files written by `update-desktop-database` (or the
+//! desktop entries' own `MimeType=` lines where no cache exists). Aliases and
+//! parent types (e.g. `text/x-csrc` falling back to `text/plain`) are followed
+//! when a type has no application of its own.
+
+use crate::fs::cache::Cache;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+/// The database, read from the XDG directories on first use.
+static DATABASE: OnceLock<MimeApps> = OnceLock::new();
+
+/// How many parent types are followed before giving up (guards against cycles).
+const MAX_PARENT_DEPTH: usize = 8;
+
+/// A `globs2` pattern that is neither a plain `*.ext` suffix nor a literal name.
+struct WildcardGlob {
+    weight: u32,
+    pattern: String,
+    mime: String,
+}
+
+/// File-name-to-MIME rules and MIME-to-application associations.
+#[derive(Default)]
+pub struct MimeApps {
+    /// Lowercased extension (`tar.gz`, `rs`) to `(weight, mime)`.
+    extensions: HashMap<String, (u32, String)>,
+    /// Lowercased exact file names (`makefile`) to `(weight, mime)`.
+    literals: HashMap<String, (u32, String)>,
+    /// Every other pattern, matched with `*` and `?`.
+    wildcards: Vec<WildcardGlob>,
+    /// Alias to canonical MIME type.
+    aliases: HashMap<String, String>,
+    /// MIME type to the types it is a subclass of.
+    parents: HashMap<String, Vec<String>>,
+    /// Desktop IDs from `[Default Applications]`, most preferred first.
+    defaults: HashMap<String, Vec<String>>,
+    /// Desktop IDs from `[Added Associations]` and `mimeinfo.cache`.
+    associations: HashMap<String, Vec<String>>,
+    /// The `applications` directories desktop entries are looked up in.
+    application_dirs: Vec<PathBuf>,
+}
+
+impl MimeApps {
+    /// Reads the database from the standard XDG data and config directories.
+    ///
+    /// # Returns
+    /// The database; empty when nothing could be read.
+    pub fn load() -> Self {
+        let home = std::env::var_os("HOME").map(PathBuf::from);
+
+        let mut data_dirs: Vec<PathBuf> = Self::env_dir("XDG_DATA_HOME")
+            .or_else(|| home.as_ref().map(|home| home.join(".local/share")))
+            .into_iter()
+            .collect();
+        data_dirs.extend(Self::env_dirs(
+            "XDG_DATA_DIRS",
+            "/usr/local/share:/usr/share",
+        ));
+
+        let mut config_dirs: Vec<PathBuf> = Self::env_dir("XDG_CONFIG_HOME")
+            .or_else(|| home.as_ref().map(|home| home.join(".config")))
+            .into_iter()
+            .collect();
+        config_dirs.extend(Self::env_dirs("XDG_CONFIG_DIRS", "/etc/xdg"));
+
+        Self::from_dirs(&data_dirs, &config_dirs)
+    }
+
+    /// Reads the database from explicit directories, most important first.
+    ///
+    /// # Parameters
+    /// - `data_dirs`: Data directories holding `mime/` and `applications/`.
+    /// - `config_dirs`: Config directories holding `mimeapps.list`.
+    ///
+    /// # Returns
+    /// The database built from every file that could be read.
+    pub fn from_dirs(data_dirs: &[PathBuf], config_dirs: &[PathBuf]) -> Self {
+        let mut database = Self {
+            application_dirs: data_dirs
+                .iter()
+                .map(|dir| dir.join("applications"))
+                .collect(),
+            ..Self::default()
+        };
+
+        for dir in data_dirs {
+            database.read_globs(&dir.join("mime/globs2"));
+            database.read_pairs(&dir.join("mime/aliases"), true);
+            database.read_pairs(&dir.join("mime/subclasses"), false);
+        }
+
+        let lists = config_dirs
+            .iter()
+            .map(|dir| dir.join("mimeapps.list"))
+            .chain(
+                data_dirs
+                    .iter()
+                    .map(|dir| dir.join("applications/mimeapps.list")),
+            );
+        for list in lists {
+            database.read_associations(&list);
+        }
+
+        // Without a cache (no update-desktop-database run), read the entries themselves
+        for dir in data_dirs.iter().map(|dir| dir.join("applications")) {
+            let cache = dir.join("mimeinfo.cache");
+            if cache.is_file() {
+                database.read_associations(&cache);
+            } else {
+                database.read_desktop_entries(&dir);
+            }
+        }
+
+        database
+    }
+
+    /// Guesses a file's MIME type from its name.
+    ///
+    /// The heaviest matching rule wins; among equal weights, the longest pattern does.
+    ///
+    /// # Parameters
+    /// - `name`: The file name (not a path).
+    ///
+    /// # Returns
+    /// The MIME type, or `None` if no rule matches.
+    pub fn mime_type(&self, name: &str) -> Option<&str> {
+        let name = name.to_lowercase();
+        let mut candidates: Vec<(u32, usize, &str)> = Vec::new();
+
+        if let Some((weight, mime)) = self.literals.get(&name) {
+            candidates.push((*weight, name.len(), mime));
+        }
+
+        // Every dot starts a candidate extension, so `a.tar.gz` tries `tar.gz` and `gz`
+        for (index, _) in name.match_indices('.') {
+            let extension = &name[index + 1..];
+            if let Some((weight, mime)) = self.extensions.get(extension) {
+                candidates.push((*weight, extension.len() + 2, mime));
+            }
+        }
+
+        for glob in &self.wildcards {
+            if Self::wildcard_match(&glob.pattern, &name) {
+                candidates.push((glob.weight, glob.pattern.len(), &glob.mime));
+            }
+        }
+
+        candidates
+            .into_iter()
+            .max_by_key(|(weight, length, _)| (*weight, *length))
+            .map(|(_, _, mime)| mime)
+    }
+
+    /// Finds the name of the default application for a MIME type.
+    ///
+    /// # Parameters
+    /// - `mime`: The MIME type (aliases are resolved).
+    ///
+    /// # Returns
+    /// The application's `Name=`, or `None` if no installed application handles the type.
+    pub fn default_app(&self, mime: &str) -> Option<String> {
+        self.app_for(mime, 0)
+    }
+
+    /// Resolves a MIME type to an application, falling back to its parent types.
+    ///
+    /// # Parameters
+    /// - `mime`: The MIME type.
+    /// - `depth`: How many parents have been followed so far.
+    ///
+    /// # Returns
+    /// The application name, or `None`.
+    fn app_for(&self, mime: &str, depth: usize) -> Option<String> {
+        let mime = self.aliases.get(mime).map_or(mime, String::as_str);
+
+        let found = self
+            .defaults
+            .get(mime)
+            .into_iter()
+            .chain(self.associations.get(mime))
+            .flatten()
+            .find_map(|id| self.desktop_name(id));
+        if found.is_some() || depth >= MAX_PARENT_DEPTH {
+            return found;
+        }
+
+        // Every text/* type is implicitly a subclass of text/plain
+        let mut parents: Vec<&str> = self
+            .parents
+            .get(mime)
+            .map(|parents| parents.iter().map(String::as_str).collect())
+            .unwrap_or_default();
+        if mime.starts_with("text/") && mime != "text/plain" && !parents.contains(&"text/plain") {
+            parents.push("text/plain");
+        }
+
+        parents
+            .into_iter()
+            .find_map(|parent| self.app_for(parent, depth + 1))
+    }
+
+    /// Reads the `Name=` of an installed desktop entry.
+    ///
+    /// An ID like `kde-okular.desktop` may also live at `kde/okular.desktop`.
+    ///
+    /// # Parameters
+    /// - `id`: The desktop file ID.
+    ///
+    /// # Returns
+    /// The name from the first matching entry, or `None` if none is installed
+    /// (or it is marked `Hidden=true`).
+    fn desktop_name(&self, id: &str) -> Option<String> {
+        let nested = id.replacen('-', "/", 1);
+
+        let content = self
+            .application_dirs
+            .iter()
+            .flat_map(|dir| [dir.join(id), dir.join(&nested)])
+            .find_map(|path| fs::read_to_string(path).ok())?;
+
+        let mut in_entry = false;
+        let mut name = None;
+        for line in content.lines().map(str::trim) {
+            if line.starts_with('[') {
+                in_entry = line == "[Desktop Entry]";
+            } else if in_entry {
+                match line
+                    .split_once('=')
+                    .map(|(key, value)| (key.trim(), value.trim()))
+                {
+                    Some(("Name", value)) => name = Some(value.to_string()),
+                    Some(("Hidden", "true")) => return None,
+                    _ => {}
+                }
+            }
+        }
+        name
+    }
+
+    /// Adds the patterns of a `globs2` file (`weight:mime:pattern[:flags]` lines).
+    ///
+    /// Rules already known at the same or a higher weight are kept, so files read
+    /// first (the more important directories) win ties.
+    ///
+    /// # Parameters
+    /// - `path`: The `globs2` file.
+    fn read_globs(&mut self, path: &Path) {
+        let Ok(content) = fs::read_to_string(path) else {
+            return;
+        };
+
+        for line in content.lines().filter(|line| !line.starts_with('#')) {
+            let mut fields = line.splitn(4, ':');
+            let (Some(weight), Some(mime), Some(pattern)) = (
+                fields.next().and_then(|weight| weight.parse::<u32>().ok()),
+                fields.next(),
+                fields.next(),
+            ) else {
+                continue;
+            };
+            let pattern = pattern.to_lowercase();
+
+            // Character classes (`*.[1-9]`) are rare and left out
+            if pattern.contains('[') {
+                continue;
+            }
+
+            let is_wild = |text: &str| text.contains(['*', '?']);
+            let table = match pattern.strip_prefix("*.") {
+                Some(extension) if !is_wild(extension) => {
+                    Some((&mut self.extensions, extension.to_string()))
+                }
+                _ if !is_wild(&pattern) => Some((&mut self.literals, pattern.clone())),
+                _ => None,
+            };
+
+            match table {
+                Some((table, key)) => {
+                    if table.get(&key).is_none_or(|(known, _)| weight > *known) {
+                        table.insert(key, (weight, mime.to_string()));
+                    }
+                }
+                None => self.wildcards.push(WildcardGlob {
+                    weight,
+                    pattern,
+                    mime: mime.to_string(),
+                }),
+            }
+        }
+    }
+
+    /// Adds the `type other` lines of a `mime/aliases` or `mime/subclasses` file.
+    ///
+    /// # Parameters
+    /// - `path`: The file to read.
+    /// - `is_alias`: Whether the file maps aliases (`true`) or parents (`false`).
+    fn read_pairs(&mut self, path: &Path, is_alias: bool) {
+        let Ok(content) = fs::read_to_string(path) else {
+            return;
+        };
+
+        for (mime, other) in content.lines().filter_map(|line| line.split_once(' ')) {
+            if is_alias {
+                self.aliases
+                    .entry(mime.to_string())
+                    .or_insert_with(|| other.to_string());
+            } else {
+                self.parents
+                    .entry(mime.to_string())
+                    .or_default()
+                    .push(other.to_string());
+            }
+        }
+    }
+
+    /// Adds the associations of a `mimeapps.list` or `mimeinfo.cache` file.
+    ///
+    /// # Parameters
+    /// - `path`: The file to read.
+    fn read_associations(&mut self, path: &Path) {
+        let Ok(content) = fs::read_to_string(path) else {
+            return;
+        };
+
+        let mut section = "";
+        for line in content.lines().map(str::trim) {
+            if line.starts_with('[') {
+                section = line;
+                continue;
+            }
+            let Some((mime, ids)) = line.split_once('=') else {
+                continue;
+            };
+
+            let table = match section {
+                "[Default Applications]" => &mut self.defaults,
+                "[Added Associations]" | "[MIME Cache]" => &mut self.associations,
+                _ => continue,
+            };
+            table.entry(mime.trim().to_string()).or_default().extend(
+                ids.split(';')
+                    .map(str::trim)
+                    .filter(|id| !id.is_empty())
+                    .map(String::from),
+            );
+        }
+    }
+
+    /// Adds the `MimeType=` lists of the desktop entries directly inside a directory.
+    ///
+    /// # Parameters
+    /// - `dir`: An `applications` directory.
+    fn read_desktop_entries(&mut self, dir: &Path) {
+        let Ok(read_dir) = fs::read_dir(dir) else {
+            return;
+        };
+
+        let mut entries: Vec<PathBuf> = read_dir
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|extension| extension == "desktop")
+            })
+            .collect();
+        entries.sort();
+
+        for path in entries {
+            let (Some(id), Ok(content)) = (path.file_name(), fs::read_to_string(&path)) else {
+                continue;
+            };
+            let id = id.to_string_lossy();
+
+            let mut in_entry = false;
+            for line in content.lines().map(str::trim) {
+                if line.starts_with('[') {
+                    in_entry = line == "[Desktop Entry]";
+                } else if in_entry && let Some(types) = line.strip_prefix("MimeType=") {
+                    for mime in types
+                        .split(';')
+                        .map(str::trim)
+                        .filter(|mime| !mime.is_empty())
+                    {
+                        self.associations
+                            .entry(mime.to_string())
+                            .or_default()
+                            .push(id.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Matches text against a pattern where `*` is any run and `?` any one character.
+    ///
+    /// # Parameters
+    /// - `pattern`: The wildcard pattern.
+    /// - `text`: The text to match.
+    ///
+    /// # Returns
+    /// `true` if the whole text matches.
+    fn wildcard_match(pattern: &str, text: &str) -> bool {
+        let pattern: Vec<char> = pattern.chars().collect();
+        let text: Vec<char> = text.chars().collect();
+        let (mut p, mut t) = (0, 0);
+        let mut backtrack: Option<(usize, usize)> = None;
+
+        while t < text.len() {
+            if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+                p += 1;
+                t += 1;
+            } else if p < pattern.len() && pattern[p] == '*' {
+                backtrack = Some((p, t));
+                p += 1;
+            } else if let Some((star, consumed)) = backtrack {
+                // Let the last `*` swallow one more character and retry
+                p = star + 1;
+                t = consumed + 1;
+                backtrack = Some((star, consumed + 1));
+            } else {
+                return false;
+            }
+        }
+
+        pattern[p..].iter().all(|&character| character == '*')
+    }
+
+    /// Reads a single directory from an environment variable, ignoring empty values.
+    fn env_dir(name: &str) -> Option<PathBuf> {
+        std::env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    }
+
+    /// Reads a colon-separated directory list from an environment variable.
+    ///
+    /// # Parameters
+    /// - `name`: The variable name.
+    /// - `default`: The list used when the variable is unset or empty.
+    ///
+    /// # Returns
+    /// The directories, in order.
+    fn env_dirs(name: &str, default: &str) -> Vec<PathBuf> {
+        let value = std::env::var(name)
+            .ok()
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| default.to_string());
+
+        value
+            .split(':')
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .collect()
+    }
+}
+
+/// Looks up the default application for listed entries.
+pub(crate) struct Apps;
+
+impl Apps {
+    /// Returns the name of the application that opens a path by default.
+    ///
+    /// Directories resolve through `inode/directory` (the file manager).
+    ///
+    /// # Parameters
+    /// - `path`: The entry to look up.
+    ///
+    /// # Returns
+    /// The application name, or `"-"` if the type or its application is unknown.
+    pub(crate) fn default_for(path: &Path) -> Arc<str> {
+        let database = DATABASE.get_or_init(MimeApps::load);
+
+        let mime = if path.is_dir() {
+            Some("inode/directory")
+        } else {
+            path.file_name()
+                .and_then(|name| database.mime_type(&name.to_string_lossy()))
+        };
+
+        match mime {
+            Some(mime) => Cache::app(mime, || {
+                database
+                    .default_app(mime)
+                    .map_or_else(|| "-".into(), Arc::from)
+            }),
+            None => "-".into(),
+        }
+    }
+}
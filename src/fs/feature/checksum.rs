@@ -25,6 +25,9 @@ SOFTWARE.
 #[cfg(feature = "checksum")]
 use crate::cli::flags::HashAlgorithm;
 
+#[cfg(feature = "checksum")]
+use crate::fs::metadata;
+
 #[cfg(feature = "checksum")]
 use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
 
@@ -64,10 +67,10 @@ impl<'a> Checksum<'a> {
     /// Computes the checksum for the file.
     ///
     /// # Returns
-    /// The hex-encoded hash digest, or `"-"` for directories or on error.
+    /// The hex-encoded hash digest, or `"-"` for directories, special files, or on error.
     pub(crate) fn compute(&self) -> Arc<str> {
-        // Skip directories
-        if self.path.is_dir() {
+        // Skip directories, and FIFOs and devices that would block or never end
+        if !metadata::has_readable_contents(self.path) {
             return "-".into();
         }
 
@@ -0,0 +1,173 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Directory reading through raw `getdents64` with a large buffer (Linux, `getdents` feature).
+//!
+//! `std::fs::read_dir` goes through libc's `readdir`, which fetches entries
+//! 32 KiB at a time, so a directory with millions of entries takes tens of
+//! thousands of syscalls. Reading them a megabyte at a time makes a thirtieth
+//! as many. `cargo bench --features getdents -- readdir` compares the two: on
+//! ext4, 100k entries read about a third faster, while at a million the
+//! kernel's own walk of the directory dominates and the two are level.
+//!
+//! Each thread keeps its buffer between calls, so a recursive listing doesn't
+//! allocate one per directory, and the buffer starts small and only grows to
+//! the full megabyte once a directory fills it.
+
+use crate::fs::vfs::DirItem;
+use std::cell::RefCell;
+use std::ffi::{CString, OsStr};
+use std::io;
+use std::mem;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+
+/// Most bytes of directory entries fetched per `getdents64` call.
+pub const BUFFER_SIZE: usize = 1024 * 1024;
+
+/// Bytes fetched per call until a directory turns out to be big, as many as `readdir` takes.
+const INITIAL_BUFFER_SIZE: usize = 32 * 1024;
+
+thread_local! {
+    /// This thread's batch buffer, in `u64` words so records stay 8-byte aligned
+    /// as the kernel lays them out.
+    static BUFFER: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Offset of `d_name` in `struct linux_dirent64`, after `d_ino`, `d_off`,
+/// `d_reclen` and `d_type`.
+const NAME_OFFSET: usize = 19;
+
+/// Lists the children of a directory, in the order the kernel returns them.
+///
+/// # Parameters
+/// - `path`: The directory to read.
+///
+/// # Returns
+/// The directory's children (without `.` and `..`), or an I/O error if it
+/// can't be opened or read.
+pub fn read_dir(path: &Path) -> io::Result<Vec<DirItem>> {
+    let c_path = CString::new(path.as_os_str().as_bytes())
+        .map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
+    let fd = unsafe {
+        libc::open(
+            c_path.as_ptr(),
+            libc::O_RDONLY | libc::O_DIRECTORY | libc::O_CLOEXEC,
+        )
+    };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+    BUFFER.with_borrow_mut(|buffer| {
+        if buffer.is_empty() {
+            buffer.resize(INITIAL_BUFFER_SIZE / mem::size_of::<u64>(), 0);
+        }
+        let mut items = Vec::new();
+
+        loop {
+            let size = buffer.len() * mem::size_of::<u64>();
+            let read = unsafe {
+                libc::syscall(
+                    libc::SYS_getdents64,
+                    fd.as_raw_fd(),
+                    buffer.as_mut_ptr(),
+                    size,
+                )
+            };
+            if read < 0 {
+                let error = io::Error::last_os_error();
+                if error.kind() == io::ErrorKind::Interrupted {
+                    continue;
+                }
+                return Err(error);
+            }
+            if read == 0 {
+                break;
+            }
+
+            let bytes =
+                unsafe { std::slice::from_raw_parts(buffer.as_ptr().cast::<u8>(), read as usize) };
+            parse(path, bytes, &mut items);
+
+            // A batch over half full means a big directory, which bigger batches read in fewer calls
+            if read as usize > size / 2 && size < BUFFER_SIZE {
+                buffer.resize(buffer.len() * 2, 0);
+            }
+        }
+
+        Ok(items)
+    })
+}
+
+/// Appends the entries in one `getdents64` batch to `items`.
+///
+/// # Parameters
+/// - `directory`: The directory being read, which the names are joined onto.
+/// - `bytes`: The `linux_dirent64` records the kernel filled in.
+/// - `items`: Where the entries go.
+fn parse(directory: &Path, bytes: &[u8], items: &mut Vec<DirItem>) {
+    let mut offset = 0;
+
+    while offset + NAME_OFFSET <= bytes.len() {
+        let record = &bytes[offset..];
+        let length = usize::from(u16::from_ne_bytes([record[16], record[17]]));
+        if length < NAME_OFFSET || offset + length > bytes.len() {
+            break;
+        }
+        let kind = record[18];
+
+        // The name is NUL-terminated, then padded out to the record length
+        let name = &record[NAME_OFFSET..length];
+        let name = &name[..name
+            .iter()
+            .position(|&byte| byte == 0)
+            .unwrap_or(name.len())];
+        offset += length;
+
+        if name == b"." || name == b".." {
+            continue;
+        }
+
+        let path = directory.join(OsStr::from_bytes(name));
+        let (is_dir, is_symlink) = match kind {
+            libc::DT_DIR => (true, false),
+            libc::DT_LNK => (false, true),
+            // Some filesystems don't fill in the type, so stat as std would
+            libc::DT_UNKNOWN => match path.symlink_metadata() {
+                Ok(metadata) => (metadata.is_dir(), metadata.file_type().is_symlink()),
+                Err(_) => (false, false),
+            },
+            _ => (false, false),
+        };
+
+        items.push(DirItem {
+            path,
+            is_dir,
+            is_symlink,
+        });
+    }
+}
@@ -1,19 +1,28 @@
-#[cfg(all(feature = "magic", not(target_os = "android")))]
+#[cfg(feature = "magic")]
 use std::fs::read_link;
 
-#[cfg(all(feature = "magic", not(target_os = "android")))]
-use std::path::PathBuf;
+#[cfg(feature = "magic")]
+use std::path::{Path, PathBuf};
 
-#[cfg(all(feature = "magic", not(target_os = "android")))]
+#[cfg(feature = "magic")]
 use std::sync::Arc;
 
-#[cfg(all(feature = "magic", not(target_os = "android")))]
+#[cfg(feature = "magic")]
 use crate::fs::cache::Cache;
 
-#[cfg(all(feature = "magic", not(target_os = "android")))]
+#[cfg(all(
+    feature = "magic",
+    not(any(target_os = "android", target_env = "musl"))
+))]
 use filemagic::Magic as FileMagic;
 
-#[cfg(all(feature = "magic", not(target_os = "android")))]
+#[cfg(feature = "magic")]
+use crate::fs::metadata;
+
+#[cfg(feature = "magic")]
+use crate::fs::sniff::Sniff;
+
+#[cfg(feature = "magic")]
 /// Truncates a string to include only content up to and including the second comma.
 ///
 /// Primarily used to simplify libmagic output for display in tables,
@@ -50,19 +59,22 @@ fn clip_2nd_comma(text: String) -> Arc<str> {
     }
 }
 
-#[cfg(all(feature = "magic", not(target_os = "android")))]
-/// Detects file types using `libmagic`.
+#[cfg(feature = "magic")]
+/// Detects file types using `libmagic`, or the built-in [`Sniff`] where libmagic
+/// isn't available: on Android, in static musl builds, and when the library or
+/// its database can't be loaded at runtime.
 pub(crate) struct Magic;
 
-#[cfg(all(feature = "magic", not(target_os = "android")))]
+#[cfg(feature = "magic")]
 impl Magic {
-    /// Returns the `libmagic` file type description for a path.
+    /// Returns the file type description for a path (from `libmagic`, or sniffed without it).
     ///
     /// # Parameters
     /// - `path`: The file to identify.
     ///
     /// # Returns
-    /// A truncated magic description, or an empty string for directories.
+    /// A truncated magic description, an empty string for directories, or `"-"`
+    /// for special files (FIFOs, sockets, devices), which are never opened.
     pub(crate) fn file(path: &PathBuf) -> Arc<str> {
         if path.is_dir() {
             return "".into();
@@ -76,31 +88,57 @@ impl Magic {
             .into();
         }
 
-        Cache::magic(path, || {
-            thread_local! {
-                static MAGIC: std::cell::RefCell<Option<FileMagic>> = const { std::cell::RefCell::new(None) };
+        // Opening a FIFO blocks until something writes to it
+        if !metadata::has_readable_contents(path) {
+            return "-".into();
+        }
+
+        Cache::magic(path, || Self::identify(path))
+    }
+
+    /// Asks `libmagic` for a path's file type, sniffing it instead if libmagic fails.
+    ///
+    /// # Parameters
+    /// - `path`: The regular file to identify.
+    ///
+    /// # Returns
+    /// A truncated magic description.
+    #[cfg(not(any(target_os = "android", target_env = "musl")))]
+    fn identify(path: &Path) -> Arc<str> {
+        thread_local! {
+            static MAGIC: std::cell::RefCell<Option<FileMagic>> = const { std::cell::RefCell::new(None) };
+        }
+
+        MAGIC.with(|cell| {
+            let mut maybe_magic = cell.borrow_mut();
+
+            if maybe_magic.is_none()
+                && let Ok(magic) = FileMagic::open(Default::default())
+            {
+                let _ = magic.load::<String>(&[]);
+                *maybe_magic = Some(magic);
             }
 
-            MAGIC.with(|cell| {
-                let mut maybe_magic = cell.borrow_mut();
-
-                if maybe_magic.is_none()
-                    && let Ok(magic) = FileMagic::open(Default::default())
-                {
-                    let _ = magic.load::<String>(&[]);
-                    *maybe_magic = Some(magic);
-                }
-
-                if let Some(magic) = maybe_magic.as_ref() {
-                    clip_2nd_comma(
-                        magic
-                            .file(path.to_str().unwrap_or_default())
-                            .unwrap_or_default(),
-                    )
-                } else {
-                    "Magic library unavailable".into()
-                }
-            })
+            // A missing magic database loads "successfully" but fails every lookup
+            match maybe_magic
+                .as_ref()
+                .and_then(|magic| magic.file(path.to_str().unwrap_or_default()).ok())
+            {
+                Some(description) => clip_2nd_comma(description),
+                None => clip_2nd_comma(Sniff::file(path).to_string()),
+            }
         })
     }
+
+    /// Sniffs a path's file type from its leading bytes (no libmagic on Android or musl).
+    ///
+    /// # Parameters
+    /// - `path`: The regular file to identify.
+    ///
+    /// # Returns
+    /// A truncated libmagic-style description.
+    #[cfg(any(target_os = "android", target_env = "musl"))]
+    fn identify(path: &Path) -> Arc<str> {
+        clip_2nd_comma(Sniff::file(path).to_string())
+    }
 }
@@ -1,3 +1,9 @@
+#[cfg(feature = "apps")]
+pub mod apps;
 pub(crate) mod checksum;
-#[cfg(all(feature = "magic", not(target_os = "android")))]
+#[cfg(all(feature = "getdents", any(target_os = "linux", target_os = "android")))]
+pub mod getdents;
+#[cfg(feature = "magic")]
 pub(crate) mod magic;
+#[cfg(feature = "checksum")]
+pub mod verify;
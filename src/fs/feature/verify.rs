@@ -0,0 +1,247 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Checking entries against a `sha256sum`-style manifest (`--checksum-verify`).
+//!
+//! Each manifest line is a hex digest and a file name, separated by two spaces
+//! (or a space and `*` for binary mode), as `sha256sum`, `md5sum` and friends
+//! write them. The algorithm is told from the digest's length, so one manifest
+//! can mix them. Names are relative to the manifest's directory.
+
+use crate::cli::flags::HashAlgorithm;
+use crate::fs::feature::checksum::Checksum;
+use crate::fs::metadata;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static MANIFEST: OnceLock<Manifest> = OnceLock::new();
+
+/// Set once an entry fails verification.
+static FAILED: AtomicBool = AtomicBool::new(false);
+
+/// The outcome of checking one entry against the manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// The digest matches.
+    Ok,
+    /// The digest differs, or the file couldn't be read.
+    Failed,
+}
+
+impl Verdict {
+    /// Returns the status as shown in the Verify column.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "OK",
+            Self::Failed => "FAILED",
+        }
+    }
+}
+
+/// One manifest line.
+#[derive(Debug)]
+struct Expected {
+    algorithm: HashAlgorithm,
+    /// The lowercase hex digest.
+    digest: String,
+    /// The file name as the manifest gives it.
+    name: String,
+}
+
+/// The expected digests of a manifest, by absolute path.
+#[derive(Debug, Default)]
+pub struct Manifest {
+    expected: HashMap<PathBuf, Expected>,
+}
+
+impl Manifest {
+    /// Reads a manifest file.
+    ///
+    /// # Parameters
+    /// - `file`: The manifest, e.g. `dist/SHA256SUMS`.
+    ///
+    /// # Returns
+    /// The parsed manifest, or the error from reading the file.
+    pub fn load(file: &Path) -> io::Result<Self> {
+        let contents = fs::read_to_string(file)?;
+        let base = file.parent().unwrap_or(Path::new(""));
+        Ok(Self::parse(&contents, base))
+    }
+
+    /// Parses manifest text, skipping blank lines, comments and lines that
+    /// aren't a digest and a name.
+    ///
+    /// # Parameters
+    /// - `contents`: The manifest text.
+    /// - `base`: The directory the names are relative to.
+    pub fn parse(contents: &str, base: &Path) -> Self {
+        let mut expected = HashMap::new();
+
+        for line in contents.lines() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((digest, name)) = line.split_once(' ') else {
+                continue;
+            };
+            // `  name` in text mode, ` *name` in binary mode
+            let name = name
+                .strip_prefix(' ')
+                .or_else(|| name.strip_prefix('*'))
+                .unwrap_or(name);
+            let Some(algorithm) = Self::algorithm(digest) else {
+                continue;
+            };
+            if name.is_empty() {
+                continue;
+            }
+
+            expected.insert(
+                Self::key(&base.join(name)),
+                Expected {
+                    algorithm,
+                    digest: digest.to_ascii_lowercase(),
+                    name: name.to_string(),
+                },
+            );
+        }
+
+        Self { expected }
+    }
+
+    /// Makes this the manifest the Verify column checks against. Only the first call takes effect.
+    pub fn setup(self) {
+        let _ = MANIFEST.set(self);
+    }
+
+    /// Returns the number of files the manifest lists.
+    pub fn len(&self) -> usize {
+        self.expected.len()
+    }
+
+    /// Checks whether the manifest lists no files.
+    pub fn is_empty(&self) -> bool {
+        self.expected.is_empty()
+    }
+
+    /// Checks a file against its manifest digest.
+    ///
+    /// # Parameters
+    /// - `path`: The file to check.
+    ///
+    /// # Returns
+    /// The verdict, or `None` if the manifest doesn't list the file.
+    pub fn verify(&self, path: &Path) -> Option<Verdict> {
+        let expected = self.expected.get(&Self::key(path))?;
+
+        let actual = Checksum::new(path, expected.algorithm).compute();
+        if actual.eq_ignore_ascii_case(&expected.digest) {
+            Some(Verdict::Ok)
+        } else {
+            Some(Verdict::Failed)
+        }
+    }
+
+    /// Returns the names of the files the manifest lists that don't exist, sorted.
+    pub fn missing(&self) -> Vec<&str> {
+        let mut missing: Vec<&str> = self
+            .expected
+            .iter()
+            .filter(|(path, _)| path.symlink_metadata().is_err())
+            .map(|(_, expected)| expected.name.as_str())
+            .collect();
+        missing.sort();
+        missing
+    }
+
+    /// Returns the Verify column's value for an entry.
+    ///
+    /// # Returns
+    /// `OK` or `FAILED`, or `"-"` for entries the manifest doesn't list, for
+    /// directories and special files (and when no manifest was given).
+    pub(crate) fn status(path: &Path) -> Arc<str> {
+        let verdict = MANIFEST
+            .get()
+            .filter(|_| metadata::has_readable_contents(path))
+            .and_then(|manifest| manifest.verify(path));
+
+        match verdict {
+            Some(verdict) => {
+                if verdict == Verdict::Failed {
+                    FAILED.store(true, Ordering::Relaxed);
+                }
+                verdict.as_str().into()
+            }
+            None => "-".into(),
+        }
+    }
+
+    /// Prints `name: MISSING` for each listed file that doesn't exist, as
+    /// `sha256sum -c` does, once the listing is done.
+    ///
+    /// # Returns
+    /// `true` if any file was missing or failed verification.
+    pub fn conclude() -> bool {
+        let Some(manifest) = MANIFEST.get() else {
+            return false;
+        };
+
+        let missing = manifest.missing();
+        for name in &missing {
+            println!("{name}: MISSING");
+        }
+
+        !missing.is_empty() || FAILED.load(Ordering::Relaxed)
+    }
+
+    /// Picks the algorithm that produces digests like this one.
+    ///
+    /// # Returns
+    /// `None` if the text isn't hex or has no matching length.
+    fn algorithm(digest: &str) -> Option<HashAlgorithm> {
+        if !digest.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        match digest.len() {
+            8 => Some(HashAlgorithm::Crc32),
+            32 => Some(HashAlgorithm::Md5),
+            56 => Some(HashAlgorithm::Sha224),
+            64 => Some(HashAlgorithm::Sha256),
+            96 => Some(HashAlgorithm::Sha384),
+            128 => Some(HashAlgorithm::Sha512),
+            _ => None,
+        }
+    }
+
+    /// Normalises a path for lookups, so `dist/a` and `./dist/a` match.
+    fn key(path: &Path) -> PathBuf {
+        std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+    }
+}
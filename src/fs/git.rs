@@ -0,0 +1,321 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Git status lookups for `--git`.
+//!
+//! The enclosing repository of a directory is found by walking up to the
+//! nearest `.git`, and its status is read once with
+//! `git status --porcelain` and shared by every entry listed from it.
+
+use crate::fs::cache::CacheSettings;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Repositories by the directory that was searched for them (`None` outside any repository).
+static REPOSITORIES: OnceLock<Mutex<HashMap<PathBuf, Option<Arc<Repository>>>>> = OnceLock::new();
+
+/// Repositories by work tree root, so nested directories share one `git status` run.
+///
+/// Each root gets its own cell, filled by whichever thread gets there first
+/// while the others wait for it, without holding up lookups in other roots.
+static ROOTS: OnceLock<Mutex<HashMap<PathBuf, RootCell>>> = OnceLock::new();
+
+/// A work tree root's repository, loaded once (`None` if `git` failed there).
+type RootCell = Arc<OnceLock<Option<Arc<Repository>>>>;
+
+/// Status codes ranked from least to most noteworthy, used when summarising a directory.
+const PRECEDENCE: &[u8] = b" !?TRCADMU";
+
+/// A two-letter git status: index (staged) then work tree (unstaged), as in `git status -s`.
+///
+/// Clean positions are shown as `-`, so a modified but unstaged file reads `-M`,
+/// a staged new file `A-`, an untracked one `??` and an ignored one `!!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GitStatus {
+    /// The index (staged) status code.
+    pub staged: u8,
+    /// The work tree (unstaged) status code.
+    pub unstaged: u8,
+}
+
+impl GitStatus {
+    /// An entry with no changes.
+    pub const CLEAN: Self = Self {
+        staged: b' ',
+        unstaged: b' ',
+    };
+
+    /// An untracked entry.
+    const UNTRACKED: Self = Self {
+        staged: b'?',
+        unstaged: b'?',
+    };
+
+    /// An ignored entry.
+    const IGNORED: Self = Self {
+        staged: b'!',
+        unstaged: b'!',
+    };
+
+    /// Combines two statuses, keeping the more noteworthy code on each side.
+    ///
+    /// # Parameters
+    /// - `other`: The status to merge in.
+    ///
+    /// # Returns
+    /// The merged status.
+    fn merge(self, other: Self) -> Self {
+        let rank = |code: u8| PRECEDENCE.iter().position(|&c| c == code).unwrap_or(0);
+        let pick = |a: u8, b: u8| if rank(b) > rank(a) { b } else { a };
+
+        Self {
+            staged: pick(self.staged, other.staged),
+            unstaged: pick(self.unstaged, other.unstaged),
+        }
+    }
+}
+
+impl fmt::Display for GitStatus {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |code: u8| if code == b' ' { '-' } else { code as char };
+        write!(formatter, "{}{}", show(self.staged), show(self.unstaged))
+    }
+}
+
+/// The parsed `git status` of one work tree.
+#[derive(Debug, Default)]
+struct Repository {
+    /// The work tree root.
+    root: PathBuf,
+    /// Status of every changed, untracked or ignored path, relative to `root`.
+    files: HashMap<PathBuf, GitStatus>,
+    /// Merged status of everything changed below each directory, relative to `root`.
+    dirs: HashMap<PathBuf, GitStatus>,
+}
+
+impl Repository {
+    /// Parses `git status --porcelain=v1 -z` output.
+    ///
+    /// Untracked and ignored directories are reported once, with a trailing `/`,
+    /// and cover everything beneath them. Ignored paths aren't rolled up into
+    /// their parents, so a directory isn't flagged just for holding build output.
+    ///
+    /// # Parameters
+    /// - `root`: The work tree root the paths are relative to.
+    /// - `porcelain`: The raw NUL-separated output.
+    ///
+    /// # Returns
+    /// The parsed repository.
+    fn parse(root: PathBuf, porcelain: &[u8]) -> Self {
+        let mut repository = Self {
+            root,
+            ..Self::default()
+        };
+        let mut records = porcelain.split(|&byte| byte == 0);
+
+        while let Some(record) = records.next() {
+            if record.len() < 4 {
+                continue;
+            }
+
+            let status = GitStatus {
+                staged: record[0],
+                unstaged: record[1],
+            };
+            let path = PathBuf::from(String::from_utf8_lossy(&record[3..]).trim_end_matches('/'));
+
+            // Renames and copies are followed by a record holding the original path
+            if matches!(status.staged, b'R' | b'C') {
+                records.next();
+            }
+
+            if status != GitStatus::IGNORED {
+                for ancestor in path.ancestors().skip(1) {
+                    let merged = repository
+                        .dirs
+                        .get(ancestor)
+                        .map_or(status, |existing| existing.merge(status));
+                    repository.dirs.insert(ancestor.to_path_buf(), merged);
+                }
+            }
+            repository.files.insert(path, status);
+        }
+
+        repository
+    }
+
+    /// Looks up the status of a path inside this work tree.
+    ///
+    /// # Parameters
+    /// - `relative`: The path relative to the work tree root.
+    /// - `is_dir`: Whether the path is a directory (its contents then count too).
+    ///
+    /// # Returns
+    /// The path's status, which is [`GitStatus::CLEAN`] if git reported nothing for it.
+    fn status(&self, relative: &Path, is_dir: bool) -> GitStatus {
+        if let Some(status) = self.files.get(relative) {
+            return *status;
+        }
+
+        // Everything inside an untracked or ignored directory shares its status
+        for ancestor in relative.ancestors().skip(1) {
+            if let Some(status) = self.files.get(ancestor)
+                && (*status == GitStatus::UNTRACKED || *status == GitStatus::IGNORED)
+            {
+                return *status;
+            }
+        }
+
+        if is_dir && let Some(status) = self.dirs.get(relative) {
+            return *status;
+        }
+
+        GitStatus::CLEAN
+    }
+}
+
+/// Resolves the git status of filesystem entries.
+pub struct Git;
+
+impl Git {
+    /// Returns the git status of a path.
+    ///
+    /// # Parameters
+    /// - `path`: The file or directory to look up.
+    /// - `is_dir`: Whether `path` is a directory, so changes inside it are summarised.
+    ///
+    /// # Returns
+    /// The status, or `None` if the path isn't inside a git work tree (or `git` can't run).
+    pub fn status(path: &Path, is_dir: bool) -> Option<GitStatus> {
+        let absolute = std::path::absolute(path).ok()?;
+        let parent = absolute.parent()?;
+
+        // The `.git` entry itself belongs to no work tree
+        if absolute.file_name().is_some_and(|name| name == ".git") {
+            return None;
+        }
+
+        let repository = Self::repository(parent)?;
+        let relative = absolute.strip_prefix(&repository.root).ok()?;
+
+        Some(repository.status(relative, is_dir))
+    }
+
+    /// Forgets the loaded repositories, so the next lookup runs `git status`
+    /// again. Called before each `--watch` redraw.
+    pub fn refresh() {
+        if let Some(repositories) = REPOSITORIES.get()
+            && let Ok(mut repositories) = repositories.lock()
+        {
+            repositories.clear();
+        }
+        if let Some(roots) = ROOTS.get()
+            && let Ok(mut roots) = roots.lock()
+        {
+            roots.clear();
+        }
+    }
+
+    /// Finds and loads the repository enclosing a directory, caching the result
+    /// unless `--no-cache` is given.
+    ///
+    /// # Parameters
+    /// - `directory`: The absolute directory to search upwards from.
+    ///
+    /// # Returns
+    /// The shared repository, or `None` outside a work tree.
+    fn repository(directory: &Path) -> Option<Arc<Repository>> {
+        let find = || {
+            directory
+                .ancestors()
+                .find(|ancestor| ancestor.join(".git").symlink_metadata().is_ok())
+                .and_then(Self::load)
+        };
+
+        if CacheSettings::is_disabled() {
+            return find();
+        }
+
+        let repositories = REPOSITORIES.get_or_init(|| Mutex::new(HashMap::new()));
+
+        if let Some(found) = repositories.lock().ok()?.get(directory) {
+            return found.clone();
+        }
+
+        let found = find();
+
+        repositories
+            .lock()
+            .ok()?
+            .insert(directory.to_path_buf(), found.clone());
+        found
+    }
+
+    /// Runs `git status` once per work tree root, even when several threads
+    /// list directories of the same repository at once.
+    ///
+    /// This holds even with `--no-cache`, which instead forgets the loaded
+    /// roots before each listing (see [`Git::refresh`]), so a listing never
+    /// sees a status from an earlier one.
+    ///
+    /// # Parameters
+    /// - `root`: The work tree root (the directory holding `.git`).
+    ///
+    /// # Returns
+    /// The parsed repository, or `None` if `git` isn't installed or fails.
+    fn load(root: &Path) -> Option<Arc<Repository>> {
+        let roots = ROOTS.get_or_init(|| Mutex::new(HashMap::new()));
+        let cell = roots
+            .lock()
+            .ok()?
+            .entry(root.to_path_buf())
+            .or_default()
+            .clone();
+
+        cell.get_or_init(|| {
+            let output = Command::new("git")
+                .arg("-C")
+                .arg(root)
+                .args([
+                    "status",
+                    "--porcelain=v1",
+                    "-z",
+                    "--ignored",
+                    "--untracked-files=normal",
+                ])
+                .output()
+                .ok()
+                .filter(|output| output.status.success())?;
+
+            Some(Arc::new(Repository::parse(
+                root.to_path_buf(),
+                &output.stdout,
+            )))
+        })
+        .clone()
+    }
+}
@@ -0,0 +1,413 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! `.gitignore` matching for `--git-ignore`.
+//!
+//! Rules come from the repository's `.git/info/exclude` and from every
+//! `.gitignore` between the work tree root and the directory being listed,
+//! read in that order so deeper files override shallower ones. Each file is
+//! parsed once and shared by every directory listed beneath it.
+
+use crate::fs::cache::CacheSettings;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Parsed ignore files by path (`None` if the file doesn't exist).
+static IGNORE_FILES: OnceLock<Mutex<HashMap<PathBuf, Option<Arc<IgnoreFile>>>>> = OnceLock::new();
+
+/// One pattern line of an ignore file.
+#[derive(Debug, Clone)]
+struct Rule {
+    /// The pattern split on `/`, with `**` kept as its own segment.
+    segments: Vec<String>,
+    /// Whether the line started with `!` and re-includes what it matches.
+    negated: bool,
+    /// Whether the line ended with `/` and only matches directories.
+    dir_only: bool,
+    /// Whether the pattern contains a `/` before its end, tying it to the file's directory.
+    anchored: bool,
+}
+
+impl Rule {
+    /// Parses one line of an ignore file.
+    ///
+    /// # Parameters
+    /// - `line`: The raw line.
+    ///
+    /// # Returns
+    /// The rule, or `None` for blank lines and comments.
+    fn parse(line: &str) -> Option<Self> {
+        let line = Self::trim_trailing_spaces(line);
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        let (negated, line) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line.strip_prefix('\\').unwrap_or(line)),
+        };
+
+        let (dir_only, line) = match line.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+
+        let anchored = line.contains('/');
+        let segments: Vec<String> = line
+            .trim_start_matches('/')
+            .split('/')
+            .map(str::to_string)
+            .collect();
+
+        if segments.iter().all(String::is_empty) {
+            return None;
+        }
+
+        Some(Self {
+            segments,
+            negated,
+            dir_only,
+            anchored,
+        })
+    }
+
+    /// Strips trailing spaces from a line, keeping one escaped as `\ `.
+    ///
+    /// # Parameters
+    /// - `line`: The raw line.
+    ///
+    /// # Returns
+    /// The line without its unescaped trailing spaces (and any `\r`).
+    fn trim_trailing_spaces(line: &str) -> &str {
+        let line = line.trim_end_matches('\r');
+        let trimmed = line.trim_end_matches(' ');
+
+        if trimmed.ends_with('\\') && trimmed.len() < line.len() {
+            &line[..trimmed.len() + 1]
+        } else {
+            trimmed
+        }
+    }
+
+    /// Checks whether the rule matches a path.
+    ///
+    /// # Parameters
+    /// - `relative`: The path's components relative to the ignore file's directory.
+    /// - `is_dir`: Whether the path is a directory.
+    ///
+    /// # Returns
+    /// `true` if the pattern matches.
+    fn matches(&self, relative: &[&str], is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+
+        if self.anchored {
+            match self.segments.split_last() {
+                // `dir/**` matches everything inside `dir`, but not `dir` itself
+                Some((last, head)) if last == "**" && !head.is_empty() => {
+                    (1..relative.len()).any(|depth| Self::match_segments(head, &relative[..depth]))
+                }
+                _ => Self::match_segments(&self.segments, relative),
+            }
+        } else {
+            relative
+                .last()
+                .is_some_and(|name| Self::match_segment(&self.segments[0], name))
+        }
+    }
+
+    /// Matches pattern segments against path components, with `**` spanning any number of them.
+    ///
+    /// Backtracks only to the most recent `**`, so the cost stays linear in the
+    /// path length per pattern segment.
+    ///
+    /// # Parameters
+    /// - `pattern`: The pattern segments.
+    /// - `path`: The path components.
+    ///
+    /// # Returns
+    /// `true` if the whole path matches the whole pattern.
+    fn match_segments(pattern: &[String], path: &[&str]) -> bool {
+        let (mut p, mut s) = (0, 0);
+        let mut star: Option<(usize, usize)> = None;
+
+        while s < path.len() {
+            if p < pattern.len() && pattern[p] == "**" {
+                star = Some((p, s));
+                p += 1;
+            } else if p < pattern.len() && Self::match_segment(&pattern[p], path[s]) {
+                p += 1;
+                s += 1;
+            } else if let Some((star_p, star_s)) = star {
+                p = star_p + 1;
+                s = star_s + 1;
+                star = Some((star_p, star_s + 1));
+            } else {
+                return false;
+            }
+        }
+
+        pattern[p..].iter().all(|segment| segment == "**")
+    }
+
+    /// Matches one pattern segment (`*`, `?`, `[...]` and `\` escapes) against one name.
+    ///
+    /// # Parameters
+    /// - `pattern`: The pattern segment.
+    /// - `name`: The path component.
+    ///
+    /// # Returns
+    /// `true` if the name matches.
+    fn match_segment(pattern: &str, name: &str) -> bool {
+        let pattern: Vec<char> = pattern.chars().collect();
+        let name: Vec<char> = name.chars().collect();
+        let (mut p, mut n) = (0, 0);
+        let mut star: Option<(usize, usize)> = None;
+
+        while n < name.len() {
+            let step = match pattern.get(p) {
+                Some('*') => {
+                    star = Some((p, n));
+                    p += 1;
+                    continue;
+                }
+                Some('?') => Some(1),
+                Some('[') => Self::match_class(&pattern[p..], name[n]),
+                Some('\\') if p + 1 < pattern.len() => (pattern[p + 1] == name[n]).then_some(2),
+                Some(&c) => (c == name[n]).then_some(1),
+                None => None,
+            };
+
+            match (step, star) {
+                (Some(width), _) => {
+                    p += width;
+                    n += 1;
+                }
+                (None, Some((star_p, star_n))) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                (None, None) => return false,
+            }
+        }
+
+        pattern[p..].iter().all(|&c| c == '*')
+    }
+
+    /// Matches a bracket expression such as `[a-z]` or `[!0-9]` against one character.
+    ///
+    /// # Parameters
+    /// - `pattern`: The pattern from the opening `[` onwards.
+    /// - `c`: The character to test.
+    ///
+    /// # Returns
+    /// The expression's length if it matches, or `None` if it doesn't (or is unterminated).
+    fn match_class(pattern: &[char], c: char) -> Option<usize> {
+        let mut i = 1;
+        let negated = matches!(pattern.get(i), Some('!' | '^'));
+        if negated {
+            i += 1;
+        }
+
+        let mut matched = false;
+        let mut first = true;
+        while let Some(&start) = pattern.get(i) {
+            if start == ']' && !first {
+                return (matched != negated).then_some(i + 1);
+            }
+            first = false;
+
+            if pattern.get(i + 1) == Some(&'-') && pattern.get(i + 2).is_some_and(|&e| e != ']') {
+                matched |= (start..=pattern[i + 2]).contains(&c);
+                i += 3;
+            } else {
+                matched |= start == c;
+                i += 1;
+            }
+        }
+
+        None
+    }
+}
+
+/// The rules of one ignore file, relative to the directory they apply from.
+#[derive(Debug)]
+pub struct IgnoreFile {
+    /// The directory the patterns are relative to.
+    base: PathBuf,
+    /// The rules in file order.
+    rules: Vec<Rule>,
+}
+
+impl IgnoreFile {
+    /// Parses the contents of an ignore file.
+    ///
+    /// # Parameters
+    /// - `base`: The directory the patterns are relative to (the work tree root for `info/exclude`).
+    /// - `contents`: The file's text.
+    ///
+    /// # Returns
+    /// The parsed file.
+    pub fn parse(base: &Path, contents: &str) -> Self {
+        Self {
+            base: base.to_path_buf(),
+            rules: contents.lines().filter_map(Rule::parse).collect(),
+        }
+    }
+
+    /// Finds the last rule in this file matching a path.
+    ///
+    /// # Parameters
+    /// - `path`: The absolute path to test.
+    /// - `is_dir`: Whether the path is a directory.
+    ///
+    /// # Returns
+    /// `Some(true)` if the path is ignored, `Some(false)` if a `!` rule
+    /// re-includes it, or `None` if no rule matches (or the path lies elsewhere).
+    pub fn verdict(&self, path: &Path, is_dir: bool) -> Option<bool> {
+        let relative = path.strip_prefix(&self.base).ok()?;
+        let components: Vec<String> = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        let components: Vec<&str> = components.iter().map(String::as_str).collect();
+
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matches(&components, is_dir))
+            .map(|rule| !rule.negated)
+    }
+
+    /// Loads and parses an ignore file, caching the result unless `--no-cache` is given.
+    ///
+    /// # Parameters
+    /// - `path`: The ignore file to read.
+    /// - `base`: The directory its patterns are relative to.
+    ///
+    /// # Returns
+    /// The shared parsed file, or `None` if it can't be read.
+    fn load(path: &Path, base: &Path) -> Option<Arc<Self>> {
+        let read = || {
+            fs::read_to_string(path)
+                .ok()
+                .map(|contents| Arc::new(Self::parse(base, &contents)))
+        };
+
+        if CacheSettings::is_disabled() {
+            return read();
+        }
+
+        let files = IGNORE_FILES.get_or_init(|| Mutex::new(HashMap::new()));
+
+        if let Some(found) = files.lock().ok()?.get(path) {
+            return found.clone();
+        }
+
+        let found = read();
+
+        files.lock().ok()?.insert(path.to_path_buf(), found.clone());
+        found
+    }
+}
+
+/// The ignore rules in effect for the entries of one directory.
+#[derive(Debug, Default)]
+pub struct GitIgnore {
+    /// The directory whose entries are tested.
+    directory: PathBuf,
+    /// Ignore files from lowest to highest precedence.
+    files: Vec<Arc<IgnoreFile>>,
+}
+
+impl GitIgnore {
+    /// Collects the ignore files that apply inside a directory.
+    ///
+    /// # Parameters
+    /// - `directory`: The directory being listed.
+    ///
+    /// # Returns
+    /// The applicable rules; empty outside a git work tree.
+    pub fn for_dir(directory: &Path) -> Self {
+        // Rebuilt from its components so `.` segments don't throw off the walk upwards
+        let Ok(directory) =
+            std::path::absolute(directory).map(|path| path.components().collect::<PathBuf>())
+        else {
+            return Self::default();
+        };
+
+        let Some(root) = directory
+            .ancestors()
+            .find(|ancestor| ancestor.join(".git").symlink_metadata().is_ok())
+        else {
+            return Self::default();
+        };
+
+        let mut files: Vec<Arc<IgnoreFile>> =
+            IgnoreFile::load(&root.join(".git/info/exclude"), root)
+                .into_iter()
+                .collect();
+
+        // Walk back down from the root so deeper files come later and win
+        let mut levels: Vec<&Path> = directory
+            .ancestors()
+            .take_while(|ancestor| ancestor.starts_with(root))
+            .collect();
+        levels.reverse();
+        files.extend(
+            levels
+                .into_iter()
+                .filter_map(|level| IgnoreFile::load(&level.join(".gitignore"), level)),
+        );
+
+        Self { directory, files }
+    }
+
+    /// Checks whether an entry of the directory is ignored.
+    ///
+    /// The `.git` directory itself is never reported as ignored.
+    ///
+    /// # Parameters
+    /// - `name`: The entry's file name.
+    /// - `is_dir`: Whether the entry is a directory.
+    ///
+    /// # Returns
+    /// `true` if the last matching rule across all files ignores it.
+    pub fn is_ignored(&self, name: &str, is_dir: bool) -> bool {
+        if self.files.is_empty() || name == ".git" {
+            return false;
+        }
+
+        let path = self.directory.join(name);
+        self.files
+            .iter()
+            .rev()
+            .find_map(|file| file.verdict(&path, is_dir))
+            .unwrap_or(false)
+    }
+}
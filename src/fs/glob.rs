@@ -27,9 +27,14 @@ SOFTWARE.
 //! Supports wildcard patterns:
 //! - `*` matches any sequence of characters
 //! - `?` matches any single character
+//!
+//! [`Glob::expand`] applies patterns to whole paths for `--glob-arg`, where a
+//! `**` component also matches any number of directories.
 
 use std::ffi::CString;
+use std::fs;
 use std::mem::MaybeUninit;
+use std::path::{Component, Path, PathBuf};
 
 /// A compiled glob pattern for matching filenames.
 ///
@@ -49,6 +54,11 @@ impl Glob {
     /// A compiled [`Glob`] or an error message if the pattern is invalid.
     pub fn new(pattern: &str) -> Result<Self, String> {
         let regex_pattern = Self::to_regex(pattern);
+        crate::debug!(
+            "compiling pattern",
+            pattern = pattern,
+            regex = regex_pattern
+        );
 
         let c_pattern =
             CString::new(regex_pattern).map_err(|_| "Invalid pattern: contains null byte")?;
@@ -64,7 +74,9 @@ impl Glob {
             })
         } else {
             let initialized = unsafe { regex.assume_init() };
-            Err(Self::error_message(&initialized, result))
+            let error = Self::error_message(&initialized, result);
+            crate::debug!("invalid pattern", pattern = pattern, error = error);
+            Err(error)
         }
     }
 
@@ -83,6 +95,121 @@ impl Glob {
         result == 0
     }
 
+    /// Expands a path pattern against the filesystem, the way a shell expands an unquoted argument.
+    ///
+    /// Each component is matched against the names in the directories reached so
+    /// far. `**` stands for the directory itself and everything below it, found by
+    /// recursive traversal that doesn't follow symlinks. As in a shell, wildcards
+    /// only match hidden names when the component itself starts with `.`.
+    ///
+    /// # Parameters
+    /// - `pattern`: The path pattern, e.g. `src/**/*.rs`.
+    ///
+    /// # Returns
+    /// The existing paths that match, sorted; empty if nothing matches or a component
+    /// is not a valid pattern.
+    pub fn expand(pattern: &Path) -> Vec<PathBuf> {
+        let mut matches = vec![PathBuf::new()];
+
+        for component in pattern.components() {
+            let Component::Normal(part) = component else {
+                // Keep `/`, `.` and `..` as written
+                matches = matches.iter().map(|path| path.join(component)).collect();
+                continue;
+            };
+            let part = part.to_string_lossy();
+
+            matches = if part == "**" {
+                matches
+                    .iter()
+                    .flat_map(|path| Self::descendants(path))
+                    .collect()
+            } else if Self::is_pattern(&part) {
+                let Ok(glob) = Self::new(&part) else {
+                    return Vec::new();
+                };
+                let dotted = part.starts_with('.');
+
+                matches
+                    .iter()
+                    .flat_map(|path| Self::children(path))
+                    .filter(|child| {
+                        let name = child.file_name().unwrap_or_default().to_string_lossy();
+                        (dotted || !name.starts_with('.')) && glob.is_match(&name)
+                    })
+                    .collect()
+            } else {
+                matches
+                    .iter()
+                    .map(|path| path.join(part.as_ref()))
+                    .collect()
+            };
+        }
+
+        matches.retain(|path| !path.as_os_str().is_empty() && path.symlink_metadata().is_ok());
+        matches.sort();
+        matches.dedup();
+        matches
+    }
+
+    /// Checks whether a path component contains wildcards.
+    ///
+    /// # Parameters
+    /// - `text`: The component to check.
+    fn is_pattern(text: &str) -> bool {
+        text.contains(['*', '?'])
+    }
+
+    /// Lists the children of a directory, keeping them relative when it is.
+    ///
+    /// # Parameters
+    /// - `dir`: The directory to read; the empty path stands for `.`.
+    fn children(dir: &Path) -> Vec<PathBuf> {
+        let readable = if dir.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            dir
+        };
+
+        fs::read_dir(readable)
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .map(|entry| dir.join(entry.file_name()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Collects a directory and every non-hidden path below it, for a `**` component.
+    ///
+    /// # Parameters
+    /// - `dir`: The directory to start from; the empty path stands for `.`.
+    fn descendants(dir: &Path) -> Vec<PathBuf> {
+        let mut found = vec![dir.to_path_buf()];
+        let mut index = 0;
+
+        // Breadth-first, descending only into real directories so symlink loops end
+        while index < found.len() {
+            let path = found[index].clone();
+            index += 1;
+
+            let is_dir = path.as_os_str().is_empty()
+                || fs::symlink_metadata(&path).is_ok_and(|metadata| metadata.is_dir());
+            if !is_dir {
+                continue;
+            }
+
+            found.extend(Self::children(&path).into_iter().filter(|child| {
+                !child
+                    .file_name()
+                    .is_some_and(|name| name.to_string_lossy().starts_with('.'))
+            }));
+        }
+
+        found
+    }
+
     /// Converts a glob pattern to an anchored POSIX extended regex string.
     ///
     /// # Parameters
@@ -91,8 +218,10 @@ impl Glob {
         let mut result = String::with_capacity(pattern.len() * 2 + 2);
         result.push('^');
 
-        for c in pattern.chars() {
+        for (index, c) in pattern.char_indices() {
             match c {
+                // A run of `*` means the same as one, and `.*.*.*...` makes regexec backtrack badly
+                '*' if pattern[..index].ends_with('*') => {}
                 '*' => result.push_str(".*"),
                 '?' => result.push('.'),
                 '.' | '+' | '(' | ')' | '[' | ']' | '{' | '}' | '^' | '$' | '|' | '\\' => {
@@ -0,0 +1,94 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Entries to highlight in a `--context-listing`.
+//!
+//! A file operand is listed as its enclosing directory instead, with the file
+//! itself picked out among its siblings.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+static HIGHLIGHTED: OnceLock<HashSet<PathBuf>> = OnceLock::new();
+
+/// Global set of paths shown highlighted (and never hidden) in listings.
+pub struct Highlight;
+
+impl Highlight {
+    /// Records the paths to highlight. Only the first call takes effect.
+    ///
+    /// # Parameters
+    /// - `paths`: The paths, as they appear under their listed directory.
+    pub fn setup(paths: HashSet<PathBuf>) {
+        let _ = HIGHLIGHTED.set(paths);
+    }
+
+    /// Checks whether an entry should be highlighted.
+    ///
+    /// # Parameters
+    /// - `path`: The entry's path.
+    ///
+    /// # Returns
+    /// `true` if the path was recorded with [`Highlight::setup`].
+    pub(crate) fn contains(path: &Path) -> bool {
+        HIGHLIGHTED.get().is_some_and(|paths| paths.contains(path))
+    }
+
+    /// Swaps each file operand for its enclosing directory.
+    ///
+    /// Directories are kept as they are. A directory that several files share
+    /// is listed once, where the first of them was given.
+    ///
+    /// # Parameters
+    /// - `paths`: The path operands.
+    ///
+    /// # Returns
+    /// The directories to list, and the files' paths as they'll appear in those
+    /// listings (`foo.txt` becomes `./foo.txt`).
+    pub fn context(paths: &[PathBuf]) -> (Vec<PathBuf>, HashSet<PathBuf>) {
+        let mut dirs: Vec<PathBuf> = Vec::new();
+        let mut files = HashSet::new();
+
+        for path in paths {
+            let dir = match (path.is_dir(), path.file_name()) {
+                (false, Some(name)) => {
+                    let parent = path
+                        .parent()
+                        .filter(|parent| !parent.as_os_str().is_empty())
+                        .unwrap_or(Path::new("."));
+                    files.insert(parent.join(name));
+                    parent.to_path_buf()
+                }
+                _ => path.clone(),
+            };
+
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+
+        (dirs, files)
+    }
+}
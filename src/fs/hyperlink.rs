@@ -23,6 +23,7 @@ SOFTWARE.
 */
 
 use crate::cli::flags::ShowHyperlink;
+use crate::cli::settings::Settings;
 use crate::display::output::terminal::is_tty;
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -45,7 +46,8 @@ impl HyperlinkSettings {
 
     /// Returns whether hyperlinks are currently enabled.
     pub(crate) fn is_enabled() -> bool {
-        HYPERLINKS_ENABLED.load(Ordering::SeqCst)
+        Settings::get(|settings| settings.hyperlinks)
+            .unwrap_or_else(|| HYPERLINKS_ENABLED.load(Ordering::SeqCst))
     }
 
     /// Configures hyperlinks at startup based on CLI flag and terminal detection.
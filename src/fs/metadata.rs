@@ -31,6 +31,8 @@ use std::path::Path;
 #[derive(Clone, Debug)]
 pub struct Metadata {
     pub mode: u32,
+    pub dev: u64,
+    pub rdev: u64,
     pub size: u64,
     pub ino: u64,
     pub nlink: u64,
@@ -41,6 +43,8 @@ pub struct Metadata {
     pub atime: i64,
     pub mtime: i64,
     pub ctime: i64,
+    /// Birth (creation) time, where the platform and filesystem record one.
+    pub btime: Option<i64>,
 }
 
 impl Metadata {
@@ -59,6 +63,11 @@ impl Metadata {
             io::Error::new(io::ErrorKind::InvalidInput, "path contains interior nul")
         })?;
 
+        #[cfg(all(target_os = "linux", target_env = "gnu"))]
+        if let Some(result) = Self::load_statx(&c_path, dereference) {
+            return result;
+        }
+
         unsafe {
             let mut st: libc::stat = std::mem::zeroed();
 
@@ -69,6 +78,8 @@ impl Metadata {
 
             Ok(Self {
                 mode: st.st_mode as u32,
+                dev: st.st_dev as u64,
+                rdev: st.st_rdev as u64,
                 size: st.st_size as u64,
                 ino: st.st_ino as u64,
                 nlink: st.st_nlink as u64,
@@ -79,10 +90,97 @@ impl Metadata {
                 atime: st.st_atime,
                 mtime: st.st_mtime,
                 ctime: st.st_ctime,
+                btime: Self::birth_time(&st),
             })
         }
     }
 
+    /// Loads metadata with `statx`, which also reports the birth time on Linux.
+    ///
+    /// # Parameters
+    /// - `c_path`: The path to query.
+    /// - `dereference`: If `true`, follows symlinks.
+    ///
+    /// # Returns
+    /// The result of the call, or `None` if the kernel has no `statx` (before
+    /// 4.11, or blocked by a seccomp filter) and plain `stat` should be used.
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    fn load_statx(c_path: &CString, dereference: bool) -> Option<io::Result<Self>> {
+        let flags = if dereference {
+            0
+        } else {
+            libc::AT_SYMLINK_NOFOLLOW
+        };
+
+        unsafe {
+            let mut stx: libc::statx = std::mem::zeroed();
+            let mask = libc::STATX_BASIC_STATS | libc::STATX_BTIME;
+            if libc::statx(libc::AT_FDCWD, c_path.as_ptr(), flags, mask, &mut stx) != 0 {
+                let error = io::Error::last_os_error();
+                return match error.raw_os_error() {
+                    Some(libc::ENOSYS | libc::EPERM) => None,
+                    _ => Some(Err(error)),
+                };
+            }
+
+            Some(Ok(Self {
+                mode: stx.stx_mode as u32,
+                dev: libc::makedev(stx.stx_dev_major, stx.stx_dev_minor),
+                rdev: libc::makedev(stx.stx_rdev_major, stx.stx_rdev_minor),
+                size: stx.stx_size,
+                ino: stx.stx_ino,
+                nlink: stx.stx_nlink as u64,
+                uid: stx.stx_uid,
+                gid: stx.stx_gid,
+                blocks: stx.stx_blocks,
+                blksize: stx.stx_blksize as u64,
+                atime: stx.stx_atime.tv_sec,
+                mtime: stx.stx_mtime.tv_sec,
+                ctime: stx.stx_ctime.tv_sec,
+                // Filesystems without a birth time leave the bit unset; some
+                // set it but report 0
+                btime: (stx.stx_mask & libc::STATX_BTIME != 0 && stx.stx_btime.tv_sec > 0)
+                    .then_some(stx.stx_btime.tv_sec),
+            }))
+        }
+    }
+
+    /// Reads the birth time from a `stat` result, on platforms whose `stat` has one.
+    ///
+    /// # Parameters
+    /// - `st`: The `stat` result.
+    ///
+    /// # Returns
+    /// The birth time, or `None` where `stat` doesn't report it (or reports it unset as 0).
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "netbsd"
+    ))]
+    fn birth_time(st: &libc::stat) -> Option<i64> {
+        (st.st_birthtime > 0).then_some(st.st_birthtime)
+    }
+
+    /// Reads the birth time from a `stat` result, on platforms whose `stat` has one.
+    ///
+    /// This platform's `stat` doesn't report it, so this is always `None`.
+    #[cfg(not(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "netbsd"
+    )))]
+    fn birth_time(_st: &libc::stat) -> Option<i64> {
+        None
+    }
+
+    /// Returns the creation time shown by `--created`: the birth time, or the
+    /// status change time where no birth time is recorded.
+    pub fn created(&self) -> i64 {
+        self.btime.unwrap_or(self.ctime)
+    }
+
     /// Creates a zeroed-out [`Metadata`] instance, useful as a default placeholder.
     ///
     /// # Returns
@@ -90,6 +188,8 @@ impl Metadata {
     pub fn empty() -> Self {
         Self {
             mode: 0,
+            dev: 0,
+            rdev: 0,
             size: 0,
             ino: 0,
             nlink: 0,
@@ -100,6 +200,319 @@ impl Metadata {
             atime: 0,
             mtime: 0,
             ctime: 0,
+            btime: None,
         }
     }
+
+    /// Checks whether this is an overlayfs whiteout: a character device numbered 0/0.
+    pub fn is_whiteout(&self) -> bool {
+        self.mode & libc::S_IFMT == libc::S_IFCHR && self.rdev == 0
+    }
+
+    /// Returns the bytes allocated on disk (`st_blocks` is always in 512-byte units).
+    pub fn allocated(&self) -> u64 {
+        self.blocks * 512
+    }
+
+    /// Returns how many times larger the apparent size is than the allocated size.
+    ///
+    /// Above `1.0` the file takes less space than its length, through transparent
+    /// compression (ZFS reports compressed blocks) or holes in a sparse file.
+    ///
+    /// # Returns
+    /// The ratio for non-empty regular files with allocated blocks, otherwise `None`
+    /// (directories, special files, and btrfs inline files with no blocks).
+    pub fn compression_ratio(&self) -> Option<f64> {
+        let is_regular = self.mode & libc::S_IFMT == libc::S_IFREG;
+        let allocated = self.allocated();
+
+        (is_regular && self.size > 0 && allocated > 0).then(|| self.size as f64 / allocated as f64)
+    }
+}
+
+/// Checks whether a path's contents can be read without blocking or side effects.
+///
+/// Only regular files qualify, after following symlinks: opening a FIFO waits
+/// for a writer, and sockets and devices aren't files to read.
+///
+/// # Parameters
+/// - `path`: The path to check.
+///
+/// # Returns
+/// `true` for regular files (or symlinks to them), `false` for everything else.
+pub fn has_readable_contents(path: &Path) -> bool {
+    std::fs::metadata(path).is_ok_and(|metadata| metadata.is_file())
+}
+
+/// `FS_IOC_FIEMAP`, i.e. `_IOWR('f', 11, struct fiemap)`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const FS_IOC_FIEMAP: libc::c_ulong = 0xC020_660B;
+
+/// The extent is the file's last.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const FIEMAP_EXTENT_LAST: u32 = 0x1;
+
+/// The extent's data is encoded on disk (compressed, on btrfs).
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const FIEMAP_EXTENT_ENCODED: u32 = 0x8;
+
+/// Extents requested per `FS_IOC_FIEMAP` call.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const FIEMAP_BATCH: usize = 32;
+
+/// Batches read before giving up on a heavily fragmented file.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const FIEMAP_MAX_BATCHES: usize = 64;
+
+/// `struct fiemap_extent` from `linux/fiemap.h`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct FiemapExtent {
+    fe_logical: u64,
+    fe_physical: u64,
+    fe_length: u64,
+    fe_reserved64: [u64; 2],
+    fe_flags: u32,
+    fe_reserved: [u32; 3],
+}
+
+/// `struct fiemap` with room for one batch of extents.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[repr(C)]
+struct Fiemap {
+    fm_start: u64,
+    fm_length: u64,
+    fm_flags: u32,
+    fm_mapped_extents: u32,
+    fm_extent_count: u32,
+    fm_reserved: u32,
+    fm_extents: [FiemapExtent; FIEMAP_BATCH],
+}
+
+/// Checks whether any of a file's extents are stored compressed.
+///
+/// btrfs reports `st_blocks` before compression, so the allocated size can't
+/// show it there; FIEMAP flags compressed extents as `FIEMAP_EXTENT_ENCODED`
+/// instead (without their compressed length).
+///
+/// # Parameters
+/// - `path`: The regular file to inspect.
+///
+/// # Returns
+/// `true` if an encoded extent was found; `false` if none was, or FIEMAP is unsupported.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn has_compressed_extents(path: &Path) -> bool {
+    use std::os::fd::AsRawFd;
+
+    let Ok(file) = std::fs::File::open(path) else {
+        return false;
+    };
+
+    let mut start = 0;
+    for _ in 0..FIEMAP_MAX_BATCHES {
+        let mut map = Fiemap {
+            fm_start: start,
+            fm_length: u64::MAX - start,
+            fm_flags: 0,
+            fm_mapped_extents: 0,
+            fm_extent_count: FIEMAP_BATCH as u32,
+            fm_reserved: 0,
+            fm_extents: [FiemapExtent::default(); FIEMAP_BATCH],
+        };
+
+        if unsafe { libc::ioctl(file.as_raw_fd(), FS_IOC_FIEMAP as _, &mut map) } != 0 {
+            return false;
+        }
+
+        let extents = &map.fm_extents[..(map.fm_mapped_extents as usize).min(FIEMAP_BATCH)];
+        if extents
+            .iter()
+            .any(|extent| extent.fe_flags & FIEMAP_EXTENT_ENCODED != 0)
+        {
+            return true;
+        }
+
+        match extents.last() {
+            Some(last) if last.fe_flags & FIEMAP_EXTENT_LAST == 0 => {
+                start = last.fe_logical + last.fe_length;
+            }
+            _ => return false,
+        }
+    }
+
+    false
+}
+
+/// Checks whether any of a file's extents are stored compressed.
+///
+/// FIEMAP is Linux-only, so this platform can't tell.
+///
+/// # Parameters
+/// - `path`: The file (unused).
+///
+/// # Returns
+/// Always `false`.
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn has_compressed_extents(_path: &Path) -> bool {
+    false
+}
+
+/// How an entry takes part in the layering of an overlay filesystem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overlay {
+    /// Hides an entry of the layers below: a 0/0 character device (overlayfs)
+    /// or a `.wh.`-prefixed file (OCI image layers).
+    Whiteout,
+    /// A directory hiding everything the layers below hold at its path.
+    Opaque,
+}
+
+/// Prefix OCI image layers give whiteout files, after AUFS.
+pub(crate) const WHITEOUT_PREFIX: &str = ".wh.";
+
+/// Marker file making its directory opaque in an OCI image layer.
+pub(crate) const OPAQUE_MARKER: &str = ".wh..wh..opq";
+
+/// Classifies an entry of an overlayfs layer (an upper or lower directory, or an unpacked image layer).
+///
+/// The merged view never shows these, so they only turn up when a layer is
+/// listed directly, where whiteouts would otherwise look like stray devices.
+///
+/// # Parameters
+/// - `name`: The entry's file name.
+/// - `path`: The entry's full path, used to look for opaque markers.
+/// - `metadata`: The entry's `lstat` metadata.
+///
+/// # Returns
+/// The entry's overlay role, or `None` for ordinary entries.
+pub fn overlay(name: &str, path: &Path, metadata: &Metadata) -> Option<Overlay> {
+    if is_whiteout_name(name) || metadata.is_whiteout() {
+        return Some(Overlay::Whiteout);
+    }
+
+    let is_opaque = metadata.mode & libc::S_IFMT == libc::S_IFDIR
+        && (has_opaque_xattr(path) || path.join(OPAQUE_MARKER).symlink_metadata().is_ok());
+
+    is_opaque.then_some(Overlay::Opaque)
+}
+
+/// Checks whether a name marks an OCI image layer whiteout (`.wh.<name>`, or the `.wh..wh..opq` marker).
+///
+/// # Parameters
+/// - `name`: The entry's file name.
+///
+/// # Returns
+/// `true` if the name carries the whiteout prefix.
+pub fn is_whiteout_name(name: &str) -> bool {
+    name.starts_with(WHITEOUT_PREFIX)
+}
+
+/// Checks a directory for overlayfs's opaque attribute.
+///
+/// `trusted.overlay.opaque` is only readable with `CAP_SYS_ADMIN`;
+/// `user.overlay.opaque` is used by unprivileged (`userxattr`) mounts.
+///
+/// # Parameters
+/// - `path`: The directory to inspect (symlinks are not followed).
+///
+/// # Returns
+/// `true` if either attribute is set to `y`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn has_opaque_xattr(path: &Path) -> bool {
+    let Ok(c_path) = CString::new(path.as_os_str().as_bytes()) else {
+        return false;
+    };
+
+    [c"trusted.overlay.opaque", c"user.overlay.opaque"]
+        .iter()
+        .any(|attribute| {
+            let mut value = [0u8; 1];
+            let length = unsafe {
+                libc::lgetxattr(
+                    c_path.as_ptr(),
+                    attribute.as_ptr(),
+                    value.as_mut_ptr() as *mut libc::c_void,
+                    value.len(),
+                )
+            };
+            length == 1 && value[0] == b'y'
+        })
+}
+
+/// Checks a directory for overlayfs's opaque attribute.
+///
+/// overlayfs is Linux-only, so there's never one here.
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn has_opaque_xattr(_path: &Path) -> bool {
+    false
+}
+
+/// Checks whether an entry should be treated as hidden.
+///
+/// An entry is hidden if its name starts with `.` (the Unix convention) or if
+/// the platform marks it hidden natively (see [`is_platform_hidden`]).
+///
+/// # Parameters
+/// - `name`: The entry's file name.
+/// - `path`: The entry's full path, used for the platform flag lookup.
+///
+/// # Returns
+/// `true` if the entry is hidden.
+pub fn is_hidden(name: &str, path: &Path) -> bool {
+    name.starts_with('.') || is_platform_hidden(path)
+}
+
+/// Checks whether a path carries the platform's native "hidden" attribute.
+///
+/// macOS and FreeBSD mark hidden files with the `UF_HIDDEN` stat flag (set by
+/// `chflags hidden`), and Windows with `FILE_ATTRIBUTE_HIDDEN`. Other platforms
+/// have no such flag and rely solely on the dot-prefix convention.
+///
+/// # Parameters
+/// - `path`: The filesystem path to query (symlinks are not followed).
+///
+/// # Returns
+/// `true` if the platform flags the path as hidden.
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+pub fn is_platform_hidden(path: &Path) -> bool {
+    let Ok(c_path) = CString::new(path.as_os_str().as_bytes()) else {
+        return false;
+    };
+
+    unsafe {
+        let mut st: libc::stat = std::mem::zeroed();
+        libc::lstat(c_path.as_ptr(), &mut st) == 0 && st.st_flags & libc::UF_HIDDEN != 0
+    }
+}
+
+/// Checks whether a path carries the platform's native "hidden" attribute.
+///
+/// # Parameters
+/// - `path`: The filesystem path to query (symlinks are not followed).
+///
+/// # Returns
+/// `true` if the path has `FILE_ATTRIBUTE_HIDDEN` set.
+#[cfg(windows)]
+pub fn is_platform_hidden(path: &Path) -> bool {
+    use std::os::windows::fs::MetadataExt;
+    const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+
+    std::fs::symlink_metadata(path)
+        .map(|metadata| metadata.file_attributes() & FILE_ATTRIBUTE_HIDDEN != 0)
+        .unwrap_or(false)
+}
+
+/// Checks whether a path carries the platform's native "hidden" attribute.
+///
+/// This platform has no hidden flag, so only dot-prefixed names are hidden.
+///
+/// # Parameters
+/// - `path`: The filesystem path (unused).
+///
+/// # Returns
+/// Always `false`.
+#[cfg(not(any(target_os = "macos", target_os = "ios", target_os = "freebsd", windows)))]
+pub fn is_platform_hidden(_path: &Path) -> bool {
+    false
 }
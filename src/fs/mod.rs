@@ -24,16 +24,36 @@ SOFTWARE.
 
 pub(crate) mod acl;
 pub mod cache;
+pub mod clock;
+#[cfg(feature = "collate")]
+pub mod collate;
 pub(crate) mod context;
+pub mod dedupe;
+pub mod descend;
+pub mod describe;
 pub mod dir;
 pub mod entry;
-pub(crate) mod feature;
+pub mod feature;
+pub mod git;
+pub mod gitignore;
 pub mod glob;
+pub mod highlight;
 pub mod hyperlink;
 pub mod metadata;
-pub(crate) mod mountpoint;
+pub mod mountpoint;
+pub mod names;
+pub mod network;
+pub mod notes;
+#[cfg(feature = "oci")]
+pub mod oci;
 pub mod permissions;
+pub mod preview;
 pub mod search;
+pub mod sniff;
+pub mod store;
 pub mod symlink;
+pub mod trash;
 pub mod tree;
+pub mod vfs;
+pub mod watch;
 pub(crate) mod xattr;
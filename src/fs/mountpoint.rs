@@ -22,6 +22,7 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, OnceLock};
@@ -29,6 +30,100 @@ use std::sync::{Arc, OnceLock};
 /// Global cache of mount points parsed from /proc/mounts
 static MOUNT_POINTS: OnceLock<Vec<(PathBuf, String)>> = OnceLock::new();
 
+/// Global cache of each mount point's device number, parsed from /proc/self/mountinfo
+static MOUNT_DEVICES: OnceLock<HashMap<PathBuf, u64>> = OnceLock::new();
+
+/// Filesystem types served over the network, where a stat can hang on a dead server.
+const NETWORK_FS_TYPES: &[&str] = &[
+    "nfs",
+    "nfs4",
+    "cifs",
+    "smb3",
+    "smbfs",
+    "ncpfs",
+    "afs",
+    "9p",
+    "ceph",
+    "glusterfs",
+    "lustre",
+    "davfs",
+    "fuse.sshfs",
+    "fuse.rclone",
+    "fuse.s3fs",
+];
+
+/// Kernel pseudo-filesystem types whose contents aren't real files.
+const PSEUDO_FS_TYPES: &[&str] = &[
+    "proc",
+    "sysfs",
+    "devtmpfs",
+    "devpts",
+    "cgroup",
+    "cgroup2",
+    "securityfs",
+    "debugfs",
+    "tracefs",
+    "pstore",
+    "bpf",
+    "mqueue",
+    "hugetlbfs",
+    "configfs",
+    "fusectl",
+    "binfmt_misc",
+    "autofs",
+];
+
+/// `statfs` magic number identifying btrfs.
+#[cfg(all(feature = "volumes", any(target_os = "linux", target_os = "android")))]
+const BTRFS_SUPER_MAGIC: u32 = 0x9123_683E;
+
+/// Inode number btrfs gives the root directory of every subvolume.
+#[cfg(all(feature = "volumes", any(target_os = "linux", target_os = "android")))]
+const BTRFS_FIRST_FREE_OBJECTID: u64 = 256;
+
+/// `BTRFS_IOC_GET_SUBVOL_INFO`, i.e. `_IOR(0x94, 60, struct btrfs_ioctl_get_subvol_info_args)`.
+#[cfg(all(feature = "volumes", any(target_os = "linux", target_os = "android")))]
+const BTRFS_IOC_GET_SUBVOL_INFO: libc::c_ulong = 0x81F8_943C;
+
+/// The leading fields of `struct btrfs_ioctl_get_subvol_info_args` from
+/// `linux/btrfs.h`, padded out to its full 504 bytes.
+#[cfg(all(feature = "volumes", any(target_os = "linux", target_os = "android")))]
+#[repr(C)]
+struct SubvolInfo {
+    treeid: u64,
+    name: [u8; 256],
+    parent_id: u64,
+    dirid: u64,
+    generation: u64,
+    flags: u64,
+    uuid: [u8; 16],
+    parent_uuid: [u8; 16],
+    rest: [u8; 176],
+}
+
+/// A directory that roots its own btrfs subvolume or ZFS dataset.
+#[cfg(feature = "volumes")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Volume {
+    BtrfsSubvolume,
+    BtrfsSnapshot,
+    ZfsDataset,
+    ZfsSnapshot,
+}
+
+#[cfg(feature = "volumes")]
+impl Volume {
+    /// Returns the short label shown in the Volume column and grid badge.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::BtrfsSubvolume => "btrfs subvol",
+            Self::BtrfsSnapshot => "btrfs snapshot",
+            Self::ZfsDataset => "zfs dataset",
+            Self::ZfsSnapshot => "zfs snapshot",
+        }
+    }
+}
+
 /// Resolves the filesystem mount point for a given path via `/proc/mounts`.
 pub struct Mountpoint;
 
@@ -49,6 +144,167 @@ impl Mountpoint {
         }
     }
 
+    /// Finds the network mount (NFS, SMB, sshfs, ...) containing a path, if any.
+    ///
+    /// The path is resolved lexically rather than canonicalised, so this never
+    /// touches the (possibly unresponsive) filesystem itself.
+    ///
+    /// # Parameters
+    /// - `path`: The file or directory path to check.
+    ///
+    /// # Returns
+    /// The mount point path if it is a network filesystem, otherwise `None`.
+    pub(crate) fn network_mount(path: &Path) -> Option<&'static Path> {
+        let mounts = MOUNT_POINTS.get_or_init(|| Self::parse_mounts().unwrap_or_default());
+        let absolute_path = std::path::absolute(path).ok()?;
+
+        let (mount_path, fs_type) = mounts
+            .iter()
+            .find(|(mount_path, _)| absolute_path.starts_with(mount_path))?;
+
+        NETWORK_FS_TYPES
+            .contains(&fs_type.as_str())
+            .then_some(mount_path.as_path())
+    }
+
+    /// Returns the device number (`st_dev`) of the filesystem mounted at a path.
+    ///
+    /// Read from `/proc/self/mountinfo`, so this never touches the filesystem itself.
+    ///
+    /// # Parameters
+    /// - `mount_path`: A mount point, e.g. from [`Mountpoint::network_mount`].
+    ///
+    /// # Returns
+    /// The device number, or `None` if the mount isn't listed.
+    pub(crate) fn device(mount_path: &Path) -> Option<u64> {
+        MOUNT_DEVICES
+            .get_or_init(Self::parse_devices)
+            .get(mount_path)
+            .copied()
+    }
+
+    /// Checks whether a path is the mount point of a kernel pseudo-filesystem (proc, sysfs, ...).
+    ///
+    /// # Parameters
+    /// - `path`: The absolute directory path to check.
+    ///
+    /// # Returns
+    /// `true` if a pseudo-filesystem is mounted exactly at `path`.
+    pub(crate) fn is_pseudo_mount(path: &Path) -> bool {
+        let mounts = MOUNT_POINTS.get_or_init(|| Self::parse_mounts().unwrap_or_default());
+
+        mounts.iter().any(|(mount_path, fs_type)| {
+            mount_path == path && PSEUDO_FS_TYPES.contains(&fs_type.as_str())
+        })
+    }
+
+    /// Detects whether a directory is a btrfs subvolume or ZFS dataset (or a snapshot of either).
+    ///
+    /// ZFS datasets are found through `/proc/mounts` and snapshots by their place
+    /// under `.zfs/snapshot`; btrfs subvolumes by their root inode number, with
+    /// `BTRFS_IOC_GET_SUBVOL_INFO` telling snapshots (which have a parent) apart.
+    ///
+    /// # Parameters
+    /// - `path`: The directory to probe.
+    ///
+    /// # Returns
+    /// The kind of volume rooted at `path`, or `None` for an ordinary directory.
+    #[cfg(feature = "volumes")]
+    pub fn volume(path: &Path) -> Option<Volume> {
+        let mounts = MOUNT_POINTS.get_or_init(|| Self::parse_mounts().unwrap_or_default());
+        let canonical_path = path.canonicalize().ok()?;
+
+        if Self::is_zfs_snapshot_path(&canonical_path) {
+            return Some(Volume::ZfsSnapshot);
+        }
+
+        let is_zfs_mount = mounts
+            .iter()
+            .any(|(mount_path, fs_type)| mount_path == &canonical_path && fs_type == "zfs");
+        if is_zfs_mount {
+            return Some(Volume::ZfsDataset);
+        }
+
+        Self::btrfs_volume(&canonical_path)
+    }
+
+    /// Checks whether a path names a snapshot in a dataset's `.zfs/snapshot` directory.
+    ///
+    /// # Parameters
+    /// - `path`: The canonical directory path to check.
+    ///
+    /// # Returns
+    /// `true` if `path` is of the form `<dataset>/.zfs/snapshot/<name>`.
+    #[cfg(feature = "volumes")]
+    pub fn is_zfs_snapshot_path(path: &Path) -> bool {
+        let Some(snapshot_dir) = path.parent() else {
+            return false;
+        };
+
+        path.file_name().is_some()
+            && snapshot_dir
+                .file_name()
+                .is_some_and(|name| name == "snapshot")
+            && snapshot_dir
+                .parent()
+                .and_then(Path::file_name)
+                .is_some_and(|name| name == ".zfs")
+    }
+
+    /// Probes a directory for a btrfs subvolume root.
+    ///
+    /// # Parameters
+    /// - `path`: The canonical directory path to probe.
+    ///
+    /// # Returns
+    /// The subvolume kind, or `None` if `path` isn't a subvolume root on btrfs.
+    #[cfg(all(feature = "volumes", any(target_os = "linux", target_os = "android")))]
+    fn btrfs_volume(path: &Path) -> Option<Volume> {
+        use std::os::fd::AsRawFd;
+        use std::os::unix::fs::MetadataExt;
+
+        if fs::symlink_metadata(path).ok()?.ino() != BTRFS_FIRST_FREE_OBJECTID {
+            return None;
+        }
+
+        let directory = fs::File::open(path).ok()?;
+
+        let mut stat: libc::statfs = unsafe { std::mem::zeroed() };
+        if unsafe { libc::fstatfs(directory.as_raw_fd(), &mut stat) } != 0
+            || stat.f_type as u32 != BTRFS_SUPER_MAGIC
+        {
+            return None;
+        }
+
+        // Kernels before 4.18 lack the ioctl, which still leaves a plain subvolume
+        let mut info: SubvolInfo = unsafe { std::mem::zeroed() };
+        let has_parent = unsafe {
+            libc::ioctl(
+                directory.as_raw_fd(),
+                BTRFS_IOC_GET_SUBVOL_INFO as _,
+                &mut info,
+            )
+        } == 0
+            && info.parent_uuid.iter().any(|&byte| byte != 0);
+
+        Some(if has_parent {
+            Volume::BtrfsSnapshot
+        } else {
+            Volume::BtrfsSubvolume
+        })
+    }
+
+    /// Probes a directory for a btrfs subvolume root.
+    ///
+    /// btrfs is Linux-only, so there's never one here.
+    #[cfg(all(
+        feature = "volumes",
+        not(any(target_os = "linux", target_os = "android"))
+    ))]
+    fn btrfs_volume(_path: &Path) -> Option<Volume> {
+        None
+    }
+
     /// Parses `/proc/mounts` to extract all mount points.
     ///
     /// # Returns
@@ -73,11 +329,41 @@ impl Mountpoint {
             .collect();
 
         // Sort by path length (longest first) to ensure we match the most specific mount
+        #[allow(clippy::unnecessary_sort_by)]
         mounts.sort_by(|a, b| b.0.as_os_str().len().cmp(&a.0.as_os_str().len()));
 
         Ok(mounts)
     }
 
+    /// Parses `/proc/self/mountinfo` into each mount point's device number.
+    ///
+    /// Each line reads `id parent major:minor root mount_point ...`; where a path
+    /// is mounted over, the last (topmost) mount wins.
+    ///
+    /// # Returns
+    /// Device numbers keyed by mount point, empty if the file cannot be read.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn parse_devices() -> HashMap<PathBuf, u64> {
+        let content = fs::read_to_string("/proc/self/mountinfo").unwrap_or_default();
+
+        content
+            .lines()
+            .filter_map(|line| {
+                let parts: Vec<&str> = line.split_whitespace().collect();
+                let (major, minor) = parts.get(2)?.split_once(':')?;
+                let mount_path = Self::unescape_mount_path(parts.get(4)?);
+                let dev = libc::makedev(major.parse().ok()?, minor.parse().ok()?);
+                Some((PathBuf::from(mount_path), dev))
+            })
+            .collect()
+    }
+
+    /// Parses mount device numbers; there is no `/proc/self/mountinfo` here.
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    fn parse_devices() -> HashMap<PathBuf, u64> {
+        HashMap::new()
+    }
+
     /// Unescapes octal sequences in mount point paths from `/proc/mounts`.
     ///
     /// # Parameters
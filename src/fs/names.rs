@@ -0,0 +1,119 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Filename checks for `--validate-names`.
+//!
+//! Flags names that can mislead a human reading a listing: embedded control
+//! characters, bidirectional overrides (the "RTLO" trick that makes
+//! `invoice<U+202E>fdp.exe` render as `invoiceexe.pdf`), letters from several scripts
+//! that look alike (Latin `a` next to Cyrillic `а`), and trailing whitespace.
+
+/// A suspicious property of a filename.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameIssue {
+    ControlCharacter,
+    BidiOverride,
+    MixedScript,
+    TrailingSpace,
+}
+
+impl NameIssue {
+    /// Returns the short label shown in the name check column.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::ControlCharacter => "control character",
+            Self::BidiOverride => "bidi override",
+            Self::MixedScript => "mixed script",
+            Self::TrailingSpace => "trailing space",
+        }
+    }
+
+    /// Checks a filename for suspicious characters.
+    ///
+    /// # Parameters
+    /// - `name`: The filename to check.
+    ///
+    /// # Returns
+    /// Every issue found, in a fixed order; empty if the name looks harmless.
+    pub fn check(name: &str) -> Vec<Self> {
+        let mut issues = Vec::new();
+
+        if name.chars().any(char::is_control) {
+            issues.push(Self::ControlCharacter);
+        }
+        if name.chars().any(Self::is_bidi_control) {
+            issues.push(Self::BidiOverride);
+        }
+        if Self::is_mixed_script(name) {
+            issues.push(Self::MixedScript);
+        }
+        if name.ends_with(char::is_whitespace) {
+            issues.push(Self::TrailingSpace);
+        }
+
+        issues
+    }
+
+    /// Checks whether a character changes the direction text is rendered in.
+    ///
+    /// # Parameters
+    /// - `character`: The character to check.
+    pub(crate) fn is_bidi_control(character: char) -> bool {
+        matches!(
+            character,
+            '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+        )
+    }
+
+    /// Checks whether any word of a name mixes Latin, Greek, and Cyrillic letters.
+    ///
+    /// These three share many identical-looking glyphs, which is what makes
+    /// confusable names possible. Words are checked separately so that a
+    /// Cyrillic name with a Latin extension (`отчёт.txt`) isn't flagged;
+    /// letters from other scripts are ignored.
+    ///
+    /// # Parameters
+    /// - `name`: The filename to check.
+    fn is_mixed_script(name: &str) -> bool {
+        name.split(|character: char| !character.is_alphanumeric())
+            .any(|word| {
+                let (mut latin, mut greek, mut cyrillic) = (false, false, false);
+
+                for character in word.chars() {
+                    match character {
+                        'a'..='z' | 'A'..='Z' | '\u{00C0}'..='\u{024F}' => latin = true,
+                        '\u{0370}'..='\u{03FF}' => greek = true,
+                        '\u{0400}'..='\u{052F}' => cyrillic = true,
+                        _ => {}
+                    }
+                }
+
+                [latin, greek, cyrillic]
+                    .iter()
+                    .filter(|&&seen| seen)
+                    .count()
+                    > 1
+            })
+    }
+}
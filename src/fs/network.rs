@@ -0,0 +1,223 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+use crate::cli::settings::Settings;
+use crate::fs::metadata::Metadata;
+use crate::fs::mountpoint::Mountpoint;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, mpsc};
+use std::thread;
+use std::time::Duration;
+
+/// How long a single lookup on a network mount may take before its device is given up on.
+const LOOKUP_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Worker threads per network device, so a big directory's lookups still overlap.
+const WORKERS_PER_DEVICE: usize = 4;
+
+/// A lookup queued for a device's workers.
+type Job = Box<dyn FnOnce() + Send>;
+
+static SKIP_NETWORK_METADATA: AtomicBool = AtomicBool::new(false);
+
+/// Devices that stopped answering; lookups on them fail straight away from then on.
+static UNRESPONSIVE: Mutex<BTreeSet<u64>> = Mutex::new(BTreeSet::new());
+
+/// The job queue feeding each network device's workers.
+static QUEUES: Mutex<BTreeMap<u64, mpsc::Sender<Job>>> = Mutex::new(BTreeMap::new());
+
+/// Global toggle controlling how metadata is loaded on network filesystems.
+pub struct NetworkSettings;
+
+impl NetworkSettings {
+    /// Configures network metadata handling at startup.
+    ///
+    /// # Parameters
+    /// - `skip`: If `true`, metadata lookups on network mounts are skipped entirely.
+    pub fn setup(skip: bool) {
+        SKIP_NETWORK_METADATA.store(skip, Ordering::SeqCst);
+    }
+
+    /// Returns whether metadata lookups on network mounts are skipped.
+    pub(crate) fn is_skipping() -> bool {
+        Settings::get(|settings| settings.skip_network_metadata)
+            .unwrap_or_else(|| SKIP_NETWORK_METADATA.load(Ordering::SeqCst))
+    }
+}
+
+/// Runs filesystem lookups for network mounts on a few long-lived workers per device.
+///
+/// A lookup that doesn't answer in time trips the device's circuit breaker:
+/// its worker stays blocked in the kernel until the mount recovers, and every
+/// later lookup on that device fails at once instead of queueing behind it.
+pub struct RemoteLookup;
+
+impl RemoteLookup {
+    /// Runs a lookup on a device's workers, waiting at most `timeout` for it.
+    ///
+    /// # Parameters
+    /// - `dev`: The device (`st_dev`) the lookup touches, which the breaker is keyed by.
+    /// - `mount`: Where the device is mounted, for the warning.
+    /// - `timeout`: How long to wait before marking the device unresponsive.
+    /// - `lookup`: The lookup itself.
+    ///
+    /// # Returns
+    /// The lookup's result, or a [`io::ErrorKind::TimedOut`] error if it timed
+    /// out now or the device already had.
+    pub fn run<T: Send + 'static>(
+        dev: u64,
+        mount: &Path,
+        timeout: Duration,
+        lookup: impl FnOnce() -> io::Result<T> + Send + 'static,
+    ) -> io::Result<T> {
+        if Self::is_unresponsive(dev) {
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "network mount not responding",
+            ));
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let job: Job = Box::new(move || {
+            let _ = sender.send(lookup());
+        });
+        if Self::queue(dev).send(job).is_err() {
+            return Err(io::Error::other("network lookup workers are gone"));
+        }
+
+        match receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(_) => {
+                let newly = UNRESPONSIVE
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .insert(dev);
+                if newly {
+                    eprintln!(
+                        "warning: network mount at '{}' is not responding (showing placeholders)",
+                        mount.display()
+                    );
+                }
+                Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "network lookup timed out",
+                ))
+            }
+        }
+    }
+
+    /// Checks whether a device has tripped the breaker.
+    ///
+    /// # Parameters
+    /// - `dev`: The device number.
+    pub fn is_unresponsive(dev: u64) -> bool {
+        UNRESPONSIVE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains(&dev)
+    }
+
+    /// Returns the job queue for a device, starting its workers on first use.
+    ///
+    /// The workers share one queue and park on it between lookups.
+    ///
+    /// # Parameters
+    /// - `dev`: The device number.
+    fn queue(dev: u64) -> mpsc::Sender<Job> {
+        let mut queues = QUEUES.lock().unwrap_or_else(PoisonError::into_inner);
+
+        queues
+            .entry(dev)
+            .or_insert_with(|| {
+                let (sender, receiver) = mpsc::channel::<Job>();
+                let receiver = Arc::new(Mutex::new(receiver));
+
+                for _ in 0..WORKERS_PER_DEVICE {
+                    let receiver = Arc::clone(&receiver);
+                    thread::spawn(move || {
+                        loop {
+                            // The lock is released before the job runs, so a hung job holds up only its worker
+                            let job = receiver
+                                .lock()
+                                .unwrap_or_else(PoisonError::into_inner)
+                                .recv();
+                            match job {
+                                Ok(job) => job(),
+                                Err(_) => break,
+                            }
+                        }
+                    });
+                }
+
+                sender
+            })
+            .clone()
+    }
+}
+
+/// Runs a lookup on a path, bounding the time spent on network mounts.
+///
+/// Paths outside network mounts are looked up directly. On network mounts the
+/// lookup goes through [`RemoteLookup`], so a dead server costs one
+/// [`LOOKUP_TIMEOUT`] and a one-time warning, and is skipped after that.
+///
+/// # Parameters
+/// - `path`: The filesystem path to query.
+/// - `lookup`: The lookup to run on `path`.
+///
+/// # Returns
+/// The lookup's result, or an error if it was skipped or timed out.
+pub(crate) fn bounded<T: Send + 'static>(
+    path: &Path,
+    lookup: impl FnOnce(&Path) -> io::Result<T> + Send + 'static,
+) -> io::Result<T> {
+    let Some(mount) = Mountpoint::network_mount(path) else {
+        return lookup(path);
+    };
+
+    if NetworkSettings::is_skipping() {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "lookup skipped on network mount",
+        ));
+    }
+
+    let dev = Mountpoint::device(mount).unwrap_or_default();
+    let path = path.to_path_buf();
+    RemoteLookup::run(dev, mount, LOOKUP_TIMEOUT, move || lookup(&path))
+}
+
+/// Loads metadata for a path, bounding the time spent on network mounts (see [`bounded`]).
+///
+/// # Parameters
+/// - `path`: The filesystem path to query.
+/// - `dereference`: If `true`, follows symlinks (stat); otherwise uses lstat.
+///
+/// # Returns
+/// The loaded [`Metadata`], or an error if the lookup was skipped, timed out, or failed.
+pub(crate) fn metadata(path: &Path, dereference: bool) -> io::Result<Metadata> {
+    bounded(path, move |path| Metadata::load(path, dereference))
+}
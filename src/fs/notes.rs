@@ -0,0 +1,125 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Per-directory annotations read from a `.ce-notes.toml` sidecar file.
+//!
+//! The file maps filenames in the same directory to short notes:
+//!
+//! ```toml
+//! "backup.tar" = "do not delete"
+//! "old-api" = "legacy, kept for v1 clients"
+//! ```
+//!
+//! Anything that isn't a string value is ignored, and an oversized or
+//! malformed file is treated as having no notes at all. Notes are returned as
+//! written; control and bidi characters are escaped like every other cell
+//! value, by [`Escape`](crate::display::output::escape::Escape).
+
+use crate::display::layout::width::Width;
+use crate::fs::cache::Cache;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+/// The sidecar filename looked up in each listed directory.
+pub const NOTES_FILE: &str = ".ce-notes.toml";
+
+/// Notes files larger than this are ignored rather than parsed.
+const MAX_FILE_SIZE: u64 = 64 * 1024;
+
+/// Notes wider than this many terminal columns are cut short with an ellipsis.
+const MAX_NOTE_WIDTH: usize = 40;
+
+/// Looks up notes for entries from their directory's `.ce-notes.toml`.
+pub struct Notes;
+
+impl Notes {
+    /// Returns the note for an entry, if its directory has one for it.
+    ///
+    /// # Parameters
+    /// - `path`: The entry's path.
+    ///
+    /// # Returns
+    /// The (trimmed, width-capped) note, or `"-"` if there is none.
+    pub fn get(path: &Path) -> Arc<str> {
+        let (Some(dir), Some(name)) = (path.parent(), path.file_name()) else {
+            return "-".into();
+        };
+
+        let notes = Cache::notes(dir, || Self::load(dir));
+
+        notes
+            .get(name.to_string_lossy().as_ref())
+            .cloned()
+            .unwrap_or_else(|| "-".into())
+    }
+
+    /// Reads and parses a directory's notes file.
+    ///
+    /// # Parameters
+    /// - `dir`: The directory to read `.ce-notes.toml` from.
+    ///
+    /// # Returns
+    /// A map of filename to note; empty if the file is missing, too large, or invalid.
+    fn load(dir: &Path) -> HashMap<String, Arc<str>> {
+        let path = dir.join(NOTES_FILE);
+
+        let too_large = fs::metadata(&path).map_or(true, |metadata| {
+            !metadata.is_file() || metadata.len() > MAX_FILE_SIZE
+        });
+        if too_large {
+            return HashMap::new();
+        }
+
+        let Ok(table) = fs::read_to_string(&path)
+            .map_err(|_| ())
+            .and_then(|content| toml::from_str::<toml::Table>(&content).map_err(|_| ()))
+        else {
+            return HashMap::new();
+        };
+
+        table
+            .into_iter()
+            .filter_map(|(name, value)| {
+                let note = Self::truncate(value.as_str()?.trim());
+                (!note.is_empty()).then(|| (name, note.into()))
+            })
+            .collect()
+    }
+
+    /// Caps a note at [`MAX_NOTE_WIDTH`] columns, ending a shortened one with `…`.
+    ///
+    /// Widths are measured like every other cell, so wide characters such as
+    /// CJK count as two columns.
+    ///
+    /// # Parameters
+    /// - `note`: The note text.
+    ///
+    /// # Returns
+    /// The note, shortened if needed.
+    pub fn truncate(note: &str) -> String {
+        Width::truncate_ansi(note, MAX_NOTE_WIDTH)
+    }
+}
@@ -0,0 +1,766 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Merged filesystem of an OCI or Docker image tarball, for `--oci`.
+//!
+//! `docker save` and `oci-archive:` tarballs both hold each layer as a nested
+//! tarball. [`Image::open`] indexes the outer archive, takes the layer order from
+//! `manifest.json` (Docker) or `index.json` (OCI layout), and replays the layers
+//! bottom to top, applying `.wh.` whiteouts and `.wh..wh..opq` opaque markers the
+//! way overlayfs would. The result implements [`Fs`], so it is listed and
+//! rendered like any directory on disk.
+//!
+//! Compressed layers are piped through `gzip -dc` or `zstd -dc`.
+
+use crate::cli::args::Args;
+use crate::fs::metadata::{self, Metadata, OPAQUE_MARKER, WHITEOUT_PREFIX};
+use crate::fs::symlink;
+use crate::fs::tree::{TreeBuilder, TreeNode};
+use crate::fs::vfs::{DirItem, Fs};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+use std::os::unix::fs::MetadataExt;
+use std::path::{Component, Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Size of a tar header and of the blocks member data is padded to.
+const BLOCK: u64 = 512;
+
+/// Leading bytes of a gzip stream.
+const GZIP_MAGIC: &[u8] = &[0x1F, 0x8B];
+
+/// Leading bytes of a zstd frame.
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xB5, 0x2F, 0xFD];
+
+/// How deep `index.json` may nest image indexes before a manifest is expected.
+const MAX_INDEX_DEPTH: usize = 4;
+
+/// One image of a `docker save` `manifest.json`.
+#[derive(Deserialize)]
+struct DockerManifest {
+    /// Layer tarballs, bottom first.
+    #[serde(rename = "Layers")]
+    layers: Vec<String>,
+}
+
+/// An OCI image index or image manifest (the fields of both that matter here).
+#[derive(Deserialize)]
+struct OciManifest {
+    /// Manifests of an image index, one per platform.
+    #[serde(default)]
+    manifests: Vec<Descriptor>,
+    /// Layers of an image manifest, bottom first.
+    #[serde(default)]
+    layers: Vec<Descriptor>,
+}
+
+/// A reference to a content-addressed blob.
+#[derive(Deserialize)]
+struct Descriptor {
+    /// The blob's digest, e.g. `sha256:…`.
+    digest: String,
+}
+
+/// A member of a tar archive, read from its header (and any PAX or GNU long-name records).
+#[derive(Debug)]
+struct Member {
+    path: String,
+    link: String,
+    kind: u8,
+    mode: u32,
+    uid: u32,
+    gid: u32,
+    size: u64,
+    mtime: i64,
+    rdev: u64,
+}
+
+/// A sequential tar reader that understands ustar, GNU long names and PAX headers.
+struct TarReader<R: Read> {
+    reader: R,
+    /// Bytes consumed so far, so callers can note where member data starts.
+    position: u64,
+    /// Data (plus padding) of the last member still to be skipped.
+    pending: u64,
+}
+
+impl<R: Read> TarReader<R> {
+    /// Wraps a reader positioned at the start of an archive.
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            position: 0,
+            pending: 0,
+        }
+    }
+
+    /// Reads the next member's header, skipping the data of the previous one.
+    ///
+    /// # Returns
+    /// The member, `None` at the end of the archive, or an I/O error for a truncated
+    /// or corrupt archive.
+    fn next_member(&mut self) -> io::Result<Option<Member>> {
+        let mut long_path = None;
+        let mut long_link = None;
+        let mut pax = HashMap::new();
+
+        loop {
+            self.skip(self.pending)?;
+            self.pending = 0;
+
+            let mut header = [0u8; BLOCK as usize];
+            match self.reader.read_exact(&mut header) {
+                Ok(()) => self.position += BLOCK,
+                // Some writers stop without the closing zero blocks
+                Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+                Err(error) => return Err(error),
+            }
+            if header.iter().all(|&byte| byte == 0) {
+                return Ok(None);
+            }
+
+            let size = parse_number(&header[124..136])?;
+            self.pending = size.div_ceil(BLOCK) * BLOCK;
+
+            match header[156] {
+                b'L' => long_path = Some(self.read_text(size)?),
+                b'K' => long_link = Some(self.read_text(size)?),
+                b'x' => pax = parse_pax(&self.read_text(size)?),
+                b'g' => {}
+                kind => {
+                    let mut member = Member {
+                        path: header_path(&header),
+                        link: field(&header[157..257]),
+                        kind,
+                        mode: parse_number(&header[100..108])? as u32,
+                        uid: parse_number(&header[108..116])? as u32,
+                        gid: parse_number(&header[116..124])? as u32,
+                        size,
+                        mtime: parse_number(&header[136..148])? as i64,
+                        rdev: libc::makedev(
+                            parse_number(&header[329..337])? as u32,
+                            parse_number(&header[337..345])? as u32,
+                        ),
+                    };
+
+                    if let Some(path) = pax.remove("path").or(long_path) {
+                        member.path = path;
+                    }
+                    if let Some(link) = pax.remove("linkpath").or(long_link) {
+                        member.link = link;
+                    }
+                    if let Some(size) = pax.get("size").and_then(|size| size.parse().ok()) {
+                        member.size = size;
+                        self.pending = size.div_ceil(BLOCK) * BLOCK;
+                    }
+                    if let Some(mtime) = pax.get("mtime").and_then(|mtime| pax_seconds(mtime)) {
+                        member.mtime = mtime;
+                    }
+
+                    return Ok(Some(member));
+                }
+            }
+        }
+    }
+
+    /// Reads the data of an extended header record as text.
+    ///
+    /// # Parameters
+    /// - `size`: The record's length in bytes.
+    fn read_text(&mut self, size: u64) -> io::Result<String> {
+        let mut data = Vec::new();
+        (&mut self.reader).take(size).read_to_end(&mut data)?;
+        if data.len() as u64 != size {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        self.position += size;
+        self.pending -= size;
+        Ok(field(&data))
+    }
+
+    /// Discards bytes from the reader.
+    ///
+    /// # Parameters
+    /// - `count`: How many bytes to discard.
+    fn skip(&mut self, count: u64) -> io::Result<()> {
+        let skipped = io::copy(&mut (&mut self.reader).take(count), &mut io::sink())?;
+        if skipped != count {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        self.position += count;
+        Ok(())
+    }
+}
+
+/// The merged filesystem of an image, rooted at the tarball's own path.
+#[derive(Debug)]
+pub struct Image {
+    root: PathBuf,
+    /// Nodes by path relative to the image root (the root itself is the empty path).
+    nodes: BTreeMap<PathBuf, Node>,
+    /// The inode number given to the next node created.
+    next_ino: u64,
+}
+
+/// A file, directory, symlink or special file in the merged image.
+#[derive(Debug, Clone)]
+struct Node {
+    metadata: Metadata,
+    /// The symlink target, for symlinks.
+    link: Option<PathBuf>,
+}
+
+impl Image {
+    /// Reads an image tarball and merges its layers.
+    ///
+    /// # Parameters
+    /// - `path`: The tarball written by `docker save` or an OCI `oci-archive`.
+    ///
+    /// # Returns
+    /// The merged image, or an I/O error if the tarball can't be read, isn't an
+    /// image, or a layer is corrupt.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let mut file = File::open(path)?;
+        let members = Self::index(&mut file)?;
+
+        let mut image = Self {
+            root: path.to_path_buf(),
+            nodes: BTreeMap::new(),
+            next_ino: 1,
+        };
+        let mtime = file.metadata().map_or(0, |metadata| metadata.mtime());
+        let root = image.directory(mtime);
+        image.nodes.insert(PathBuf::new(), root);
+
+        for layer in Self::layers(&mut file, &members)? {
+            let &(offset, size) = members
+                .get(&layer)
+                .ok_or_else(|| invalid(format!("layer '{}' is missing from the archive", layer)))?;
+            image.apply(Self::read_layer(&file, offset, size)?);
+        }
+
+        Ok(image)
+    }
+
+    /// Builds the image's tree for the tree renderer.
+    ///
+    /// Symlinks carry their targets in their names under `--long`, read from the
+    /// image rather than the disk.
+    ///
+    /// # Parameters
+    /// - `args`: CLI arguments controlling filters, metadata, and sorting.
+    ///
+    /// # Returns
+    /// The root [`TreeNode`], named after the tarball.
+    pub fn tree(&self, args: &Args) -> TreeNode {
+        let mut node = TreeBuilder::new(self.root.clone()).build_in(self, args);
+        if args.long {
+            self.name_link_targets(&mut node);
+        }
+        node
+    }
+
+    /// Appends ` -> target` to the names of the symlinks in a tree.
+    ///
+    /// # Parameters
+    /// - `node`: The subtree to update.
+    fn name_link_targets(&self, node: &mut TreeNode) {
+        if node.entry.is_symlink()
+            && let Some(target) = self
+                .node(node.entry.path())
+                .and_then(|node| node.link.as_ref())
+        {
+            let name = node
+                .entry
+                .path()
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy();
+            let name = symlink::format_symlink(&name, &target.to_string_lossy());
+            node.entry.set_name(name.into());
+        }
+
+        for child in &mut node.children {
+            self.name_link_targets(child);
+        }
+    }
+
+    /// Looks up the node for a path below the image root.
+    ///
+    /// # Parameters
+    /// - `path`: A path starting with the tarball's path.
+    fn node(&self, path: &Path) -> Option<&Node> {
+        self.nodes.get(path.strip_prefix(&self.root).ok()?)
+    }
+
+    /// Finds where each member of the outer archive keeps its data.
+    ///
+    /// # Parameters
+    /// - `file`: The image tarball.
+    ///
+    /// # Returns
+    /// The data offset and size of every regular file, by normalised path.
+    fn index(file: &mut File) -> io::Result<HashMap<String, (u64, u64)>> {
+        let mut head = [0u8; 4];
+        let read = file.read(&mut head)?;
+        if head[..read].starts_with(GZIP_MAGIC) || head[..read].starts_with(ZSTD_MAGIC) {
+            return Err(invalid(
+                "compressed image archives aren't supported; decompress it first".to_string(),
+            ));
+        }
+        file.rewind()?;
+
+        let mut members = HashMap::new();
+        let mut tar = TarReader::new(BufReader::new(&*file));
+        while let Some(member) = tar.next_member()? {
+            if matches!(member.kind, b'0' | 0 | b'7') {
+                let path = normalise(&member.path).to_string_lossy().into_owned();
+                members.insert(path, (tar.position, member.size));
+            }
+        }
+
+        Ok(members)
+    }
+
+    /// Reads the layer list, bottom first, from the archive's manifest.
+    ///
+    /// Only the first image of a multi-image `manifest.json` is used, and only the
+    /// first manifest of an OCI image index.
+    ///
+    /// # Parameters
+    /// - `file`: The image tarball.
+    /// - `members`: The archive index from [`Image::index`].
+    ///
+    /// # Returns
+    /// The paths of the layer tarballs within the archive.
+    fn layers(file: &mut File, members: &HashMap<String, (u64, u64)>) -> io::Result<Vec<String>> {
+        let mut read_json = |name: &str| -> io::Result<Option<Vec<u8>>> {
+            let Some(&(offset, size)) = members.get(name) else {
+                return Ok(None);
+            };
+            let mut data = Vec::new();
+            file.seek(SeekFrom::Start(offset))?;
+            (&mut *file).take(size).read_to_end(&mut data)?;
+            Ok(Some(data))
+        };
+
+        if let Some(data) = read_json("manifest.json")? {
+            let manifests: Vec<DockerManifest> = serde_json::from_slice(&data)
+                .map_err(|error| invalid(format!("invalid manifest.json: {}", error)))?;
+            let manifest = manifests
+                .into_iter()
+                .next()
+                .ok_or_else(|| invalid("manifest.json lists no images".to_string()))?;
+            return Ok(manifest
+                .layers
+                .iter()
+                .map(|layer| normalise_string(layer))
+                .collect());
+        }
+
+        let mut name = "index.json".to_string();
+        for _ in 0..MAX_INDEX_DEPTH {
+            let data = read_json(&name)?.ok_or_else(|| {
+                invalid(format!(
+                    "'{}' not found; not a Docker or OCI image archive",
+                    name
+                ))
+            })?;
+            let manifest: OciManifest = serde_json::from_slice(&data)
+                .map_err(|error| invalid(format!("invalid {}: {}", name, error)))?;
+
+            if !manifest.layers.is_empty() {
+                return Ok(manifest
+                    .layers
+                    .iter()
+                    .map(|layer| blob(&layer.digest))
+                    .collect());
+            }
+            let next = manifest
+                .manifests
+                .first()
+                .ok_or_else(|| invalid(format!("{} lists no manifests", name)))?;
+            name = blob(&next.digest);
+        }
+
+        Err(invalid("image indexes are nested too deeply".to_string()))
+    }
+
+    /// Reads the members of one layer, decompressing it if needed.
+    ///
+    /// # Parameters
+    /// - `file`: The image tarball.
+    /// - `offset`: Where the layer's data starts in the tarball.
+    /// - `size`: The layer's size in bytes.
+    ///
+    /// # Returns
+    /// The layer's members in archive order.
+    fn read_layer(file: &File, offset: u64, size: u64) -> io::Result<Vec<Member>> {
+        let mut file = file.try_clone()?;
+        file.seek(SeekFrom::Start(offset))?;
+        let mut layer = BufReader::new(file.take(size));
+
+        let head = layer.fill_buf()?;
+        let program = if head.starts_with(GZIP_MAGIC) {
+            "gzip"
+        } else if head.starts_with(ZSTD_MAGIC) {
+            "zstd"
+        } else {
+            return read_members(layer);
+        };
+
+        let mut child = Command::new(program)
+            .arg("-dc")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|error| {
+                io::Error::new(error.kind(), format!("cannot run {}: {}", program, error))
+            })?;
+
+        // Feed the layer in from another thread so neither pipe fills up and stalls
+        let mut stdin = child.stdin.take().expect("stdin is piped");
+        let feeder = std::thread::spawn(move || io::copy(&mut layer, &mut stdin).map(drop));
+
+        let stdout = child.stdout.take().expect("stdout is piped");
+        let members = read_members(BufReader::new(stdout));
+
+        // A decompressor cut off after the closing blocks may fail on the closed pipe
+        let _ = child.kill();
+        let _ = child.wait();
+        let _ = feeder.join();
+
+        members
+    }
+
+    /// Replays one layer's members over the layers below it.
+    ///
+    /// Whiteouts only hide what the lower layers hold, so they are applied before
+    /// the layer's own entries.
+    ///
+    /// # Parameters
+    /// - `members`: The layer's members.
+    fn apply(&mut self, members: Vec<Member>) {
+        let (whiteouts, entries): (Vec<Member>, Vec<Member>) =
+            members.into_iter().partition(|member| {
+                let path = normalise(&member.path);
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                metadata::is_whiteout_name(&name)
+            });
+
+        for whiteout in whiteouts {
+            let path = normalise(&whiteout.path);
+            let parent = path.parent().unwrap_or(Path::new(""));
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+
+            if name == OPAQUE_MARKER {
+                self.remove_below(parent);
+            } else if let Some(hidden) = name.strip_prefix(WHITEOUT_PREFIX) {
+                let hidden = parent.join(hidden);
+                self.remove_below(&hidden);
+                self.nodes.remove(&hidden);
+            }
+        }
+
+        for member in entries {
+            self.insert(member);
+        }
+    }
+
+    /// Adds a layer's member, creating any missing parent directories.
+    ///
+    /// # Parameters
+    /// - `member`: The member to add.
+    fn insert(&mut self, member: Member) {
+        let path = normalise(&member.path);
+        if path.as_os_str().is_empty() {
+            return;
+        }
+
+        let node = match member.kind {
+            // Hard links share the node (and inode) of an earlier member
+            b'1' => match self.nodes.get_mut(&normalise(&member.link)) {
+                Some(target) => {
+                    target.metadata.nlink += 1;
+                    target.clone()
+                }
+                None => return,
+            },
+            kind => {
+                let file_type = match kind {
+                    b'2' => libc::S_IFLNK,
+                    b'3' => libc::S_IFCHR,
+                    b'4' => libc::S_IFBLK,
+                    b'5' => libc::S_IFDIR,
+                    b'6' => libc::S_IFIFO,
+                    _ => libc::S_IFREG,
+                };
+                let size = match kind {
+                    b'2' => member.link.len() as u64,
+                    b'3' | b'4' | b'5' | b'6' => 0,
+                    _ => member.size,
+                };
+
+                Node {
+                    metadata: Metadata {
+                        mode: file_type | (member.mode & 0o7777),
+                        rdev: member.rdev,
+                        size,
+                        ino: self.take_ino(),
+                        nlink: 1,
+                        uid: member.uid,
+                        gid: member.gid,
+                        blocks: size.div_ceil(BLOCK),
+                        blksize: BLOCK,
+                        atime: member.mtime,
+                        mtime: member.mtime,
+                        ctime: member.mtime,
+                        ..Metadata::empty()
+                    },
+                    link: (kind == b'2').then(|| PathBuf::from(&member.link)),
+                }
+            }
+        };
+
+        for ancestor in path.ancestors().skip(1) {
+            if !self.nodes.get(ancestor).is_some_and(Node::is_dir) {
+                let parent = self.directory(member.mtime);
+                self.nodes.insert(ancestor.to_path_buf(), parent);
+            }
+        }
+
+        // A directory keeps what the layers below put in it; anything else replaces it
+        if !node.is_dir() {
+            self.remove_below(&path);
+        }
+        self.nodes.insert(path, node);
+    }
+
+    /// Creates a directory node for a directory the layers imply but don't list
+    /// (including the image root).
+    ///
+    /// # Parameters
+    /// - `mtime`: The modification time in seconds since the Unix epoch.
+    fn directory(&mut self, mtime: i64) -> Node {
+        Node {
+            metadata: Metadata {
+                mode: libc::S_IFDIR | 0o755,
+                ino: self.take_ino(),
+                nlink: 1,
+                blksize: BLOCK,
+                atime: mtime,
+                mtime,
+                ctime: mtime,
+                ..Metadata::empty()
+            },
+            link: None,
+        }
+    }
+
+    /// Hands out the next inode number.
+    fn take_ino(&mut self) -> u64 {
+        self.next_ino += 1;
+        self.next_ino - 1
+    }
+
+    /// Removes every node below a directory, keeping the directory itself.
+    ///
+    /// # Parameters
+    /// - `dir`: The directory to empty.
+    fn remove_below(&mut self, dir: &Path) {
+        // Paths order component by component, so a directory's descendants follow it directly
+        let below: Vec<PathBuf> = self
+            .nodes
+            .range(dir.to_path_buf()..)
+            .map(|(path, _)| path)
+            .take_while(|path| path.starts_with(dir))
+            .filter(|path| path.as_path() != dir)
+            .cloned()
+            .collect();
+
+        for path in below {
+            self.nodes.remove(&path);
+        }
+    }
+}
+
+impl Node {
+    /// Checks whether the node is a directory.
+    fn is_dir(&self) -> bool {
+        self.metadata.mode & libc::S_IFMT == libc::S_IFDIR
+    }
+}
+
+impl Fs for Image {
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirItem>> {
+        let relative = path
+            .strip_prefix(&self.root)
+            .map_err(|_| io::ErrorKind::NotFound)?;
+        if !self.nodes.get(relative).is_some_and(Node::is_dir) {
+            return Err(io::ErrorKind::NotFound.into());
+        }
+
+        Ok(self
+            .nodes
+            .range(relative.to_path_buf()..)
+            .skip(1)
+            .take_while(|(child, _)| child.starts_with(relative))
+            .filter(|(child, _)| child.parent() == Some(relative))
+            .map(|(child, node)| DirItem {
+                path: self.root.join(child),
+                is_dir: node.is_dir(),
+                is_symlink: node.link.is_some(),
+            })
+            .collect())
+    }
+
+    /// Describes symlinks themselves; targets inside the image aren't followed.
+    fn metadata(&self, path: &Path, _dereference: bool) -> io::Result<Metadata> {
+        self.node(path)
+            .map(|node| node.metadata.clone())
+            .ok_or_else(|| io::ErrorKind::NotFound.into())
+    }
+}
+
+/// Reads every member of an uncompressed layer.
+///
+/// # Parameters
+/// - `reader`: The layer's tar stream.
+fn read_members(reader: impl Read) -> io::Result<Vec<Member>> {
+    let mut tar = TarReader::new(reader);
+    let mut members = Vec::new();
+    while let Some(member) = tar.next_member()? {
+        members.push(member);
+    }
+    Ok(members)
+}
+
+/// Reads a ustar header's path, joining the `prefix` field when present.
+fn header_path(header: &[u8]) -> String {
+    let name = field(&header[0..100]);
+    let prefix = if &header[257..262] == b"ustar" {
+        field(&header[345..500])
+    } else {
+        String::new()
+    };
+
+    if prefix.is_empty() {
+        name
+    } else {
+        format!("{}/{}", prefix, name)
+    }
+}
+
+/// Reads a NUL-terminated header field (or extended record) as text.
+fn field(bytes: &[u8]) -> String {
+    let end = bytes
+        .iter()
+        .position(|&byte| byte == 0)
+        .unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+/// Parses a numeric header field: octal text, or GNU base-256 for large values.
+///
+/// # Returns
+/// The value, or an `InvalidData` error for a corrupt field.
+fn parse_number(bytes: &[u8]) -> io::Result<u64> {
+    if bytes.first().is_some_and(|&byte| byte & 0x80 != 0) {
+        return Ok(bytes[1..]
+            .iter()
+            .fold(u64::from(bytes[0] & 0x7F), |value, &byte| {
+                value << 8 | u64::from(byte)
+            }));
+    }
+
+    let text = field(bytes);
+    let text = text.trim_matches([' ', '\0']);
+    if text.is_empty() {
+        return Ok(0);
+    }
+    u64::from_str_radix(text, 8)
+        .map_err(|_| invalid(format!("corrupt tar header field '{}'", text)))
+}
+
+/// Parses PAX extended header records (`<length> <key>=<value>\n`).
+fn parse_pax(records: &str) -> HashMap<String, String> {
+    let mut values = HashMap::new();
+    let mut rest = records;
+
+    while let Some((length, after)) = rest.split_once(' ') {
+        let Ok(length) = length.parse::<usize>() else {
+            break;
+        };
+        let record_len = length.saturating_sub(length.to_string().len() + 1);
+        let Some(record) = after.get(..record_len) else {
+            break;
+        };
+        if let Some((key, value)) = record.trim_end_matches('\n').split_once('=') {
+            values.insert(key.to_string(), value.to_string());
+        }
+        rest = &after[record_len..];
+    }
+
+    values
+}
+
+/// Parses a PAX timestamp (`1700000000.123456`), dropping the fraction.
+fn pax_seconds(value: &str) -> Option<i64> {
+    value.split('.').next()?.parse().ok()
+}
+
+/// Normalises a member path: no leading `./` or `/`, no trailing `/`, no `..`.
+fn normalise(path: &str) -> PathBuf {
+    Path::new(path)
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Normalises a member path to a string, for looking it up in the archive index.
+fn normalise_string(path: &str) -> String {
+    normalise(path).to_string_lossy().into_owned()
+}
+
+/// Returns the archive path of a blob in an OCI layout.
+///
+/// # Parameters
+/// - `digest`: The blob's digest, e.g. `sha256:…`.
+fn blob(digest: &str) -> String {
+    let (algorithm, hex) = digest.split_once(':').unwrap_or(("sha256", digest));
+    format!("blobs/{}/{}", algorithm, hex)
+}
+
+/// Builds an `InvalidData` error with a message.
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
@@ -22,6 +22,7 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
+use crate::fs::network;
 use libc::{
     S_IFBLK, S_IFCHR, S_IFDIR, S_IFIFO, S_IFLNK, S_IFMT, S_IFREG, S_IFSOCK, S_IRGRP, S_IROTH,
     S_IRUSR, S_ISGID, S_ISUID, S_ISVTX, S_IWGRP, S_IWOTH, S_IWUSR, S_IXGRP, S_IXOTH, S_IXUSR,
@@ -95,21 +96,22 @@ impl Permissions {
     /// - `path`: The file path to query.
     ///
     /// # Returns
-    /// `true` if the file has at least one extended attribute, `false` otherwise
-    /// or if the path contains a null byte.
+    /// `true` if the file has at least one extended attribute, `false` otherwise,
+    /// if the path contains a null byte, or if its network mount is skipped or
+    /// not answering.
     pub fn check_xattr(path: &Path) -> bool {
-        let c_path = match CString::new(path.as_os_str().as_bytes()) {
-            Ok(p) => p,
-            Err(_) => return false,
-        };
+        network::bounded(path, |path| {
+            let Ok(c_path) = CString::new(path.as_os_str().as_bytes()) else {
+                return Ok(false);
+            };
 
-        unsafe {
             // Call listxattr with NULL buffer to get the size needed
-            let size = libc::listxattr(c_path.as_ptr(), std::ptr::null_mut(), 0);
+            let size = unsafe { libc::listxattr(c_path.as_ptr(), std::ptr::null_mut(), 0) };
 
             // If size > 0, extended attributes exist
-            size > 0
-        }
+            Ok(size > 0)
+        })
+        .unwrap_or(false)
     }
 
     /// Determines the file type indicator character from a raw `mode_t` value.
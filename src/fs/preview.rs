@@ -0,0 +1,134 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Single-line previews of the first or last bytes of a file, for the
+//! `--head` and `--tail` columns.
+//!
+//! Only regular files are read. Anything that isn't text (invalid UTF-8 or
+//! containing NUL, as for [`Sniff`]) shows [`BINARY`] instead of its bytes.
+//! Control and bidi characters in text are left for
+//! [`Escape`](crate::display::output::escape::Escape) to make visible.
+
+use crate::fs::sniff::Sniff;
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Bytes previewed when a `head` or `tail` column is picked with `--columns`
+/// and no `--head`/`--tail` count was given.
+pub const DEFAULT_BYTES: u64 = 32;
+
+/// Shown in place of the preview for files that aren't text.
+pub const BINARY: &str = "<binary>";
+
+/// Reads previews of file contents.
+pub struct Preview;
+
+impl Preview {
+    /// Previews the first `bytes` bytes of a file.
+    ///
+    /// # Parameters
+    /// - `path`: The file to read.
+    /// - `bytes`: How many bytes to read from the start.
+    ///
+    /// # Returns
+    /// The preview, [`BINARY`], or `"-"` for empty, unreadable, or non-regular files.
+    pub fn head(path: &Path, bytes: u64) -> Arc<str> {
+        Self::read(path, bytes, |_| 0)
+    }
+
+    /// Previews the last `bytes` bytes of a file.
+    ///
+    /// # Parameters
+    /// - `path`: The file to read.
+    /// - `bytes`: How many bytes to read from the end.
+    ///
+    /// # Returns
+    /// The preview, [`BINARY`], or `"-"` for empty, unreadable, or non-regular files.
+    pub fn tail(path: &Path, bytes: u64) -> Arc<str> {
+        Self::read(path, bytes, |len| len.saturating_sub(bytes))
+    }
+
+    /// Reads up to `bytes` bytes from the offset `start` picks for the file's length.
+    ///
+    /// # Parameters
+    /// - `path`: The file to read.
+    /// - `bytes`: How many bytes to read.
+    /// - `start`: Maps the file's length to the offset to read from.
+    fn read(path: &Path, bytes: u64, start: impl FnOnce(u64) -> u64) -> Arc<str> {
+        // Opening a FIFO or device could block or have side effects
+        let Ok(metadata) = fs::symlink_metadata(path) else {
+            return "-".into();
+        };
+        if !metadata.is_file() || metadata.len() == 0 {
+            return "-".into();
+        }
+
+        let offset = start(metadata.len());
+        let mut buffer = Vec::new();
+        let read = File::open(path).and_then(|mut file| {
+            file.seek(SeekFrom::Start(offset))?;
+            file.take(bytes).read_to_end(&mut buffer)
+        });
+        if read.is_err() {
+            return "-".into();
+        }
+
+        // Reading from the middle can start partway through a multi-byte character
+        let cut = if offset > 0 {
+            buffer
+                .iter()
+                .take(3)
+                .take_while(|byte| (0x80..0xC0).contains(*byte))
+                .count()
+        } else {
+            0
+        };
+
+        Self::text(&buffer[cut..]).unwrap_or_else(|| BINARY.into())
+    }
+
+    /// Turns raw file bytes into a preview that fits on one table row.
+    ///
+    /// A character cut off at the end is dropped and runs of whitespace
+    /// (including line breaks and tabs) collapse into one space.
+    ///
+    /// # Parameters
+    /// - `bytes`: The bytes read from the file.
+    ///
+    /// # Returns
+    /// The preview (`"-"` if only whitespace was read), or `None` if the bytes aren't text.
+    pub fn text(bytes: &[u8]) -> Option<Arc<str>> {
+        let text = Sniff::as_text(bytes)?;
+
+        let cleaned = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        Some(if cleaned.is_empty() {
+            "-".into()
+        } else {
+            cleaned.into()
+        })
+    }
+}
@@ -55,7 +55,7 @@ impl Search {
     /// If `args.recursive` is true, searches subdirectories as well.
     ///
     /// # Parameters
-    /// - `args`: CLI arguments controlling recursion, filters, verbosity, and metadata.
+    /// - `args`: CLI arguments controlling recursion, filters, and metadata.
     ///
     /// # Returns
     /// A `Vec<Entry>` of all entries whose names match the glob pattern.
@@ -64,13 +64,11 @@ impl Search {
         let dir_reader = DirReader::from(self.base_path.clone());
         self.search_dir(&dir_reader, args, &mut matches);
 
-        if args.verbose {
-            println!(
-                "Found {} matches in {}\n",
-                matches.len(),
-                self.base_path.display()
-            );
-        }
+        crate::debug!(
+            "search finished",
+            dir = self.base_path.display(),
+            matches = matches.len(),
+        );
 
         matches
     }
@@ -79,12 +77,10 @@ impl Search {
     ///
     /// # Parameters
     /// - `dir_reader`: The directory to scan.
-    /// - `args`: CLI arguments controlling filters, recursion, and verbosity.
+    /// - `args`: CLI arguments controlling filters and recursion.
     /// - `matches`: Accumulator for entries whose names match the glob.
     fn search_dir(&self, dir_reader: &DirReader, args: &Args, matches: &mut Vec<Entry>) {
-        if args.verbose {
-            println!("Searching in {} ...", dir_reader.path().display());
-        }
+        crate::debug!("searching", dir = dir_reader.path().display());
 
         for mut entry in dir_reader.list(args) {
             let is_dir_like = entry.is_dir_like();
@@ -97,9 +93,7 @@ impl Search {
             };
 
             if dominated_match {
-                if args.verbose {
-                    println!("Match: {}", entry.path().display());
-                }
+                crate::debug!("match", path = entry.path().display());
 
                 entry.conditional_metadata(args);
 
@@ -131,6 +125,7 @@ impl Search {
             .path()
             .parent()
             .and_then(|p| p.strip_prefix(&self.base_path).ok())
+            .filter(|p| !p.as_os_str().is_empty())
             .map(|p| format!("{}/", p.display()))
             .unwrap_or_default();
 
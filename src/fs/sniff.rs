@@ -0,0 +1,218 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Pure-Rust file type sniffing.
+//!
+//! Recognises common formats from their leading bytes, with descriptions
+//! worded like libmagic's so icons and the `--magic` column treat both
+//! alike. Builds where libmagic isn't available (Android/Termux) use this
+//! in its place.
+
+use crate::fs::metadata;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::sync::Arc;
+
+/// How many leading bytes are read to identify a file.
+const HEAD_LEN: usize = 512;
+
+/// Byte signatures at fixed offsets, checked in order.
+const SIGNATURES: &[(usize, &[u8], &str)] = &[
+    (0, b"\x89PNG\r\n\x1a\n", "PNG image data"),
+    (0, b"\xff\xd8\xff", "JPEG image data"),
+    (0, b"GIF87a", "GIF image data, version 87a"),
+    (0, b"GIF89a", "GIF image data, version 89a"),
+    (0, b"%PDF-", "PDF document"),
+    (0, b"PK\x03\x04", "Zip archive data"),
+    (0, b"PK\x05\x06", "Zip archive data (empty)"),
+    (0, b"\x1f\x8b", "gzip compressed data"),
+    (0, b"BZh", "bzip2 compressed data"),
+    (0, b"\xfd7zXZ\x00", "XZ compressed data"),
+    (0, b"\x28\xb5\x2f\xfd", "Zstandard compressed data"),
+    (0, b"7z\xbc\xaf\x27\x1c", "7-zip archive data"),
+    (0, b"Rar!\x1a\x07", "RAR archive data"),
+    (257, b"ustar", "POSIX tar archive"),
+    (0, b"\x7fELF", "ELF executable"),
+    (0, b"MZ", "PE32 executable (MS Windows)"),
+    (0, b"\xcf\xfa\xed\xfe", "Mach-O 64-bit executable"),
+    (0, b"\xca\xfe\xba\xbe", "Mach-O universal binary"),
+    (0, b"\x00asm", "WebAssembly (wasm) binary module"),
+    (0, b"SQLite format 3\x00", "SQLite 3.x database"),
+    (0, b"fLaC", "FLAC audio bitstream data"),
+    (0, b"ID3", "Audio file with ID3 version 2"),
+    (0, b"OggS", "Ogg data, audio"),
+    (0, b"\x1a\x45\xdf\xa3", "Matroska data, video"),
+    (4, b"ftyp", "ISO Media"),
+    (0, b"dex\n", "Dalvik dex file"),
+];
+
+/// Script interpreters recognised on a `#!` line.
+const INTERPRETERS: &[(&str, &str)] = &[
+    ("python", "Python script"),
+    ("perl", "Perl script"),
+    ("ruby", "Ruby script"),
+    ("node", "Node script"),
+    ("bash", "Bourne-Again shell script"),
+    ("zsh", "Paul Falstad's zsh script"),
+    ("fish", "fish shell script"),
+    ("sh", "POSIX shell script"),
+];
+
+/// Identifies files by content without libmagic.
+pub struct Sniff;
+
+impl Sniff {
+    /// Reads the start of a file and describes its type.
+    ///
+    /// # Parameters
+    /// - `path`: The file to identify.
+    ///
+    /// # Returns
+    /// A libmagic-style description, or an empty string if the file can't be read
+    /// (special files included, as opening a FIFO would block).
+    pub fn file(path: &Path) -> Arc<str> {
+        if !metadata::has_readable_contents(path) {
+            return "".into();
+        }
+
+        let mut head = Vec::with_capacity(HEAD_LEN);
+        match File::open(path).and_then(|file| file.take(HEAD_LEN as u64).read_to_end(&mut head)) {
+            Ok(_) => Self::describe(&head).into(),
+            Err(_) => "".into(),
+        }
+    }
+
+    /// Describes the type of data starting with `head`.
+    ///
+    /// # Parameters
+    /// - `head`: The first bytes of the file (up to 512 are examined).
+    ///
+    /// # Returns
+    /// A libmagic-style description such as `"PNG image data"` or `"ASCII text"`.
+    pub fn describe(head: &[u8]) -> String {
+        if head.is_empty() {
+            return "empty".to_string();
+        }
+
+        if let Some((_, _, description)) = SIGNATURES.iter().find(|(offset, signature, _)| {
+            head.get(*offset..)
+                .is_some_and(|rest| rest.starts_with(signature))
+        }) {
+            return Self::refine(head, description).to_string();
+        }
+
+        let Some(text) = Self::as_text(head) else {
+            return "data".to_string();
+        };
+        let encoding = if head.is_ascii() {
+            "ASCII text"
+        } else {
+            "Unicode text, UTF-8 text"
+        };
+
+        if let Some(script) = Self::script(text) {
+            return format!("{script}, {encoding} executable");
+        }
+
+        let markup = text.trim_start().to_ascii_lowercase();
+        if markup.starts_with("<?xml") {
+            if markup.contains("<svg") {
+                return "SVG Scalable Vector Graphics image".to_string();
+            }
+            return format!("XML document, {encoding}");
+        }
+        if markup.starts_with("<svg") {
+            return "SVG Scalable Vector Graphics image".to_string();
+        }
+        if markup.starts_with("<!doctype html") || markup.starts_with("<html") {
+            return format!("HTML document, {encoding}");
+        }
+
+        encoding.to_string()
+    }
+
+    /// Distinguishes RIFF containers and similar formats that share a signature.
+    ///
+    /// # Parameters
+    /// - `head`: The first bytes of the file.
+    /// - `description`: The description the signature table matched.
+    ///
+    /// # Returns
+    /// A more specific description where the header allows one.
+    fn refine(head: &[u8], description: &'static str) -> &'static str {
+        match description {
+            "ISO Media" if head.get(8..12) == Some(b"heic") => "ISO Media, HEIF image",
+            "ISO Media" if head.get(8..11) == Some(b"M4A") => "ISO Media, Apple iTunes audio",
+            "ISO Media" => "ISO Media, MP4 video",
+            "Zip archive data" if head.get(30..58) == Some(b"mimetypeapplication/epub+zip") => {
+                "EPUB document"
+            }
+            other => other,
+        }
+    }
+
+    /// Names the interpreter of a `#!` script.
+    ///
+    /// # Parameters
+    /// - `text`: The decoded start of the file.
+    ///
+    /// # Returns
+    /// A description such as `"Python script"`, or `None` for other text.
+    fn script(text: &str) -> Option<&'static str> {
+        let line = text.strip_prefix("#!")?.lines().next()?;
+        let mut words = line.split_whitespace();
+        let mut program = words.next()?.rsplit('/').next()?;
+
+        // `#!/usr/bin/env -S python3 -u` names the interpreter in a later word
+        if program == "env" {
+            program = words.find(|word| !word.starts_with('-'))?;
+        }
+
+        INTERPRETERS
+            .iter()
+            .find(|(name, _)| program.starts_with(name))
+            .map(|(_, description)| *description)
+    }
+
+    /// Decodes `head` as UTF-8 text, tolerating a character cut off at the end.
+    ///
+    /// # Parameters
+    /// - `head`: The first bytes of the file.
+    ///
+    /// # Returns
+    /// The text, or `None` if the bytes are binary (invalid UTF-8 or containing NUL).
+    pub(crate) fn as_text(head: &[u8]) -> Option<&str> {
+        let text = match std::str::from_utf8(head) {
+            Ok(text) => text,
+            // A multi-byte character split by the read limit is still text
+            Err(error) if error.error_len().is_none() => {
+                std::str::from_utf8(&head[..error.valid_up_to()]).ok()?
+            }
+            Err(_) => return None,
+        };
+
+        (!text.contains('\0')).then_some(text)
+    }
+}
@@ -0,0 +1,377 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Persistent cache for values that are slow to compute (`--persistent-cache`).
+//!
+//! Checksums and magic descriptions are kept in
+//! `$XDG_CACHE_HOME/cerium/values` (or `~/.cache/cerium/values`) between runs,
+//! one record per line:
+//!
+//! ```text
+//! KIND <TAB> SIZE <TAB> MTIME_NS <TAB> STORED <TAB> PATH <TAB> VALUE
+//! ```
+//!
+//! A record is reused only while its path's size and modification time match.
+//! Recursive directory sizes aren't stored: a directory's size and mtime
+//! don't change when a file deeper down is edited, so that stamp can't tell
+//! whether a stored total is still right.
+//!
+//! The file is read on the first lookup and written back once after listing.
+//! Writing merges with whatever another run saved meanwhile, drops records
+//! older than [`MAX_AGE`] and keeps the newest [`MAX_RECORDS`].
+
+#[cfg(any(feature = "magic", feature = "checksum"))]
+use crate::fs::cache::CacheSettings;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, Write};
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
+#[cfg(any(feature = "magic", feature = "checksum"))]
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// First line of the cache file; files with another header are ignored.
+const HEADER: &str = "# cerium cache v1";
+
+/// How long a record is kept after it was computed.
+pub const MAX_AGE: Duration = Duration::from_secs(30 * 86_400);
+
+/// How many records the file holds at most; the oldest are dropped first.
+pub const MAX_RECORDS: usize = 100_000;
+
+/// Whether the persistent cache is used (`--persistent-cache` without `--no-cache`).
+static STORE_ENABLED: AtomicBool = AtomicBool::new(false);
+
+static STORE: OnceLock<Mutex<State>> = OnceLock::new();
+
+/// Kind of value and the path it was computed for.
+type Key = (String, PathBuf);
+
+/// A cached value and what it was computed from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    /// Size of the path when the value was computed
+    pub size: u64,
+    /// Modification time of the path, in nanoseconds since the epoch
+    pub mtime: u128,
+    /// When the value was computed, in seconds since the epoch
+    pub stored: u64,
+    pub value: Arc<str>,
+}
+
+/// Records read from disk, and those computed by this run.
+#[derive(Default)]
+struct State {
+    records: HashMap<Key, Record>,
+    added: HashMap<Key, Record>,
+}
+
+/// The on-disk cache shared between runs.
+pub struct Store;
+
+impl Store {
+    /// Returns where the cache file lives.
+    ///
+    /// # Returns
+    /// `$XDG_CACHE_HOME/cerium/values`, `$HOME/.cache/cerium/values`, or `None`
+    /// if neither variable is set.
+    pub fn path() -> Option<PathBuf> {
+        let cache_dir = std::env::var_os("XDG_CACHE_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+        Some(cache_dir.join("cerium").join("values"))
+    }
+
+    #[cfg(any(feature = "magic", feature = "checksum"))]
+    /// Whether the persistent cache is in use for this run.
+    pub(crate) fn is_enabled() -> bool {
+        STORE_ENABLED.load(Ordering::Relaxed) && !CacheSettings::is_disabled()
+    }
+
+    #[cfg(any(feature = "magic", feature = "checksum"))]
+    /// Looks up a value computed by an earlier run.
+    ///
+    /// # Parameters
+    /// - `kind`: What the value is (e.g. `"magic"`, `"sha256"`).
+    /// - `path`: The path it was computed for.
+    /// - `stamp`: The path's current modification time and size.
+    ///
+    /// # Returns
+    /// The stored value if the stamp still matches, or `None` on a miss or when disabled.
+    pub(crate) fn get(kind: &str, path: &Path, stamp: (SystemTime, u64)) -> Option<Arc<str>> {
+        let state = Self::state()?.lock().ok()?;
+        let key = Self::key(kind, path);
+        let (mtime, size) = (Self::nanos(stamp.0), stamp.1);
+
+        state
+            .added
+            .get(&key)
+            .or_else(|| state.records.get(&key))
+            .filter(|record| record.size == size && record.mtime == mtime)
+            .map(|record| record.value.clone())
+    }
+
+    #[cfg(any(feature = "magic", feature = "checksum"))]
+    /// Records a freshly computed value, to be written by [`Store::save`].
+    ///
+    /// # Parameters
+    /// - `kind`: What the value is.
+    /// - `path`: The path it was computed for.
+    /// - `stamp`: The path's modification time and size before computing it.
+    /// - `value`: The computed value.
+    pub(crate) fn put(kind: &str, path: &Path, stamp: (SystemTime, u64), value: Arc<str>) {
+        let Some(Ok(mut state)) = Self::state().map(Mutex::lock) else {
+            return;
+        };
+
+        let record = Record {
+            size: stamp.1,
+            mtime: Self::nanos(stamp.0),
+            stored: Self::now(),
+            value,
+        };
+        state.added.insert(Self::key(kind, path), record);
+    }
+
+    /// Writes the values computed by this run to the cache file.
+    ///
+    /// Merges them with the file as it is now, prunes it and replaces it
+    /// atomically. Does nothing if nothing new was computed.
+    ///
+    /// # Returns
+    /// An I/O error if the cache directory or file can't be written.
+    pub fn save() -> io::Result<()> {
+        let Some(Ok(mut state)) = STORE.get().map(Mutex::lock) else {
+            return Ok(());
+        };
+        if state.added.is_empty() {
+            return Ok(());
+        }
+        let Some(path) = Self::path() else {
+            return Ok(());
+        };
+
+        let mut records = fs::read(&path)
+            .map(|content| Self::parse(&content))
+            .unwrap_or_default();
+        records.extend(state.added.drain());
+        Self::prune(&mut records, Self::now());
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temporary = path.with_extension(format!("{}.tmp", std::process::id()));
+        fs::write(&temporary, Self::serialise(&records))?;
+        fs::rename(&temporary, &path)?;
+
+        state.records = records;
+        Ok(())
+    }
+
+    /// Parses the cache file, skipping malformed lines.
+    ///
+    /// # Parameters
+    /// - `content`: The raw file contents.
+    ///
+    /// # Returns
+    /// The records by kind and path, or none if the header doesn't match.
+    pub fn parse(content: &[u8]) -> HashMap<Key, Record> {
+        let mut lines = content.split(|&byte| byte == b'\n');
+        if lines.next() != Some(HEADER.as_bytes()) {
+            return HashMap::new();
+        }
+
+        lines
+            .filter_map(|line| {
+                let mut fields = line.split(|&byte| byte == b'\t');
+                let mut field = || fields.next();
+                let kind = String::from_utf8(field()?.to_vec()).ok()?;
+                let size = std::str::from_utf8(field()?).ok()?.parse().ok()?;
+                let mtime = std::str::from_utf8(field()?).ok()?.parse().ok()?;
+                let stored = std::str::from_utf8(field()?).ok()?.parse().ok()?;
+                let path = PathBuf::from(OsString::from_vec(Self::decode(field()?)?));
+                let value = String::from_utf8(Self::decode(field()?)?).ok()?;
+
+                let record = Record {
+                    size,
+                    mtime,
+                    stored,
+                    value: value.into(),
+                };
+                Some(((kind, path), record))
+            })
+            .collect()
+    }
+
+    /// Renders records in the cache file format, newest first.
+    ///
+    /// # Parameters
+    /// - `records`: The records to write.
+    ///
+    /// # Returns
+    /// The file contents, starting with the header line.
+    pub fn serialise(records: &HashMap<Key, Record>) -> Vec<u8> {
+        let mut sorted: Vec<_> = records.iter().collect();
+        sorted.sort_unstable_by(|a, b| b.1.stored.cmp(&a.1.stored).then_with(|| a.0.cmp(b.0)));
+
+        let mut content = Vec::with_capacity(records.len() * 96);
+        let _ = writeln!(content, "{HEADER}");
+        for ((kind, path), record) in sorted {
+            let _ = write!(
+                content,
+                "{kind}\t{}\t{}\t{}\t",
+                record.size, record.mtime, record.stored
+            );
+            Self::encode(path.as_os_str().as_bytes(), &mut content);
+            content.push(b'\t');
+            Self::encode(record.value.as_bytes(), &mut content);
+            content.push(b'\n');
+        }
+        content
+    }
+
+    /// Drops records older than [`MAX_AGE`], then all but the newest [`MAX_RECORDS`].
+    ///
+    /// # Parameters
+    /// - `records`: The records to prune in place.
+    /// - `now`: The current time, in seconds since the epoch.
+    pub fn prune(records: &mut HashMap<Key, Record>, now: u64) {
+        records.retain(|_, record| now.saturating_sub(record.stored) <= MAX_AGE.as_secs());
+
+        if records.len() > MAX_RECORDS {
+            let mut keys: Vec<(u64, Key)> = records
+                .iter()
+                .map(|(key, record)| (record.stored, key.clone()))
+                .collect();
+            keys.sort_unstable_by_key(|(stored, _)| std::cmp::Reverse(*stored));
+            for (_, key) in keys.drain(MAX_RECORDS..) {
+                records.remove(&key);
+            }
+        }
+    }
+
+    #[cfg(any(feature = "magic", feature = "checksum"))]
+    /// Returns the shared state, reading the cache file on first use.
+    ///
+    /// # Returns
+    /// The state, or `None` when the persistent cache is disabled.
+    fn state() -> Option<&'static Mutex<State>> {
+        if !Self::is_enabled() {
+            return None;
+        }
+
+        Some(STORE.get_or_init(|| {
+            let records = Self::path()
+                .and_then(|path| fs::read(path).ok())
+                .map(|content| Self::parse(&content))
+                .unwrap_or_default();
+            Mutex::new(State {
+                records,
+                added: HashMap::new(),
+            })
+        }))
+    }
+
+    #[cfg(any(feature = "magic", feature = "checksum"))]
+    /// Builds a record key, making the path absolute so runs from other directories share it.
+    ///
+    /// # Parameters
+    /// - `kind`: What the value is.
+    /// - `path`: The path, possibly relative to the working directory.
+    fn key(kind: &str, path: &Path) -> Key {
+        let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        (kind.to_string(), path)
+    }
+
+    /// Percent-encodes `%` and control bytes, which would break the line and field structure.
+    ///
+    /// # Parameters
+    /// - `bytes`: The raw path or value.
+    /// - `out`: The buffer to append to.
+    fn encode(bytes: &[u8], out: &mut Vec<u8>) {
+        for &byte in bytes {
+            if byte == b'%' || byte.is_ascii_control() {
+                let _ = write!(out, "%{byte:02X}");
+            } else {
+                out.push(byte);
+            }
+        }
+    }
+
+    /// Reverses [`Store::encode`].
+    ///
+    /// # Parameters
+    /// - `field`: The encoded field.
+    ///
+    /// # Returns
+    /// The raw bytes, or `None` for a malformed escape.
+    fn decode(field: &[u8]) -> Option<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(field.len());
+        let mut rest = field.iter();
+
+        while let Some(&byte) = rest.next() {
+            if byte == b'%' {
+                let high = (*rest.next()? as char).to_digit(16)?;
+                let low = (*rest.next()? as char).to_digit(16)?;
+                bytes.push((high * 16 + low) as u8);
+            } else {
+                bytes.push(byte);
+            }
+        }
+
+        Some(bytes)
+    }
+
+    #[cfg(any(feature = "magic", feature = "checksum"))]
+    /// Converts a modification time to nanoseconds since the epoch (0 before it).
+    fn nanos(time: SystemTime) -> u128 {
+        time.duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos())
+    }
+
+    /// Returns the wall-clock time in seconds since the epoch.
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs())
+    }
+}
+
+/// Global persistent cache switch, set up once from `--persistent-cache` and `--no-cache`.
+pub struct StoreSettings;
+
+impl StoreSettings {
+    /// Turns the persistent cache on or off for this run.
+    ///
+    /// # Parameters
+    /// - `enabled`: Whether to read and write the cache file.
+    pub fn setup(enabled: bool) {
+        STORE_ENABLED.store(enabled, Ordering::Relaxed);
+    }
+}
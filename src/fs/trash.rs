@@ -0,0 +1,189 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Trash-aware listing of an XDG trash can (`~/.local/share/Trash`).
+//!
+//! Trashed files live in the trash's `files` directory, and each has a
+//! `NAME.trashinfo` file beside it in `info` recording where it came from and
+//! when it was deleted:
+//!
+//! ```text
+//! [Trash Info]
+//! Path=/home/user/notes%20old.txt
+//! DeletionDate=2024-01-01T12:00:00
+//! ```
+//!
+//! Listing a `files` directory adds the Original Path and Deleted columns.
+//! Per-volume trash cans (`.Trash-1000`) record paths relative to the
+//! volume's root, which are resolved against it.
+
+use chrono::{Local, NaiveDateTime, TimeZone};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Info files larger than this are ignored rather than parsed.
+const MAX_FILE_SIZE: u64 = 16 * 1024;
+
+/// What a `.trashinfo` file records about a trashed entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashInfo {
+    /// Where the entry was before it was trashed.
+    pub origin: Option<PathBuf>,
+    /// When it was trashed.
+    pub deleted: Option<SystemTime>,
+}
+
+/// Reads trash metadata for entries in a trash can's `files` directory.
+pub struct Trash;
+
+impl Trash {
+    /// Checks whether a directory is the `files` directory of a trash can.
+    ///
+    /// # Parameters
+    /// - `dir`: The directory to check.
+    ///
+    /// # Returns
+    /// `true` for `Trash/files` or `.Trash-UID/files` with an `info` directory beside it.
+    pub fn is_files_dir(dir: &Path) -> bool {
+        Self::trash_of(dir).is_some()
+    }
+
+    /// Returns the trash metadata for a trashed entry.
+    ///
+    /// # Parameters
+    /// - `path`: The entry's path inside a trash can's `files` directory.
+    ///
+    /// # Returns
+    /// The parsed info, or `None` if the entry isn't in a trash can or its
+    /// info file is missing, too large or unreadable.
+    pub fn info(path: &Path) -> Option<TrashInfo> {
+        let trash = Self::trash_of(path.parent()?)?;
+
+        let mut name = path.file_name()?.to_os_string();
+        name.push(".trashinfo");
+        let info = trash.join("info").join(name);
+
+        let metadata = fs::metadata(&info).ok()?;
+        if !metadata.is_file() || metadata.len() > MAX_FILE_SIZE {
+            return None;
+        }
+        let content = fs::read_to_string(&info).ok()?;
+
+        // Relative paths are relative to the volume the trash can sits on
+        let root = trash.parent().unwrap_or(Path::new("/"));
+        Some(Self::parse(&content, root))
+    }
+
+    /// Finds the trash can a `files` directory belongs to.
+    ///
+    /// Paths such as `.` or `../files` are resolved first, so listing from inside
+    /// the trash works too; any other name is turned down without touching the disk.
+    ///
+    /// # Parameters
+    /// - `dir`: The directory path as listed.
+    ///
+    /// # Returns
+    /// The trash can's directory, or `None` if `dir` isn't a trash can's `files`.
+    fn trash_of(dir: &Path) -> Option<PathBuf> {
+        if dir.file_name().is_some_and(|name| name != "files") {
+            return None;
+        }
+
+        let dir = fs::canonicalize(dir).ok()?;
+        let trash = dir.parent()?;
+        let is_trash = trash
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .is_some_and(|name| name == "Trash" || name.starts_with(".Trash"));
+
+        (dir.file_name()? == "files" && is_trash && trash.join("info").is_dir())
+            .then(|| trash.to_path_buf())
+    }
+
+    /// Parses the contents of a `.trashinfo` file.
+    ///
+    /// # Parameters
+    /// - `content`: The file's text.
+    /// - `root`: The directory relative paths are resolved against.
+    ///
+    /// # Returns
+    /// The info, with fields that are missing or malformed left as `None`.
+    pub fn parse(content: &str, root: &Path) -> TrashInfo {
+        let mut info = TrashInfo {
+            origin: None,
+            deleted: None,
+        };
+        let mut in_section = false;
+
+        for line in content.lines().map(str::trim) {
+            if line.starts_with('[') {
+                in_section = line == "[Trash Info]";
+                continue;
+            }
+            if !in_section {
+                continue;
+            }
+
+            match line.split_once('=') {
+                Some(("Path", value)) => {
+                    info.origin = Self::decode(value).map(|path| root.join(path));
+                }
+                Some(("DeletionDate", value)) => {
+                    info.deleted = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+                        .ok()
+                        .and_then(|date| Local.from_local_datetime(&date).earliest())
+                        .map(SystemTime::from);
+                }
+                _ => {}
+            }
+        }
+
+        info
+    }
+
+    /// Decodes the percent-encoding of a `Path=` value, e.g. `%20` for a space.
+    ///
+    /// # Parameters
+    /// - `value`: The encoded path.
+    ///
+    /// # Returns
+    /// The decoded path, or `None` if an escape is malformed or the result isn't UTF-8.
+    fn decode(value: &str) -> Option<String> {
+        let mut bytes = Vec::with_capacity(value.len());
+        let mut rest = value.bytes();
+
+        while let Some(byte) = rest.next() {
+            if byte == b'%' {
+                let high = (rest.next()? as char).to_digit(16)?;
+                let low = (rest.next()? as char).to_digit(16)?;
+                bytes.push((high * 16 + low) as u8);
+            } else {
+                bytes.push(byte);
+            }
+        }
+
+        String::from_utf8(bytes).ok()
+    }
+}
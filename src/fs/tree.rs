@@ -25,9 +25,12 @@ SOFTWARE.
 //! Tree structure for hierarchical directory representation.
 
 use crate::cli::args::Args;
+use crate::fs::descend::Descend;
 use crate::fs::dir::DirReader;
 use crate::fs::entry::Entry;
-use std::path::PathBuf;
+use crate::fs::vfs::{Fs, RealFs};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 
 /// A node in a directory tree, holding an entry and its recursive children.
 #[derive(Debug, Clone)]
@@ -36,6 +39,107 @@ pub struct TreeNode {
     pub children: Vec<TreeNode>,
 }
 
+/// Decides which directories traversal enters, tracking the open ones by device and inode.
+///
+/// Real directories are entered as long as [`Descend`] allows it. With
+/// `--dereference`, symlinks to directories are entered too, except when
+/// they lead back to a directory that is still open above them (the root or
+/// an ancestor), which would otherwise loop forever. Such a link is listed
+/// but not entered. With `--one-file-system`, directories on a different
+/// device than the root (mount points) are listed but not entered either.
+#[derive(Debug)]
+pub struct Visited {
+    /// The directories entered below the root, innermost last (`None` when
+    /// entered without a device and inode)
+    ancestors: Vec<Option<(u64, u64)>>,
+    /// The same directories, for lookup
+    open: HashSet<(u64, u64)>,
+    follow: bool,
+    /// The root's device, when traversal must stay on it
+    device: Option<u64>,
+}
+
+impl Visited {
+    /// Starts a traversal at the listing root, which counts as open.
+    ///
+    /// # Parameters
+    /// - `filesystem`: The filesystem being traversed.
+    /// - `root`: The directory the listing starts from.
+    /// - `args`: CLI arguments carrying `--dereference` and `--one-file-system`.
+    pub fn new(filesystem: &dyn Fs, root: &Path, args: &Args) -> Self {
+        let mut visited = Self {
+            ancestors: Vec::new(),
+            open: HashSet::new(),
+            follow: args.dereference,
+            device: None,
+        };
+        if (args.dereference || args.one_file_system)
+            && let Ok(metadata) = filesystem.metadata(root, true)
+        {
+            if args.dereference {
+                visited.open.insert((metadata.dev, metadata.ino));
+            }
+            if args.one_file_system {
+                visited.device = Some(metadata.dev);
+            }
+        }
+        visited
+    }
+
+    /// Checks whether traversal should descend into an entry, opening it if so.
+    ///
+    /// Every successful call must be paired with a [`Visited::leave`] once the
+    /// entry's contents have been traversed.
+    ///
+    /// # Parameters
+    /// - `filesystem`: The filesystem being traversed.
+    /// - `entry`: The child entry about to be descended into.
+    /// - `args`: CLI arguments consulted by [`Descend::allowed`].
+    ///
+    /// # Returns
+    /// `true` for a directory (or, when following, a symlink to one) that is
+    /// allowed, isn't already open, and is on the root's device if required.
+    pub fn enter(&mut self, filesystem: &dyn Fs, entry: &Entry, args: &Args) -> bool {
+        let followed = self.follow && entry.is_symlink() && entry.is_dir_like();
+        if !(entry.is_dir() || followed) || !Descend::allowed(entry.path(), args) {
+            return false;
+        }
+        if !self.follow && self.device.is_none() {
+            return true;
+        }
+
+        // Without a device and inode there's nothing to check against, so
+        // only real directories are entered blind
+        let Ok(metadata) = filesystem.metadata(entry.path(), true) else {
+            if self.follow && !followed {
+                self.ancestors.push(None);
+            }
+            return !followed;
+        };
+        if self.device.is_some_and(|device| metadata.dev != device) {
+            return false;
+        }
+        if !self.follow {
+            return true;
+        }
+
+        let key = (metadata.dev, metadata.ino);
+        if !self.open.insert(key) {
+            return false;
+        }
+        self.ancestors.push(Some(key));
+        true
+    }
+
+    /// Closes the directory most recently opened by [`Visited::enter`].
+    pub fn leave(&mut self) {
+        // Without --dereference nothing is recorded, so there is nothing to pop
+        if let Some(Some(key)) = self.ancestors.pop() {
+            self.open.remove(&key);
+        }
+    }
+}
+
 /// Builds a recursive tree representation of a directory.
 pub struct TreeBuilder {
     path: PathBuf,
@@ -61,7 +165,26 @@ impl TreeBuilder {
         // Create the root entry (requires stat since we only have a path)
         let mut root_entry = Entry::from_path(self.path.clone(), args.long);
         root_entry.conditional_metadata(args);
-        self.build_node(root_entry, args)
+        let mut visited = Visited::new(&RealFs, &self.path, args);
+        self.build_node(&RealFs, root_entry, args, &mut visited)
+    }
+
+    /// Builds the tree like [`TreeBuilder::build`], reading through the given filesystem.
+    ///
+    /// # Parameters
+    /// - `filesystem`: The filesystem to read (e.g. an image's merged layers).
+    /// - `args`: CLI arguments controlling filters, metadata, and sorting.
+    ///
+    /// # Returns
+    /// A [`TreeNode`] representing the root, with recursively populated children.
+    pub fn build_in(&self, filesystem: &dyn Fs, args: &Args) -> TreeNode {
+        let is_dir = filesystem.is_dir(&self.path);
+        let mut root_entry = Entry::create(self.path.clone(), is_dir, false, args.long);
+        if Args::is_args_requesting_metadata(args) {
+            root_entry.metadata_from(filesystem, args.dereferences(true));
+        }
+        let mut visited = Visited::new(filesystem, &self.path, args);
+        self.build_node(filesystem, root_entry, args, &mut visited)
     }
 
     /// Recursively builds a tree node from an existing entry.
@@ -70,13 +193,22 @@ impl TreeBuilder {
     /// are already created efficiently via `from_dir_entry()` in [`DirReader::list`].
     ///
     /// # Parameters
+    /// - `filesystem`: The filesystem to read children from.
     /// - `entry`: The pre-built entry for this node.
     /// - `args`: CLI arguments controlling filters, metadata, and sorting.
+    /// - `visited`: The directories open above this one.
     ///
     /// # Returns
-    /// A [`TreeNode`] with children populated recursively if the entry is a directory.
-    fn build_node(&self, entry: Entry, args: &Args) -> TreeNode {
-        let is_dir = entry.is_dir();
+    /// A [`TreeNode`] with children populated recursively if the entry was entered.
+    fn build_node(
+        &self,
+        filesystem: &dyn Fs,
+        entry: Entry,
+        args: &Args,
+        visited: &mut Visited,
+    ) -> TreeNode {
+        // Children only reach here once `visited` let them in
+        let is_dir = entry.is_dir() || (args.dereference && entry.is_dir_like());
         let path = entry.path().clone();
 
         let mut node = TreeNode {
@@ -86,11 +218,23 @@ impl TreeBuilder {
 
         if is_dir {
             let dir_reader = DirReader::from(path);
-            let entries = dir_reader.list(args);
+            let entries = dir_reader.list_in(filesystem, args);
 
             for child_entry in entries {
+                // Keep system directories (/proc, /sys, ...), unfollowed links and
+                // directories already entered as leaves
+                if !visited.enter(filesystem, &child_entry, args) {
+                    node.children.push(TreeNode {
+                        entry: child_entry,
+                        children: Vec::new(),
+                    });
+                    continue;
+                }
+
                 // Recursively build, reusing the Entry created by from_dir_entry()
-                node.children.push(self.build_node(child_entry, args));
+                node.children
+                    .push(self.build_node(filesystem, child_entry, args, visited));
+                visited.leave();
             }
         }
 
@@ -0,0 +1,233 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Filesystem abstraction for directory traversal and metadata loading.
+//!
+//! [`DirReader::list_in`](crate::fs::dir::DirReader::list_in) reads through an
+//! [`Fs`], so traversal edge cases (hidden entries, pruning, sort order) can be
+//! tested against an in-memory [`MockFs`] instead of a temporary directory.
+
+use crate::fs::cache::Cache;
+use crate::fs::metadata::Metadata;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// One child of a directory, with its type as reported by `readdir`.
+#[derive(Debug, Clone)]
+pub struct DirItem {
+    pub path: PathBuf,
+    pub is_dir: bool,
+    pub is_symlink: bool,
+}
+
+/// Read access to a filesystem.
+///
+/// Implementations are shared across the threads that load a large directory's entries.
+pub trait Fs: Sync {
+    /// Lists the children of a directory.
+    ///
+    /// # Parameters
+    /// - `path`: The directory to read.
+    ///
+    /// # Returns
+    /// The directory's children, or an I/O error if it cannot be read.
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirItem>>;
+
+    /// Loads metadata for a path.
+    ///
+    /// # Parameters
+    /// - `path`: The path to query.
+    /// - `dereference`: If `true`, follows symlinks; otherwise describes the link itself.
+    ///
+    /// # Returns
+    /// The path's [`Metadata`], or an I/O error.
+    fn metadata(&self, path: &Path, dereference: bool) -> io::Result<Metadata>;
+
+    /// Checks whether a path is a directory (following symlinks).
+    fn is_dir(&self, path: &Path) -> bool {
+        self.metadata(path, true)
+            .is_ok_and(|metadata| metadata.mode & libc::S_IFMT == libc::S_IFDIR)
+    }
+}
+
+/// The real filesystem.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RealFs;
+
+impl Fs for RealFs {
+    /// Reads through raw `getdents64` with a large buffer when built with the
+    /// `getdents` feature on Linux.
+    #[cfg(all(feature = "getdents", any(target_os = "linux", target_os = "android")))]
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirItem>> {
+        crate::fs::feature::getdents::read_dir(path)
+    }
+
+    #[cfg(not(all(feature = "getdents", any(target_os = "linux", target_os = "android"))))]
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirItem>> {
+        Ok(std::fs::read_dir(path)?
+            .filter_map(Result::ok)
+            .map(|dir_entry| {
+                let path = dir_entry.path();
+                // Use d_type to avoid stat calls, falling back only if it's unavailable
+                let (is_dir, is_symlink) = match dir_entry.file_type() {
+                    Ok(file_type) => (file_type.is_dir(), file_type.is_symlink()),
+                    Err(_) => (path.is_dir(), path.is_symlink()),
+                };
+                DirItem {
+                    path,
+                    is_dir,
+                    is_symlink,
+                }
+            })
+            .collect())
+    }
+
+    /// Loads metadata through [`Cache::metadata`], which bounds time spent on network mounts.
+    fn metadata(&self, path: &Path, dereference: bool) -> io::Result<Metadata> {
+        Cache::metadata(path, dereference)
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        path.is_dir()
+    }
+}
+
+/// A node in a [`MockFs`].
+#[derive(Debug, Clone)]
+enum MockNode {
+    Dir { ino: u64, dev: u64 },
+    File { ino: u64, size: u64, mtime: i64 },
+}
+
+/// An in-memory filesystem for tests, built up with [`MockFs::dir`] and [`MockFs::file`].
+///
+/// Children are listed in path order, so traversal results are deterministic.
+#[derive(Debug, Clone, Default)]
+pub struct MockFs {
+    nodes: BTreeMap<PathBuf, MockNode>,
+    /// How long metadata lookups on a path take, like a stat on a dead mount
+    stalls: BTreeMap<PathBuf, Duration>,
+}
+
+impl MockFs {
+    /// Creates an empty mock filesystem.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a directory.
+    ///
+    /// # Parameters
+    /// - `path`: The directory's path.
+    pub fn dir(self, path: impl Into<PathBuf>) -> Self {
+        self.mount(path, 0)
+    }
+
+    /// Adds a directory on another device, like a mount point.
+    ///
+    /// # Parameters
+    /// - `path`: The directory's path.
+    /// - `dev`: The device number reported for it (other nodes report `0`).
+    pub fn mount(mut self, path: impl Into<PathBuf>, dev: u64) -> Self {
+        let ino = self.next_ino();
+        self.nodes.insert(path.into(), MockNode::Dir { ino, dev });
+        self
+    }
+
+    /// Adds a regular file.
+    ///
+    /// # Parameters
+    /// - `path`: The file's path.
+    /// - `size`: The file size in bytes.
+    /// - `mtime`: The modification time in seconds since the Unix epoch.
+    pub fn file(mut self, path: impl Into<PathBuf>, size: u64, mtime: i64) -> Self {
+        let ino = self.next_ino();
+        self.nodes
+            .insert(path.into(), MockNode::File { ino, size, mtime });
+        self
+    }
+
+    /// Makes metadata lookups on a path take a while before answering.
+    ///
+    /// # Parameters
+    /// - `path`: A path already added.
+    /// - `delay`: How long each lookup blocks.
+    pub fn stall(mut self, path: impl Into<PathBuf>, delay: Duration) -> Self {
+        self.stalls.insert(path.into(), delay);
+        self
+    }
+
+    /// Returns the inode number for the next node added.
+    fn next_ino(&self) -> u64 {
+        self.nodes.len() as u64 + 1
+    }
+}
+
+impl Fs for MockFs {
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirItem>> {
+        if !matches!(self.nodes.get(path), Some(MockNode::Dir { .. })) {
+            return Err(io::ErrorKind::NotFound.into());
+        }
+
+        Ok(self
+            .nodes
+            .iter()
+            .filter(|(child, _)| child.parent() == Some(path))
+            .map(|(child, node)| DirItem {
+                path: child.clone(),
+                is_dir: matches!(node, MockNode::Dir { .. }),
+                is_symlink: false,
+            })
+            .collect())
+    }
+
+    fn metadata(&self, path: &Path, _dereference: bool) -> io::Result<Metadata> {
+        if let Some(delay) = self.stalls.get(path) {
+            std::thread::sleep(*delay);
+        }
+
+        let node = self.nodes.get(path).ok_or(io::ErrorKind::NotFound)?;
+
+        let (mode, dev, ino, size, mtime) = match *node {
+            MockNode::Dir { ino, dev } => (libc::S_IFDIR | 0o755, dev, ino, 4096, 0),
+            MockNode::File { ino, size, mtime } => (libc::S_IFREG | 0o644, 0, ino, size, mtime),
+        };
+
+        Ok(Metadata {
+            mode,
+            dev,
+            ino,
+            size,
+            nlink: 1,
+            blocks: size.div_ceil(512),
+            blksize: 4096,
+            atime: mtime,
+            mtime,
+            ctime: mtime,
+            ..Metadata::empty()
+        })
+    }
+}
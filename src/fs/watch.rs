@@ -0,0 +1,437 @@
+/*
+MIT License
+
+Copyright (c) 2025 Ritchie Mwewa
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Waits for changes to listed paths, for `--watch`.
+//!
+//! Linux and Android use inotify; macOS and the BSDs use kqueue. Only the
+//! listed paths themselves are watched: a directory reports entries being
+//! added, removed, renamed or (with inotify) written, but not changes deeper
+//! in its subdirectories.
+
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How long a burst of changes must go quiet before it is reported, so that
+/// e.g. a download writing many chunks causes one redraw rather than hundreds.
+const SETTLE: Duration = Duration::from_millis(100);
+
+/// Watches a set of paths for changes.
+pub struct Watch {
+    backend: Backend,
+}
+
+impl Watch {
+    /// Starts watching the given paths.
+    ///
+    /// # Parameters
+    /// - `paths`: The files and directories to watch.
+    ///
+    /// # Returns
+    /// The watch, or an error if a path can't be watched (or the platform
+    /// has neither inotify nor kqueue).
+    pub fn new(paths: &[PathBuf]) -> io::Result<Self> {
+        Ok(Self {
+            backend: Backend::new(paths)?,
+        })
+    }
+
+    /// Blocks until something changes, then until the changes settle.
+    ///
+    /// # Returns
+    /// The changed paths: an entry inside a watched directory where the
+    /// platform names it, otherwise the watched path itself.
+    pub fn wait(&mut self) -> io::Result<Vec<PathBuf>> {
+        let changed = loop {
+            let changed = self.backend.next(None)?;
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+
+        self.settle(changed)
+    }
+
+    /// Like [`Watch::wait`], but also returns (with no paths) once `ready`
+    /// holds, which is checked every [`SETTLE`] interval.
+    ///
+    /// # Parameters
+    /// - `ready`: Whether to stop waiting without a change, e.g. because
+    ///   values filled in the background call for a redraw.
+    ///
+    /// # Returns
+    /// The changed paths as for [`Watch::wait`], or none if `ready` held first.
+    pub fn wait_or(&mut self, ready: impl Fn() -> bool) -> io::Result<Vec<PathBuf>> {
+        let changed = loop {
+            let changed = self.backend.next(Some(SETTLE))?;
+            if !changed.is_empty() {
+                break changed;
+            }
+            if ready() {
+                return Ok(Vec::new());
+            }
+        };
+
+        self.settle(changed)
+    }
+
+    /// Collects further changes until they go quiet for [`SETTLE`].
+    ///
+    /// # Parameters
+    /// - `changed`: The changes reported so far.
+    ///
+    /// # Returns
+    /// All the changed paths, sorted and without duplicates.
+    fn settle(&mut self, mut changed: Vec<PathBuf>) -> io::Result<Vec<PathBuf>> {
+        loop {
+            let more = self.backend.next(Some(SETTLE))?;
+            if more.is_empty() {
+                break;
+            }
+            changed.extend(more);
+        }
+
+        changed.sort();
+        changed.dedup();
+        Ok(changed)
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use inotify::Backend;
+
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly"
+))]
+use kqueue::Backend;
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly"
+)))]
+use unsupported::Backend;
+
+/// Converts a path to a C string for the watch syscalls.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly"
+))]
+fn c_path(path: &std::path::Path) -> io::Result<std::ffi::CString> {
+    use std::os::unix::ffi::OsStrExt;
+
+    std::ffi::CString::new(path.as_os_str().as_bytes())
+        .map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod inotify {
+    use super::c_path;
+    use std::collections::HashMap;
+    use std::ffi::OsStr;
+    use std::io;
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use std::{mem, ptr};
+
+    /// Events that change what a listing shows.
+    const MASK: u32 = libc::IN_CREATE
+        | libc::IN_DELETE
+        | libc::IN_MODIFY
+        | libc::IN_ATTRIB
+        | libc::IN_MOVED_FROM
+        | libc::IN_MOVED_TO
+        | libc::IN_DELETE_SELF
+        | libc::IN_MOVE_SELF;
+
+    /// An inotify instance with one watch per path.
+    pub(super) struct Backend {
+        fd: OwnedFd,
+        /// Watched path by watch descriptor.
+        watches: HashMap<libc::c_int, PathBuf>,
+    }
+
+    impl Backend {
+        pub(super) fn new(paths: &[PathBuf]) -> io::Result<Self> {
+            let fd = unsafe { libc::inotify_init1(libc::IN_CLOEXEC) };
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+            let mut watches = HashMap::new();
+            for path in paths {
+                let c_path = c_path(path)?;
+                let wd = unsafe { libc::inotify_add_watch(fd.as_raw_fd(), c_path.as_ptr(), MASK) };
+                if wd < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                watches.insert(wd, path.clone());
+            }
+
+            Ok(Self { fd, watches })
+        }
+
+        /// Reads the next batch of events, waiting at most `timeout` (forever if `None`).
+        ///
+        /// Returns no paths on timeout or when interrupted by a signal.
+        pub(super) fn next(&mut self, timeout: Option<Duration>) -> io::Result<Vec<PathBuf>> {
+            let mut poll = libc::pollfd {
+                fd: self.fd.as_raw_fd(),
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            let millis = timeout.map_or(-1, |timeout| timeout.as_millis() as libc::c_int);
+            match unsafe { libc::poll(&mut poll, 1, millis) } {
+                0 => return Ok(Vec::new()),
+                ready if ready < 0 => return Self::interrupted(),
+                _ => {}
+            }
+
+            // Aligned for `inotify_event`, and large enough for any single event
+            let mut buffer = [0u64; 512];
+            let read = unsafe {
+                libc::read(
+                    self.fd.as_raw_fd(),
+                    buffer.as_mut_ptr().cast(),
+                    mem::size_of_val(&buffer),
+                )
+            };
+            if read < 0 {
+                return Self::interrupted();
+            }
+            let bytes =
+                unsafe { std::slice::from_raw_parts(buffer.as_ptr().cast::<u8>(), read as usize) };
+
+            let header = mem::size_of::<libc::inotify_event>();
+            let mut changed = Vec::new();
+            let mut offset = 0;
+            while offset + header <= bytes.len() {
+                let event: libc::inotify_event =
+                    unsafe { ptr::read_unaligned(bytes[offset..].as_ptr().cast()) };
+                let end = (offset + header + event.len as usize).min(bytes.len());
+
+                // The name is NUL-padded, and empty for events on the watched path itself
+                let name = bytes[offset + header..end]
+                    .split(|&byte| byte == 0)
+                    .next()
+                    .unwrap_or_default();
+                if let Some(path) = self.watches.get(&event.wd) {
+                    changed.push(if name.is_empty() {
+                        path.clone()
+                    } else {
+                        path.join(OsStr::from_bytes(name))
+                    });
+                }
+
+                offset = end;
+            }
+
+            Ok(changed)
+        }
+
+        /// Treats a `poll` or `read` cut short by a signal as "nothing yet".
+        fn interrupted() -> io::Result<Vec<PathBuf>> {
+            let error = io::Error::last_os_error();
+            match error.kind() {
+                io::ErrorKind::Interrupted => Ok(Vec::new()),
+                _ => Err(error),
+            }
+        }
+    }
+}
+
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly"
+))]
+mod kqueue {
+    use super::c_path;
+    use std::io;
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use std::{mem, ptr};
+
+    /// Opens a path only to receive events on it (`O_EVTONLY` also keeps
+    /// volumes unmountable on Apple platforms).
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    const OPEN_FLAGS: libc::c_int = libc::O_EVTONLY | libc::O_CLOEXEC;
+    #[cfg(not(any(target_os = "macos", target_os = "ios")))]
+    const OPEN_FLAGS: libc::c_int = libc::O_RDONLY | libc::O_CLOEXEC;
+
+    /// Vnode events that change what a listing shows.
+    const NOTES: u32 = libc::NOTE_WRITE
+        | libc::NOTE_EXTEND
+        | libc::NOTE_ATTRIB
+        | libc::NOTE_LINK
+        | libc::NOTE_RENAME
+        | libc::NOTE_DELETE;
+
+    /// A kqueue with a vnode filter on an open descriptor per path.
+    pub(super) struct Backend {
+        queue: OwnedFd,
+        /// Each watched path with the descriptor its events are keyed on.
+        files: Vec<(OwnedFd, PathBuf)>,
+    }
+
+    impl Backend {
+        pub(super) fn new(paths: &[PathBuf]) -> io::Result<Self> {
+            let queue = unsafe { libc::kqueue() };
+            if queue < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let queue = unsafe { OwnedFd::from_raw_fd(queue) };
+
+            let mut files = Vec::new();
+            for path in paths {
+                let c_path = c_path(path)?;
+                let fd = unsafe { libc::open(c_path.as_ptr(), OPEN_FLAGS) };
+                if fd < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+                let mut change: libc::kevent = unsafe { mem::zeroed() };
+                change.ident = fd.as_raw_fd() as _;
+                change.filter = libc::EVFILT_VNODE as _;
+                change.flags = (libc::EV_ADD | libc::EV_CLEAR) as _;
+                change.fflags = NOTES as _;
+                let registered = unsafe {
+                    libc::kevent(
+                        queue.as_raw_fd(),
+                        &change,
+                        1,
+                        ptr::null_mut(),
+                        0,
+                        ptr::null(),
+                    )
+                };
+                if registered < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+
+                files.push((fd, path.clone()));
+            }
+
+            Ok(Self { queue, files })
+        }
+
+        /// Reads the next batch of events, waiting at most `timeout` (forever if `None`).
+        ///
+        /// Returns no paths on timeout or when interrupted by a signal.
+        pub(super) fn next(&mut self, timeout: Option<Duration>) -> io::Result<Vec<PathBuf>> {
+            let timeout = timeout.map(|timeout| libc::timespec {
+                tv_sec: timeout.as_secs() as _,
+                tv_nsec: timeout.subsec_nanos() as _,
+            });
+            let timeout = timeout
+                .as_ref()
+                .map_or(ptr::null(), |timeout| timeout as *const _);
+
+            let mut events: [libc::kevent; 16] = unsafe { mem::zeroed() };
+            let count = unsafe {
+                libc::kevent(
+                    self.queue.as_raw_fd(),
+                    ptr::null(),
+                    0,
+                    events.as_mut_ptr(),
+                    events.len() as _,
+                    timeout,
+                )
+            };
+            if count < 0 {
+                let error = io::Error::last_os_error();
+                return match error.kind() {
+                    io::ErrorKind::Interrupted => Ok(Vec::new()),
+                    _ => Err(error),
+                };
+            }
+
+            Ok(events[..count as usize]
+                .iter()
+                .filter_map(|event| {
+                    self.files
+                        .iter()
+                        .find(|(fd, _)| fd.as_raw_fd() as usize == event.ident as usize)
+                        .map(|(_, path)| path.clone())
+                })
+                .collect())
+        }
+    }
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly"
+)))]
+mod unsupported {
+    use std::io;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    /// Stands in where neither inotify nor kqueue is available.
+    pub(super) struct Backend;
+
+    impl Backend {
+        pub(super) fn new(_paths: &[PathBuf]) -> io::Result<Self> {
+            Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "watching needs inotify or kqueue",
+            ))
+        }
+
+        pub(super) fn next(&mut self, _timeout: Option<Duration>) -> io::Result<Vec<PathBuf>> {
+            Ok(Vec::new())
+        }
+    }
+}
@@ -22,7 +22,10 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
+use crate::display::output::errors::{ErrorKind, Errors};
+use crate::fs::network;
 use std::ffi::CString;
+use std::io;
 use std::os::unix::ffi::OsStrExt;
 use std::path::Path;
 use std::sync::Arc;
@@ -38,11 +41,22 @@ impl Xattr {
     ///
     /// # Returns
     /// A comma-separated list of xattr names (e.g. `"user.mime_type, security.selinux"`),
-    /// or `"-"` if the file has no extended attributes or an error occurs.
+    /// or `"-"` if the file has no extended attributes or they can't be read.
+    /// Failures other than the filesystem lacking xattr support (or a network
+    /// mount being skipped or not answering) are reported against the entry.
     pub fn list(path: &Path) -> Arc<str> {
-        match Self::list_xattrs(path) {
+        match network::bounded(path, Self::list_xattrs) {
             Ok(attrs) if !attrs.is_empty() => attrs.join(", ").into(),
-            _ => "-".into(),
+            Ok(_) => "-".into(),
+            Err(error) => {
+                if !matches!(
+                    error.kind(),
+                    io::ErrorKind::Unsupported | io::ErrorKind::TimedOut
+                ) {
+                    Errors::report(ErrorKind::XattrFailed, path, &error.to_string());
+                }
+                "-".into()
+            }
         }
     }
 
@@ -55,16 +69,17 @@ impl Xattr {
     /// - `path`: Path to the file to query.
     ///
     /// # Returns
-    /// `Ok(Vec<String>)` of attribute names (possibly empty), or `Err(())`
-    /// if the path contains a null byte or the libc call fails.
-    fn list_xattrs(path: &Path) -> Result<Vec<String>, ()> {
-        let path_c = CString::new(path.as_os_str().as_bytes()).map_err(|_| ())?;
+    /// `Ok(Vec<String>)` of attribute names (possibly empty), or the error if
+    /// the path contains a null byte or the libc call fails.
+    fn list_xattrs(path: &Path) -> io::Result<Vec<String>> {
+        let path_c = CString::new(path.as_os_str().as_bytes())
+            .map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
 
         // First call to get size needed
         let size = unsafe { libc::listxattr(path_c.as_ptr(), std::ptr::null_mut(), 0) };
 
         if size < 0 {
-            return Err(());
+            return Err(io::Error::last_os_error());
         }
 
         if size == 0 {
@@ -83,7 +98,7 @@ impl Xattr {
         };
 
         if result < 0 {
-            return Err(());
+            return Err(io::Error::last_os_error());
         }
 
         // Parse null-terminated attribute names
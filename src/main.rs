@@ -23,49 +23,330 @@ SOFTWARE.
 */
 
 use cerium::cli::args::Args;
+use cerium::cli::bookmarks::Bookmarks;
+use cerium::cli::clipboard::Clipboard;
+use cerium::cli::exec::Exec;
+use cerium::cli::flags::OutputFormat;
+use cerium::cli::profiles::Profiles;
+use cerium::cli::shell::ExitDir;
+use cerium::display::catalog::Catalog;
+use cerium::display::events::Events;
 use cerium::display::factory::DisplayFactory;
+use cerium::display::layout::locale::HeaderLocale;
+use cerium::display::layout::pending::Pending;
+use cerium::display::layout::unicode_width;
+use cerium::display::layout::width::Width;
+use cerium::display::output::errors::{ErrorKind, Errors};
+use cerium::display::output::log::Log;
+use cerium::display::output::terminal;
 use cerium::display::styles::cli_help;
-use cerium::display::theme::colours::{ColourSettings, RgbColours};
+use cerium::display::theme::colours::{ColourSettings, RgbColours, StyleCapsSettings};
 use cerium::display::theme::config;
 use cerium::display::theme::icons::IconSettings;
-use cerium::fs::dir::DirReader;
+use cerium::fs::cache::{Cache, CacheSettings};
+use cerium::fs::clock::ClockSettings;
+#[cfg(feature = "checksum")]
+use cerium::fs::feature::verify::Manifest;
+use cerium::fs::git::Git;
+use cerium::fs::glob::Glob;
+use cerium::fs::highlight::Highlight;
 use cerium::fs::hyperlink::HyperlinkSettings;
+use cerium::fs::network::NetworkSettings;
+use cerium::fs::store::{Store, StoreSettings};
+use cerium::fs::watch::Watch;
+use clap::parser::ValueSource;
 use clap::{CommandFactory, FromArgMatches};
+use std::io::{self, Write};
+use std::path::PathBuf;
 use std::process;
 
 /// Parses CLI arguments, validates the target directory, and invokes the appropriate display mode.
 fn main() {
-    // Load theme from config file (or use built-in Gruvbox) BEFORE parsing args
-    let theme = config::load_theme();
+    // Load theme from config file (or use built-in Catppuccin Mocha) BEFORE parsing args
+    let mut theme = config::load_theme();
 
     // Initialise theme system for cli help
     let help_style = cli_help::HelpStyle::new(&theme);
 
-    // Apply theme colours to CLI and parse arguments
-    let arg_matches = Args::command()
-        .styles(help_style.get_styles())
-        .get_matches();
-    let args = Args::from_arg_matches(&arg_matches).expect("Failed to parse arguments");
+    // Apply theme colours to CLI
+    let command = Args::command().styles(help_style.get_styles());
+
+    // Expand `@bookmark` operands into their path and default flags
+    let bookmarks = Bookmarks::load();
+    let argv = bookmarks
+        .expand(&command, std::env::args_os().collect())
+        .unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            process::exit(2);
+        });
+
+    // Parse arguments
+    let mut arg_matches = command.clone().get_matches_from(&argv);
+
+    // Put the selected profile's flags under the explicit ones and parse again
+    match Profiles::load().apply(&command, &arg_matches, &argv) {
+        Ok(Some(merged)) => arg_matches = command.get_matches_from(merged),
+        Ok(None) => {}
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(2);
+        }
+    }
+    let mut args = Args::from_arg_matches(&arg_matches).expect("Failed to parse arguments");
+
+    // `--today`/`--this-week` filter by modification time and, unless sorted otherwise, list newest first
+    args.apply_time_window(arg_matches.value_source("sort") == Some(ValueSource::CommandLine));
+
+    // `--du` shows directories at their recursive size
+    args.apply_du();
+
+    // Ignore the terminal, theme, and locale when output must be reproducible
+    if args.deterministic {
+        args.pin_for_determinism();
+        unicode_width::pin_utf8_locale();
+    }
+
+    // `--theme` swaps in a built-in theme for this run
+    if let Some(builtin) = args.theme.as_deref().and_then(config::Theme::builtin) {
+        theme = builtin;
+    }
 
     // Initialise theme system for output
     RgbColours::init(theme);
 
-    // Setup colours, icons, and hyperlinks
+    // Setup colours, text attributes, icons, hyperlinks, network metadata handling, error reporting, caching, clock, header language, and layout width
     ColourSettings::setup(args.colours);
-    IconSettings::setup(args.icons);
+    StyleCapsSettings::setup(args.style_caps);
+    IconSettings::setup(args.icons, config::load_font_hint());
     HyperlinkSettings::setup(args.hyperlink);
+    NetworkSettings::setup(args.no_network_metadata);
+    Errors::setup(args.errors, args.output);
+    setup_log(&args);
+    CacheSettings::setup(args.no_cache);
+    StoreSettings::setup(args.persistent_cache && !args.no_cache);
+    ClockSettings::setup(args.deterministic);
+    HeaderLocale::setup(if args.deterministic {
+        Some("en".to_string())
+    } else {
+        config::load_language()
+    });
+    Width::assume(args.assume_width.map(usize::from));
+    Width::force_grid(args.force_grid);
+
+    if args.check_config {
+        process::exit(if config::print_check() { 0 } else { 1 });
+    }
+
+    // Warn about a config file that couldn't be used, and about keys migrated from older versions
+    config::report();
+
+    if args.bookmarks {
+        bookmarks.print();
+        return;
+    }
+
+    if args.list_columns {
+        Catalog::print_columns();
+        return;
+    }
+
+    if args.list_themes {
+        Catalog::print_themes(&args);
+        return;
+    }
+
+    // Expand wildcard operands the shell passed through untouched (`--glob-arg`),
+    // keeping a pattern that matches nothing so it is reported as missing
+    if args.glob_arg {
+        args.paths = args
+            .paths
+            .iter()
+            .flat_map(|path| {
+                let matches = Glob::expand(path);
+                if matches.is_empty() {
+                    vec![path.clone()]
+                } else {
+                    matches
+                }
+            })
+            .collect();
+    }
+
+    // Validate that the paths exist (use lstat to handle broken symlinks), listing the rest
+    let (roots, missing): (Vec<PathBuf>, Vec<PathBuf>) = args
+        .paths
+        .iter()
+        .cloned()
+        .partition(|path| std::fs::symlink_metadata(path).is_ok());
+    for path in &missing {
+        if Errors::is_structured() {
+            Errors::report(ErrorKind::NotFound, path, "No such file or directory");
+        } else {
+            println!("file or directory not found: {}", path.display());
+        }
+    }
+    if roots.is_empty() {
+        // A JSON document is still printed, carrying the errors
+        if args.output == OutputFormat::Json {
+            DisplayFactory::print_all(&[], &args);
+        }
+        process::exit(1);
+    }
+    args.paths = roots;
+
+    // `--context-listing` lists a file's directory with the file highlighted
+    if args.context_listing {
+        let (dirs, files) = Highlight::context(&args.paths);
+        Highlight::setup(files);
+        args.paths = dirs;
+    }
+
+    // `--checksum-verify` loads the manifest the Verify column checks entries against
+    #[cfg(feature = "checksum")]
+    if let Some(file) = &args.checksum_verify {
+        match Manifest::load(file) {
+            Ok(manifest) => manifest.setup(),
+            Err(error) => {
+                eprintln!("cannot read manifest {}: {}", file.display(), error);
+                process::exit(1);
+            }
+        }
+    }
+
+    // `--select ... --exec ...` acts on the matches instead of listing them
+    if let Some(exec) = Exec::from_args(&args) {
+        if let Err(error) = exec.run(&args) {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+        return;
+    }
 
-    // Convert input path to PathBuf
-    let path = &args.path;
-    let dir_reader = DirReader::from(path.to_path_buf());
+    // `--watch` redraws the listing after each change until interrupted, or
+    // with `--output ndjson` prints an event per change
+    if args.watch {
+        match args.output {
+            OutputFormat::Text => watch(&args),
+            OutputFormat::Ndjson | OutputFormat::Json => watch_events(&args),
+            OutputFormat::Html => {
+                eprintln!("--watch can't write --output html; use text or ndjson");
+                process::exit(2);
+            }
+        }
+    }
+
+    // Use the factory to create the appropriate display mode for each path
+    let tally = DisplayFactory::print_all(&args.paths, &args);
+    save_store();
+
+    // Like `sha256sum -c`, name the files that are gone and fail if anything didn't verify
+    #[cfg(feature = "checksum")]
+    let unverified = Manifest::conclude();
+    #[cfg(not(feature = "checksum"))]
+    let unverified = false;
+
+    // Put the listed paths on the clipboard through the terminal (works over SSH)
+    if args.copy_paths
+        && let Err(error) = Clipboard::copy(tally.paths(), &args)
+    {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+
+    if args.cache_stats {
+        Cache::print_stats();
+    }
 
-    // Validate that the path exists (use lstat to handle broken symlinks)
-    if std::fs::symlink_metadata(path).is_err() {
-        println!("file or directory not found: {}", &path.display());
+    // Hand the directory back to a shell wrapper that wants to `cd` into it
+    if let Some(fd) = args.print_dir_on_exit
+        && let Err(error) = ExitDir::write(fd, args.root())
+    {
+        eprintln!("{}", error);
         process::exit(1);
     }
 
-    // Use the factory to create the appropriate display mode
-    let display = DisplayFactory::create(&dir_reader, args);
-    display.print();
+    if !missing.is_empty() || unverified {
+        process::exit(1);
+    }
+}
+
+/// Lists the paths, then redraws the listing whenever one of them changes.
+///
+/// Never returns; exits if the paths can't be watched.
+fn watch(args: &Args) -> ! {
+    let mut watch = Watch::new(&args.paths).unwrap_or_else(|error| {
+        eprintln!("cannot watch: {}", error);
+        process::exit(1);
+    });
+
+    // Slow values below the first screenful are filled in the background
+    Pending::setup(args, Width::terminal_height().unwrap_or(usize::MAX));
+
+    loop {
+        terminal::clear_screen();
+        Pending::begin_draw();
+        DisplayFactory::print_all(&args.paths, args);
+        let _ = io::stdout().flush();
+        save_store();
+
+        let changed = watch
+            .wait_or(Pending::take_refresh)
+            .unwrap_or_else(|error| {
+                eprintln!("cannot watch: {}", error);
+                process::exit(1);
+            });
+        for path in &changed {
+            Cache::invalidate_subtree(path);
+            Pending::invalidate(path);
+        }
+        Cache::expire_dates();
+        if !changed.is_empty() {
+            Git::refresh();
+        }
+    }
+}
+
+/// Prints a JSON line per change to the listed paths until interrupted (`--watch --output ndjson`).
+///
+/// An endless stream can't be one document, so `--output json` streams lines too.
+fn watch_events(args: &Args) -> ! {
+    let mut watch = Watch::new(&args.paths).unwrap_or_else(|error| {
+        eprintln!("cannot watch: {}", error);
+        process::exit(1);
+    });
+    let mut events = Events::new(args);
+
+    loop {
+        let changed = watch.wait().unwrap_or_else(|error| {
+            eprintln!("cannot watch: {}", error);
+            process::exit(1);
+        });
+        for path in &changed {
+            Cache::invalidate_subtree(path);
+        }
+        events.record(&changed);
+    }
+}
+
+/// Starts `--debug` logging; `--verbose` adds the `--find` and `--hide` modules.
+///
+/// Exits if the `--debug-file` can't be opened.
+fn setup_log(args: &Args) {
+    let mut targets = args.debug.clone();
+    if args.verbose {
+        targets.extend(["fs::dir", "fs::search"].map(String::from));
+    }
+
+    if let Err(error) = Log::setup(&targets, args.debug_file.as_deref()) {
+        let file = args.debug_file.clone().unwrap_or_default();
+        eprintln!("cannot open debug log {}: {}", file.display(), error);
+        process::exit(1);
+    }
+}
+
+/// Writes the values computed by this listing to the persistent cache (`--persistent-cache`).
+fn save_store() {
+    if let Err(error) = Store::save() {
+        eprintln!("cannot write cache: {}", error);
+    }
 }
@@ -0,0 +1,133 @@
+use cerium::cli::args::Args;
+use cerium::cli::bookmarks::Bookmarks;
+use clap::CommandFactory;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+const CONFIG: &str = r#"
+[colors]
+entry_directory = "blue"
+
+[bookmarks]
+work = "/srv/work"
+logs = { path = "/var/log", flags = ["-l", "--sort=modified"] }
+broken = 42
+"#;
+
+fn argv(args: &[&str]) -> Vec<OsString> {
+    args.iter().map(OsString::from).collect()
+}
+
+#[test]
+fn test_bookmarks_parse() {
+    let bookmarks = Bookmarks::from_toml(CONFIG);
+
+    assert_eq!(
+        bookmarks.get("work").unwrap().path,
+        PathBuf::from("/srv/work")
+    );
+    assert_eq!(
+        bookmarks.get("logs").unwrap().flags,
+        ["-l", "--sort=modified"]
+    );
+    assert!(bookmarks.get("broken").is_none());
+}
+
+#[test]
+fn test_bookmarks_expand_path_and_flags() {
+    let bookmarks = Bookmarks::from_toml(CONFIG);
+
+    assert_eq!(
+        bookmarks
+            .expand(&Args::command(), argv(&["ce", "@logs", "-a"]))
+            .unwrap(),
+        argv(&["ce", "-l", "--sort=modified", "/var/log", "-a"])
+    );
+}
+
+#[test]
+fn test_bookmarks_explicit_flags_win() {
+    let bookmarks = Bookmarks::from_toml(CONFIG);
+
+    assert_eq!(
+        bookmarks
+            .expand(&Args::command(), argv(&["ce", "--sort=size", "@logs"]))
+            .unwrap(),
+        argv(&["ce", "-l", "--sort=size", "/var/log"])
+    );
+}
+
+#[test]
+fn test_bookmarks_unknown_name_errors() {
+    let bookmarks = Bookmarks::from_toml(CONFIG);
+
+    assert!(
+        bookmarks
+            .expand(&Args::command(), argv(&["ce", "@nope"]))
+            .is_err()
+    );
+    assert_eq!(
+        bookmarks
+            .expand(&Args::command(), argv(&["ce", "src"]))
+            .unwrap(),
+        argv(&["ce", "src"])
+    );
+}
+
+#[test]
+fn test_bookmarks_option_values_not_expanded() {
+    let bookmarks = Bookmarks::from_toml(CONFIG);
+
+    assert_eq!(
+        bookmarks
+            .expand(&Args::command(), argv(&["ce", "--find", "@todo", "."]))
+            .unwrap(),
+        argv(&["ce", "--find", "@todo", "."])
+    );
+    assert_eq!(
+        bookmarks
+            .expand(&Args::command(), argv(&["ce", "--find=@work", "@work"]))
+            .unwrap(),
+        argv(&["ce", "--find=@work", "/srv/work"])
+    );
+}
+
+#[test]
+fn test_bookmarks_flag_values_dropped_with_flag() {
+    let bookmarks = Bookmarks::from_toml(CONFIG);
+
+    assert_eq!(
+        bookmarks
+            .expand(&Args::command(), argv(&["ce", "@logs", "--sort", "size"]))
+            .unwrap(),
+        argv(&["ce", "-l", "/var/log", "--sort", "size"])
+    );
+}
+
+#[test]
+fn test_bookmarks_short_and_long_flags_match() {
+    let bookmarks = Bookmarks::from_toml(CONFIG);
+
+    assert_eq!(
+        bookmarks
+            .expand(&Args::command(), argv(&["ce", "--long", "@logs"]))
+            .unwrap(),
+        argv(&["ce", "--sort=modified", "--long", "/var/log"])
+    );
+}
+
+#[test]
+fn test_bookmarks_unknown_flag_errors() {
+    let bookmarks = Bookmarks::from_toml(
+        r#"
+[bookmarks]
+odd = { path = "/tmp", flags = ["--no-such-flag"] }
+"#,
+    );
+
+    assert!(
+        bookmarks
+            .expand(&Args::command(), argv(&["ce", "@odd"]))
+            .is_err()
+    );
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use cerium::display::theme::config::Theme;
+use common::{ce, stdout};
+use tempfile::TempDir;
+
+#[test]
+fn test_list_columns_names_the_enabling_flags() {
+    let stdout = stdout(&mut ce(&["--list-columns"]));
+    let rows: Vec<Vec<&str>> = stdout
+        .lines()
+        .skip(1)
+        .map(|line| line.split("  ").filter(|cell| !cell.is_empty()).collect())
+        .collect();
+    let row = |name: &str| rows.iter().find(|row| row[0].trim() == name).unwrap();
+
+    assert!(stdout.starts_with("Column"));
+    assert!(row("perm")[2].contains("--permissions"));
+    assert!(row("size")[2].contains("--size"));
+    assert!(row("mtime")[2].contains("--modified"));
+    assert_eq!(row("name")[2].trim(), "always shown");
+}
+
+#[test]
+fn test_list_columns_names_are_accepted_by_columns() {
+    let stdout = stdout(&mut ce(&["--list-columns"]));
+    let temp_dir = TempDir::new().unwrap();
+
+    for line in stdout.lines().skip(1) {
+        let name = line.split_whitespace().next().unwrap();
+        let status = ce(&["--columns", name])
+            .arg(temp_dir.path())
+            .output()
+            .unwrap()
+            .status;
+
+        assert!(status.success(), "--columns {name} was rejected");
+    }
+}
+
+#[test]
+fn test_list_themes_marks_the_selected_theme() {
+    let stdout = stdout(&mut ce(&["--list-themes", "--theme", "nord"]));
+    let names: Vec<&str> = stdout
+        .lines()
+        .map(|line| line[2..].split_whitespace().next().unwrap())
+        .collect();
+
+    assert_eq!(names, Theme::BUILTIN);
+    assert!(stdout.lines().any(|line| line == "* nord"));
+    assert_eq!(stdout.matches('*').count(), 1);
+}
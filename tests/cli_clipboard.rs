@@ -0,0 +1,142 @@
+use cerium::cli::args::Args;
+use cerium::cli::clipboard::{Clipboard, MAX_PAYLOAD};
+use cerium::cli::flags::CopySeparator;
+use cerium::display::factory::DisplayFactory;
+use cerium::display::theme::colours::RgbColours;
+use cerium::display::theme::config::Theme;
+use clap::Parser;
+use std::ffi::OsStr;
+use std::fs::{self, File};
+use std::os::unix::ffi::OsStrExt;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+/// Lists as `main` does and returns the paths `--copy-paths` would copy.
+fn copied(args: &Args) -> Vec<PathBuf> {
+    RgbColours::init(Theme::default());
+    DisplayFactory::print_all(&args.paths, args)
+        .paths()
+        .to_vec()
+}
+
+#[test]
+fn test_osc52_encodes_base64() {
+    assert_eq!(Clipboard::osc52("", false).unwrap(), "\x1b]52;c;\x07");
+    assert_eq!(Clipboard::osc52("f", false).unwrap(), "\x1b]52;c;Zg==\x07");
+    assert_eq!(Clipboard::osc52("fo", false).unwrap(), "\x1b]52;c;Zm8=\x07");
+    assert_eq!(
+        Clipboard::osc52("/tmp/a b\n/ü", false).unwrap(),
+        "\x1b]52;c;L3RtcC9hIGIKL8O8\x07"
+    );
+}
+
+#[test]
+fn test_osc52_tmux_passthrough() {
+    assert_eq!(
+        Clipboard::osc52("f", true).unwrap(),
+        "\x1bPtmux;\x1b\x1b]52;c;Zg==\x07\x1b\\"
+    );
+}
+
+#[test]
+fn test_osc52_refuses_oversized_payload() {
+    let fits = "x".repeat(MAX_PAYLOAD / 4 * 3);
+    assert!(Clipboard::osc52(&fits, false).is_ok());
+
+    let too_big = "x".repeat(MAX_PAYLOAD / 4 * 3 + 1);
+    let error = Clipboard::osc52(&too_big, false).unwrap_err();
+    assert!(error.contains("clipboard limit"), "{error}");
+}
+
+#[test]
+fn test_join_separators() {
+    let paths = [PathBuf::from("/a/plain"), PathBuf::from("/a/with space")];
+
+    assert_eq!(
+        Clipboard::join(&paths, CopySeparator::Newline).unwrap(),
+        "/a/plain\n/a/with space"
+    );
+    assert_eq!(
+        Clipboard::join(&paths, CopySeparator::Space).unwrap(),
+        "/a/plain '/a/with space'"
+    );
+}
+
+#[test]
+fn test_join_quotes_each_path_as_one_word() {
+    let paths = [PathBuf::from("/tmp/x -> y")];
+
+    assert_eq!(
+        Clipboard::join(&paths, CopySeparator::Space).unwrap(),
+        "'/tmp/x -> y'"
+    );
+}
+
+#[test]
+fn test_join_refuses_non_utf8_paths() {
+    let paths = [PathBuf::from(OsStr::from_bytes(b"/tmp/caf\xe9"))];
+
+    let error = Clipboard::join(&paths, CopySeparator::Newline).unwrap_err();
+    assert!(error.contains("not valid UTF-8"));
+}
+
+#[test]
+fn test_paths_follow_listing_filters() {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path();
+    File::create(base.join("b.txt")).unwrap();
+    File::create(base.join("a.rs")).unwrap();
+    File::create(base.join(".hidden")).unwrap();
+    fs::create_dir(base.join("dir")).unwrap();
+    File::create(base.join("dir/c.txt")).unwrap();
+
+    let root = base.to_str().unwrap();
+    let listed = copied(&Args::parse_from(["ce", "--copy-paths", root]));
+    assert_eq!(
+        listed,
+        vec![base.join("a.rs"), base.join("b.txt"), base.join("dir")]
+    );
+
+    let files = copied(&Args::parse_from(["ce", "--copy-paths", "-f", "-a", root]));
+    assert_eq!(
+        files,
+        vec![base.join(".hidden"), base.join("a.rs"), base.join("b.txt")]
+    );
+
+    let found = copied(&Args::parse_from([
+        "ce",
+        "--copy-paths",
+        "--find",
+        "*.txt",
+        "-R",
+        root,
+    ]));
+    assert!(found.contains(&base.join("b.txt")));
+    assert!(found.contains(&base.join("dir/c.txt")));
+    assert_eq!(found.len(), 2);
+}
+
+#[test]
+fn test_recursive_and_tree_listings_copy_every_entry_shown() {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path();
+    fs::create_dir_all(base.join("dir/nested")).unwrap();
+    File::create(base.join("top.txt")).unwrap();
+    File::create(base.join("dir/inner.txt")).unwrap();
+    File::create(base.join("dir/nested/deep.txt")).unwrap();
+
+    let root = base.to_str().unwrap();
+    let expected = [
+        base.join("dir"),
+        base.join("dir/inner.txt"),
+        base.join("dir/nested"),
+        base.join("dir/nested/deep.txt"),
+        base.join("top.txt"),
+    ];
+
+    for mode in ["-R", "--tree"] {
+        let mut paths = copied(&Args::parse_from(["ce", "--copy-paths", mode, root]));
+        paths.sort();
+        assert_eq!(paths, expected, "{mode}");
+    }
+}
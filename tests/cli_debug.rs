@@ -0,0 +1,86 @@
+mod common;
+
+use common::run_in;
+use std::fs;
+use std::process::Output;
+use tempfile::TempDir;
+
+/// Runs `ce` in a directory holding `a.txt` and `b.rs`.
+fn run(dir: &TempDir, args: &[&str]) -> Output {
+    fs::write(dir.path().join("a.txt"), "").unwrap();
+    fs::write(dir.path().join("b.rs"), "").unwrap();
+
+    run_in(dir.path(), args)
+}
+
+fn targets(log: &str) -> Vec<&str> {
+    log.lines()
+        .map(|line| {
+            let target = line.split(' ').nth(1).unwrap();
+            target.strip_prefix("target=").unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_debug_logs_only_the_named_modules_and_their_children() {
+    let dir = TempDir::new().unwrap();
+    let output = run(&dir, &["-1", "--hide", "*.txt", "--debug", "fs"]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(output.status.success());
+    assert!(stderr.lines().all(|line| line.starts_with("t=")));
+    assert!(targets(&stderr).contains(&"fs::glob"));
+    assert!(targets(&stderr).contains(&"fs::dir"));
+    assert!(
+        targets(&stderr)
+            .iter()
+            .all(|target| target.starts_with("fs::"))
+    );
+    assert!(stderr.contains(r#"msg="compiling pattern" pattern=*.txt regex=^.*\.txt$"#));
+}
+
+#[test]
+fn test_debug_file_takes_the_log_instead_of_stderr() {
+    let dir = TempDir::new().unwrap();
+    let output = run(
+        &dir,
+        &[
+            "-1",
+            "--hide",
+            "*.txt",
+            "--debug",
+            "fs::dir",
+            "--debug-file",
+            "debug.log",
+        ],
+    );
+    let written = fs::read_to_string(dir.path().join("debug.log")).unwrap();
+
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+    assert!(written.contains(r#"target=fs::dir msg="hid entries" dir=. patterns=*.txt removed=1"#));
+    assert!(targets(&written).iter().all(|target| *target == "fs::dir"));
+}
+
+#[test]
+fn test_verbose_find_logs_to_stderr_not_stdout() {
+    let dir = TempDir::new().unwrap();
+    let output = run(&dir, &["--find", "*.rs", "-v"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(stdout.contains("b.rs"));
+    assert!(!stdout.contains("Searching"));
+    assert!(stderr.contains("target=fs::search msg=match path=./b.rs"));
+    assert!(stderr.contains(r#"msg="search finished" dir=. matches=1"#));
+}
+
+#[test]
+fn test_nothing_is_logged_without_debug() {
+    let dir = TempDir::new().unwrap();
+    let output = run(&dir, &["-1", "--hide", "*.txt"]);
+
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+}
@@ -0,0 +1,178 @@
+mod common;
+
+use cerium::cli::exec::Exec;
+use common::run_in;
+use std::fs::{self, File};
+use std::path::Path;
+use tempfile::TempDir;
+
+fn setup_dir() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path();
+
+    File::create(base.join("a.log")).unwrap();
+    File::create(base.join("it's.log")).unwrap();
+    File::create(base.join(".hidden.log")).unwrap();
+    File::create(base.join("keep.txt")).unwrap();
+    fs::create_dir(base.join("nested")).unwrap();
+    File::create(base.join("nested/b.log")).unwrap();
+
+    temp_dir
+}
+
+#[test]
+fn test_exec_command_substitution() {
+    assert_eq!(
+        Exec::command_for("gzip -k {}", Path::new("./a b.log")),
+        "gzip -k './a b.log'"
+    );
+    assert_eq!(
+        Exec::command_for("mv {} {}.bak", Path::new("x")),
+        "mv 'x' 'x'.bak"
+    );
+    assert_eq!(
+        Exec::command_for("rm --", Path::new("it's")),
+        "rm -- 'it'\\''s'"
+    );
+}
+
+#[test]
+fn test_exec_command_shows_names_safely() {
+    assert_eq!(
+        Exec::command_for("rm {}", Path::new("./evil\x1b[2K\rok.log")),
+        "rm './evil\\x1b[2K\\x0dok.log'"
+    );
+    assert_eq!(
+        Exec::command_for("rm {}", Path::new("./x -> y.log")),
+        "rm './x -> y.log'"
+    );
+}
+
+#[test]
+fn test_exec_dry_run_lists_commands_only() {
+    let temp_dir = setup_dir();
+    let output = run_in(
+        temp_dir.path(),
+        &["--select", "*.log", "--exec", "rm {}", "--dry-run"],
+    );
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "rm './a.log'\nrm './it'\\''s.log'\n"
+    );
+    assert!(temp_dir.path().join("a.log").exists());
+}
+
+#[test]
+fn test_exec_respects_listing_filters() {
+    let temp_dir = setup_dir();
+    let output = run_in(
+        temp_dir.path(),
+        &[
+            "--select",
+            "*.log",
+            "--exec",
+            "echo",
+            "--dry-run",
+            "-a",
+            "-R",
+        ],
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(stdout.contains("'./.hidden.log'"));
+    assert!(stdout.contains("'./nested/b.log'"));
+    assert!(!stdout.contains("keep.txt"));
+}
+
+#[test]
+fn test_exec_runs_with_yes() {
+    let temp_dir = setup_dir();
+    let output = run_in(
+        temp_dir.path(),
+        &["--select", "*.log", "--exec", "mv {} {}.done", "--yes"],
+    );
+
+    assert!(output.status.success());
+    assert!(temp_dir.path().join("a.log.done").exists());
+    assert!(temp_dir.path().join("it's.log.done").exists());
+    assert!(!temp_dir.path().join("a.log").exists());
+}
+
+#[test]
+fn test_exec_reports_failures() {
+    let temp_dir = setup_dir();
+    let output = run_in(
+        temp_dir.path(),
+        &["--select", "*.log", "--exec", "false", "--yes"],
+    );
+
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("2 of 2 commands failed")
+    );
+}
+
+#[test]
+fn test_exec_requires_select() {
+    let temp_dir = setup_dir();
+    let output = run_in(temp_dir.path(), &["--exec", "rm {}", "--yes"]);
+
+    assert!(!output.status.success());
+    assert!(temp_dir.path().join("a.log").exists());
+}
+
+#[test]
+fn test_exec_script_passes_the_path_as_an_argument() {
+    assert_eq!(Exec::script_for("gzip -k {}"), "gzip -k \"$1\"");
+    assert_eq!(Exec::script_for("echo '{}'"), "echo \"$1\"");
+    assert_eq!(Exec::script_for("mv {} {}.bak"), "mv \"$1\" \"$1\".bak");
+    assert_eq!(Exec::script_for("rm --"), "rm -- \"$1\"");
+}
+
+#[test]
+fn test_exec_never_runs_shell_syntax_from_file_names() {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path();
+    let names = [
+        "a;touch PWNED;b.log",
+        "$(touch PWNED2).log",
+        "q'uote`s`.log",
+    ];
+    for name in names {
+        File::create(base.join(name)).unwrap();
+    }
+
+    for template in ["echo '{}' >> seen", "echo {} >> seen", "echo >> seen"] {
+        let output = run_in(base, &["--select", "*.log", "--exec", template, "--yes"]);
+        assert!(output.status.success());
+    }
+
+    assert!(!base.join("PWNED").exists());
+    assert!(!base.join("PWNED2").exists());
+    let seen = fs::read_to_string(base.join("seen")).unwrap();
+    for name in names {
+        assert_eq!(seen.matches(&format!("./{name}\n")).count(), 3);
+    }
+}
+
+#[test]
+fn test_exec_passes_non_utf8_names_unchanged() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path();
+    File::create(base.join(OsStr::from_bytes(b"caf\xe9.log"))).unwrap();
+
+    let output = run_in(
+        base,
+        &["--select", "*.log", "--exec", "mv {} {}.done", "--yes"],
+    );
+
+    assert!(output.status.success());
+    assert!(base.join(OsStr::from_bytes(b"caf\xe9.log.done")).exists());
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use common::{ce, listing, stdout};
+use std::fs;
+use tempfile::TempDir;
+
+/// Runs `ce` in a directory of 30 long names, as if on a terminal 10 lines tall and 80 wide.
+fn run(args: &[&str]) -> String {
+    let dir = TempDir::new().unwrap();
+    for number in 1..=30 {
+        fs::write(
+            dir.path()
+                .join(format!("quarterly_report_{number:02}_final.pdf")),
+            "",
+        )
+        .unwrap();
+    }
+
+    stdout(
+        ce(args)
+            .args(["--width", "80"])
+            .current_dir(dir.path())
+            .env("LINES", "10"),
+    )
+}
+
+#[test]
+fn test_fit_screen_shortens_names_until_the_grid_fits() {
+    let plain = run(&[]);
+    let fitted = run(&["--fit-screen"]);
+
+    assert!(listing(&plain).len() > 7);
+    assert!(listing(&fitted).len() <= 7);
+    assert!(fitted.contains('…'));
+    assert!(fitted.contains("30 files."));
+}
+
+#[test]
+fn test_fit_screen_spreads_oneline_into_a_grid_but_keeps_long_rows() {
+    assert_eq!(listing(&run(&["-1"])).len(), 30);
+    assert!(listing(&run(&["-1", "--fit-screen"])).len() <= 7);
+    assert_eq!(listing(&run(&["-l", "--fit-screen"])).len(), 30);
+}
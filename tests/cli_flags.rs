@@ -0,0 +1,181 @@
+use cerium::cli::args::Args;
+use cerium::cli::flags::{
+    ContentLimit, DateFormat, SizeComparison, SizeFilter, SortBy, TimeFilter,
+};
+use cerium::display::layout::column::Column;
+use cerium::fs::clock::FixedClock;
+use chrono::{Datelike, Local, NaiveDate, TimeZone, Timelike, Weekday};
+use clap::Parser;
+use std::time::{Duration, SystemTime};
+
+#[test]
+fn test_size_filter_parses_sign_and_units() {
+    let larger: SizeFilter = "+10M".parse().unwrap();
+    assert_eq!(larger.comparison, SizeComparison::Larger);
+    assert_eq!(larger.bytes, 10 * 1024 * 1024);
+
+    let smaller: SizeFilter = "-4k".parse().unwrap();
+    assert_eq!(smaller.comparison, SizeComparison::Smaller);
+    assert_eq!(smaller.bytes, 4096);
+
+    let exact: SizeFilter = "512".parse().unwrap();
+    assert_eq!(exact.comparison, SizeComparison::Exactly);
+    assert_eq!(exact.bytes, 512);
+
+    assert_eq!("+1GiB".parse::<SizeFilter>().unwrap().bytes, 1 << 30);
+    assert_eq!("2kb".parse::<SizeFilter>().unwrap().bytes, 2048);
+}
+
+#[test]
+fn test_size_filter_rejects_malformed_expressions() {
+    for expression in ["", "+", "10X", "M", "+-5", "99999999999T"] {
+        assert!(expression.parse::<SizeFilter>().is_err(), "{expression}");
+    }
+}
+
+#[test]
+fn test_size_filter_matches() {
+    let larger: SizeFilter = "+100".parse().unwrap();
+    assert!(larger.matches(101));
+    assert!(!larger.matches(100));
+
+    let smaller: SizeFilter = "-100".parse().unwrap();
+    assert!(smaller.matches(99));
+    assert!(!smaller.matches(100));
+}
+
+#[test]
+fn test_time_filter_parses_ages() {
+    assert_eq!(
+        "2d".parse::<TimeFilter>().unwrap(),
+        TimeFilter::Ago(Duration::from_secs(2 * 24 * 60 * 60))
+    );
+    assert_eq!(
+        "90m".parse::<TimeFilter>().unwrap(),
+        TimeFilter::Ago(Duration::from_secs(90 * 60))
+    );
+    assert!("2y".parse::<TimeFilter>().is_err());
+    assert!("d".parse::<TimeFilter>().is_err());
+}
+
+#[test]
+fn test_time_filter_parses_dates() {
+    let midnight = NaiveDate::from_ymd_opt(2024, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    assert_eq!(
+        "2024-01-01".parse::<TimeFilter>().unwrap(),
+        TimeFilter::At(midnight)
+    );
+
+    let afternoon = NaiveDate::from_ymd_opt(2024, 1, 1)
+        .unwrap()
+        .and_hms_opt(12, 30, 0)
+        .unwrap();
+    assert_eq!(
+        "2024-01-01 12:30".parse::<TimeFilter>().unwrap(),
+        TimeFilter::At(afternoon)
+    );
+    assert!("2024-13-01".parse::<TimeFilter>().is_err());
+}
+
+#[test]
+fn test_age_cutoff_counts_back_from_the_clock() {
+    let clock = FixedClock(SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000));
+    let filter: TimeFilter = "1h".parse().unwrap();
+
+    assert_eq!(filter.cutoff(&clock), 1_000_000 - 3600);
+}
+
+#[test]
+fn test_time_windows_start_at_midnight_and_monday() {
+    // A Thursday afternoon (2025-01-02 15:00 UTC)
+    let clock = FixedClock(SystemTime::UNIX_EPOCH + Duration::from_secs(1_735_830_000));
+    let now = 1_735_830_000;
+
+    let today = TimeFilter::Today.cutoff(&clock);
+    let week = TimeFilter::ThisWeek.cutoff(&clock);
+
+    assert!(week <= today && today <= now);
+    assert!(now - today <= 25 * 60 * 60);
+    assert!(now - week < 8 * 24 * 60 * 60);
+
+    let start = Local.timestamp_opt(week, 0).unwrap();
+    assert_eq!(start.weekday(), Weekday::Mon);
+    assert_eq!((start.hour(), start.minute()), (0, 0));
+}
+
+#[test]
+fn test_time_window_presets_sort_newest_first() {
+    let mut args = Args::parse_from(["ce", "--today"]);
+    args.apply_time_window(false);
+    assert_eq!(args.newer, Some(TimeFilter::Today));
+    assert!(matches!(args.sort[..], [SortBy::Modified]));
+    assert!(args.reverse);
+
+    let mut args = Args::parse_from(["ce", "--this-week", "--sort", "size"]);
+    args.apply_time_window(true);
+    assert_eq!(args.newer, Some(TimeFilter::ThisWeek));
+    assert!(matches!(args.sort[..], [SortBy::Size]));
+    assert!(!args.reverse);
+
+    assert!(Args::try_parse_from(["ce", "--today", "--newer", "2d"]).is_err());
+}
+
+#[test]
+fn test_columns_keep_the_given_order() {
+    let args = Args::parse_from(["ce", "--columns", "perm,user,size,git,name"]);
+    assert_eq!(
+        args.columns,
+        [
+            Column::Permissions,
+            Column::User,
+            Column::Size,
+            Column::Git,
+            Column::Name
+        ]
+    );
+
+    assert!(Args::try_parse_from(["ce", "--columns", "perm,bogus"]).is_err());
+}
+
+#[test]
+fn test_ctime_names_the_changed_column() {
+    let args = Args::parse_from(["ce", "--columns", "ctime,changed,created"]);
+    assert_eq!(
+        args.columns,
+        [Column::Changed, Column::Changed, Column::Created]
+    );
+
+    let args = Args::parse_from(["ce", "--changed", "--sort", "changed"]);
+    assert!(args.changed);
+    assert!(matches!(args.sort[..], [SortBy::Changed]));
+}
+
+#[test]
+fn test_content_limit_parses_sizes_and_none() {
+    let limit: ContentLimit = "64M".parse().unwrap();
+    assert_eq!(limit.bytes, Some(64 << 20));
+    assert_eq!(&*limit.placeholder(), ">64M");
+
+    let limit: ContentLimit = "none".parse().unwrap();
+    assert_eq!(limit.bytes, None);
+    assert!(limit.admits(std::path::Path::new("/nonexistent")));
+
+    assert!("+1G".parse::<ContentLimit>().is_err());
+    assert_eq!(Args::parse_from(["ce"]).content_limit.bytes, Some(1 << 30));
+}
+
+#[test]
+fn test_date_format_parses_custom_patterns() {
+    assert_eq!("timestamp".parse(), Ok(DateFormat::Timestamp));
+    assert_eq!(
+        "custom:%Y-%m-%d %H:%M".parse(),
+        Ok(DateFormat::Custom("%Y-%m-%d %H:%M".into()))
+    );
+    assert!("custom:%Q".parse::<DateFormat>().is_err());
+    assert!("custom:".parse::<DateFormat>().is_err());
+    assert!("iso".parse::<DateFormat>().is_err());
+    assert_eq!(Args::parse_from(["ce"]).date_format, DateFormat::Humanly);
+}
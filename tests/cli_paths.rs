@@ -0,0 +1,281 @@
+mod common;
+
+use common::{ce, run_in};
+use std::fs::{self, File};
+use std::path::Path;
+use std::process::Output;
+use tempfile::TempDir;
+
+fn setup_dir() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path();
+
+    fs::create_dir(base.join("one")).unwrap();
+    fs::create_dir(base.join("two")).unwrap();
+    File::create(base.join("one/a.txt")).unwrap();
+    File::create(base.join("two/b.txt")).unwrap();
+    File::create(base.join("f.txt")).unwrap();
+
+    temp_dir
+}
+
+/// Runs `ce --deterministic` inside `base`.
+fn deterministic(base: &Path, args: &[&str]) -> Output {
+    run_in(base, &[&["--deterministic"], args].concat())
+}
+
+/// Returns stdout with the column padding trimmed from each line.
+fn trimmed(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone())
+        .unwrap()
+        .lines()
+        .map(|line| format!("{}\n", line.trim_end()))
+        .collect()
+}
+
+#[test]
+fn test_single_path_has_no_header() {
+    let temp_dir = setup_dir();
+    let output = deterministic(temp_dir.path(), &["-1", "one"]);
+
+    assert!(output.status.success());
+    assert_eq!(trimmed(&output), "a.txt\n\n1 file.\n");
+}
+
+#[test]
+fn test_multiple_directories_get_headers() {
+    let temp_dir = setup_dir();
+    let output = deterministic(temp_dir.path(), &["-1", "two", "one"]);
+
+    assert!(output.status.success());
+    assert_eq!(
+        trimmed(&output),
+        "two:\nb.txt\n\n1 file.\n\none:\na.txt\n\n1 file.\n"
+    );
+}
+
+#[test]
+fn test_files_are_listed_before_directories() {
+    let temp_dir = setup_dir();
+    let output = deterministic(temp_dir.path(), &["-1", "one", "f.txt", "two/b.txt"]);
+
+    assert!(output.status.success());
+    assert_eq!(
+        trimmed(&output),
+        "f.txt\ntwo/b.txt\n\n2 files.\n\none:\na.txt\n\n1 file.\n"
+    );
+}
+
+#[test]
+fn test_missing_path_is_reported_and_rest_listed() {
+    let temp_dir = setup_dir();
+    let output = deterministic(temp_dir.path(), &["-1", "missing", "one"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    let text = trimmed(&output);
+    assert!(text.starts_with("file or directory not found: missing\n"));
+    assert!(text.contains("a.txt"));
+}
+
+#[test]
+fn test_recursive_uses_its_own_titles() {
+    let temp_dir = setup_dir();
+    let output = deterministic(temp_dir.path(), &["-1", "-R", "one", "two"]);
+
+    assert!(output.status.success());
+    let text = trimmed(&output);
+    assert!(text.contains("one: (1 entry)"));
+    assert!(text.contains("two: (1 entry)"));
+    assert!(!text.contains("one:\n"));
+}
+
+#[test]
+fn test_ndjson_stays_one_object_per_line() {
+    let temp_dir = setup_dir();
+    let output = deterministic(
+        temp_dir.path(),
+        &["--output", "ndjson", "one", "two", "f.txt"],
+    );
+
+    assert!(output.status.success());
+    let text = trimmed(&output);
+    assert_eq!(text.lines().count(), 3);
+    assert!(text.lines().all(|line| line.starts_with('{')));
+}
+
+#[test]
+fn test_dereference_stops_at_cycles() {
+    let temp_dir = setup_dir();
+    let base = temp_dir.path();
+    std::os::unix::fs::symlink(base.join("one"), base.join("two/link")).unwrap();
+    std::os::unix::fs::symlink(base, base.join("one/up")).unwrap();
+
+    let output = deterministic(base, &["-R", "-1", "-L", "."]);
+
+    assert!(output.status.success());
+    let stdout = trimmed(&output);
+    // `two/link` leads to a sibling, so it is listed again; `one/up` leads back to the root
+    assert!(
+        stdout.contains("./one: (2 entries)\na.txt\nup\n"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("./two/link: (2 entries)\na.txt\nup\n"),
+        "{stdout}"
+    );
+    assert!(!stdout.contains("up:"), "{stdout}");
+}
+
+#[test]
+fn test_grand_total_footer_covers_every_path_and_mode() {
+    let temp_dir = setup_dir();
+    let base = temp_dir.path();
+    fs::write(base.join("one/a.txt"), "hello").unwrap();
+    std::os::unix::fs::symlink("f.txt", base.join("two/link")).unwrap();
+    let footer = "\n2 files, 1 symlink, 5 total\n";
+
+    for mode in [&["-1"][..], &["-l"], &["--long-stream"]] {
+        let args = [
+            mode,
+            &["--grand-total", "--size-format", "bytes", "one", "two"],
+        ]
+        .concat();
+        let output = deterministic(base, &args);
+
+        assert!(output.status.success());
+        assert!(trimmed(&output).ends_with(footer), "{}", trimmed(&output));
+    }
+
+    let output = deterministic(
+        base,
+        &["--tree", "--grand-total", "--size-format", "bytes", "."],
+    );
+    assert!(
+        trimmed(&output).ends_with("\n3 files, 2 dirs, 1 symlink, 5 total\n"),
+        "{}",
+        trimmed(&output)
+    );
+}
+
+#[test]
+fn test_dedupe_counts_links_once_across_operands() {
+    let temp_dir = setup_dir();
+    let base = temp_dir.path();
+    fs::write(base.join("one/a.txt"), "hello").unwrap();
+    fs::hard_link(base.join("one/a.txt"), base.join("two/link.txt")).unwrap();
+
+    let output = deterministic(
+        base,
+        &[
+            "-1",
+            "--dedupe",
+            "--grand-total",
+            "--size-format",
+            "bytes",
+            "one",
+            "two",
+        ],
+    );
+
+    assert!(output.status.success());
+    let stdout = trimmed(&output);
+    assert!(!stdout.contains("link.txt"), "{stdout}");
+    assert!(stdout.ends_with("\n2 files, 5 total\n"), "{stdout}");
+}
+
+#[test]
+fn test_dedupe_counts_links_once_across_recursive_sections() {
+    let temp_dir = setup_dir();
+    let base = temp_dir.path();
+    fs::write(base.join("one/a.txt"), "hello").unwrap();
+    fs::hard_link(base.join("one/a.txt"), base.join("two/link.txt")).unwrap();
+
+    let output = deterministic(
+        base,
+        &[
+            "-1",
+            "-R",
+            "--dedupe",
+            "--grand-total",
+            "--size-format",
+            "bytes",
+            ".",
+        ],
+    );
+
+    assert!(output.status.success());
+    let stdout = trimmed(&output);
+    assert!(!stdout.contains("link.txt"), "{stdout}");
+    assert!(stdout.ends_with("\n3 files, 2 dirs, 5 total\n"), "{stdout}");
+}
+
+#[test]
+fn test_special_files_show_placeholders_without_being_read() {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path();
+    let fifo = std::ffi::CString::new(base.join("pipe").to_str().unwrap()).unwrap();
+    assert_eq!(unsafe { libc::mkfifo(fifo.as_ptr(), 0o644) }, 0);
+    let _socket = std::os::unix::net::UnixListener::bind(base.join("sock")).unwrap();
+
+    let mut columns = vec!["name", "perm", "head", "tail"];
+    if cfg!(feature = "checksum") {
+        columns.push("sha256");
+    }
+    if cfg!(feature = "magic") {
+        columns.push("magic");
+    }
+    let mut child = ce(&[
+        "--deterministic",
+        "--columns",
+        &columns.join(","),
+        "pipe",
+        "sock",
+    ])
+    .current_dir(base)
+    .stdout(std::process::Stdio::piped())
+    .spawn()
+    .unwrap();
+
+    // Reading the FIFO would block forever, as nothing writes to it
+    let started = std::time::Instant::now();
+    while child.try_wait().unwrap().is_none() {
+        if started.elapsed() > std::time::Duration::from_secs(10) {
+            child.kill().unwrap();
+            panic!("ce blocked on a special file");
+        }
+        std::thread::sleep(std::time::Duration::from_millis(20));
+    }
+
+    let output = trimmed(&child.wait_with_output().unwrap());
+    let lines: Vec<Vec<&str>> = output
+        .lines()
+        .take_while(|line| !line.is_empty())
+        .map(|line| line.split_whitespace().collect())
+        .collect();
+    assert_eq!(lines.len(), 2, "{output}");
+    for cells in lines {
+        assert!(cells[1].starts_with(['p', 's']), "{cells:?}");
+        assert!(cells[2..].iter().all(|cell| *cell == "-"), "{cells:?}");
+    }
+}
+
+#[test]
+fn test_content_limit_skips_large_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path();
+    fs::write(base.join("small.txt"), "hello").unwrap();
+    // Sparse, so it takes no space on disk
+    File::create(base.join("large.bin"))
+        .unwrap()
+        .set_len(2 << 20)
+        .unwrap();
+
+    let args = ["--columns", "name,head", "--content-limit", "1M"];
+    let output = trimmed(&deterministic(base, &args));
+    assert!(output.contains("large.bin >1M\n"), "{output}");
+    assert!(output.contains("small.txt hello\n"), "{output}");
+
+    let args = ["--columns", "name,head", "--content-limit", "none"];
+    let output = trimmed(&deterministic(base, &args));
+    assert!(output.contains("large.bin <binary>\n"), "{output}");
+}
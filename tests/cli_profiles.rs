@@ -0,0 +1,104 @@
+use cerium::cli::args::Args;
+use cerium::cli::profiles::Profiles;
+use clap::CommandFactory;
+use std::ffi::OsString;
+
+const CONFIG: &str = r#"
+[profile.review]
+long = true
+git = true
+sort = "modified"
+
+[profile.deep]
+tree = true
+level = 2
+skip-dirs = ["none"]
+reverse = false
+
+[profile.broken]
+not_a_flag = true
+
+not_a_table = 1
+"#;
+
+fn profiles() -> Profiles {
+    Profiles::from_table(&toml::from_str(CONFIG).unwrap())
+}
+
+fn argv(args: &[&str]) -> Vec<OsString> {
+    args.iter().map(OsString::from).collect()
+}
+
+/// Applies the selected profile to a command line, as `main` does.
+fn apply(args: &[&str]) -> Result<Option<Vec<OsString>>, String> {
+    let command = Args::command();
+    let argv = argv(args);
+    let matches = command.clone().get_matches_from(&argv);
+    profiles().apply(&command, &matches, &argv)
+}
+
+#[test]
+fn test_profile_flags_go_before_user_arguments() {
+    assert_eq!(
+        apply(&["ce", "--profile", "review", "src"]).unwrap(),
+        Some(argv(&[
+            "ce",
+            "--git",
+            "--long",
+            "--sort=modified",
+            "--profile",
+            "review",
+            "src"
+        ]))
+    );
+}
+
+#[test]
+fn test_profile_keys_accept_dashes_arrays_and_numbers() {
+    assert_eq!(
+        apply(&["ce", "--profile=deep"]).unwrap(),
+        Some(argv(&[
+            "ce",
+            "--level=2",
+            "--skip-dirs=none",
+            "--tree",
+            "--profile=deep"
+        ]))
+    );
+}
+
+#[test]
+fn test_explicit_and_conflicting_flags_win() {
+    // --level is given outright; -R conflicts with the profile's --tree
+    let merged = apply(&["ce", "--profile", "deep", "-R", "--level", "3"]).unwrap();
+
+    assert_eq!(
+        merged,
+        Some(argv(&[
+            "ce",
+            "--skip-dirs=none",
+            "--profile",
+            "deep",
+            "-R",
+            "--level",
+            "3"
+        ]))
+    );
+    assert!(
+        Args::command()
+            .try_get_matches_from(merged.unwrap())
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_unknown_profile_and_flag_are_errors() {
+    assert_eq!(
+        apply(&["ce", "--profile", "missing"]),
+        Err("unknown profile: missing".to_string())
+    );
+    assert_eq!(
+        apply(&["ce", "--profile", "broken"]),
+        Err("unknown flag `not_a_flag` in profile broken".to_string())
+    );
+}
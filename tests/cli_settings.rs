@@ -0,0 +1,83 @@
+use cerium::cli::settings::Settings;
+use cerium::display::layout::locale::Language;
+use cerium::display::layout::width::Width;
+use cerium::display::theme::colours::RgbColours;
+use cerium::display::theme::config::Theme;
+use cerium::fs::clock::ClockSettings;
+use std::panic;
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+/// Returns the process-wide settings with the theme set up, as `main` would.
+fn base() -> Settings {
+    RgbColours::init(Theme::default());
+    Settings::current()
+}
+
+#[test]
+fn test_scope_overrides_then_restores_settings() {
+    let outside = base();
+    let scoped = Arc::new(Settings {
+        colours: !outside.colours,
+        language: Language::German,
+        assumed_width: Some(123),
+        ..outside.clone()
+    });
+
+    scoped.scope(|| {
+        let inside = Settings::current();
+        assert_eq!(inside.colours, !outside.colours);
+        assert_eq!(inside.language, Language::German);
+        assert_eq!(Width::terminal_width(), 123);
+    });
+
+    let after = Settings::current();
+    assert_eq!(after.colours, outside.colours);
+    assert_eq!(after.language, outside.language);
+    assert_eq!(after.assumed_width, outside.assumed_width);
+}
+
+#[test]
+fn test_scope_is_restored_after_a_panic() {
+    let outside = base();
+    let scoped = Arc::new(Settings {
+        assumed_width: Some(7),
+        ..outside.clone()
+    });
+
+    let result = panic::catch_unwind(|| scoped.scope(|| panic!("listing failed")));
+
+    assert!(result.is_err());
+    assert_eq!(Settings::current().assumed_width, outside.assumed_width);
+}
+
+#[test]
+fn test_concurrent_scopes_do_not_see_each_other() {
+    let outside = base();
+    let barrier = Arc::new(Barrier::new(2));
+
+    let workers: Vec<_> = [(40, false), (200, true)]
+        .into_iter()
+        .map(|(width, deterministic)| {
+            let settings = Arc::new(Settings {
+                assumed_width: Some(width),
+                deterministic,
+                ..outside.clone()
+            });
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                settings.scope(|| {
+                    barrier.wait();
+                    for _ in 0..10_000 {
+                        assert_eq!(Width::terminal_width(), width);
+                        assert_eq!(ClockSettings::is_deterministic(), deterministic);
+                    }
+                })
+            })
+        })
+        .collect();
+
+    for worker in workers {
+        worker.join().unwrap();
+    }
+}
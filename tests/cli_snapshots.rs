@@ -0,0 +1,308 @@
+//! Golden snapshots of `ce --deterministic` over a synthetic fixture tree.
+//!
+//! Each case runs the real binary and compares its stdout with
+//! `tests/snapshots/<case>.txt`. After an intended output change, regenerate
+//! the files with `UPDATE_SNAPSHOTS=1 cargo test --test cli_snapshots` and
+//! review the diff.
+
+mod common;
+
+use common::ce;
+use std::ffi::CString;
+use std::fs::{self, File, Permissions};
+use std::io::Write;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::{PermissionsExt, symlink};
+use std::os::unix::net::UnixListener;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// "Now" under `--deterministic` (2025-01-01 00:00:00 UTC).
+const EPOCH: i64 = 1_735_689_600;
+const DAY: i64 = 86_400;
+
+/// Flag combinations to snapshot, keyed by snapshot file name.
+///
+/// Owner, group, inode and block columns depend on the machine and are left out.
+const CASES: &[(&str, &[&str])] = &[
+    ("grid", &[]),
+    ("grid_all", &["-a"]),
+    ("grid_narrow", &["-w", "40"]),
+    ("oneline", &["-1"]),
+    ("oneline_classify", &["-1", "-a", "-F"]),
+    ("grid_classify", &["-a", "-F"]),
+    ("oneline_quote_double", &["-1", "-q", "double"]),
+    ("oneline_quote_single", &["-1", "-q", "single"]),
+    ("oneline_quote_never", &["-1", "-q", "never"]),
+    ("columns", &["-p", "-s", "-m"]),
+    ("columns_headers", &["-p", "-s", "-m", "-H"]),
+    ("columns_locale_dates", &["-m", "--date-format", "locale"]),
+    ("columns_timestamps", &["-m", "--date-format", "timestamp"]),
+    ("columns_octal", &["-p", "--permission-format", "octal"]),
+    ("columns_bytes", &["-s", "--size-format", "bytes", "-S"]),
+    ("columns_placeholder", &["-p", "-L", "--placeholder", "?"]),
+    ("columns_preview", &["--head", "12", "--tail", "6"]),
+    (
+        "columns_explicit",
+        &[
+            "--columns",
+            "size,perm,name",
+            "--size-format",
+            "bytes",
+            "-H",
+        ],
+    ),
+    ("sort_size", &["-1", "-s", "--sort", "size"]),
+    (
+        "sort_modified_reverse",
+        &["-1", "-m", "--sort", "modified", "-r"],
+    ),
+    ("sort_extension", &["-1", "--sort", "extension"]),
+    (
+        "sort_extension_subtotals",
+        &[
+            "-s",
+            "--size-format",
+            "bytes",
+            "--sort",
+            "extension",
+            "--subtotals",
+        ],
+    ),
+    (
+        "format_template",
+        &[
+            "--format",
+            "{perm} {size:>6} {mtime} {name}",
+            "--size-format",
+            "bytes",
+            "--date-format",
+            "timestamp",
+        ],
+    ),
+    ("dirs_only", &["-1", "-d"]),
+    ("files_only", &["-1", "-f"]),
+    ("recursive", &["-R"]),
+    ("recursive_oneline", &["-R", "-1", "-a"]),
+    ("tree", &["-t"]),
+    ("tree_all", &["-t", "-a"]),
+    ("tree_classify", &["-t", "-a", "-F"]),
+    ("tree_columns", &["-t", "-s", "-m", "-H"]),
+    ("tree_dirs", &["-t", "-d"]),
+    ("tree_level", &["-t", "--level", "2"]),
+    ("recursive_level", &["-R", "--level", "2"]),
+    ("group_by_type", &["--group-by", "type"]),
+    ("group_by_ext", &["-1", "--group-by", "ext"]),
+    ("group_by_date", &["-1", "--group-by", "date"]),
+    ("max_entries", &["-1", "--max-entries", "4"]),
+    ("find", &["--find", "*.txt"]),
+    ("find_recursive", &["--find", "*.rs", "-R", "-s"]),
+];
+
+/// Sets access and modification times without following symlinks.
+///
+/// # Parameters
+/// - `path`: The entry to touch.
+/// - `days_ago`: How many days before [`EPOCH`] to date it.
+fn touch(path: &Path, days_ago: i64) {
+    let seconds = EPOCH - days_ago * DAY;
+    let time = libc::timespec {
+        tv_sec: seconds as libc::time_t,
+        tv_nsec: 0,
+    };
+    let times = [time, time];
+    let c_path = CString::new(path.as_os_str().as_bytes()).unwrap();
+
+    let result = unsafe {
+        libc::utimensat(
+            libc::AT_FDCWD,
+            c_path.as_ptr(),
+            times.as_ptr(),
+            libc::AT_SYMLINK_NOFOLLOW,
+        )
+    };
+    assert_eq!(result, 0, "utimensat failed for {}", path.display());
+}
+
+/// Writes a file with fixed contents and permissions.
+fn file(base: &Path, name: &str, contents: &str, mode: u32) {
+    let path = base.join(name);
+    File::create(&path)
+        .unwrap()
+        .write_all(contents.as_bytes())
+        .unwrap();
+    fs::set_permissions(&path, Permissions::from_mode(mode)).unwrap();
+}
+
+/// Builds the fixture tree and dates every entry, children before parents.
+///
+/// # Returns
+/// The temporary directory holding the fixture (removed on drop) and the
+/// open socket listener, which must outlive the test run.
+fn build_fixture() -> (TempDir, UnixListener) {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path().join("fixture");
+    fs::create_dir(&base).unwrap();
+
+    file(&base, "ünïcødé.txt", "unicode\n", 0o644);
+    file(&base, "日本語.md", "# 見出し\n", 0o644);
+    file(&base, "emoji 🦀.rs", "fn main() {}\n", 0o644);
+    file(&base, "with space.txt", "", 0o644);
+    file(&base, "quote's.txt", "'\n", 0o600);
+    file(&base, ".hidden", "secret\n", 0o600);
+    file(&base, "Makefile", "all:\n\ttrue\n", 0o644);
+    file(&base, "archive.tar.gz", &"x".repeat(2048), 0o644);
+    file(&base, "script.sh", "#!/bin/sh\necho hi\n", 0o755);
+
+    fs::create_dir(base.join("empty")).unwrap();
+    fs::create_dir_all(base.join("deep/a/b/c/d")).unwrap();
+    file(&base, "deep/a/b/c/d/leaf.txt", "leaf\n", 0o644);
+    file(&base, "deep/a/sibling.rs", "", 0o644);
+    fs::create_dir(base.join("src")).unwrap();
+    file(&base, "src/main.rs", "fn main() {}\n", 0o644);
+    file(&base, "src/lib.rs", "", 0o644);
+    file(&base, "src/.gitkeep", "", 0o644);
+
+    symlink("ünïcødé.txt", base.join("link.txt")).unwrap();
+    symlink("missing", base.join("broken")).unwrap();
+    symlink("deep", base.join("dir-link")).unwrap();
+
+    let listener = UnixListener::bind(base.join("sock")).unwrap();
+    fs::set_permissions(base.join("sock"), Permissions::from_mode(0o755)).unwrap();
+
+    let fifo = CString::new(base.join("pipe").as_os_str().as_bytes()).unwrap();
+    assert_eq!(unsafe { libc::mkfifo(fifo.as_ptr(), 0o644) }, 0);
+    fs::set_permissions(base.join("pipe"), Permissions::from_mode(0o644)).unwrap();
+
+    for directory in [
+        "empty",
+        "deep",
+        "deep/a",
+        "deep/a/b",
+        "deep/a/b/c",
+        "deep/a/b/c/d",
+        "src",
+    ] {
+        fs::set_permissions(base.join(directory), Permissions::from_mode(0o755)).unwrap();
+    }
+
+    // Deepest entries first, so filling a directory never bumps its stamp afterwards
+    let dated: &[(&str, i64)] = &[
+        ("deep/a/b/c/d/leaf.txt", 400),
+        ("deep/a/b/c/d", 400),
+        ("deep/a/b/c", 400),
+        ("deep/a/b", 400),
+        ("deep/a/sibling.rs", 45),
+        ("deep/a", 45),
+        ("deep", 45),
+        ("src/main.rs", 2),
+        ("src/lib.rs", 3),
+        ("src/.gitkeep", 3),
+        ("src", 2),
+        ("empty", 10),
+        ("ünïcødé.txt", 1),
+        ("日本語.md", 7),
+        ("emoji 🦀.rs", 0),
+        ("with space.txt", 30),
+        ("quote's.txt", 90),
+        (".hidden", 365),
+        ("Makefile", 700),
+        ("archive.tar.gz", 120),
+        ("script.sh", 14),
+        ("link.txt", 5),
+        ("broken", 6),
+        ("dir-link", 8),
+        ("sock", 0),
+        ("pipe", 0),
+        ("", 0),
+    ];
+    for (name, days_ago) in dated {
+        touch(&base.join(name), *days_ago);
+    }
+
+    (temp_dir, listener)
+}
+
+/// Runs `ce --deterministic` with `flags` inside `base`.
+fn run(base: &Path, flags: &[&str]) -> String {
+    let output = ce(&["--deterministic"])
+        .args(flags)
+        .current_dir(base)
+        // Keep the developer's bookmarks out of the listing too
+        .env("HOME", base.join(".no-home"))
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "ce {} failed: {}",
+        flags.join(" "),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn snapshot_path(case: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{case}.txt"))
+}
+
+#[test]
+fn test_snapshots_match() {
+    let (temp_dir, _listener) = build_fixture();
+    let base = temp_dir.path().join("fixture");
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+
+    let mut mismatched = Vec::new();
+    for (case, flags) in CASES {
+        let actual = run(&base, flags);
+        let path = snapshot_path(case);
+
+        if update {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, &actual).unwrap();
+            continue;
+        }
+
+        match fs::read_to_string(&path) {
+            Ok(expected) if expected == actual => {}
+            Ok(expected) => mismatched.push(format!(
+                "{case} (ce {}):\n--- expected\n{expected}--- actual\n{actual}",
+                flags.join(" ")
+            )),
+            Err(_) => mismatched.push(format!("{case}: missing {}", path.display())),
+        }
+    }
+
+    assert!(
+        mismatched.is_empty(),
+        "{} snapshot(s) differ; rerun with UPDATE_SNAPSHOTS=1 to accept:\n\n{}",
+        mismatched.len(),
+        mismatched.join("\n")
+    );
+}
+
+#[test]
+fn test_deterministic_output_is_stable() {
+    let (temp_dir, _listener) = build_fixture();
+    let base = temp_dir.path().join("fixture");
+
+    for flags in [&["-1", "-a", "-m", "-s"][..], &["-t", "-a"]] {
+        assert_eq!(run(&base, flags), run(&base, flags));
+    }
+}
+
+#[test]
+fn test_deterministic_ignores_colour_environment() {
+    let (temp_dir, _listener) = build_fixture();
+    let base = temp_dir.path().join("fixture");
+
+    let output = ce(&["--deterministic", "-l", "-C", "always", "-I", "always"])
+        .current_dir(&base)
+        .env("FORCE_COLOR", "1")
+        .output()
+        .unwrap();
+
+    assert!(!output.stdout.contains(&0x1b), "escape sequence in output");
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use common::{ce, stdout};
+use std::fs;
+use tempfile::TempDir;
+
+/// Runs `ce --head 1` over a binary file, whose `<binary>` preview is drawn in italics.
+fn run(term: &str, caps: &str) -> String {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("blob.bin"), [0u8, 1, 2]).unwrap();
+
+    stdout(
+        ce(&["--head", "1", "--colours", "always", "--style-caps", caps])
+            .current_dir(dir.path())
+            .env("TERM", term),
+    )
+}
+
+#[test]
+fn test_style_caps_auto_drops_italics_on_limited_terminals() {
+    assert!(run("xterm-256color", "auto").contains("\x1b[3;90m<binary>"));
+    assert!(run("linux", "auto").contains("\x1b[90m<binary>"));
+    assert!(run("screen", "auto").contains("\x1b[90m<binary>"));
+}
+
+#[test]
+fn test_style_caps_flag_overrides_the_terminal() {
+    assert!(run("linux", "full").contains("\x1b[3;90m<binary>"));
+    assert!(run("xterm-256color", "basic").contains("\x1b[90m<binary>"));
+
+    let none = run("xterm-256color", "none");
+    assert!(none.contains("\x1b[90m<binary>"));
+    assert!(!none.contains("\x1b[1;"));
+}
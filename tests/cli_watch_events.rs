@@ -0,0 +1,81 @@
+mod common;
+
+use common::ce;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+use tempfile::TempDir;
+
+/// Starts `ce --watch --output ndjson` on `dir`, with its stdout lines sent to a channel.
+fn watch(dir: &TempDir, output: &str) -> (Child, Receiver<String>) {
+    let mut child = ce(&["--watch", "--output", output])
+        .arg(dir.path())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let stdout = child.stdout.take().unwrap();
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    // Let it list the directory and start watching
+    thread::sleep(Duration::from_millis(500));
+    (child, receiver)
+}
+
+fn next(receiver: &Receiver<String>) -> String {
+    receiver.recv_timeout(Duration::from_secs(5)).unwrap()
+}
+
+#[test]
+fn test_watch_ndjson_prints_created_modified_and_deleted_events() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("existing.txt"), "").unwrap();
+    let (mut child, receiver) = watch(&dir, "ndjson");
+    let path = dir.path().join("new.txt");
+
+    fs::write(&path, "").unwrap();
+    let created = next(&receiver);
+    fs::write(&path, "more").unwrap();
+    let modified = next(&receiver);
+    fs::remove_file(&path).unwrap();
+    let deleted = next(&receiver);
+    child.kill().unwrap();
+
+    assert!(created.starts_with(r#"{"event":"created","name":"new.txt","#));
+    assert!(created.contains(r#""size":0"#));
+    assert!(modified.starts_with(r#"{"event":"modified","name":"new.txt","#));
+    assert!(modified.contains(r#""size":4"#));
+    assert!(deleted.starts_with(r#"{"event":"deleted","name":"new.txt","#));
+    // No initial listing, and nothing about the untouched entry
+    assert!(!created.contains("existing.txt"));
+    assert!(receiver.try_recv().is_err());
+}
+
+#[test]
+fn test_watch_json_streams_events_too_but_html_is_refused() {
+    let dir = TempDir::new().unwrap();
+    let (mut child, receiver) = watch(&dir, "json");
+
+    fs::create_dir(dir.path().join("sub")).unwrap();
+    let created = next(&receiver);
+    child.kill().unwrap();
+
+    assert!(created.starts_with(r#"{"event":"created","name":"sub","#));
+    assert!(created.contains(r#""type":"directory""#));
+
+    let refused = ce(&["--watch", "--output", "html"])
+        .arg(dir.path())
+        .output()
+        .unwrap();
+    assert_eq!(refused.status.code(), Some(2));
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use common::{ce, listing, stdout};
+use std::fs::{self, File};
+use std::os::fd::{AsRawFd, FromRawFd};
+use std::os::unix::process::CommandExt;
+use std::process::Stdio;
+use std::{io, ptr};
+use tempfile::TempDir;
+
+/// Runs `ce` piped over 12 files named `file_01.txt` to `file_12.txt`, with `COLUMNS` set or unset.
+fn run(args: &[&str], columns: Option<&str>) -> String {
+    run_with_tty(args, columns, None)
+}
+
+/// Like [`run`], in a new session whose controlling terminal is a pty `tty` columns wide, or
+/// none at all.
+///
+/// Stdout stays a pipe, so only `/dev/tty` can tell the terminal width.
+fn run_with_tty(args: &[&str], columns: Option<&str>, tty: Option<u16>) -> String {
+    let dir = TempDir::new().unwrap();
+    for number in 1..=12 {
+        fs::write(dir.path().join(format!("file_{number:02}.txt")), "").unwrap();
+    }
+
+    let mut command = ce(args);
+    command.current_dir(dir.path()).stdin(Stdio::null());
+    if let Some(columns) = columns {
+        command.env("COLUMNS", columns);
+    }
+
+    // Both ends stay open until the child has exited
+    let pty = tty.map(open_pty);
+    let terminal = pty.as_ref().map(|(_, terminal)| terminal.as_raw_fd());
+    // SAFETY: only async-signal-safe calls between fork and exec.
+    unsafe {
+        command.pre_exec(move || {
+            if libc::setsid() < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            if let Some(terminal) = terminal
+                && libc::ioctl(terminal, libc::TIOCSCTTY, 0) < 0
+            {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+
+    stdout(&mut command)
+}
+
+/// Opens a pseudo-terminal `width` columns wide.
+///
+/// # Returns
+/// The controller and terminal sides.
+fn open_pty(width: u16) -> (File, File) {
+    let mut controller = 0;
+    let mut terminal = 0;
+    let size = libc::winsize {
+        ws_row: 24,
+        ws_col: width,
+        ws_xpixel: 0,
+        ws_ypixel: 0,
+    };
+
+    // SAFETY: all pointers are valid for the duration of the call.
+    let status = unsafe {
+        libc::openpty(
+            &mut controller,
+            &mut terminal,
+            ptr::null_mut(),
+            ptr::null(),
+            &size,
+        )
+    };
+    assert_eq!(status, 0, "openpty: {}", io::Error::last_os_error());
+
+    // SAFETY: `openpty` returned two descriptors we now own.
+    unsafe { (File::from_raw_fd(controller), File::from_raw_fd(terminal)) }
+}
+
+#[test]
+fn test_assume_width_pins_the_grid_width() {
+    assert_eq!(listing(&run(&["--assume-width", "40"], None)).len(), 4);
+    assert_eq!(listing(&run(&["--assume-width", "200"], None)).len(), 1);
+    assert_eq!(
+        listing(&run(&["--assume-width", "40"], Some("200"))).len(),
+        4
+    );
+}
+
+#[test]
+fn test_columns_sets_the_width_when_not_a_terminal() {
+    assert_eq!(listing(&run(&[], None)).len(), 2);
+    assert_eq!(listing(&run(&[], Some("40"))).len(), 4);
+    assert_eq!(listing(&run(&[], Some("not a number"))).len(), 2);
+}
+
+#[test]
+fn test_force_grid_reads_the_width_of_the_controlling_terminal() {
+    assert_eq!(listing(&run_with_tty(&[], None, Some(200))).len(), 2);
+    assert_eq!(
+        listing(&run_with_tty(&["--force-grid"], None, Some(200))).len(),
+        1
+    );
+    assert_eq!(
+        listing(&run_with_tty(&["--force-grid"], None, Some(40))).len(),
+        4
+    );
+}
+
+#[test]
+fn test_force_grid_prefers_columns_and_survives_without_a_terminal() {
+    assert_eq!(
+        listing(&run_with_tty(&["--force-grid"], Some("40"), Some(200))).len(),
+        4
+    );
+    assert_eq!(
+        listing(&run_with_tty(&["--force-grid"], None, None)).len(),
+        2
+    );
+}
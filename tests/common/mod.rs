@@ -5,6 +5,10 @@ use clap::Parser;
 #[allow(unused)]
 use std::fs::{self, File};
 #[allow(unused)]
+use std::path::Path;
+#[allow(unused)]
+use std::process::{Command, Output};
+#[allow(unused)]
 use tempfile::TempDir;
 
 #[allow(dead_code)]
@@ -32,3 +36,44 @@ pub fn setup_test_dir() -> TempDir {
 
     temp_dir
 }
+
+/// Builds a `ce` command kept away from the developer's setup: `XDG_CONFIG_HOME`
+/// points at a directory that doesn't exist, colours are off unless asked for,
+/// and `COLUMNS` is unset.
+#[allow(dead_code)]
+pub fn ce(args: &[&str]) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_ce"));
+    command
+        .args(args)
+        .env(
+            "XDG_CONFIG_HOME",
+            Path::new(env!("CARGO_TARGET_TMPDIR")).join("no-config"),
+        )
+        .env("NO_COLOR", "1")
+        .env_remove("COLUMNS");
+    command
+}
+
+/// Runs `ce` (as set up by [`ce`]) inside `dir`.
+#[allow(dead_code)]
+pub fn run_in(dir: &Path, args: &[&str]) -> Output {
+    ce(args).current_dir(dir).output().unwrap()
+}
+
+/// Runs a command, checks that it succeeded, and returns its stdout.
+#[allow(dead_code)]
+pub fn stdout(command: &mut Command) -> String {
+    let output = command.output().unwrap();
+    assert!(
+        output.status.success(),
+        "ce failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// The listing itself, without the summary after the blank line.
+#[allow(dead_code)]
+pub fn listing(stdout: &str) -> Vec<&str> {
+    stdout.lines().take_while(|line| !line.is_empty()).collect()
+}
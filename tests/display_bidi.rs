@@ -0,0 +1,27 @@
+mod common;
+
+use cerium::display::layout::bidi::{Bidi, FIRST_STRONG_ISOLATE, POP_DIRECTIONAL_ISOLATE};
+use common::{ce, stdout};
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_isolate_wraps_only_right_to_left_names() {
+    assert_eq!(Bidi::isolate("notes.txt"), "notes.txt");
+    assert_eq!(Bidi::isolate("שלום.txt"), "\u{2068}שלום.txt\u{2069}");
+    assert_eq!(Bidi::isolate("تقرير"), "\u{2068}تقرير\u{2069}");
+}
+
+#[test]
+fn test_styled_output_isolates_names_and_plain_output_keeps_them() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("שלום.txt"), "").unwrap();
+
+    let run = |colour: &str| stdout(ce(&["-1", "--colours", colour]).current_dir(dir.path()));
+
+    let styled = run("always");
+    assert!(styled.contains(FIRST_STRONG_ISOLATE) && styled.contains(POP_DIRECTIONAL_ISOLATE));
+
+    let plain = run("never");
+    assert_eq!(plain.lines().next(), Some("שלום.txt"));
+}
@@ -0,0 +1,181 @@
+mod common;
+
+use cerium::display::theme::config::schema::{Issue, Schema, VERSION};
+use common::ce;
+use std::fs;
+use std::process::Output;
+use tempfile::TempDir;
+
+fn check(contents: &str) -> Vec<Issue> {
+    let (table, mut issues) = Schema::parse(contents);
+    issues.extend(Schema::check(&table.unwrap()));
+    issues
+}
+
+/// Runs `ce --check-config` against a config directory holding `contents`.
+fn check_config(contents: &str) -> Output {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("cerium.toml"), contents).unwrap();
+
+    ce(&["--check-config"])
+        .env("XDG_CONFIG_HOME", temp_dir.path())
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_valid_config_has_no_issues() {
+    let issues = check(
+        r##"
+        version = 1
+        theme = "nord"
+        language = "de"
+        nerd_font = true
+        entry_file = "white"
+
+        [palette]
+        accent = "#89b4fa"
+
+        [colors]
+        entry_directory = "accent"
+
+        [bookmarks]
+        logs = "/var/log"
+    "##,
+    );
+
+    assert_eq!(issues, []);
+}
+
+#[test]
+fn test_unknown_keys_are_reported() {
+    let issues = check(
+        r##"
+        colour_scheme = "dark"
+
+        [colors]
+        entry_directroy = "red"
+    "##,
+    );
+
+    assert_eq!(
+        issues,
+        [
+            Issue::UnknownKey("colors.entry_directroy".to_string()),
+            Issue::UnknownKey("colour_scheme".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_values_are_reported() {
+    let issues = check(
+        r##"
+        version = "one"
+        theme = "solarized"
+        nerd_font = "maybe"
+        table_header = "not-a-colour"
+
+        [palette]
+        accent = 12
+    "##,
+    );
+
+    assert_eq!(
+        issues,
+        [
+            Issue::InvalidValue {
+                key: "nerd_font",
+                expected: "true or false",
+            },
+            Issue::InvalidColour("palette.accent".to_string()),
+            Issue::InvalidColour("table_header".to_string()),
+            Issue::UnknownTheme("solarized".to_string()),
+            Issue::InvalidVersion,
+        ]
+    );
+}
+
+#[test]
+fn test_renamed_table_is_migrated() {
+    let (table, issues) = Schema::parse("[colours]\nentry_directory = \"red\"\n");
+    let table = table.unwrap();
+
+    assert_eq!(
+        issues,
+        [Issue::Renamed {
+            from: "colours",
+            to: "colors"
+        }]
+    );
+    assert!(issues[0].is_notice());
+    assert!(table.get("colours").is_none());
+    assert_eq!(table["colors"]["entry_directory"].as_str(), Some("red"));
+}
+
+#[test]
+fn test_renamed_key_loses_to_new_name() {
+    let (table, issues) =
+        Schema::parse("[colours]\nentry_file = \"red\"\n\n[colors]\nentry_file = \"blue\"\n");
+    let table = table.unwrap();
+
+    assert_eq!(
+        issues,
+        [Issue::Shadowed {
+            from: "colours",
+            to: "colors"
+        }]
+    );
+    assert!(!issues[0].is_notice());
+    assert_eq!(table["colors"]["entry_file"].as_str(), Some("blue"));
+}
+
+#[test]
+fn test_newer_version_is_read_with_a_notice() {
+    let (table, issues) = Schema::parse(&format!(
+        "version = {}\nentry_file = \"red\"\n",
+        VERSION + 1
+    ));
+
+    assert!(table.is_some());
+    assert_eq!(issues, [Issue::NewerVersion(VERSION + 1)]);
+    assert!(issues[0].is_notice());
+}
+
+#[test]
+fn test_syntax_error_reports_line() {
+    let (table, issues) = Schema::parse("version = 1\ntheme = \"nord\nentry_file = \"red\"\n");
+
+    assert!(table.is_none());
+    assert!(matches!(issues[..], [Issue::Syntax { line: Some(2), .. }]));
+    assert!(
+        issues[0]
+            .to_string()
+            .starts_with("invalid TOML on line 2: ")
+    );
+}
+
+#[test]
+fn test_check_config_reports_ok() {
+    let output = check_config("version = 1\nentry_file = \"red\"\n");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert!(stdout.ends_with("cerium.toml: ok\n"), "{stdout}");
+}
+
+#[test]
+fn test_check_config_fails_on_unknown_key() {
+    let output = check_config("[colours]\nentry_file = \"red\"\nentry_flie = \"blue\"\n");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        stdout.contains("`colours` has been renamed to `colors`\n"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("unknown key `colors.entry_flie`\n"),
+        "{stdout}"
+    );
+}
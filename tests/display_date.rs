@@ -0,0 +1,41 @@
+use cerium::cli::flags::DateFormat;
+use cerium::display::output::formats::date::Date;
+use cerium::display::output::formats::format::Format;
+use cerium::fs::clock::FixedClock;
+use std::time::{Duration, UNIX_EPOCH};
+
+#[test]
+fn test_humanised_date_uses_injected_clock() {
+    let now = UNIX_EPOCH + Duration::from_secs(1_000_000);
+    let date = Date::with_clock(DateFormat::Humanly, Box::new(FixedClock(now)));
+
+    let three_minutes = date.format(Some(now - Duration::from_secs(180)));
+    let two_days = date.format(Some(now - Duration::from_secs(2 * 86_400)));
+
+    assert!(three_minutes.contains("3 minutes"), "{three_minutes}");
+    assert!(two_days.contains("2 days"), "{two_days}");
+}
+
+#[test]
+fn test_timestamp_date_ignores_clock() {
+    let date = Date::with_clock(DateFormat::Timestamp, Box::new(FixedClock(UNIX_EPOCH)));
+
+    assert_eq!(
+        &*date.format(Some(UNIX_EPOCH + Duration::from_secs(42))),
+        "42"
+    );
+    assert_eq!(&*date.format(None), "-");
+}
+
+#[test]
+fn test_custom_date_uses_strftime_pattern() {
+    let date = Date::with_clock(
+        DateFormat::Custom("%Y/%m".into()),
+        Box::new(FixedClock(UNIX_EPOCH)),
+    );
+
+    // Mid-month, so the local time zone can't change the year or month
+    let moment = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+    assert_eq!(&*date.format(Some(moment)), "2001/09");
+    assert_eq!(&*date.format(None), "-");
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use cerium::cli::flags::{ErrorFormat, OutputFormat};
+use cerium::display::output::errors::{ErrorKind, Errors};
+use cerium::fs::dir::DirReader;
+use cerium::fs::metadata::Metadata;
+use cerium::fs::vfs::{DirItem, Fs};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use common::default_args;
+
+/// A filesystem whose only directory can't be read.
+struct Unreadable;
+
+impl Fs for Unreadable {
+    fn read_dir(&self, _path: &Path) -> io::Result<Vec<DirItem>> {
+        Err(io::Error::from(io::ErrorKind::PermissionDenied))
+    }
+
+    fn metadata(&self, _path: &Path, _dereference: bool) -> io::Result<Metadata> {
+        Ok(Metadata {
+            mode: libc::S_IFDIR | 0o700,
+            ..Metadata::empty()
+        })
+    }
+}
+
+// The sink is global, so everything is checked in one test.
+#[test]
+fn test_json_document_collects_each_error_once() {
+    Errors::setup(ErrorFormat::Text, OutputFormat::Json);
+    assert!(Errors::is_structured());
+
+    let reader = DirReader::from(PathBuf::from("/locked"));
+    assert!(reader.list_in(&Unreadable, &default_args()).is_empty());
+    assert!(reader.list_in(&Unreadable, &default_args()).is_empty());
+    Errors::report(
+        ErrorKind::NotFound,
+        Path::new("/missing"),
+        "No such file or directory",
+    );
+
+    let errors = Errors::take();
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].starts_with("{\"error\":\"unreadable_directory\",\"path\":\"/locked\""));
+    assert_eq!(
+        errors[1],
+        "{\"error\":\"not_found\",\"path\":\"/missing\",\"message\":\"No such file or directory\"}"
+    );
+    assert!(Errors::take().is_empty());
+
+    // Failures on an entry's own data also badge it
+    assert_eq!(Errors::badge(Path::new("/locked"), false), None);
+    Errors::report(
+        ErrorKind::StatFailed,
+        Path::new("/denied"),
+        "Permission denied",
+    );
+    Errors::report(ErrorKind::AclFailed, Path::new("/denied"), "I/O error");
+    assert_eq!(
+        Errors::badge(Path::new("/denied"), false).as_deref(),
+        Some("⚠")
+    );
+    assert_eq!(
+        Errors::badge(Path::new("/denied"), true).as_deref(),
+        Some("⚠ metadata: Permission denied; ACL: I/O error")
+    );
+    assert_eq!(Errors::take().len(), 2);
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use cerium::display::output::escape::Escape;
+use cerium::fs::notes::NOTES_FILE;
+use common::{ce, stdout};
+use std::borrow::Cow;
+use std::fs;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+#[test]
+fn test_plain_text_is_borrowed() {
+    assert!(matches!(Escape::text("report final.pdf"), Cow::Borrowed(_)));
+    assert!(matches!(Escape::text("отчёт.txt"), Cow::Borrowed(_)));
+}
+
+#[test]
+fn test_control_characters_are_escaped() {
+    assert_eq!(Escape::text("a\x1b[31mb"), "a\\x1b[31mb");
+    assert_eq!(Escape::text("line\nbreak\t"), "line\\x0abreak\\x09");
+    assert_eq!(Escape::text("\x1b]0;title\x07"), "\\x1b]0;title\\x07");
+    assert_eq!(Escape::text("c1\u{9b}2J"), "c1\\u{9b}2J");
+}
+
+#[test]
+fn test_bidi_controls_are_escaped() {
+    assert_eq!(Escape::text("evil\u{202e}fdp.exe"), "evil\\u{202e}fdp.exe");
+    assert_eq!(Escape::text("\u{2066}x\u{2069}"), "\\u{2066}x\\u{2069}");
+}
+
+#[test]
+fn test_safe_values_keep_their_allocation() {
+    let value: Arc<str> = Arc::from("user.comment");
+    assert!(Arc::ptr_eq(&Escape::value(value.clone()), &value));
+
+    let value: Arc<str> = Arc::from("system_u:object_r:\x1b[8m");
+    assert_eq!(&*Escape::value(value), "system_u:object_r:\\x1b[8m");
+}
+
+#[test]
+fn test_notes_descriptions_and_previews_are_escaped() {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path();
+    fs::write(base.join(NOTES_FILE), "\"a.service\" = \"keep\\u202eme\"\n").unwrap();
+    fs::write(
+        base.join("a.service"),
+        "[Unit]\nDescription=evil\u{202e}exe.txt\x1b[2J\n",
+    )
+    .unwrap();
+
+    let stdout =
+        stdout(ce(&["--columns", "name,note,description,head", "a.service"]).current_dir(base));
+
+    assert!(!stdout.contains('\u{202e}'), "{stdout}");
+    assert!(!stdout.contains('\x1b'), "{stdout}");
+    assert!(stdout.contains("keep\\u{202e}me"), "{stdout}");
+    assert!(stdout.contains("evil\\u{202e}exe.txt\\x1b[2J"), "{stdout}");
+}
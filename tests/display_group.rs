@@ -0,0 +1,40 @@
+mod common;
+
+use common::{ce, stdout};
+use std::fs;
+use tempfile::TempDir;
+
+/// Runs `ce -1 --group-by ext` over a directory, a few files and an extensionless file.
+///
+/// # Returns
+/// The section titles, in the order they were printed.
+fn titles(args: &[&str]) -> Vec<String> {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join("sub")).unwrap();
+    for name in ["b.rs", "a.rs", "Notes.md", "c.txt", "README"] {
+        fs::write(dir.path().join(name), "").unwrap();
+    }
+
+    let output = stdout(ce(&[&["-1", "--group-by", "ext"], args].concat()).current_dir(dir.path()));
+    output
+        .lines()
+        .filter(|line| *line == "Directories" || *line == "No extension" || line.starts_with("*."))
+        .map(str::to_owned)
+        .collect()
+}
+
+#[test]
+fn test_group_by_orders_sections_like_entries() {
+    assert_eq!(
+        titles(&[]),
+        ["Directories", "*.md", "*.rs", "*.txt", "No extension"]
+    );
+}
+
+#[test]
+fn test_group_by_reverse_flips_the_sections() {
+    assert_eq!(
+        titles(&["--reverse"]),
+        ["No extension", "*.txt", "*.rs", "*.md", "Directories"]
+    );
+}
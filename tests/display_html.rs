@@ -0,0 +1,100 @@
+mod common;
+
+use cerium::display::output::html::Html;
+use common::ce;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_escape_replaces_markup_characters() {
+    assert_eq!(
+        Html::escape(r#"<a href="x">Tom & 'Jerry'</a>"#),
+        "&lt;a href=&quot;x&quot;&gt;Tom &amp; &#39;Jerry&#39;&lt;/a&gt;"
+    );
+}
+
+#[test]
+fn test_from_ansi_turns_sgr_into_inline_css() {
+    assert_eq!(
+        Html::from_ansi("\x1b[1;31mred\x1b[0m plain"),
+        r#"<span style="color:#cd0000;font-weight:bold">red</span> plain"#
+    );
+    assert_eq!(
+        Html::from_ansi("\x1b[38;2;1;2;255;3mrgb\x1b[0m"),
+        r#"<span style="color:#0102ff;font-style:italic">rgb</span>"#
+    );
+    assert_eq!(
+        Html::from_ansi("\x1b[38;5;196;4m<x>\x1b[24mrest\x1b[m"),
+        concat!(
+            r#"<span style="color:#ff0000;text-decoration:underline">&lt;x&gt;</span>"#,
+            r#"<span style="color:#ff0000">rest</span>"#
+        )
+    );
+}
+
+#[test]
+fn test_from_ansi_turns_osc8_into_links_and_drops_other_escapes() {
+    assert_eq!(
+        Html::from_ansi("\x1b[34m\x1b]8;;file:///tmp/a&b\x1b\\a&b\x1b]8;;\x1b\\\x1b[0m\x1b[2K"),
+        concat!(
+            r#"<span style="color:#0000ee"></span><a href="file:///tmp/a&amp;b">"#,
+            r#"<span style="color:#0000ee">a&amp;b</span></a>"#,
+            r#"<span style="color:#0000ee"></span>"#
+        )
+    );
+    assert_eq!(Html::from_ansi("tab\there\x07"), "tabhere");
+}
+
+#[test]
+fn test_from_ansi_links_only_file_and_web_urls() {
+    assert_eq!(
+        Html::from_ansi("\x1b]8;;https://example.com/\x07site\x1b]8;;\x07"),
+        r#"<a href="https://example.com/">site</a>"#
+    );
+    assert_eq!(
+        Html::from_ansi("\x1b]8;;HTTP://example.com/\x07site\x1b]8;;\x07"),
+        r#"<a href="HTTP://example.com/">site</a>"#
+    );
+    for url in [
+        "javascript:alert(1)",
+        "data:text/html,<b>",
+        "vbscript:x",
+        "file:relative",
+    ] {
+        assert_eq!(
+            Html::from_ansi(&format!("\x1b]8;;{url}\x07name\x1b]8;;\x07")),
+            "name",
+            "{url}"
+        );
+    }
+}
+
+#[test]
+fn test_html_output_is_a_page_with_a_row_per_entry() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir(root.join("dir")).unwrap();
+    fs::write(root.join("dir").join("inner.txt"), "").unwrap();
+    fs::write(root.join("<b>.txt"), "").unwrap();
+
+    let output = ce(&["--output", "html", "--tree", "--icons", "never"])
+        .arg(root)
+        .output()
+        .unwrap();
+    let page = String::from_utf8(output.stdout).unwrap();
+    let rows: Vec<&str> = page
+        .lines()
+        .filter(|line| line.starts_with("<tr>"))
+        .collect();
+
+    assert!(output.status.success());
+    assert!(page.starts_with("<!DOCTYPE html>"));
+    assert!(page.trim_end().ends_with("</html>"));
+    assert_eq!(rows.len(), 3);
+    assert!(rows[0].contains("├── ") && rows[0].contains("&lt;b&gt;.txt"));
+    assert!(rows[1].contains("╰── ") && rows[1].contains(">dir<"));
+    assert!(rows[2].contains("    ╰── ") && rows[2].contains(">inner.txt<"));
+    // Colours are kept although stdout is not a terminal
+    assert!(rows[1].contains("<span style=\"color:"));
+    assert!(page.contains("<footer>2 files, 1 dir"));
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use cerium::display::theme::icons::{FontHint, IconSettings};
+use common::ce;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_icon_guess_from_nerdfont_variable() {
+    assert_eq!(
+        IconSettings::guess(Some("1"), None, "", "linux"),
+        Some(true)
+    );
+    assert_eq!(
+        IconSettings::guess(Some("No"), None, "WezTerm", ""),
+        Some(false)
+    );
+    // The variable wins over the config, and unknown values are ignored
+    assert_eq!(
+        IconSettings::guess(Some("0"), Some(FontHint::Yes), "", ""),
+        Some(false)
+    );
+    assert_eq!(IconSettings::guess(Some("maybe"), None, "", ""), None);
+}
+
+#[test]
+fn test_icon_guess_from_config_and_terminal() {
+    assert_eq!(
+        IconSettings::guess(None, Some(FontHint::Yes), "", "linux"),
+        Some(true)
+    );
+    assert_eq!(
+        IconSettings::guess(None, Some(FontHint::No), "ghostty", ""),
+        Some(false)
+    );
+
+    // Terminals bundling the symbols, and ones that can't draw them
+    assert_eq!(IconSettings::guess(None, None, "WezTerm", ""), Some(true));
+    assert_eq!(
+        IconSettings::guess(None, None, "", "xterm-kitty"),
+        Some(true)
+    );
+    assert_eq!(IconSettings::guess(None, None, "", "linux"), Some(false));
+
+    // Anything else is unknown
+    assert_eq!(
+        IconSettings::guess(None, None, "Apple_Terminal", "xterm-256color"),
+        None
+    );
+}
+
+/// Checks whether `c` is in a Private Use Area, where Nerd Font glyphs live.
+fn is_private_use(c: char) -> bool {
+    matches!(c, '\u{e000}'..='\u{f8ff}' | '\u{f0000}'..='\u{ffffd}')
+}
+
+/// Lists `root` with icons forced on and `NERDFONT` set to `nerdfont`.
+fn list_with_icons(root: &std::path::Path, nerdfont: &str) -> String {
+    let output = ce(&["--icons", "always"])
+        .arg(root)
+        .env("NERDFONT", nerdfont)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_icons_fall_back_to_unicode_without_a_nerd_font() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir(root.join("src")).unwrap();
+    fs::write(root.join("src").join("main.rs"), "").unwrap();
+    fs::write(root.join("photo.png"), "").unwrap();
+    fs::write(root.join("notes"), "").unwrap();
+
+    let stdout = list_with_icons(root, "0");
+    assert!(stdout.contains("📁 src"), "{stdout}");
+    assert!(stdout.contains("🎨 photo.png"), "{stdout}");
+    assert!(stdout.contains("📄 notes"), "{stdout}");
+    // No Private Use Area glyphs slip through
+    assert!(!stdout.chars().any(is_private_use), "{stdout}");
+}
+
+#[test]
+fn test_icons_use_nerd_font_glyphs_when_available() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir(root.join("src")).unwrap();
+    fs::write(root.join("src").join("main.rs"), "").unwrap();
+
+    let stdout = list_with_icons(root, "1");
+    assert!(!stdout.contains('📁'), "{stdout}");
+    assert!(stdout.chars().any(is_private_use), "{stdout}");
+}
@@ -0,0 +1,37 @@
+use cerium::cli::settings::Settings;
+use cerium::display::layout::intern::Intern;
+use cerium::display::theme::colours::RgbColours;
+use cerium::display::theme::config::Theme;
+use std::sync::Arc;
+
+#[test]
+fn test_equal_strings_share_one_allocation() {
+    let first = Intern::get("intern-test-user");
+    let second = Intern::shared(Arc::from("intern-test-user"));
+    assert!(Arc::ptr_eq(&first, &second));
+
+    // A string seen first as an `Arc` becomes the shared copy itself
+    let owned: Arc<str> = Arc::from("intern-test-group");
+    let interned = Intern::shared(owned.clone());
+    assert!(Arc::ptr_eq(&owned, &interned));
+    assert!(Arc::ptr_eq(&Intern::get("intern-test-group"), &owned));
+
+    assert!(Intern::len() >= 2);
+}
+
+#[test]
+fn test_no_cache_skips_the_pool() {
+    RgbColours::init(Theme::default());
+    let no_cache = Arc::new(Settings {
+        no_cache: true,
+        ..Settings::current()
+    });
+
+    let pooled = Intern::get("intern-test-no-cache");
+    no_cache.scope(|| {
+        assert!(!Arc::ptr_eq(&Intern::get("intern-test-no-cache"), &pooled));
+
+        let owned: Arc<str> = Arc::from("intern-test-no-cache");
+        assert!(Arc::ptr_eq(&Intern::shared(owned.clone()), &owned));
+    });
+}
@@ -0,0 +1,40 @@
+use cerium::display::output::json::Json;
+use cerium::fs::entry::Entry;
+use std::fs::File;
+use tempfile::TempDir;
+
+#[test]
+fn test_json_string_escapes_specials() {
+    assert_eq!(Json::string("plain"), "\"plain\"");
+    assert_eq!(Json::string("a\"b\\c"), "\"a\\\"b\\\\c\"");
+    assert_eq!(Json::string("line\nbreak\t"), "\"line\\nbreak\\t\"");
+    assert_eq!(Json::string("\u{1b}[31m"), "\"\\u001b[31m\"");
+}
+
+#[test]
+fn test_json_entry_without_metadata() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("file.txt");
+    File::create(&path).unwrap();
+
+    let json = Json::entry(&Entry::from_path(path.clone(), false));
+
+    assert!(json.starts_with("{\"name\":\"file.txt\""));
+    assert!(json.contains("\"type\":\"file\""));
+    assert!(!json.contains("\"size\""));
+    assert!(json.ends_with('}'));
+}
+
+#[test]
+fn test_json_entry_with_metadata() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("file.txt");
+    std::fs::write(&path, "hello").unwrap();
+
+    let mut entry = Entry::from_path(path, false);
+    entry.unconditional_metadata(false);
+    let json = Json::entry(&entry);
+
+    assert!(json.contains("\"size\":5"));
+    assert!(json.contains("\"modified\":"));
+}
@@ -0,0 +1,62 @@
+use cerium::display::layout::alignment::Alignment;
+use cerium::display::layout::term_grid::{Cell, Direction, Filling, GridOptions, TermGrid};
+use proptest::prelude::*;
+
+fn grid(names: &[String], direction: Direction, spacing: usize) -> TermGrid {
+    let mut grid = TermGrid::new(GridOptions {
+        direction,
+        filling: Filling::Spaces(spacing),
+    });
+
+    for name in names {
+        grid.add(Cell {
+            width: name.len(),
+            contents: name.clone(),
+            alignment: Alignment::Left,
+        });
+    }
+
+    grid
+}
+
+fn direction() -> impl Strategy<Value = Direction> {
+    prop_oneof![Just(Direction::TopToBottom), Just(Direction::LeftToRight)]
+}
+
+proptest! {
+    #[test]
+    fn prop_grid_never_exceeds_width(
+        names in prop::collection::vec("[a-z0-9._-]{1,30}", 0..60),
+        width in 1usize..200,
+        spacing in 1usize..4,
+        direction in direction(),
+    ) {
+        let display = grid(&names, direction, spacing).fit_into_width(width).unwrap();
+        let widest = names.iter().map(String::len).max().unwrap_or(0);
+
+        // Only a single name wider than the terminal may overflow it, alone on its line
+        for line in display.to_string().lines() {
+            prop_assert!(
+                line.trim_end().len() <= width.max(widest),
+                "{:?} is wider than {}",
+                line,
+                width
+            );
+        }
+    }
+
+    #[test]
+    fn prop_grid_shows_every_cell_once(
+        names in prop::collection::vec("[a-z0-9]{1,12}", 0..60),
+        width in 1usize..200,
+        direction in direction(),
+    ) {
+        let display = grid(&names, direction, 2).fit_into_width(width).unwrap().to_string();
+
+        let mut shown: Vec<&str> = display.split_whitespace().collect();
+        let mut expected: Vec<&str> = names.iter().map(String::as_str).collect();
+        shown.sort();
+        expected.sort();
+        prop_assert_eq!(shown, expected);
+    }
+}
@@ -0,0 +1,26 @@
+use cerium::display::layout::column::Column;
+use cerium::display::layout::locale::Language;
+
+#[test]
+fn test_language_from_locale_codes() {
+    assert_eq!(Language::from_code("de"), Some(Language::German));
+    assert_eq!(Language::from_code("fr_FR.UTF-8"), Some(Language::French));
+    assert_eq!(Language::from_code("pt-BR"), Some(Language::Portuguese));
+    assert_eq!(Language::from_code("C.UTF-8"), None);
+    assert_eq!(Language::from_code("POSIX"), None);
+}
+
+#[test]
+fn test_header_translation() {
+    assert_eq!(Language::German.translate(&Column::Size), "Größe");
+    assert_eq!(Language::Spanish.translate(&Column::Modified), "Modificado");
+    assert_eq!(Language::English.translate(&Column::Size), "Size");
+    assert_eq!(Language::French.translate(&Column::SizeGraph), "Part");
+}
+
+#[test]
+fn test_untranslated_header_falls_back_to_english() {
+    assert_eq!(Language::French.translate(&Column::Acl), "ACL");
+    assert_eq!(Language::German.translate(&Column::Name), "Name");
+    assert_eq!(Language::Portuguese.translate(&Column::Inode), "inode");
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::{ce, listing, stdout};
+use std::fs;
+use tempfile::TempDir;
+
+/// Runs `ce` in a directory holding `notes.txt`.
+fn run(args: &[&str]) -> String {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("notes.txt"), "hello").unwrap();
+
+    stdout(ce(args).current_dir(dir.path()))
+}
+
+#[test]
+fn test_tables_below_forty_columns_stack_under_the_name() {
+    let stdout = run(&["-p", "-s", "-w", "30"]);
+
+    assert_eq!(
+        listing(&stdout),
+        ["notes.txt", "  Size        5", "  Permissions .rw-r--r--"]
+    );
+}
+
+#[test]
+fn test_tables_at_forty_columns_keep_their_rows() {
+    let stdout = run(&["-s", "-w", "40"]);
+
+    assert_eq!(listing(&stdout).len(), 1);
+    assert!(listing(&stdout)[0].contains("notes.txt"));
+}
+
+#[test]
+fn test_narrow_flag_stacks_at_any_width_but_names_alone_stay_put() {
+    assert_eq!(
+        listing(&run(&["-s", "--narrow", "-w", "200"])),
+        ["notes.txt", "  Size 5"]
+    );
+    assert_eq!(listing(&run(&["-1", "-w", "20"])), ["notes.txt"]);
+}
@@ -1,8 +1,14 @@
+mod common;
+
 use cerium::cli::flags::PermissionFormat;
 use cerium::display::output::formats::format::Format;
 use cerium::display::output::formats::permission::Permission;
+use common::{ce, stdout};
 use libc::{S_IFDIR, S_IFREG, S_ISGID, S_ISUID, S_ISVTX};
+use std::fs::{self, File};
+use std::os::unix::fs::PermissionsExt;
 use std::path::PathBuf;
+use tempfile::TempDir;
 
 #[test]
 fn test_format_symbolic_regular_file() {
@@ -94,3 +100,19 @@ fn test_format_hex() {
     assert!(result.starts_with('.'));
     assert!(result.len() > 1);
 }
+
+#[test]
+fn test_exposed_secret_is_marked_after_its_permissions() {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path();
+    for (name, mode) in [("id_ed25519", 0o600), ("id_rsa", 0o644)] {
+        File::create(base.join(name)).unwrap();
+        fs::set_permissions(base.join(name), fs::Permissions::from_mode(mode)).unwrap();
+    }
+
+    let stdout = stdout(ce(&["-p"]).arg(base));
+    let lines: Vec<&str> = stdout.lines().take(2).collect();
+
+    // The marker widens the column, so the names stay aligned
+    assert_eq!(lines, [".rw-------  id_ed25519", ".rw-r--r--! id_rsa    "]);
+}
@@ -0,0 +1,17 @@
+use cerium::cli::flags::QuoteStyle;
+use cerium::display::output::quotes::Quotes;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn prop_quoting_round_trips_through_shell(
+        // Any non-empty name without NUL; " -> " is excluded as it marks a symlink target
+        name in "[^\u{0}]{1,24}".prop_filter("symlink arrow", |name| !name.contains(" -> ")),
+        style in prop_oneof![Just(QuoteStyle::Auto), Just(QuoteStyle::Single), Just(QuoteStyle::Double)],
+        align in any::<bool>(),
+    ) {
+        let quoted = Quotes::new(&name).apply(style, align);
+
+        prop_assert_eq!(shlex::split(&quoted), Some(vec![name.clone()]), "quoted as {}", quoted);
+    }
+}
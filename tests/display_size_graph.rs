@@ -0,0 +1,23 @@
+use cerium::display::layout::graph::{SizeGraph, WIDTH};
+
+#[test]
+fn test_bars_are_always_full_width() {
+    for (part, total) in [(0, 0), (0, 10), (1, 3), (5, 10), (10, 10), (7, 1000)] {
+        assert_eq!(SizeGraph::draw(part, total).chars().count(), WIDTH);
+    }
+}
+
+#[test]
+fn test_bars_round_to_half_cells() {
+    assert_eq!(SizeGraph::draw(0, 100), "░░░░░░░░░░");
+    assert_eq!(SizeGraph::draw(35, 100), "███▓░░░░░░");
+    assert_eq!(SizeGraph::draw(50, 100), "█████░░░░░");
+    assert_eq!(SizeGraph::draw(96, 100), "█████████▓");
+    assert_eq!(SizeGraph::draw(98, 100), "██████████");
+    assert_eq!(SizeGraph::draw(100, 100), "██████████");
+}
+
+#[test]
+fn test_empty_total_draws_an_empty_bar() {
+    assert_eq!(SizeGraph::draw(0, 0), "░░░░░░░░░░");
+}
@@ -0,0 +1,29 @@
+use cerium::display::layout::column::Column;
+use cerium::display::layout::template::Template;
+
+#[test]
+fn test_template_parses_fields_in_order() {
+    let template: Template = "{perm} {size:>8} {mtime} {name:<20}".parse().unwrap();
+    assert_eq!(
+        template.columns(),
+        [
+            Column::Permissions,
+            Column::Size,
+            Column::Modified,
+            Column::Name
+        ]
+    );
+}
+
+#[test]
+fn test_template_escaped_braces_are_literal() {
+    let template: Template = "{{{name}}} {{}}".parse().unwrap();
+    assert_eq!(template.columns(), [Column::Name]);
+}
+
+#[test]
+fn test_template_rejects_bad_input() {
+    for bad in ["{nope}", "{size:x}", "{size:>}", "{name", "name}"] {
+        assert!(bad.parse::<Template>().is_err(), "'{bad}' should not parse");
+    }
+}
@@ -84,3 +84,13 @@ fn test_theme_deserialisation() {
     assert!(matches!(theme.size_bytes.colour, Colour::Rgb(255, 0, 0)));
     assert!(matches!(theme.size_kb.colour, Colour::Green));
 }
+
+#[test]
+fn test_theme_warning_colour() {
+    let theme: Theme = toml::from_str(r#"warning = "lightred""#).unwrap();
+    assert!(matches!(theme.warning.colour, Colour::LightRed));
+
+    // Themes written before the key existed keep the default
+    let theme: Theme = toml::from_str(r#"summary = "white""#).unwrap();
+    assert_eq!(theme.warning.colour, Theme::default().warning.colour);
+}
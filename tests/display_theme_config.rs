@@ -143,3 +143,39 @@ fn test_load_config_with_valid_toml() {
     let theme: Theme = toml::from_str(toml_content).unwrap();
     assert!(matches!(theme.size_bytes.colour, Colour::Rgb(255, 0, 0)));
 }
+
+#[test]
+fn test_every_builtin_theme_resolves() {
+    for name in Theme::BUILTIN {
+        assert!(Theme::builtin(name).is_some(), "built-in theme '{name}' failed to load");
+    }
+    assert!(Theme::builtin("no-such-theme").is_none());
+
+    // Nord's directory colour is nord8, not the Catppuccin Mocha blue.
+    let nord = Theme::builtin("nord").unwrap();
+    assert!(matches!(nord.entry_directory.colour, Colour::Rgb(136, 192, 208)));
+}
+
+#[test]
+fn test_theme_key_selects_base_and_keys_still_override() {
+    let theme: Theme = toml::from_str(
+        r##"
+        theme = "solarized-light"
+        table_header = "#00ff00"
+    "##,
+    )
+    .unwrap();
+    // Explicit key wins over the selected base.
+    assert!(matches!(theme.table_header.colour, Colour::Rgb(0, 255, 0)));
+    // Unspecified keys come from Solarized Light (base00), not Catppuccin.
+    assert!(matches!(theme.entry_file.colour, Colour::Rgb(101, 123, 131)));
+}
+
+#[test]
+fn test_unknown_theme_key_falls_back_to_default() {
+    let theme: Theme = toml::from_str(r#"theme = "no-such-theme""#).unwrap();
+    assert_eq!(
+        format!("{:?}", theme.entry_directory.colour),
+        format!("{:?}", Theme::default().entry_directory.colour)
+    );
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use common::{ce, stdout};
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Runs `ce` with byte sizes, returning its lines without their padding.
+fn run(root: &Path, args: &[&str]) -> Vec<String> {
+    stdout(ce(args).args(["--size-format", "bytes"]).arg(root))
+        .lines()
+        .map(|line| line.trim_end().to_string())
+        .collect()
+}
+
+/// A directory holding a 5 and a 1200 byte file, and a subdirectory with 100 bytes.
+fn setup() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::write(root.join("a.txt"), "hello").unwrap();
+    fs::write(root.join("b.bin"), [0u8; 1200]).unwrap();
+    fs::create_dir(root.join("sub")).unwrap();
+    fs::write(root.join("sub/c"), [0u8; 100]).unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_list_footer_sums_sizes_and_counts_entries() {
+    let temp_dir = setup();
+    let lines = run(temp_dir.path(), &["-s", "-H", "--totals"]);
+    assert!(
+        lines.contains(&"  1205 3 entries".to_string()),
+        "{lines:#?}"
+    );
+
+    // With --dir-size the directory counts too, and the column widens to fit
+    let lines = run(temp_dir.path(), &["-s", "-H", "--totals", "--dir-size"]);
+    assert!(lines.contains(&"1305 3 entries".to_string()), "{lines:#?}");
+    assert!(lines.contains(&"   5 a.txt".to_string()), "{lines:#?}");
+}
+
+#[test]
+fn test_tree_footer_covers_the_root_descendants() {
+    let temp_dir = setup();
+    let lines = run(temp_dir.path(), &["-s", "-H", "--totals", "--tree"]);
+    assert!(
+        lines.contains(&"   1305 4 entries".to_string()),
+        "{lines:#?}"
+    );
+}
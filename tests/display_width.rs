@@ -23,3 +23,39 @@ fn test_clear_cache() {
     calc.clear_cache();
     assert_eq!(calc.cache_size(), 0);
 }
+
+#[test]
+fn test_measure_skips_csi_with_non_alphabetic_final_byte() {
+    // `~` and `@` are valid CSI final bytes, so the name after them must still count
+    assert_eq!(Width::measure_ansi_text("\x1b[2~name"), 4);
+    assert_eq!(Width::measure_ansi_text("\x1b[1@name"), 4);
+}
+
+#[test]
+fn test_measure_malformed_csi_keeps_remaining_text() {
+    assert_eq!(Width::measure_ansi_text("\x1b[12\u{4e2d}\u{6587}"), 4);
+    assert_eq!(Width::measure_ansi_text("\x1b["), 0);
+    assert_eq!(Width::measure_ansi_text("\x1b]8;;unterminated"), 0);
+}
+
+#[test]
+fn test_truncate_keeps_text_that_fits() {
+    assert_eq!(Width::truncate_ansi("short.txt", 9), "short.txt");
+}
+
+#[test]
+fn test_truncate_ends_with_ellipsis_and_keeps_escapes() {
+    let styled = "\x1b[1;34mdocuments_2024\x1b[0m";
+    let shortened = Width::truncate_ansi(styled, 8);
+
+    assert_eq!(shortened, "\x1b[1;34mdocumen…\x1b[0m");
+    assert_eq!(Width::measure_ansi_text(&shortened), 8);
+}
+
+#[test]
+fn test_truncate_counts_wide_characters_and_keeps_isolation_marks() {
+    let shortened = Width::truncate_ansi("\u{2068}日本語のファイル\u{2069}", 7);
+
+    assert_eq!(shortened, "\u{2068}日本語…\u{2069}");
+    assert_eq!(Width::measure_ansi_text(&shortened), 7);
+}
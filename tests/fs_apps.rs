@@ -0,0 +1,139 @@
+#![cfg(feature = "apps")]
+
+use cerium::fs::feature::apps::MimeApps;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn write(base: &Path, name: &str, contents: &str) {
+    let path = base.join(name);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+fn desktop(name: &str, mime_types: &str) -> String {
+    format!("[Desktop Entry]\nType=Application\nName={name}\nMimeType={mime_types}\n")
+}
+
+/// Builds a user data dir, a system data dir and a config dir.
+fn setup() -> (TempDir, MimeApps) {
+    let temp_dir = TempDir::new().unwrap();
+    let user = temp_dir.path().join("user");
+    let system = temp_dir.path().join("system");
+    let config = temp_dir.path().join("config");
+
+    write(
+        &system,
+        "mime/globs2",
+        "# comment\n\
+         50:application/pdf:*.pdf\n\
+         50:application/gzip:*.gz\n\
+         55:application/x-compressed-tar:*.tar.gz\n\
+         50:text/x-csrc:*.c\n\
+         50:text/plain:*.txt\n\
+         50:text/x-makefile:makefile\n\
+         10:text/x-readme:readme*\n\
+         50:image/jpeg:*.jpg\n",
+    );
+    write(&system, "mime/aliases", "image/jpg image/jpeg\n");
+    write(&system, "mime/subclasses", "text/x-csrc text/plain\n");
+    write(
+        &system,
+        "applications/mimeinfo.cache",
+        "[MIME Cache]\napplication/pdf=evince.desktop;\ntext/plain=gedit.desktop;\n",
+    );
+    write(
+        &system,
+        "applications/evince.desktop",
+        &desktop("Document Viewer", "application/pdf;"),
+    );
+    write(
+        &system,
+        "applications/gedit.desktop",
+        &desktop("Text Editor", "text/plain;"),
+    );
+    write(
+        &system,
+        "applications/kde/okular.desktop",
+        &desktop("Okular", "application/pdf;"),
+    );
+    write(
+        &system,
+        "applications/gone.desktop",
+        "[Desktop Entry]\nName=Gone\nHidden=true\n",
+    );
+
+    // No mimeinfo.cache here: the entries are read directly
+    write(
+        &user,
+        "applications/viewer.desktop",
+        &desktop("Image Viewer", "image/jpeg;"),
+    );
+
+    write(
+        &config,
+        "mimeapps.list",
+        "[Default Applications]\napplication/pdf=gone.desktop;kde-okular.desktop;\n",
+    );
+
+    let database = MimeApps::from_dirs(&[user, system], &[config]);
+    (temp_dir, database)
+}
+
+#[test]
+fn test_mime_type_from_globs() {
+    let (_temp_dir, database) = setup();
+
+    assert_eq!(database.mime_type("report.PDF"), Some("application/pdf"));
+    assert_eq!(database.mime_type("Makefile"), Some("text/x-makefile"));
+    assert_eq!(database.mime_type("README.md"), Some("text/x-readme"));
+    assert_eq!(database.mime_type("notes"), None);
+}
+
+#[test]
+fn test_mime_type_prefers_weight_then_length() {
+    let (_temp_dir, database) = setup();
+
+    assert_eq!(
+        database.mime_type("backup.tar.gz"),
+        Some("application/x-compressed-tar")
+    );
+    assert_eq!(database.mime_type("log.gz"), Some("application/gzip"));
+}
+
+#[test]
+fn test_default_app_skips_hidden_and_uses_nested_ids() {
+    let (_temp_dir, database) = setup();
+
+    assert_eq!(
+        database.default_app("application/pdf").as_deref(),
+        Some("Okular")
+    );
+}
+
+#[test]
+fn test_default_app_follows_parents_and_aliases() {
+    let (_temp_dir, database) = setup();
+
+    assert_eq!(
+        database.default_app("text/x-csrc").as_deref(),
+        Some("Text Editor")
+    );
+    assert_eq!(
+        database.default_app("text/x-readme").as_deref(),
+        Some("Text Editor")
+    );
+    assert_eq!(
+        database.default_app("image/jpg").as_deref(),
+        Some("Image Viewer")
+    );
+    assert_eq!(database.default_app("application/x-unknown"), None);
+}
+
+#[test]
+fn test_empty_database() {
+    let database = MimeApps::from_dirs(&[PathBuf::from("/nonexistent")], &[]);
+
+    assert_eq!(database.mime_type("a.pdf"), None);
+    assert_eq!(database.default_app("application/pdf"), None);
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "collate")]
+
+mod common;
+
+use cerium::fs::collate::Collation;
+use common::{ce, listing, stdout};
+use std::cmp::Ordering;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_collation_keeps_accented_letters_beside_their_base_letter() {
+    let mut names = vec!["zebra", "éclair", "apple", "Banana"];
+    names.sort_by(|left, right| Collation::compare(left, right));
+
+    assert_eq!(names, ["apple", "Banana", "éclair", "zebra"]);
+}
+
+#[test]
+fn test_collation_never_ties_distinct_names() {
+    assert_ne!(Collation::compare("readme", "README"), Ordering::Equal);
+    assert_eq!(Collation::compare("same", "same"), Ordering::Equal);
+}
+
+#[test]
+fn test_sort_key_locale_orders_cyrillic_names_by_the_alphabet() {
+    let dir = TempDir::new().unwrap();
+    for name in ["Яблоко", "ёж", "дом", "Ель", "apple"] {
+        fs::write(dir.path().join(name), "").unwrap();
+    }
+
+    let stdout = stdout(
+        ce(&["-1", "--sort-key", "locale"])
+            .current_dir(dir.path())
+            .env("LC_ALL", "ru_RU.UTF-8"),
+    );
+    let names = listing(&stdout);
+
+    // Russian puts Cyrillic ahead of Latin and sorts `ё` with `е`, not after `я`
+    assert_eq!(names, ["дом", "ёж", "Ель", "Яблоко", "apple"]);
+}
@@ -0,0 +1,77 @@
+use cerium::fs::dedupe::Dedupe;
+use cerium::fs::entry::Entry;
+use std::fs;
+use tempfile::TempDir;
+
+fn entries(dir: &TempDir, names: &[&str]) -> Vec<Entry> {
+    names
+        .iter()
+        .map(|name| Entry::from_path(dir.path().join(name), false))
+        .collect()
+}
+
+#[test]
+fn test_collapse_hard_links() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("a.txt"), "shared").unwrap();
+    fs::hard_link(dir.path().join("a.txt"), dir.path().join("b.txt")).unwrap();
+    fs::write(dir.path().join("c.txt"), "other").unwrap();
+
+    let collapsed = Dedupe::collapse(entries(&dir, &["a.txt", "c.txt", "b.txt"]), false);
+
+    let names: Vec<&str> = collapsed
+        .iter()
+        .map(|entry| entry.name().as_ref())
+        .collect();
+    assert_eq!(names, ["a.txt, b.txt", "c.txt"]);
+}
+
+#[test]
+fn test_collapse_keeps_distinct_files() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("a.txt"), "same").unwrap();
+    fs::write(dir.path().join("b.txt"), "same").unwrap();
+    fs::create_dir(dir.path().join("sub")).unwrap();
+
+    let collapsed = Dedupe::collapse(entries(&dir, &["a.txt", "b.txt", "sub"]), false);
+
+    assert_eq!(collapsed.len(), 3);
+}
+
+#[test]
+fn test_collapse_symlink_only_when_dereferencing() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("a.txt"), "target").unwrap();
+    std::os::unix::fs::symlink("a.txt", dir.path().join("link")).unwrap();
+
+    assert_eq!(
+        Dedupe::collapse(entries(&dir, &["a.txt", "link"]), false).len(),
+        2
+    );
+    assert_eq!(
+        Dedupe::collapse(entries(&dir, &["a.txt", "link"]), true).len(),
+        1
+    );
+}
+
+#[test]
+fn test_collapse_across_listings_drops_files_already_listed() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("a.txt"), "shared").unwrap();
+    fs::hard_link(dir.path().join("a.txt"), dir.path().join("b.txt")).unwrap();
+    fs::write(dir.path().join("c.txt"), "other").unwrap();
+
+    let (first, second) = Dedupe::across(|| {
+        (
+            Dedupe::collapse(entries(&dir, &["a.txt"]), false),
+            Dedupe::collapse(entries(&dir, &["b.txt", "c.txt"]), false),
+        )
+    });
+
+    assert_eq!(first.len(), 1);
+    let names: Vec<&str> = second.iter().map(|entry| entry.name().as_ref()).collect();
+    assert_eq!(names, ["c.txt"]);
+
+    // Outside the scope, each call stands alone again
+    assert_eq!(Dedupe::collapse(entries(&dir, &["b.txt"]), false).len(), 1);
+}
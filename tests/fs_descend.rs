@@ -0,0 +1,102 @@
+mod common;
+
+use cerium::cli::args::Args;
+use cerium::fs::descend::Descend;
+use clap::Parser;
+use common::default_args;
+use std::path::Path;
+use tempfile::TempDir;
+
+#[test]
+fn test_descend_allows_regular_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    assert!(Descend::allowed(temp_dir.path(), &default_args()));
+}
+
+#[test]
+fn test_descend_skips_default_system_dirs() {
+    let args = default_args();
+    assert!(!Descend::allowed(Path::new("/proc"), &args));
+    assert!(!Descend::allowed(Path::new("/sys"), &args));
+}
+
+#[test]
+fn test_descend_skips_custom_dirs() {
+    let temp_dir = TempDir::new().unwrap();
+    let skip = format!("--skip-dirs={}", temp_dir.path().display());
+    let args = Args::parse_from(["ce", ".", &skip]);
+
+    assert!(!Descend::allowed(temp_dir.path(), &args));
+}
+
+#[test]
+fn test_descend_skips_relative_dirs() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let args = Args::parse_from([
+        "ce",
+        root.to_str().unwrap(),
+        "--skip-dirs",
+        "target,build/cache",
+    ]);
+
+    // A bare name matches at any depth
+    assert!(!Descend::allowed(&root.join("target"), &args));
+    assert!(!Descend::allowed(&root.join("crates/core/target"), &args));
+    assert!(Descend::allowed(&root.join("targets"), &args));
+
+    // A relative path is resolved against the listing root
+    assert!(!Descend::allowed(&root.join("build/cache"), &args));
+    assert!(Descend::allowed(&root.join("src/build/cache"), &args));
+}
+
+#[test]
+fn test_descend_none_disables_skipping() {
+    let args = Args::parse_from(["ce", ".", "--skip-dirs", "none"]);
+    assert!(Descend::allowed(Path::new("/proc"), &args));
+}
+
+#[test]
+fn test_descend_enter_matches_names_and_subtrees() {
+    let args = Args::parse_from(["ce", "/repo", "--enter", "src*"]);
+
+    assert!(Descend::allowed(Path::new("/repo/src"), &args));
+    assert!(Descend::allowed(Path::new("/repo/src-old/nested"), &args));
+    assert!(!Descend::allowed(Path::new("/repo/target"), &args));
+}
+
+#[test]
+fn test_descend_enter_path_pattern_opens_parents() {
+    let args = Args::parse_from(["ce", "/repo", "--enter", "crates/*"]);
+
+    assert!(Descend::allowed(Path::new("/repo/crates"), &args));
+    assert!(Descend::allowed(Path::new("/repo/crates/core"), &args));
+    assert!(Descend::allowed(Path::new("/repo/crates/core/src"), &args));
+    assert!(!Descend::allowed(Path::new("/repo/docs"), &args));
+}
+
+#[test]
+fn test_descend_level_limits_depth() {
+    let args = Args::parse_from(["ce", "/repo", "--skip-dirs", "none", "--level", "2"]);
+
+    assert!(Descend::allowed(Path::new("/repo"), &args));
+    assert!(Descend::allowed(Path::new("/repo/src"), &args));
+    assert!(!Descend::allowed(Path::new("/repo/src/cli"), &args));
+}
+
+#[test]
+fn test_descend_level_one_keeps_to_the_root() {
+    let args = Args::parse_from(["ce", "/repo", "--skip-dirs", "none", "--level", "1"]);
+
+    assert!(Descend::allowed(Path::new("/repo"), &args));
+    assert!(!Descend::allowed(Path::new("/repo/src"), &args));
+    assert!(Args::try_parse_from(["ce", "--level", "0"]).is_err());
+}
+
+#[test]
+fn test_descend_depth_is_an_alias_of_level() {
+    let args = Args::parse_from(["ce", "/repo", "-R", "--skip-dirs", "none", "--depth", "2"]);
+
+    assert_eq!(args.level, Some(2));
+    assert!(!Descend::allowed(Path::new("/repo/src/cli"), &args));
+}
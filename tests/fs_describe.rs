@@ -0,0 +1,48 @@
+use cerium::fs::describe::Describe;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_find_reads_the_key_from_its_section_only() {
+    let unit = "[Service]\nDescription=wrong\n\n[Unit]\nDescription = Nightly backup\nAfter=network.target\n";
+    assert_eq!(
+        Describe::find(unit, "[Unit]", "Description").as_deref(),
+        Some("Nightly backup")
+    );
+
+    let desktop = "[Desktop Entry]\nName[de]=Texteditor\nName=Text Editor\n";
+    assert_eq!(
+        Describe::find(desktop, "[Desktop Entry]", "Name").as_deref(),
+        Some("Text Editor")
+    );
+    assert_eq!(
+        Describe::find("Name=loose\n", "[Desktop Entry]", "Name"),
+        None
+    );
+}
+
+#[test]
+fn test_get_summarises_units_and_desktop_entries() {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path();
+    fs::write(
+        base.join("backup.service"),
+        "[Unit]\nDescription=Nightly\x1b[31m backup\n",
+    )
+    .unwrap();
+    fs::write(
+        base.join("editor.desktop"),
+        "[Desktop Entry]\nType=Application\nName=Text Editor\n",
+    )
+    .unwrap();
+    fs::write(base.join("notes.txt"), "[Unit]\nDescription=not a unit\n").unwrap();
+    fs::write(base.join("empty.timer"), "").unwrap();
+    std::os::unix::fs::symlink("/dev/null", base.join("masked.service")).unwrap();
+
+    let get = |name: &str| Describe::get(&base.join(name)).to_string();
+    assert_eq!(get("backup.service"), "Nightly\x1b[31m backup");
+    assert_eq!(get("editor.desktop"), "Text Editor");
+    assert_eq!(get("notes.txt"), "-");
+    assert_eq!(get("empty.timer"), "-");
+    assert_eq!(get("masked.service"), "-");
+}
@@ -1,11 +1,15 @@
 mod common;
 
+use cerium::cli::args::Args;
 use cerium::cli::flags::SortBy;
 use cerium::fs::dir::DirReader;
+use clap::Parser;
 use common::{default_args, setup_test_dir};
+use std::collections::HashSet;
 use std::fs::File;
 use std::io::Write;
 use std::path::PathBuf;
+use std::sync::Mutex;
 use tempfile::TempDir;
 
 #[test]
@@ -107,7 +111,7 @@ fn test_sort_by_name() {
     let temp_dir = setup_test_dir();
     let dir_reader = DirReader::from(temp_dir.path().to_path_buf());
     let mut args = default_args();
-    args.sort = SortBy::Name;
+    args.sort = vec![SortBy::Name];
 
     let entries = dir_reader.list(&args);
 
@@ -122,7 +126,7 @@ fn test_sort_by_extension() {
     let temp_dir = setup_test_dir();
     let dir_reader = DirReader::from(temp_dir.path().to_path_buf());
     let mut args = default_args();
-    args.sort = SortBy::Extension;
+    args.sort = vec![SortBy::Extension];
 
     let entries = dir_reader.list(&args);
 
@@ -137,7 +141,7 @@ fn test_reverse_sort() {
     let temp_dir = setup_test_dir();
     let dir_reader = DirReader::from(temp_dir.path().to_path_buf());
     let mut args = default_args();
-    args.sort = SortBy::Name;
+    args.sort = vec![SortBy::Name];
     args.reverse = true;
 
     let entries = dir_reader.list(&args);
@@ -162,8 +166,11 @@ fn test_dir_size_with_hidden() {
 
     let dir_reader = DirReader::from(base.to_path_buf());
 
-    let size_with_hidden = dir_reader.dir_size(true);
-    let size_without_hidden = dir_reader.dir_size(false);
+    let mut args = default_args();
+    args.all = true;
+    let size_with_hidden = dir_reader.dir_size(&args);
+    args.all = false;
+    let size_without_hidden = dir_reader.dir_size(&args);
 
     assert!(size_with_hidden > size_without_hidden);
     assert!(size_with_hidden >= 11); // At least "Hello" + "Secret"
@@ -179,7 +186,9 @@ fn test_dir_size_recursive() {
     nested.write_all(b"Nested content").unwrap();
 
     let dir_reader = DirReader::from(base.to_path_buf());
-    let size = dir_reader.dir_size(true);
+    let mut args = default_args();
+    args.all = true;
+    let size = dir_reader.dir_size(&args);
 
     // Should include nested files
     assert!(size >= 14); // At least "Nested content"
@@ -191,7 +200,9 @@ fn test_dir_size_non_directory() {
     let file_path = temp_dir.path().join("file1.txt");
 
     let dir_reader = DirReader::from(file_path);
-    let size = dir_reader.dir_size(true);
+    let mut args = default_args();
+    args.all = true;
+    let size = dir_reader.dir_size(&args);
 
     assert_eq!(size, 0); // Non-directories return 0
 }
@@ -216,3 +227,282 @@ fn test_list_special_file_types() {
     assert_eq!(entries.len(), 1, "Special file types should be listed");
     assert_eq!(entries[0].name().as_ref(), "test.sock");
 }
+
+#[test]
+fn test_sort_by_children() {
+    let temp_dir = TempDir::new().unwrap();
+    for (dir, count) in [("busy", 3), ("quiet", 1), ("empty", 0)] {
+        let path = temp_dir.path().join(dir);
+        std::fs::create_dir(&path).unwrap();
+        for i in 0..count {
+            File::create(path.join(format!("f{}", i))).unwrap();
+        }
+    }
+    File::create(temp_dir.path().join("afile")).unwrap();
+
+    let dir_reader = DirReader::from(temp_dir.path().to_path_buf());
+    let mut args = default_args();
+    args.sort = vec![SortBy::Children];
+
+    let names: Vec<String> = dir_reader
+        .list(&args)
+        .iter()
+        .map(|e| e.name().to_string())
+        .collect();
+
+    assert_eq!(names, ["afile", "empty", "quiet", "busy"]);
+}
+
+#[test]
+fn test_du_sorts_directories_by_recursive_size() {
+    let temp_dir = TempDir::new().unwrap();
+    for (dir, bytes) in [("big", 5000), ("small", 10)] {
+        let path = temp_dir.path().join(dir);
+        std::fs::create_dir(&path).unwrap();
+        std::fs::write(path.join("data"), vec![0u8; bytes]).unwrap();
+    }
+    std::fs::write(temp_dir.path().join("medium"), vec![0u8; 1000]).unwrap();
+
+    let dir_reader = DirReader::from(temp_dir.path().to_path_buf());
+    let mut args = default_args();
+    args.sort = vec![SortBy::Size];
+    args.du = true;
+    args.apply_du();
+
+    let names: Vec<String> = dir_reader
+        .list(&args)
+        .iter()
+        .map(|e| e.name().to_string())
+        .collect();
+
+    assert!(args.size && args.dir_size);
+    assert_eq!(names, ["small", "medium", "big"]);
+}
+
+#[test]
+fn test_sort_column_compares_numbers_by_value_and_keeps_ties() {
+    let temp_dir = TempDir::new().unwrap();
+    for (name, bytes) in [
+        ("nine", 9000),
+        ("ten", 10000),
+        ("b_tie", 500),
+        ("a_tie", 500),
+    ] {
+        std::fs::write(temp_dir.path().join(name), vec![0u8; bytes]).unwrap();
+    }
+
+    let dir_reader = DirReader::from(temp_dir.path().to_path_buf());
+    let mut args = default_args();
+    args.sort_column = Some("size".parse().unwrap());
+    let names = |args: &Args| -> Vec<String> {
+        dir_reader
+            .list(args)
+            .iter()
+            .map(|e| e.name().to_string())
+            .collect()
+    };
+
+    // "9.0 KB" would sort after "10 KB" as text
+    assert_eq!(names(&args), ["a_tie", "b_tie", "nine", "ten"]);
+
+    args.reverse = true;
+    assert_eq!(names(&args), ["ten", "nine", "b_tie", "a_tie"]);
+
+    args.sort_column = Some("name".parse().unwrap());
+    args.reverse = false;
+    assert_eq!(names(&args), ["a_tie", "b_tie", "nine", "ten"]);
+}
+
+#[test]
+fn test_inode_count_dedupes_hard_links() {
+    let temp_dir = TempDir::new().unwrap();
+    let nested = temp_dir.path().join("nested");
+    std::fs::create_dir(&nested).unwrap();
+    File::create(nested.join("a")).unwrap();
+    std::fs::hard_link(nested.join("a"), nested.join("b")).unwrap();
+    File::create(temp_dir.path().join("c")).unwrap();
+
+    let dir_reader = DirReader::from(temp_dir.path().to_path_buf());
+
+    // root, nested, a (= b), c
+    assert_eq!(
+        dir_reader.inode_count(&default_args(), &Mutex::new(HashSet::new())),
+        4
+    );
+}
+
+#[test]
+fn test_inode_count_includes_hidden_entries() {
+    let temp_dir = TempDir::new().unwrap();
+    let nested = temp_dir.path().join("nested");
+    std::fs::create_dir(&nested).unwrap();
+    File::create(nested.join(".hidden")).unwrap();
+    std::fs::create_dir(temp_dir.path().join(".cache")).unwrap();
+    File::create(temp_dir.path().join(".cache/blob")).unwrap();
+
+    let dir_reader = DirReader::from(temp_dir.path().to_path_buf());
+    let mut args = default_args();
+    args.all = false;
+
+    // root, nested, .hidden, .cache, blob
+    assert_eq!(
+        dir_reader.inode_count(&args, &Mutex::new(HashSet::new())),
+        5
+    );
+}
+
+#[test]
+fn test_inode_count_shares_seen_links_between_calls() {
+    let temp_dir = TempDir::new().unwrap();
+    let (one, two) = (temp_dir.path().join("one"), temp_dir.path().join("two"));
+    std::fs::create_dir(&one).unwrap();
+    std::fs::create_dir(&two).unwrap();
+    File::create(one.join("a")).unwrap();
+    std::fs::hard_link(one.join("a"), two.join("b")).unwrap();
+
+    let seen = Mutex::new(HashSet::new());
+    let args = default_args();
+
+    // one and a, then only two: b is the file already counted as a
+    assert_eq!(DirReader::from(one).inode_count(&args, &seen), 2);
+    assert_eq!(DirReader::from(two).inode_count(&args, &seen), 1);
+}
+
+#[test]
+fn test_stream_applies_list_filters() {
+    let temp_dir = setup_test_dir();
+    let dir_reader = DirReader::from(temp_dir.path().to_path_buf());
+    let mut args = default_args();
+    args.long = true;
+    args.hide = vec!["*.rs".to_string()];
+
+    let mut streamed = Vec::new();
+    dir_reader.stream(&args, |entry| {
+        assert!(entry.metadata().is_some());
+        streamed.push(entry.name().to_string());
+    });
+    streamed.sort();
+
+    let mut listed: Vec<String> = dir_reader
+        .list(&args)
+        .iter()
+        .map(|entry| entry.name().to_string())
+        .collect();
+    listed.sort();
+
+    assert_eq!(streamed, listed);
+    assert!(!streamed.iter().any(|name| name == "file2.rs"));
+}
+
+#[test]
+fn test_dir_size_counts_hard_links_once() {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path();
+    std::fs::write(base.join("data.bin"), [0u8; 1000]).unwrap();
+    std::fs::create_dir(base.join("copy")).unwrap();
+    std::fs::hard_link(base.join("data.bin"), base.join("copy/data.bin")).unwrap();
+
+    let dir_reader = DirReader::from(base.to_path_buf());
+    let mut args = default_args();
+    assert_eq!(dir_reader.dir_size(&args), 1000);
+
+    args.count_links = true;
+    assert_eq!(dir_reader.dir_size(&args), 2000);
+}
+
+#[test]
+fn test_dir_size_dereference_without_looping() {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path().join("root");
+    let outside = temp_dir.path().join("outside");
+    std::fs::create_dir_all(base.join("sub")).unwrap();
+    std::fs::create_dir(&outside).unwrap();
+    std::fs::write(base.join("sub/own.bin"), [0u8; 100]).unwrap();
+    std::fs::write(outside.join("big.bin"), [0u8; 5000]).unwrap();
+    std::os::unix::fs::symlink(outside.join("big.bin"), base.join("big")).unwrap();
+    std::os::unix::fs::symlink(&base, base.join("sub/loop")).unwrap();
+
+    let dir_reader = DirReader::from(base.clone());
+    let mut args = default_args();
+    assert_eq!(dir_reader.dir_size(&args), 100);
+
+    args.dereference = true;
+    assert_eq!(dir_reader.dir_size(&args), 5100);
+}
+
+#[test]
+fn test_dereference_command_line_follows_only_the_operand() {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path();
+    std::fs::create_dir(base.join("dir")).unwrap();
+    std::fs::write(base.join("target.bin"), vec![0u8; 4096]).unwrap();
+    std::os::unix::fs::symlink(base.join("target.bin"), base.join("link")).unwrap();
+    std::os::unix::fs::symlink(base.join("target.bin"), base.join("dir/inner")).unwrap();
+
+    let size = |path: PathBuf, args: &Args| {
+        let entries = DirReader::from(path).list(args);
+        entries[0].metadata().unwrap().size
+    };
+    let mut args = default_args();
+    args.size = true;
+    assert_ne!(size(base.join("link"), &args), 4096);
+
+    args.dereference_command_line = true;
+    assert_eq!(size(base.join("link"), &args), 4096);
+    assert_ne!(size(base.join("dir"), &args), 4096);
+
+    args.dereference = true;
+    assert_eq!(size(base.join("dir"), &args), 4096);
+}
+
+#[test]
+fn test_sort_keys_break_ties_in_order() {
+    let temp_dir = TempDir::new().unwrap();
+    for (name, bytes) in [("b.txt", 10), ("a.rs", 10), ("c.md", 20), ("d.rs", 5)] {
+        std::fs::write(temp_dir.path().join(name), vec![0u8; bytes]).unwrap();
+    }
+
+    let dir_reader = DirReader::from(temp_dir.path().to_path_buf());
+    let names = |sort: &str, reverse: bool| -> Vec<String> {
+        let mut args = Args::parse_from(["ce", "--sort", sort]);
+        args.reverse = reverse;
+        dir_reader
+            .list(&args)
+            .iter()
+            .map(|e| e.name().to_string())
+            .collect()
+    };
+
+    assert_eq!(names("size,name", false), ["d.rs", "a.rs", "b.txt", "c.md"]);
+    assert_eq!(
+        names("extension,size", false),
+        ["c.md", "d.rs", "a.rs", "b.txt"]
+    );
+    assert_eq!(names("size,name", true), ["c.md", "b.txt", "a.rs", "d.rs"]);
+}
+
+#[test]
+fn test_unsorted_keeps_directory_order() {
+    let temp_dir = TempDir::new().unwrap();
+    for name in ["m", "z", "a", "k", "b", "y"] {
+        File::create(temp_dir.path().join(name)).unwrap();
+    }
+    let readdir: Vec<String> = std::fs::read_dir(temp_dir.path())
+        .unwrap()
+        .map(|item| item.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+
+    let dir_reader = DirReader::from(temp_dir.path().to_path_buf());
+    let names = |argv: &[&str]| -> Vec<String> {
+        let args = Args::parse_from(argv);
+        dir_reader
+            .list(&args)
+            .iter()
+            .map(|e| e.name().to_string())
+            .collect()
+    };
+
+    assert_eq!(names(&["ce", "--sort", "none"]), readdir);
+    assert_eq!(names(&["ce", "-U", "-r"]), readdir);
+    assert_eq!(names(&["ce", "-U", "--sort", "size"]), readdir);
+}
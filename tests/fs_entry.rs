@@ -351,6 +351,8 @@ fn test_entry_clone() {
 fn test_metadata_clone() {
     let meta1 = Metadata {
         mode: 0o644,
+        dev: 2049,
+        rdev: 0,
         size: 1024,
         ino: 12345,
         nlink: 1,
@@ -361,11 +363,13 @@ fn test_metadata_clone() {
         atime: 1000000000,
         mtime: 1000000001,
         ctime: 1000000002,
+        btime: Some(999999999),
     };
 
     let meta2 = meta1.clone();
 
     assert_eq!(meta1.mode, meta2.mode);
+    assert_eq!(meta1.dev, meta2.dev);
     assert_eq!(meta1.size, meta2.size);
     assert_eq!(meta1.ino, meta2.ino);
     assert_eq!(meta1.nlink, meta2.nlink);
@@ -376,6 +380,7 @@ fn test_metadata_clone() {
     assert_eq!(meta1.atime, meta2.atime);
     assert_eq!(meta1.mtime, meta2.mtime);
     assert_eq!(meta1.ctime, meta2.ctime);
+    assert_eq!(meta1.btime, meta2.btime);
 }
 
 #[test]
@@ -448,3 +453,15 @@ fn test_set_name() {
     entry.set_name(Arc::from("new_name.txt"));
     assert_eq!(entry.name().as_ref(), "new_name.txt");
 }
+
+#[test]
+fn test_entry_is_hidden() {
+    let temp_dir = TempDir::new().unwrap();
+    let hidden_path = temp_dir.path().join(".hidden");
+    let visible_path = temp_dir.path().join("visible");
+    File::create(&hidden_path).unwrap();
+    File::create(&visible_path).unwrap();
+
+    assert!(Entry::from_path(hidden_path, false).is_hidden());
+    assert!(!Entry::from_path(visible_path, false).is_hidden());
+}
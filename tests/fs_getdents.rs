@@ -0,0 +1,66 @@
+#![cfg(all(feature = "getdents", any(target_os = "linux", target_os = "android")))]
+
+use cerium::fs::feature::getdents;
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::os::unix::fs::symlink;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// Lists a directory through std, as `(path, is_dir, is_symlink)`.
+fn std_listing(path: &Path) -> BTreeSet<(PathBuf, bool, bool)> {
+    fs::read_dir(path)
+        .unwrap()
+        .map(|entry| {
+            let entry = entry.unwrap();
+            let file_type = entry.file_type().unwrap();
+            (entry.path(), file_type.is_dir(), file_type.is_symlink())
+        })
+        .collect()
+}
+
+fn getdents_listing(path: &Path) -> BTreeSet<(PathBuf, bool, bool)> {
+    getdents::read_dir(path)
+        .unwrap()
+        .into_iter()
+        .map(|item| (item.path, item.is_dir, item.is_symlink))
+        .collect()
+}
+
+#[test]
+fn test_matches_std_read_dir() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    File::create(root.join("file.txt")).unwrap();
+    File::create(root.join(".hidden")).unwrap();
+    File::create(root.join("ünïcødé 🦀")).unwrap();
+    fs::create_dir(root.join("sub")).unwrap();
+    symlink("sub", root.join("link")).unwrap();
+    symlink("missing", root.join("broken")).unwrap();
+
+    let listing = getdents_listing(root);
+    assert_eq!(listing.len(), 6);
+    assert_eq!(listing, std_listing(root));
+}
+
+#[test]
+fn test_reads_past_one_buffer() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+
+    // Long names so a few thousand records overflow the buffer
+    let count = getdents::BUFFER_SIZE / 200 + 500;
+    for index in 0..count {
+        File::create(root.join(format!("{index:0>200}"))).unwrap();
+    }
+
+    assert_eq!(getdents::read_dir(root).unwrap().len(), count);
+}
+
+#[test]
+fn test_missing_directory_is_an_error() {
+    let temp = TempDir::new().unwrap();
+    assert!(getdents::read_dir(&temp.path().join("missing")).is_err());
+    File::create(temp.path().join("file")).unwrap();
+    assert!(getdents::read_dir(&temp.path().join("file")).is_err());
+}
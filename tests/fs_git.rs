@@ -0,0 +1,125 @@
+use cerium::cli::args::Args;
+use cerium::cli::settings::Settings;
+use cerium::display::factory::DisplayFactory;
+use cerium::display::theme::colours::RgbColours;
+use cerium::display::theme::config::Theme;
+use cerium::fs::git::{Git, GitStatus};
+use clap::Parser;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+fn git(directory: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .arg("-C")
+        .arg(directory)
+        .args(args)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {} failed", args.join(" "));
+}
+
+/// A repository with one committed file, then a mix of staged, unstaged, untracked and ignored changes.
+fn setup_repository() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path();
+
+    git(base, &["init", "-q"]);
+    fs::write(base.join("clean.txt"), "clean\n").unwrap();
+    fs::write(base.join("edited.txt"), "before\n").unwrap();
+    fs::write(base.join(".gitignore"), "build/\n*.log\n").unwrap();
+    fs::create_dir(base.join("src")).unwrap();
+    fs::write(base.join("src/lib.rs"), "").unwrap();
+    git(base, &["add", "."]);
+    git(base, &["commit", "-q", "-m", "initial"]);
+
+    fs::write(base.join("edited.txt"), "after\n").unwrap();
+    fs::write(base.join("staged.txt"), "new\n").unwrap();
+    git(base, &["add", "staged.txt"]);
+    fs::write(base.join("src/lib.rs"), "// changed\n").unwrap();
+    fs::write(base.join("untracked.txt"), "").unwrap();
+    fs::write(base.join("debug.log"), "").unwrap();
+    fs::create_dir_all(base.join("build/out")).unwrap();
+    fs::write(base.join("build/out/app"), "").unwrap();
+
+    temp_dir
+}
+
+fn status(path: &Path, is_dir: bool) -> String {
+    Git::status(path, is_dir).unwrap().to_string()
+}
+
+#[test]
+fn test_git_file_statuses() {
+    let repository = setup_repository();
+    let base = repository.path();
+
+    assert_eq!(status(&base.join("clean.txt"), false), "--");
+    assert_eq!(status(&base.join("edited.txt"), false), "-M");
+    assert_eq!(status(&base.join("staged.txt"), false), "A-");
+    assert_eq!(status(&base.join("untracked.txt"), false), "??");
+    assert_eq!(status(&base.join("debug.log"), false), "!!");
+}
+
+#[test]
+fn test_git_ignored_directory_covers_contents() {
+    let repository = setup_repository();
+    let base = repository.path();
+
+    assert_eq!(status(&base.join("build"), true), "!!");
+    assert_eq!(status(&base.join("build/out/app"), false), "!!");
+}
+
+#[test]
+fn test_git_directory_summarises_contents() {
+    let repository = setup_repository();
+    let base = repository.path();
+
+    assert_eq!(status(&base.join("src"), true), "-M");
+    assert_eq!(status(&base.join("src/lib.rs"), false), "-M");
+}
+
+#[test]
+fn test_git_outside_repository() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("file.txt"), "").unwrap();
+
+    assert!(Git::status(&temp_dir.path().join("file.txt"), false).is_none());
+}
+
+#[test]
+fn test_git_status_display() {
+    assert_eq!(GitStatus::CLEAN.to_string(), "--");
+    assert_eq!(
+        GitStatus {
+            staged: b'R',
+            unstaged: b'M'
+        }
+        .to_string(),
+        "RM"
+    );
+}
+
+#[test]
+fn test_no_cache_runs_git_status_afresh_for_each_listing() {
+    let repository = setup_repository();
+    let base = repository.path();
+    assert_eq!(status(&base.join("clean.txt"), false), "--");
+
+    fs::write(base.join("clean.txt"), "changed\n").unwrap();
+    assert_eq!(status(&base.join("clean.txt"), false), "--");
+
+    RgbColours::init(Theme::default());
+    let no_cache = Arc::new(Settings {
+        no_cache: true,
+        ..Settings::current()
+    });
+    no_cache.scope(|| {
+        DisplayFactory::print_all(&[base.join("clean.txt")], &Args::parse_from(["ce"]));
+        assert_eq!(status(&base.join("clean.txt"), false), "-M");
+    });
+}
@@ -0,0 +1,83 @@
+//! Kept in its own binary: the test points `PATH` at a logging `git`, which
+//! is only sound while no other test is running.
+
+use cerium::fs::git::Git;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::{Arc, Barrier};
+use std::{env, thread};
+use tempfile::TempDir;
+
+#[test]
+fn test_git_status_runs_once_per_repository_across_threads() {
+    let repository = TempDir::new().unwrap();
+    let base = repository.path();
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(base)
+        .args(["init", "-q"])
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let files: Vec<PathBuf> = (0..16)
+        .map(|number| {
+            let directory = base.join(format!("module_{number:02}"));
+            fs::create_dir(&directory).unwrap();
+            fs::write(directory.join("mod.rs"), "").unwrap();
+            directory.join("mod.rs")
+        })
+        .collect();
+
+    // A `git` that logs each invocation before handing over to the real one
+    let path = env::var_os("PATH").unwrap();
+    let real = env::split_paths(&path)
+        .map(|directory| directory.join("git"))
+        .find(|git| git.is_file())
+        .unwrap();
+    let shims = TempDir::new().unwrap();
+    let log = shims.path().join("git.log");
+    let shim = shims.path().join("git");
+    fs::write(
+        &shim,
+        format!(
+            "#!/bin/sh\necho \"$*\" >> '{}'\nexec '{}' \"$@\"\n",
+            log.display(),
+            real.display()
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&shim, fs::Permissions::from_mode(0o755)).unwrap();
+
+    let shimmed = env::join_paths(
+        [shims.path().to_path_buf()]
+            .into_iter()
+            .chain(env::split_paths(&path)),
+    );
+    // SAFETY: this is the only test in the binary, so no other thread reads the environment.
+    unsafe { env::set_var("PATH", shimmed.unwrap()) };
+
+    // Every thread looks up a different directory of the same work tree at once
+    let barrier = Arc::new(Barrier::new(files.len()));
+    let handles: Vec<_> = files
+        .into_iter()
+        .map(|file| {
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                Git::status(&file, false).map(|status| status.to_string())
+            })
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap().as_deref(), Some("??"));
+    }
+
+    let runs = fs::read_to_string(&log).unwrap();
+    assert_eq!(
+        runs.lines().filter(|line| line.contains("status")).count(),
+        1
+    );
+}
@@ -0,0 +1,142 @@
+use cerium::cli::args::Args;
+use cerium::cli::settings::Settings;
+use cerium::display::theme::colours::RgbColours;
+use cerium::display::theme::config::Theme;
+use cerium::fs::dir::DirReader;
+use cerium::fs::gitignore::{GitIgnore, IgnoreFile};
+use clap::Parser;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+fn ignored(rules: &str, path: &str, is_dir: bool) -> Option<bool> {
+    IgnoreFile::parse(Path::new("/repo"), rules).verdict(&Path::new("/repo").join(path), is_dir)
+}
+
+fn names(dir: &Path, args: &Args) -> Vec<String> {
+    let mut names: Vec<String> = DirReader::from(dir.to_path_buf())
+        .list(args)
+        .iter()
+        .map(|entry| entry.name().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_unanchored_pattern_matches_at_any_depth() {
+    assert_eq!(ignored("*.log", "debug.log", false), Some(true));
+    assert_eq!(ignored("*.log", "a/b/debug.log", false), Some(true));
+    assert_eq!(ignored("*.log", "debug.txt", false), None);
+}
+
+#[test]
+fn test_anchored_pattern_matches_from_base() {
+    assert_eq!(ignored("/build", "build", true), Some(true));
+    assert_eq!(ignored("/build", "src/build", true), None);
+    assert_eq!(ignored("doc/*.txt", "doc/notes.txt", false), Some(true));
+    assert_eq!(ignored("doc/*.txt", "doc/server/notes.txt", false), None);
+}
+
+#[test]
+fn test_directory_only_pattern() {
+    assert_eq!(ignored("target/", "target", true), Some(true));
+    assert_eq!(ignored("target/", "target", false), None);
+}
+
+#[test]
+fn test_double_star_patterns() {
+    assert_eq!(ignored("**/cache", "a/b/cache", true), Some(true));
+    assert_eq!(ignored("**/cache", "cache", true), Some(true));
+    assert_eq!(ignored("a/**/z", "a/z", false), Some(true));
+    assert_eq!(ignored("a/**/z", "a/b/c/z", false), Some(true));
+    assert_eq!(ignored("out/**", "out/x/y", false), Some(true));
+    assert_eq!(ignored("out/**", "out", true), None);
+}
+
+#[test]
+fn test_negation_last_match_wins() {
+    let rules = "*.log\n!keep.log\n";
+    assert_eq!(ignored(rules, "drop.log", false), Some(true));
+    assert_eq!(ignored(rules, "keep.log", false), Some(false));
+}
+
+#[test]
+fn test_comments_escapes_and_classes() {
+    assert_eq!(ignored("# note\n\n", "# note", false), None);
+    assert_eq!(ignored("\\#hash", "#hash", false), Some(true));
+    assert_eq!(ignored("\\!bang", "!bang", false), Some(true));
+    assert_eq!(ignored("file[0-9].o", "file7.o", false), Some(true));
+    assert_eq!(ignored("file[!0-9].o", "file7.o", false), None);
+    assert_eq!(ignored("trail\\ ", "trail ", false), Some(true));
+    assert_eq!(ignored("spaced   ", "spaced", false), Some(true));
+}
+
+#[test]
+fn test_nested_gitignore_overrides_parent() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir_all(root.join(".git/info")).unwrap();
+    fs::create_dir_all(root.join("sub")).unwrap();
+    fs::write(root.join(".gitignore"), "*.tmp\n").unwrap();
+    fs::write(root.join("sub/.gitignore"), "!keep.tmp\n").unwrap();
+    fs::write(root.join(".git/info/exclude"), "secret\n").unwrap();
+
+    let ignore = GitIgnore::for_dir(&root.join("sub"));
+    assert!(ignore.is_ignored("drop.tmp", false));
+    assert!(!ignore.is_ignored("keep.tmp", false));
+    assert!(ignore.is_ignored("secret", false));
+    assert!(!ignore.is_ignored(".git", true));
+}
+
+#[test]
+fn test_list_hides_ignored_entries() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir(root.join(".git")).unwrap();
+    fs::create_dir(root.join("target")).unwrap();
+    fs::write(root.join(".gitignore"), "target/\n*.bak\n").unwrap();
+    fs::write(root.join("main.rs"), "").unwrap();
+    fs::write(root.join("main.rs.bak"), "").unwrap();
+
+    let ignoring = Args::parse_from(["ce", "--git-ignore"]);
+    assert_eq!(names(root, &ignoring), vec!["main.rs"]);
+
+    let plain = Args::parse_from(["ce"]);
+    assert_eq!(
+        names(root, &plain),
+        vec!["main.rs", "main.rs.bak", "target"]
+    );
+}
+
+#[test]
+fn test_outside_a_repository_nothing_is_ignored() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join(".gitignore"), "*\n").unwrap();
+    fs::write(temp_dir.path().join("file.txt"), "").unwrap();
+
+    let ignore = GitIgnore::for_dir(temp_dir.path());
+    assert!(!ignore.is_ignored("file.txt", false));
+}
+
+#[test]
+fn test_no_cache_rereads_a_changed_ignore_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir(root.join(".git")).unwrap();
+    fs::write(root.join(".gitignore"), "*.bak\n").unwrap();
+    assert!(GitIgnore::for_dir(root).is_ignored("main.rs.bak", false));
+
+    fs::write(root.join(".gitignore"), "*.tmp\n").unwrap();
+    RgbColours::init(Theme::default());
+    let no_cache = Arc::new(Settings {
+        no_cache: true,
+        ..Settings::current()
+    });
+    no_cache.scope(|| {
+        let ignore = GitIgnore::for_dir(root);
+        assert!(!ignore.is_ignored("main.rs.bak", false));
+        assert!(ignore.is_ignored("main.rs.tmp", false));
+    });
+}
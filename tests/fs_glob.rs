@@ -1,4 +1,6 @@
 use cerium::fs::glob::Glob;
+use std::fs;
+use tempfile::TempDir;
 
 #[test]
 fn test_literal_match() {
@@ -50,3 +52,47 @@ fn test_empty_pattern() {
     assert!(g.is_match(""));
     assert!(!g.is_match("anything"));
 }
+
+#[test]
+fn test_star_runs_collapse() {
+    let glob = Glob::new(&"*".repeat(500)).unwrap();
+    assert!(glob.is_match(&"a".repeat(200)));
+
+    let glob = Glob::new("a**b").unwrap();
+    assert!(glob.is_match("axxb"));
+    assert!(!glob.is_match("axx"));
+}
+
+#[test]
+fn test_expand_path_patterns() {
+    let temp_dir = TempDir::new().unwrap();
+    let base = temp_dir.path();
+    fs::create_dir_all(base.join("src/fs/entry")).unwrap();
+    fs::create_dir_all(base.join(".git")).unwrap();
+    for file in [
+        "src/main.rs",
+        "src/fs/dir.rs",
+        "src/fs/entry/mod.rs",
+        "src/notes.txt",
+        "src/.hidden.rs",
+        ".git/hook.rs",
+    ] {
+        fs::write(base.join(file), "").unwrap();
+    }
+
+    let relative = |pattern: &str| -> Vec<String> {
+        Glob::expand(&base.join(pattern))
+            .iter()
+            .map(|path| path.strip_prefix(base).unwrap().display().to_string())
+            .collect()
+    };
+
+    assert_eq!(relative("src/*.rs"), ["src/main.rs"]);
+    assert_eq!(
+        relative("**/*.rs"),
+        ["src/fs/dir.rs", "src/fs/entry/mod.rs", "src/main.rs"]
+    );
+    assert_eq!(relative("src/.*.rs"), ["src/.hidden.rs"]);
+    assert_eq!(relative("src/f?/dir.rs"), ["src/fs/dir.rs"]);
+    assert!(relative("src/*.md").is_empty());
+}
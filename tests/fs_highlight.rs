@@ -0,0 +1,34 @@
+use cerium::fs::highlight::Highlight;
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+#[test]
+fn test_context_swaps_files_for_their_directory() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    fs::create_dir(root.join("sub")).unwrap();
+    fs::write(root.join("a.txt"), "").unwrap();
+    fs::write(root.join("b.txt"), "").unwrap();
+
+    let paths = [root.join("a.txt"), root.join("sub"), root.join("b.txt")];
+    let (dirs, files) = Highlight::context(&paths);
+
+    // The shared directory is listed once, where its first file was given
+    assert_eq!(dirs, [root.to_path_buf(), root.join("sub")]);
+    assert_eq!(
+        files,
+        HashSet::from([root.join("a.txt"), root.join("b.txt")])
+    );
+}
+
+#[test]
+fn test_context_bare_file_name_lists_current_directory() {
+    let (dirs, files) = Highlight::context(&[PathBuf::from("no-such-dir-file.txt")]);
+    assert_eq!(dirs, [PathBuf::from(".")]);
+    assert_eq!(
+        files,
+        HashSet::from([PathBuf::from("./no-such-dir-file.txt")])
+    );
+}
@@ -0,0 +1,122 @@
+use cerium::fs::metadata::{self, Metadata, Overlay};
+use std::fs::File;
+use std::io::Write;
+use tempfile::TempDir;
+
+#[test]
+fn test_allocated_counts_512_byte_blocks() {
+    let mut meta = Metadata::empty();
+    meta.blocks = 8;
+    assert_eq!(meta.allocated(), 4096);
+}
+
+#[test]
+fn test_created_falls_back_to_ctime_without_birth_time() {
+    let mut meta = Metadata::empty();
+    meta.ctime = 1_700_000_000;
+    assert_eq!(meta.created(), 1_700_000_000);
+
+    meta.btime = Some(1_600_000_000);
+    assert_eq!(meta.created(), 1_600_000_000);
+}
+
+#[test]
+fn test_birth_time_is_not_after_status_change() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("born");
+    File::create(&path).unwrap();
+
+    let meta = Metadata::load(&path, false).unwrap();
+    if let Some(btime) = meta.btime {
+        assert!(btime <= meta.ctime);
+        assert!(btime > 0);
+    }
+}
+
+#[test]
+fn test_compression_ratio_for_regular_files_only() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("data.bin");
+    File::create(&path)
+        .unwrap()
+        .write_all(&vec![7u8; 64 * 1024])
+        .unwrap();
+
+    let file = Metadata::load(&path, false).unwrap();
+    let ratio = file.compression_ratio().unwrap();
+    assert_eq!(ratio, file.size as f64 / file.allocated() as f64);
+
+    let dir = Metadata::load(temp_dir.path(), false).unwrap();
+    assert!(dir.compression_ratio().is_none());
+
+    let empty_path = temp_dir.path().join("empty");
+    File::create(&empty_path).unwrap();
+    assert!(
+        Metadata::load(&empty_path, false)
+            .unwrap()
+            .compression_ratio()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_compression_ratio_of_sparse_file_exceeds_one() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("sparse");
+    let file = File::create(&path).unwrap();
+    file.set_len(8 * 1024 * 1024).unwrap();
+    (&file).write_all(b"x").unwrap();
+    file.sync_all().unwrap();
+
+    let meta = Metadata::load(&path, false).unwrap();
+    if let Some(ratio) = meta.compression_ratio() {
+        assert!(ratio > 1.0, "ratio {ratio}");
+    }
+}
+
+#[test]
+fn test_has_compressed_extents_is_false_for_missing_files() {
+    let temp_dir = TempDir::new().unwrap();
+    assert!(!metadata::has_compressed_extents(
+        &temp_dir.path().join("missing")
+    ));
+}
+
+#[test]
+fn test_whiteout_is_a_zero_zero_character_device() {
+    let mut meta = Metadata::empty();
+    meta.mode = libc::S_IFCHR;
+    assert!(meta.is_whiteout());
+
+    meta.rdev = libc::makedev(1, 3); // /dev/null
+    assert!(!meta.is_whiteout());
+
+    meta.mode = libc::S_IFREG | 0o644;
+    meta.rdev = 0;
+    assert!(!meta.is_whiteout());
+}
+
+#[test]
+fn test_overlay_recognises_oci_whiteouts_and_opaque_markers() {
+    let temp_dir = TempDir::new().unwrap();
+    let layer = temp_dir.path();
+    std::fs::create_dir(layer.join("etc")).unwrap();
+    std::fs::create_dir(layer.join("var")).unwrap();
+    File::create(layer.join("etc/.wh..wh..opq")).unwrap();
+    File::create(layer.join(".wh.removed.conf")).unwrap();
+
+    let whiteout = layer.join(".wh.removed.conf");
+    let meta = Metadata::load(&whiteout, false).unwrap();
+    assert_eq!(
+        metadata::overlay(".wh.removed.conf", &whiteout, &meta),
+        Some(Overlay::Whiteout)
+    );
+
+    let etc = layer.join("etc");
+    let meta = Metadata::load(&etc, false).unwrap();
+    assert_eq!(metadata::overlay("etc", &etc, &meta), Some(Overlay::Opaque));
+
+    let var = layer.join("var");
+    let meta = Metadata::load(&var, false).unwrap();
+    assert_eq!(metadata::overlay("var", &var, &meta), None);
+}
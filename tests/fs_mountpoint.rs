@@ -0,0 +1,47 @@
+#![cfg(feature = "volumes")]
+
+use cerium::fs::mountpoint::{Mountpoint, Volume};
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+#[test]
+fn test_zfs_snapshot_path_detection() {
+    assert!(Mountpoint::is_zfs_snapshot_path(Path::new(
+        "/tank/home/.zfs/snapshot/daily-2026-10-01"
+    )));
+    assert!(!Mountpoint::is_zfs_snapshot_path(Path::new(
+        "/tank/home/.zfs/snapshot"
+    )));
+    assert!(!Mountpoint::is_zfs_snapshot_path(Path::new(
+        "/tank/home/snapshot/daily"
+    )));
+    assert!(!Mountpoint::is_zfs_snapshot_path(Path::new("/")));
+}
+
+#[test]
+fn test_zfs_snapshot_directory_is_a_volume() {
+    let temp_dir = TempDir::new().unwrap();
+    let snapshot = temp_dir.path().join(".zfs/snapshot/weekly");
+    fs::create_dir_all(&snapshot).unwrap();
+
+    assert_eq!(Mountpoint::volume(&snapshot), Some(Volume::ZfsSnapshot));
+}
+
+#[test]
+fn test_plain_directory_is_not_a_volume() {
+    let temp_dir = TempDir::new().unwrap();
+    let plain = temp_dir.path().join("plain");
+    fs::create_dir(&plain).unwrap();
+
+    assert_eq!(Mountpoint::volume(&plain), None);
+    assert_eq!(Mountpoint::volume(&temp_dir.path().join("missing")), None);
+}
+
+#[test]
+fn test_volume_labels() {
+    assert_eq!(Volume::BtrfsSubvolume.label(), "btrfs subvol");
+    assert_eq!(Volume::BtrfsSnapshot.label(), "btrfs snapshot");
+    assert_eq!(Volume::ZfsDataset.label(), "zfs dataset");
+    assert_eq!(Volume::ZfsSnapshot.label(), "zfs snapshot");
+}
@@ -0,0 +1,58 @@
+use cerium::fs::names::NameIssue;
+
+#[test]
+fn test_plain_names_pass() {
+    assert!(NameIssue::check("README.md").is_empty());
+    assert!(NameIssue::check("résumé final.pdf").is_empty());
+    assert!(NameIssue::check("отчёт.txt").is_empty());
+}
+
+#[test]
+fn test_control_characters_flagged() {
+    assert_eq!(
+        NameIssue::check("evil\nname"),
+        vec![NameIssue::ControlCharacter]
+    );
+    assert_eq!(
+        NameIssue::check("bell\u{7}"),
+        vec![NameIssue::ControlCharacter]
+    );
+}
+
+#[test]
+fn test_bidi_override_flagged() {
+    assert_eq!(
+        NameIssue::check("invoice\u{202E}fdp.exe"),
+        vec![NameIssue::BidiOverride]
+    );
+}
+
+#[test]
+fn test_mixed_script_flagged() {
+    // Cyrillic 'а' (U+0430) in an otherwise Latin name
+    assert_eq!(
+        NameIssue::check("p\u{0430}ypal.html"),
+        vec![NameIssue::MixedScript]
+    );
+}
+
+#[test]
+fn test_trailing_space_flagged() {
+    assert_eq!(
+        NameIssue::check("notes.txt "),
+        vec![NameIssue::TrailingSpace]
+    );
+}
+
+#[test]
+fn test_multiple_issues_reported_in_order() {
+    assert_eq!(
+        NameIssue::check("\u{202E}a\u{0430}\t"),
+        vec![
+            NameIssue::ControlCharacter,
+            NameIssue::BidiOverride,
+            NameIssue::MixedScript,
+            NameIssue::TrailingSpace,
+        ]
+    );
+}
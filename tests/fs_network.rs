@@ -0,0 +1,70 @@
+use cerium::fs::network::RemoteLookup;
+use cerium::fs::vfs::{Fs, MockFs};
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+fn mock_mount() -> MockFs {
+    MockFs::new()
+        .dir("/mnt")
+        .file("/mnt/hung", 1, 0)
+        .file("/mnt/fine", 7, 0)
+        .stall("/mnt/hung", Duration::from_secs(5))
+}
+
+#[test]
+fn test_hung_stat_trips_the_breaker_for_its_device() {
+    const DEV: u64 = 0xdead_0001;
+    let filesystem = mock_mount();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let lookup = |path: &'static str| {
+        let filesystem = filesystem.clone();
+        let calls = Arc::clone(&calls);
+        move || {
+            calls.fetch_add(1, Ordering::SeqCst);
+            filesystem.metadata(Path::new(path), false)
+        }
+    };
+
+    let started = Instant::now();
+    let error = RemoteLookup::run(
+        DEV,
+        Path::new("/mnt"),
+        Duration::from_millis(50),
+        lookup("/mnt/hung"),
+    )
+    .unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::TimedOut);
+    assert!(RemoteLookup::is_unresponsive(DEV));
+
+    // Later lookups on the device fail at once, without reaching the filesystem
+    let error = RemoteLookup::run(
+        DEV,
+        Path::new("/mnt"),
+        Duration::from_secs(5),
+        lookup("/mnt/fine"),
+    )
+    .unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::TimedOut);
+    assert!(started.elapsed() < Duration::from_secs(2));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_responsive_device_keeps_answering() {
+    const DEV: u64 = 0xdead_0002;
+    let filesystem = mock_mount();
+
+    for _ in 0..50 {
+        let filesystem = filesystem.clone();
+        let metadata =
+            RemoteLookup::run(DEV, Path::new("/mnt"), Duration::from_secs(5), move || {
+                filesystem.metadata(Path::new("/mnt/fine"), false)
+            })
+            .unwrap();
+        assert_eq!(metadata.size, 7);
+    }
+    assert!(!RemoteLookup::is_unresponsive(DEV));
+}
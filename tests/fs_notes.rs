@@ -0,0 +1,61 @@
+use cerium::display::layout::width::Width;
+use cerium::fs::notes::{NOTES_FILE, Notes};
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_notes_lookup() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(NOTES_FILE),
+        "\"backup.tar\" = \"do not delete\"\n\"count\" = 3\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        Notes::get(&temp_dir.path().join("backup.tar")).as_ref(),
+        "do not delete"
+    );
+    // Non-string values and unknown files have no note
+    assert_eq!(Notes::get(&temp_dir.path().join("count")).as_ref(), "-");
+    assert_eq!(Notes::get(&temp_dir.path().join("other")).as_ref(), "-");
+}
+
+#[test]
+fn test_notes_invalid_file_is_ignored() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join(NOTES_FILE), "not = [valid toml").unwrap();
+
+    assert_eq!(Notes::get(&temp_dir.path().join("not")).as_ref(), "-");
+}
+
+#[test]
+fn test_notes_truncate() {
+    assert_eq!(Notes::truncate("legacy"), "legacy");
+
+    let long = "x".repeat(100);
+    let capped = Notes::truncate(&long);
+    assert_eq!(capped.chars().count(), 40);
+    assert!(capped.ends_with('…'));
+
+    let wide = "注".repeat(30);
+    let capped = Notes::truncate(&wide);
+    assert_eq!(Width::measure_ansi_text(&capped), 39);
+    assert_eq!(capped.chars().count(), 20);
+    assert!(capped.ends_with('…'));
+}
+
+#[test]
+fn test_notes_are_trimmed_and_left_for_escaping() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(NOTES_FILE),
+        "\"a\" = \"  legacy\\u001b[31m \"\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        Notes::get(&temp_dir.path().join("a")).as_ref(),
+        "legacy\u{1b}[31m"
+    );
+}
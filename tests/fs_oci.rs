@@ -0,0 +1,176 @@
+#![cfg(feature = "oci")]
+
+use cerium::cli::args::Args;
+use cerium::fs::dir::DirReader;
+use cerium::fs::oci::Image;
+use cerium::fs::vfs::Fs;
+use clap::Parser;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// A member to write into a test tarball: path, type flag, data (or link target).
+type Member<'a> = (&'a str, u8, &'a [u8]);
+
+/// Writes a numeric header field as NUL-terminated octal.
+fn octal(field: &mut [u8], value: u64) {
+    let text = format!("{:0width$o}\0", value, width = field.len() - 1);
+    field.copy_from_slice(text.as_bytes());
+}
+
+/// Builds an uncompressed ustar archive.
+fn tar(members: &[Member]) -> Vec<u8> {
+    let mut archive = Vec::new();
+
+    for &(path, kind, data) in members {
+        let mut header = [0u8; 512];
+        header[..path.len()].copy_from_slice(path.as_bytes());
+        octal(
+            &mut header[100..108],
+            if kind == b'5' { 0o755 } else { 0o644 },
+        );
+        octal(&mut header[108..116], 0);
+        octal(&mut header[116..124], 0);
+        let size = if kind == b'0' { data.len() } else { 0 };
+        octal(&mut header[124..136], size as u64);
+        octal(&mut header[136..148], 1_700_000_000);
+        header[156] = kind;
+        if kind == b'2' {
+            header[157..157 + data.len()].copy_from_slice(data);
+        }
+        header[257..263].copy_from_slice(b"ustar\0");
+        header[263..265].copy_from_slice(b"00");
+
+        header[148..156].fill(b' ');
+        let checksum: u64 = header.iter().map(|&byte| u64::from(byte)).sum();
+        header[148..156].copy_from_slice(format!("{:06o}\0 ", checksum).as_bytes());
+
+        archive.extend_from_slice(&header);
+        if size > 0 {
+            archive.extend_from_slice(data);
+            archive.resize(archive.len().next_multiple_of(512), 0);
+        }
+    }
+
+    archive.resize(archive.len() + 1024, 0);
+    archive
+}
+
+/// Writes a `docker save` style tarball holding the given layers.
+fn docker_image(dir: &Path, layers: &[Vec<u8>]) -> std::path::PathBuf {
+    let names: Vec<String> = (0..layers.len())
+        .map(|i| format!("{}/layer.tar", i))
+        .collect();
+    let manifest = format!(
+        r#"[{{"Config":"config.json","RepoTags":["test:latest"],"Layers":[{}]}}]"#,
+        names
+            .iter()
+            .map(|name| format!("\"{}\"", name))
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+
+    let mut members: Vec<Member> = names
+        .iter()
+        .zip(layers)
+        .map(|(name, layer)| (name.as_str(), b'0', layer.as_slice()))
+        .collect();
+    members.push(("manifest.json", b'0', manifest.as_bytes()));
+
+    let path = dir.join("image.tar");
+    fs::write(&path, tar(&members)).unwrap();
+    path
+}
+
+fn names(image: &Image, path: &Path) -> Vec<String> {
+    let args = Args::parse_from(["ce", "-a"]);
+    DirReader::from(path.to_path_buf())
+        .list_in(image, &args)
+        .iter()
+        .map(|entry| entry.name().to_string())
+        .collect()
+}
+
+#[test]
+fn test_layers_merge_in_order() {
+    let temp_dir = TempDir::new().unwrap();
+    let base = tar(&[
+        ("etc/", b'5', b""),
+        ("etc/hosts", b'0', b"127.0.0.1"),
+        ("bin/busybox", b'0', b"ELF"),
+    ]);
+    let app = tar(&[
+        ("etc/hosts", b'0', b"10.0.0.1 db"),
+        ("app/main.py", b'0', b"print(1)"),
+    ]);
+    let path = docker_image(temp_dir.path(), &[base, app]);
+
+    let image = Image::open(&path).unwrap();
+
+    assert_eq!(names(&image, &path), vec!["app", "bin", "etc"]);
+    assert_eq!(names(&image, &path.join("bin")), vec!["busybox"]);
+    assert_eq!(
+        image.metadata(&path.join("etc/hosts"), false).unwrap().size,
+        11
+    );
+}
+
+#[test]
+fn test_whiteouts_hide_lower_layers() {
+    let temp_dir = TempDir::new().unwrap();
+    let base = tar(&[
+        ("etc/hosts", b'0', b"x"),
+        ("etc/passwd", b'0', b"root"),
+        ("var/cache/old.bin", b'0', b"1"),
+        ("var/cache/sub/deep.bin", b'0', b"1"),
+    ]);
+    let upper = tar(&[
+        ("etc/.wh.hosts", b'0', b""),
+        ("var/cache/.wh..wh..opq", b'0', b""),
+        ("var/cache/new.bin", b'0', b"2"),
+    ]);
+    let path = docker_image(temp_dir.path(), &[base, upper]);
+
+    let image = Image::open(&path).unwrap();
+
+    assert_eq!(names(&image, &path.join("etc")), vec!["passwd"]);
+    assert_eq!(names(&image, &path.join("var/cache")), vec!["new.bin"]);
+    assert!(
+        image
+            .metadata(&path.join("var/cache/sub/deep.bin"), false)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_symlinks_and_implied_directories() {
+    let temp_dir = TempDir::new().unwrap();
+    let layer = tar(&[
+        ("bin/sh", b'2', b"busybox"),
+        ("usr/lib/libc.so", b'0', b"so"),
+    ]);
+    let path = docker_image(temp_dir.path(), &[layer]);
+
+    let image = Image::open(&path).unwrap();
+
+    let link = image.metadata(&path.join("bin/sh"), false).unwrap();
+    assert_eq!(link.mode & libc::S_IFMT, libc::S_IFLNK);
+    assert!(image.is_dir(&path.join("usr/lib")));
+
+    let args = Args::parse_from(["ce", "-l"]);
+    let tree = image.tree(&args);
+    assert_eq!(tree.entry.name().as_ref(), "image.tar");
+    assert_eq!(
+        tree.children[0].children[0].entry.name().as_ref(),
+        "sh -> busybox"
+    );
+}
+
+#[test]
+fn test_non_image_tarball_is_rejected() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("plain.tar");
+    fs::write(&path, tar(&[("notes.txt", b'0', b"hello")])).unwrap();
+
+    assert!(Image::open(&path).is_err());
+}
@@ -0,0 +1,66 @@
+use cerium::fs::preview::{BINARY, Preview};
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_preview_head_and_tail() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("notes.txt");
+    fs::write(&path, "first\tline\n\nsecond line\n").unwrap();
+
+    assert_eq!(Preview::head(&path, 10).as_ref(), "first line");
+    assert_eq!(Preview::tail(&path, 5).as_ref(), "line");
+    // Asking for more than the file holds previews all of it
+    assert_eq!(
+        Preview::head(&path, 4096).as_ref(),
+        "first line second line"
+    );
+}
+
+#[test]
+fn test_preview_drops_split_characters() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("utf8.txt");
+    fs::write(&path, "café résumé").unwrap();
+
+    // Both cuts fall inside an "é"
+    assert_eq!(Preview::head(&path, 4).as_ref(), "caf");
+    assert_eq!(Preview::tail(&path, 1).as_ref(), "-");
+    assert_eq!(Preview::tail(&path, 6).as_ref(), "sumé");
+}
+
+#[test]
+fn test_preview_binary_and_non_regular() {
+    let temp_dir = TempDir::new().unwrap();
+    let binary = temp_dir.path().join("program");
+    fs::write(&binary, b"\x7fELF\x02\x01\x01\x00").unwrap();
+    let empty = temp_dir.path().join("empty");
+    fs::write(&empty, "").unwrap();
+
+    assert_eq!(Preview::head(&binary, 16).as_ref(), BINARY);
+    assert_eq!(Preview::head(&empty, 16).as_ref(), "-");
+    assert_eq!(Preview::head(temp_dir.path(), 16).as_ref(), "-");
+    assert_eq!(
+        Preview::tail(&temp_dir.path().join("missing"), 16).as_ref(),
+        "-"
+    );
+}
+
+#[test]
+fn test_preview_text_collapses_whitespace() {
+    assert_eq!(
+        Preview::text(b"one\r\n\ttwo  three\n").as_deref(),
+        Some("one two three")
+    );
+    // Control and bidi characters are escaped with the other cell values
+    assert_eq!(
+        Preview::text(b"\x1b[31mred\x1b[0m\r\n").as_deref(),
+        Some("\x1b[31mred\x1b[0m")
+    );
+    assert_eq!(
+        Preview::text("evil\u{202E}txt.exe".as_bytes()).as_deref(),
+        Some("evil\u{202E}txt.exe")
+    );
+    assert_eq!(Preview::text(b" \n\t ").as_deref(), Some("-"));
+    assert_eq!(Preview::text(b"\xff\xfe"), None);
+}
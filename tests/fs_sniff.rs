@@ -0,0 +1,74 @@
+use cerium::fs::sniff::Sniff;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_sniff_binary_signatures() {
+    assert_eq!(
+        Sniff::describe(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"),
+        "PNG image data"
+    );
+    assert_eq!(Sniff::describe(b"%PDF-1.7\n"), "PDF document");
+    assert_eq!(Sniff::describe(b"\x1f\x8b\x08\0"), "gzip compressed data");
+    assert_eq!(Sniff::describe(b"\x7fELF\x02\x01\x01"), "ELF executable");
+    assert_eq!(
+        Sniff::describe(b"\0\0\0\x18ftypmp42"),
+        "ISO Media, MP4 video"
+    );
+}
+
+#[test]
+fn test_sniff_tar_signature_at_offset() {
+    let mut header = vec![0u8; 512];
+    header[..8].copy_from_slice(b"file.txt");
+    header[257..262].copy_from_slice(b"ustar");
+
+    assert_eq!(Sniff::describe(&header), "POSIX tar archive");
+}
+
+#[test]
+fn test_sniff_scripts() {
+    assert_eq!(
+        Sniff::describe(b"#!/usr/bin/env python3\nprint()\n"),
+        "Python script, ASCII text executable"
+    );
+    assert_eq!(
+        Sniff::describe(b"#!/bin/bash\necho hi\n"),
+        "Bourne-Again shell script, ASCII text executable"
+    );
+    assert_eq!(
+        Sniff::describe(b"#!/usr/bin/env -S node --no-warnings\n"),
+        "Node script, ASCII text executable"
+    );
+}
+
+#[test]
+fn test_sniff_text_and_data() {
+    assert_eq!(Sniff::describe(b""), "empty");
+    assert_eq!(Sniff::describe(b"hello\n"), "ASCII text");
+    assert_eq!(
+        Sniff::describe("naïve\n".as_bytes()),
+        "Unicode text, UTF-8 text"
+    );
+    assert_eq!(
+        Sniff::describe(b"<!DOCTYPE html>\n<html>"),
+        "HTML document, ASCII text"
+    );
+    assert_eq!(Sniff::describe(b"\x00\x01\x02\xff"), "data");
+}
+
+#[test]
+fn test_sniff_text_cut_mid_character() {
+    // "é" is two bytes; a read limit landing between them must not make it binary
+    assert_eq!(Sniff::describe(b"caf\xc3"), "Unicode text, UTF-8 text");
+}
+
+#[test]
+fn test_sniff_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("image");
+    fs::write(&path, b"GIF89a\x01\0\x01\0").unwrap();
+
+    assert_eq!(&*Sniff::file(&path), "GIF image data, version 89a");
+    assert_eq!(&*Sniff::file(&temp_dir.path().join("missing")), "");
+}
@@ -0,0 +1,150 @@
+mod common;
+
+use cerium::cli::args::Args;
+use cerium::cli::flags::SortBy;
+use cerium::fs::dir::DirReader;
+use cerium::fs::entry::Entry;
+use cerium::fs::vfs::MockFs;
+use common::default_args;
+use proptest::prelude::*;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+const SORTS: [SortBy; 10] = [
+    SortBy::Name,
+    SortBy::Size,
+    SortBy::Created,
+    SortBy::Changed,
+    SortBy::Accessed,
+    SortBy::Modified,
+    SortBy::Extension,
+    SortBy::Inode,
+    SortBy::Children,
+    SortBy::Version,
+];
+
+/// Generates a directory of uniquely named files (size, mtime) and subdirectories (`None`).
+fn listing() -> impl Strategy<Value = BTreeMap<String, Option<(u64, i64)>>> {
+    prop::collection::btree_map(
+        "[a-zA-Z0-9_][a-zA-Z0-9_.]{0,7}",
+        prop::option::weighted(0.8, (0u64..4, 0i64..4)),
+        0..24,
+    )
+}
+
+fn mock(listing: &BTreeMap<String, Option<(u64, i64)>>) -> MockFs {
+    listing
+        .iter()
+        .fold(MockFs::new().dir("/root"), |filesystem, (name, file)| {
+            let path = format!("/root/{name}");
+            match file {
+                Some((size, mtime)) => filesystem.file(path, *size, *mtime),
+                None => filesystem.dir(path),
+            }
+        })
+}
+
+fn list(filesystem: &MockFs, sort: SortBy, reverse: bool) -> Vec<Entry> {
+    let mut args: Args = default_args();
+    args.all = true;
+    args.long = true;
+    args.sort = vec![sort];
+    args.reverse = reverse;
+    DirReader::from(PathBuf::from("/root")).list_in(filesystem, &args)
+}
+
+/// The value an entry is ordered by: a number for metadata sorts, text otherwise.
+fn key(entry: &Entry, sort: SortBy) -> (i128, String) {
+    let meta = entry.metadata().expect("metadata is loaded with --long");
+    match sort {
+        SortBy::Name | SortBy::Children => (0, entry.name().to_lowercase()),
+        SortBy::Extension => (0, entry.extension().to_lowercase()),
+        SortBy::Size => (meta.size.into(), String::new()),
+        SortBy::Created => (meta.created().into(), String::new()),
+        SortBy::Changed => (meta.ctime.into(), String::new()),
+        SortBy::Accessed => (meta.atime.into(), String::new()),
+        SortBy::Modified => (meta.mtime.into(), String::new()),
+        SortBy::Inode => (meta.ino.into(), String::new()),
+        // Checked with the comparator itself in prop_version_sort_is_ordered
+        SortBy::Version => (0, String::new()),
+        // Keeps readdir order, so there's nothing to check (and it isn't in SORTS)
+        SortBy::None => (0, String::new()),
+    }
+}
+
+fn names(entries: &[Entry]) -> Vec<String> {
+    entries
+        .iter()
+        .map(|entry| entry.name().to_string())
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn prop_sort_is_a_permutation(listing in listing()) {
+        let filesystem = mock(&listing);
+
+        for sort in SORTS {
+            let mut sorted = names(&list(&filesystem, sort, false));
+            sorted.sort();
+            prop_assert_eq!(sorted, listing.keys().cloned().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn prop_sort_is_ordered_and_stable(listing in listing()) {
+        let filesystem = mock(&listing);
+        let input_order: Vec<&String> = listing.keys().collect();
+
+        for sort in SORTS {
+            let entries = list(&filesystem, sort, false);
+
+            // Keys never decrease, and equal keys keep the order entries were read in
+            let ranks: Vec<((i128, String), usize)> = entries
+                .iter()
+                .map(|entry| {
+                    let position = input_order
+                        .iter()
+                        .position(|name| name.as_str() == entry.name().as_ref())
+                        .unwrap();
+                    (key(entry, sort), position)
+                })
+                .collect();
+
+            if matches!(sort, SortBy::Version) {
+                continue;
+            }
+            for pair in ranks.windows(2) {
+                prop_assert!(pair[0] < pair[1], "{:?}: {:?} before {:?}", sort, pair[0], pair[1]);
+            }
+        }
+    }
+
+    #[test]
+    fn prop_reverse_is_exact_mirror(listing in listing()) {
+        let filesystem = mock(&listing);
+
+        for sort in SORTS {
+            let mut forward = names(&list(&filesystem, sort, false));
+            forward.reverse();
+            prop_assert_eq!(forward, names(&list(&filesystem, sort, true)));
+        }
+    }
+
+    #[test]
+    fn prop_version_sort_is_ordered(listing in listing()) {
+        let filesystem = mock(&listing);
+        let entries = names(&list(&filesystem, SortBy::Version, false));
+
+        for pair in entries.windows(2) {
+            prop_assert_eq!(
+                DirReader::compare_versions(&pair[0], &pair[1]),
+                Ordering::Less,
+                "{:?} before {:?}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+}
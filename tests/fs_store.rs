@@ -0,0 +1,132 @@
+mod common;
+
+use cerium::fs::store::{MAX_AGE, MAX_RECORDS, Record, Store};
+use common::stdout;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs;
+use std::os::unix::ffi::OsStringExt;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn record(stored: u64, value: &str) -> Record {
+    Record {
+        size: 42,
+        mtime: 1_700_000_000_123_456_789,
+        stored,
+        value: value.into(),
+    }
+}
+
+#[test]
+fn test_serialise_round_trips_awkward_paths_and_values() {
+    let odd_path = PathBuf::from(OsString::from_vec(b"/tmp/a\tb\nc%d\xff".to_vec()));
+    let mut records = HashMap::new();
+    records.insert(
+        ("magic".to_string(), odd_path),
+        record(10, "ASCII text, 100% \t"),
+    );
+    records.insert(
+        ("size".to_string(), PathBuf::from("/srv")),
+        record(20, "4096"),
+    );
+
+    let content = Store::serialise(&records);
+    assert!(content.starts_with(b"# cerium cache v1\nsize\t"));
+    assert_eq!(Store::parse(&content), records);
+}
+
+#[test]
+fn test_parse_ignores_other_versions_and_malformed_lines() {
+    assert!(Store::parse(b"# cerium cache v0\nsize\t1\t2\t3\t/a\t4\n").is_empty());
+
+    let parsed = Store::parse(
+        b"# cerium cache v1\nsize\tone\t2\t3\t/a\t4\nmagic\t1\t2\t3\t/b%zz\tx\nsize\t1\t2\t3\t/c\t4\n",
+    );
+    assert_eq!(parsed.len(), 1);
+    assert!(parsed.contains_key(&("size".to_string(), PathBuf::from("/c"))));
+}
+
+#[test]
+fn test_prune_drops_expired_then_oldest_records() {
+    let now = MAX_AGE.as_secs() + 1_000;
+    let mut records = HashMap::new();
+    records.insert(
+        ("size".to_string(), PathBuf::from("/old")),
+        record(999, "1"),
+    );
+    for index in 0..=MAX_RECORDS as u64 {
+        let path = PathBuf::from(format!("/{index}"));
+        records.insert(("size".to_string(), path), record(1_000 + index, "1"));
+    }
+
+    Store::prune(&mut records, now);
+
+    assert_eq!(records.len(), MAX_RECORDS);
+    assert!(!records.contains_key(&("size".to_string(), PathBuf::from("/old"))));
+    assert!(!records.contains_key(&("size".to_string(), PathBuf::from("/0"))));
+    assert!(records.contains_key(&("size".to_string(), PathBuf::from("/1"))));
+}
+
+/// Runs `ce --deterministic` on `dir` with its cache in `cache`, returning stdout.
+fn ce(dir: &Path, cache: &Path, args: &[&str]) -> String {
+    stdout(
+        common::ce(&["--deterministic"])
+            .args(args)
+            .arg(dir)
+            .env("XDG_CACHE_HOME", cache),
+    )
+}
+
+#[cfg(feature = "checksum")]
+#[test]
+fn test_persistent_cache_is_written_and_reused_while_the_path_is_unchanged() {
+    const SHA256_HELLO: &str = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+
+    let temp_dir = TempDir::new().unwrap();
+    let (dir, cache) = (temp_dir.path().join("data"), temp_dir.path().join("cache"));
+    fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("a.txt");
+    fs::write(&file, "hello").unwrap();
+
+    ce(&dir, &cache, &["--checksum", "sha256"]);
+    assert!(!cache.join("cerium/values").exists());
+
+    let persistent = ["--checksum", "sha256", "--persistent-cache"];
+    assert!(ce(&dir, &cache, &persistent).contains(SHA256_HELLO));
+    let stored = Store::parse(&fs::read(cache.join("cerium/values")).unwrap());
+    let key = ("sha256".to_string(), file.clone());
+    assert_eq!(&*stored[&key].value, SHA256_HELLO);
+
+    // A planted value is trusted while the file's size and mtime match
+    let mut planted = stored.clone();
+    planted.get_mut(&key).unwrap().value = "planted".into();
+    fs::write(cache.join("cerium/values"), Store::serialise(&planted)).unwrap();
+    assert!(ce(&dir, &cache, &persistent).contains("planted"));
+    assert!(ce(&dir, &cache, &["--checksum", "sha256"]).contains(SHA256_HELLO));
+
+    // ...and recomputed once the file changes
+    fs::write(&file, "hello!").unwrap();
+    assert!(!ce(&dir, &cache, &persistent).contains("planted"));
+}
+
+#[test]
+fn test_persistent_cache_leaves_out_directory_sizes() {
+    let temp_dir = TempDir::new().unwrap();
+    let (dir, cache) = (temp_dir.path().join("data"), temp_dir.path().join("cache"));
+    let deep = dir.join("sub/deep");
+    fs::create_dir_all(&deep).unwrap();
+    fs::write(deep.join("a.txt"), "hello").unwrap();
+
+    let args = ["-sS", "--persistent-cache"];
+    assert!(ce(&dir, &cache, &args).contains("5 sub"));
+
+    // Editing a file deeper down leaves `sub`'s own size and mtime alone
+    fs::write(deep.join("a.txt"), "hello world").unwrap();
+    assert!(ce(&dir, &cache, &args).contains("11 sub"));
+
+    let stored = fs::read(cache.join("cerium/values"))
+        .map(|content| Store::parse(&content))
+        .unwrap_or_default();
+    assert!(stored.keys().all(|(kind, _)| !kind.starts_with("size")));
+}
@@ -0,0 +1,55 @@
+use cerium::fs::trash::Trash;
+use chrono::{Local, TimeZone};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tempfile::TempDir;
+
+#[test]
+fn test_parse_decodes_path_and_deletion_date() {
+    let info = Trash::parse(
+        "[Trash Info]\nPath=/home/user/notes%20old.txt\nDeletionDate=2024-01-01T12:00:00\n",
+        Path::new("/"),
+    );
+
+    let deleted = Local.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    assert_eq!(info.origin, Some(PathBuf::from("/home/user/notes old.txt")));
+    assert_eq!(info.deleted, Some(SystemTime::from(deleted)));
+}
+
+#[test]
+fn test_parse_resolves_relative_paths_and_skips_bad_fields() {
+    let info = Trash::parse(
+        "[Other]\nPath=/ignored\n[Trash Info]\nPath=photos/a%2\nDeletionDate=yesterday\n",
+        Path::new("/mnt/usb"),
+    );
+    assert_eq!(info.origin, None);
+    assert_eq!(info.deleted, None);
+
+    let info = Trash::parse("[Trash Info]\nPath=photos/a.jpg\n", Path::new("/mnt/usb"));
+    assert_eq!(info.origin, Some(PathBuf::from("/mnt/usb/photos/a.jpg")));
+}
+
+#[test]
+fn test_info_reads_the_matching_trashinfo() {
+    let temp_dir = TempDir::new().unwrap();
+    let trash = temp_dir.path().join("Trash");
+    fs::create_dir_all(trash.join("files")).unwrap();
+    fs::create_dir_all(trash.join("info")).unwrap();
+    fs::write(trash.join("files/report.pdf"), "").unwrap();
+    fs::write(trash.join("files/orphan"), "").unwrap();
+    fs::write(
+        trash.join("info/report.pdf.trashinfo"),
+        "[Trash Info]\nPath=/home/user/report.pdf\nDeletionDate=2024-05-06T07:08:09\n",
+    )
+    .unwrap();
+
+    assert!(Trash::is_files_dir(&trash.join("files")));
+    assert!(!Trash::is_files_dir(&trash));
+    assert!(!Trash::is_files_dir(temp_dir.path()));
+
+    let info = Trash::info(&trash.join("files/report.pdf")).unwrap();
+    assert_eq!(info.origin, Some(PathBuf::from("/home/user/report.pdf")));
+    assert!(Trash::info(&trash.join("files/orphan")).is_none());
+    assert!(Trash::info(&trash.join("info/report.pdf.trashinfo")).is_none());
+}
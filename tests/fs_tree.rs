@@ -1,7 +1,9 @@
 mod common;
 
+use cerium::cli::args::Args;
 use cerium::fs::entry::Entry;
 use cerium::fs::tree::{TreeBuilder, TreeNode};
+use clap::Parser;
 use common::default_args;
 use std::fs::{self, File};
 use tempfile::TempDir;
@@ -48,6 +50,23 @@ fn test_tree_nested() {
     assert!(!subdir.unwrap().children.is_empty());
 }
 
+#[test]
+fn test_tree_level_stops_descent() {
+    let temp_dir = setup_test_dir();
+    let builder = TreeBuilder::new(temp_dir.path().to_path_buf());
+    let root = temp_dir.path().to_str().unwrap();
+    let args = Args::parse_from(["ce", root, "--level", "1"]);
+
+    let tree = builder.build(&args);
+
+    let subdir = tree
+        .children
+        .iter()
+        .find(|n| n.entry.name().as_ref() == "subdir")
+        .unwrap();
+    assert!(subdir.children.is_empty());
+}
+
 #[test]
 fn test_tree_node_structure() {
     let temp_dir = setup_test_dir();
@@ -61,3 +80,57 @@ fn test_tree_node_structure() {
     assert_eq!(node.entry.path(), entry.path());
     assert!(node.children.is_empty());
 }
+
+/// Finds a child node by name.
+fn child<'a>(node: &'a TreeNode, name: &str) -> &'a TreeNode {
+    node.children
+        .iter()
+        .find(|n| n.entry.name().as_ref() == name)
+        .unwrap()
+}
+
+#[test]
+fn test_tree_symlinks_not_followed_by_default() {
+    let temp_dir = setup_test_dir();
+    let base = temp_dir.path();
+    std::os::unix::fs::symlink(base.join("subdir"), base.join("link")).unwrap();
+
+    let args = Args::parse_from(["ce", "--tree", base.to_str().unwrap()]);
+    let tree = TreeBuilder::new(base.to_path_buf()).build(&args);
+
+    assert!(child(&tree, "link").children.is_empty());
+}
+
+#[test]
+fn test_tree_dereference_enters_linked_directory() {
+    let temp_dir = setup_test_dir();
+    let outside = TempDir::new().unwrap();
+    File::create(outside.path().join("elsewhere.txt")).unwrap();
+    let base = temp_dir.path();
+    std::os::unix::fs::symlink(outside.path(), base.join("link")).unwrap();
+
+    let args = Args::parse_from(["ce", "--tree", "--dereference", base.to_str().unwrap()]);
+    let tree = TreeBuilder::new(base.to_path_buf()).build(&args);
+
+    let link = child(&tree, "link");
+    assert!(link.entry.is_symlink());
+    assert_eq!(link.children.len(), 1);
+    assert_eq!(link.children[0].entry.name().as_ref(), "elsewhere.txt");
+}
+
+#[test]
+fn test_tree_dereference_stops_at_cycles() {
+    let temp_dir = setup_test_dir();
+    let base = temp_dir.path();
+    // subdir/up -> the root, and subdir/self -> subdir itself
+    std::os::unix::fs::symlink(base, base.join("subdir/up")).unwrap();
+    std::os::unix::fs::symlink(base.join("subdir"), base.join("subdir/self")).unwrap();
+
+    let args = Args::parse_from(["ce", "--tree", "--dereference", base.to_str().unwrap()]);
+    let tree = TreeBuilder::new(base.to_path_buf()).build(&args);
+
+    let subdir = child(&tree, "subdir");
+    assert_eq!(subdir.children.len(), 3);
+    assert!(child(subdir, "up").children.is_empty());
+    assert!(child(subdir, "self").children.is_empty());
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "checksum")]
+
+use cerium::fs::feature::verify::{Manifest, Verdict};
+use std::fs;
+use tempfile::TempDir;
+
+/// `sha256sum` of "a\n".
+const SHA256_A: &str = "87428fc522803d31065e7bce3cf03fe475096631e5e07bbd7a0fde60c4cf25c7";
+
+/// `md5sum` of "b\n".
+const MD5_B: &str = "3b5d5c3712955042212316173ccf37be";
+
+#[test]
+fn test_verify_against_manifest() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    fs::write(root.join("a.tar"), "a\n").unwrap();
+    fs::write(root.join("b.tar"), "tampered\n").unwrap();
+    fs::write(root.join("extra"), "").unwrap();
+
+    // Text and binary mode lines, mixed algorithms, and lines to skip
+    let contents = format!(
+        "# release digests\n{SHA256_A}  a.tar\n{MD5_B} *b.tar\nnot a digest  c.tar\n\n{SHA256_A}  gone.tar\n"
+    );
+    let manifest = Manifest::parse(&contents, root);
+    assert_eq!(manifest.len(), 3);
+
+    assert_eq!(manifest.verify(&root.join("a.tar")), Some(Verdict::Ok));
+    assert_eq!(manifest.verify(&root.join("b.tar")), Some(Verdict::Failed));
+    assert_eq!(manifest.verify(&root.join("extra")), None);
+    assert_eq!(manifest.missing(), ["gone.tar"]);
+}
+
+#[test]
+fn test_load_resolves_names_beside_manifest() {
+    let temp = TempDir::new().unwrap();
+    let dist = temp.path().join("dist");
+    fs::create_dir(&dist).unwrap();
+    fs::write(dist.join("a.tar"), "a\n").unwrap();
+    fs::write(dist.join("SHA256SUMS"), format!("{SHA256_A}  a.tar\n")).unwrap();
+
+    let manifest = Manifest::load(&dist.join("SHA256SUMS")).unwrap();
+    assert_eq!(manifest.verify(&dist.join("./a.tar")), Some(Verdict::Ok));
+    assert!(manifest.missing().is_empty());
+    assert!(Manifest::load(&dist.join("MISSING")).is_err());
+}
@@ -0,0 +1,214 @@
+mod common;
+
+use cerium::cli::flags::{SortBy, SortKey};
+use cerium::fs::dir::DirReader;
+use cerium::fs::tree::TreeBuilder;
+use cerium::fs::vfs::{Fs, MockFs};
+use common::default_args;
+use std::path::PathBuf;
+
+fn mock_tree() -> MockFs {
+    MockFs::new()
+        .dir("/root")
+        .file("/root/b.txt", 300, 0)
+        .file("/root/a.txt", 10, 0)
+        .file("/root/.hidden", 5, 0)
+        .file("/root/empty.txt", 0, 0)
+        .dir("/root/empty")
+        .dir("/root/full")
+        .file("/root/full/one", 1, 0)
+        .file("/root/full/two", 2, 0)
+}
+
+fn names(filesystem: &MockFs, args: &cerium::cli::args::Args) -> Vec<String> {
+    DirReader::from(PathBuf::from("/root"))
+        .list_in(filesystem, args)
+        .iter()
+        .map(|entry| entry.name().to_string())
+        .collect()
+}
+
+#[test]
+fn test_mock_read_dir_lists_direct_children_only() {
+    let children = mock_tree().read_dir("/root/full".as_ref()).unwrap();
+
+    assert_eq!(children.len(), 2);
+    assert!(mock_tree().read_dir("/missing".as_ref()).is_err());
+}
+
+#[test]
+fn test_list_in_hides_dotfiles_and_sorts_by_name() {
+    let args = default_args();
+
+    assert_eq!(
+        names(&mock_tree(), &args),
+        ["a.txt", "b.txt", "empty", "empty.txt", "full"]
+    );
+}
+
+#[test]
+fn test_list_in_prunes_empty_entries() {
+    let mut args = default_args();
+    args.prune = true;
+
+    assert_eq!(names(&mock_tree(), &args), ["a.txt", "b.txt", "full"]);
+}
+
+#[test]
+fn test_list_in_sorts_by_size_and_children() {
+    let mut args = default_args();
+    args.files = true;
+    args.sort = vec![SortBy::Size];
+    assert_eq!(names(&mock_tree(), &args), ["empty.txt", "a.txt", "b.txt"]);
+
+    let mut args = default_args();
+    args.dirs = true;
+    args.sort = vec![SortBy::Children];
+    assert_eq!(names(&mock_tree(), &args), ["empty", "full"]);
+}
+
+#[test]
+fn test_list_in_large_directory_keeps_sort_order_and_metadata() {
+    // Enough entries to take the parallel path
+    let filesystem = (0..2_000u64).fold(MockFs::new().dir("/root"), |filesystem, index| {
+        filesystem.file(format!("/root/{index:05}"), (index * 7919) % 2_000, 0)
+    });
+
+    let mut args = default_args();
+    args.size = true;
+    let entries = DirReader::from(PathBuf::from("/root")).list_in(&filesystem, &args);
+    assert_eq!(entries.len(), 2_000);
+    assert!(
+        entries
+            .windows(2)
+            .all(|pair| pair[0].name() < pair[1].name())
+    );
+    assert!(entries.iter().all(|entry| entry.metadata().is_some()));
+
+    args.sort = vec![SortBy::Size];
+    let sizes: Vec<u64> = DirReader::from(PathBuf::from("/root"))
+        .list_in(&filesystem, &args)
+        .iter()
+        .map(|entry| entry.metadata().unwrap().size)
+        .collect();
+    assert!(sizes.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn test_list_in_filters_files_by_size() {
+    let mut args = default_args();
+    args.size_filter = Some("+10".parse().unwrap());
+    assert_eq!(names(&mock_tree(), &args), ["b.txt", "empty", "full"]);
+
+    args.size_filter = Some("-10".parse().unwrap());
+    assert_eq!(names(&mock_tree(), &args), ["empty", "empty.txt", "full"]);
+}
+
+#[test]
+fn test_list_in_filters_files_by_modification_time() {
+    let filesystem = MockFs::new()
+        .dir("/root")
+        .file("/root/old.txt", 1, 946_684_800) // 2000-01-01
+        .file("/root/new.txt", 1, 1_735_689_600) // 2025-01-01
+        .dir("/root/dir");
+
+    let mut args = default_args();
+    args.newer = Some("2010-06-15".parse().unwrap());
+    assert_eq!(names(&filesystem, &args), ["dir", "new.txt"]);
+
+    args.newer = None;
+    args.older = Some("2010-06-15".parse().unwrap());
+    assert_eq!(names(&filesystem, &args), ["dir", "old.txt"]);
+}
+
+#[test]
+fn test_list_in_groups_directories_first_or_last() {
+    let mut args = default_args();
+    args.group_directories_first = true;
+
+    assert_eq!(
+        names(&mock_tree(), &args),
+        ["empty", "full", "a.txt", "b.txt", "empty.txt"]
+    );
+
+    args.reverse = true;
+    assert_eq!(
+        names(&mock_tree(), &args),
+        ["full", "empty", "empty.txt", "b.txt", "a.txt"]
+    );
+
+    args.reverse = false;
+    args.group_directories_first = false;
+    args.group_directories_last = true;
+    assert_eq!(
+        names(&mock_tree(), &args),
+        ["a.txt", "b.txt", "empty.txt", "empty", "full"]
+    );
+}
+
+#[test]
+fn test_list_in_sorts_by_version() {
+    let filesystem = ["file10", "file2", "File1", "v1.10", "v1.9", "file02"]
+        .iter()
+        .fold(MockFs::new().dir("/root"), |filesystem, name| {
+            filesystem.file(format!("/root/{name}"), 1, 0)
+        });
+    let mut args = default_args();
+    args.sort = vec![SortBy::Version];
+
+    assert_eq!(
+        names(&filesystem, &args),
+        ["File1", "file02", "file2", "file10", "v1.9", "v1.10"]
+    );
+}
+
+#[test]
+fn test_list_in_sort_key_orders_case_only_differences() {
+    let filesystem = ["readme", "b", "README", "Readme", "A"]
+        .iter()
+        .fold(MockFs::new().dir("/root"), |filesystem, name| {
+            filesystem.file(format!("/root/{name}"), 1, 0)
+        });
+    let mut args = default_args();
+
+    // Case-insensitive, with names differing only in case in a fixed order
+    assert_eq!(
+        names(&filesystem, &args),
+        ["A", "b", "README", "Readme", "readme"]
+    );
+
+    args.sort_key = SortKey::Raw;
+    assert_eq!(
+        names(&filesystem, &args),
+        ["A", "README", "Readme", "b", "readme"]
+    );
+
+    args.sort = vec![SortBy::Version];
+    assert_eq!(
+        names(&filesystem, &args),
+        ["A", "README", "Readme", "b", "readme"]
+    );
+}
+
+#[test]
+fn test_one_file_system_keeps_mount_points_as_leaves() {
+    let filesystem = mock_tree()
+        .mount("/root/mnt", 7)
+        .file("/root/mnt/remote.txt", 1, 0);
+    let mut args = default_args();
+    args.tree = true;
+
+    let children = |args: &cerium::cli::args::Args| {
+        let tree = TreeBuilder::new(PathBuf::from("/root")).build_in(&filesystem, args);
+        let mnt = tree
+            .children
+            .into_iter()
+            .find(|node| node.entry.name().as_ref() == "mnt")
+            .unwrap();
+        mnt.children.len()
+    };
+
+    assert_eq!(children(&args), 1);
+    args.one_file_system = true;
+    assert_eq!(children(&args), 0);
+}
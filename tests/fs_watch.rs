@@ -0,0 +1,45 @@
+use cerium::fs::watch::Watch;
+use std::fs;
+use std::thread;
+use std::time::Duration;
+use tempfile::TempDir;
+
+#[test]
+fn test_wait_reports_new_entry() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().to_path_buf();
+    let mut watch = Watch::new(std::slice::from_ref(&root)).unwrap();
+
+    let writer = {
+        let root = root.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            fs::write(root.join("new.txt"), "one").unwrap();
+            fs::write(root.join("new.txt"), "two").unwrap();
+        })
+    };
+
+    let changed = watch.wait().unwrap();
+    writer.join().unwrap();
+
+    // inotify names the entry; kqueue only says the directory changed
+    assert!(changed.contains(&root.join("new.txt")) || changed.contains(&root));
+    // The writes settle into one report
+    assert_eq!(changed.len(), 1);
+}
+
+#[test]
+fn test_missing_path_cannot_be_watched() {
+    let temp = TempDir::new().unwrap();
+    assert!(Watch::new(&[temp.path().join("missing")]).is_err());
+}
+
+#[test]
+fn test_wait_or_returns_when_ready_without_changes() {
+    let temp = TempDir::new().unwrap();
+    let mut watch = Watch::new(&[temp.path().to_path_buf()]).unwrap();
+
+    let changed = watch.wait_or(|| true).unwrap();
+
+    assert!(changed.is_empty());
+}